@@ -201,6 +201,12 @@ pub enum ProcMacroExpansionError {
     Panic(String),
     /// Things like "proc macro server was killed by OOM".
     System(String),
+    /// The macro produced an expansion, but reported errors or warnings with
+    /// `proc_macro::Diagnostic`.
+    Reported {
+        value: Subtree,
+        message: String,
+    },
 }
 
 #[derive(Debug, Clone)]
@@ -6,7 +6,10 @@ pub mod fixture;
 use std::{panic, sync::Arc};
 
 use rustc_hash::FxHashSet;
-use syntax::{ast, Parse, SourceFile, TextRange, TextSize};
+use syntax::{
+    ast::{self, HasAttrs},
+    AstNode, Parse, ParserFeatures, SourceFile, SyntaxKind, TextRange, TextSize,
+};
 
 pub use crate::{
     change::Change,
@@ -69,12 +72,44 @@ pub trait SourceDatabase: FileLoader + std::fmt::Debug {
     /// The crate graph.
     #[salsa::input]
     fn crate_graph(&self) -> Arc<CrateGraph>;
+
+    /// The unstable syntax enabled with `#![feature]` attributes in the root of the crate.
+    fn crate_parser_features(&self, krate: CrateId) -> ParserFeatures;
 }
 
 fn parse_query(db: &dyn SourceDatabase, file_id: FileId) -> Parse<ast::SourceFile> {
     let _p = profile::span("parse_query").detail(|| format!("{:?}", file_id));
     let text = db.file_text(file_id);
-    SourceFile::parse(&*text)
+    // A file can be part of several crates, accept syntax which is enabled in any of them.
+    let crates = db.relevant_crates(file_id);
+    let features = if crates.is_empty() {
+        ParserFeatures::ALL
+    } else {
+        crates
+            .iter()
+            .fold(ParserFeatures::EMPTY, |acc, &krate| acc.union(db.crate_parser_features(krate)))
+    };
+    SourceFile::parse_with_features(&text, features)
+}
+
+fn crate_parser_features(db: &dyn SourceDatabase, krate: CrateId) -> ParserFeatures {
+    let root_file = db.crate_graph()[krate].root_file_id;
+    // Attributes parse the same regardless of the enabled features, and going through
+    // `db.parse` would be a cycle for the root file.
+    let root = SourceFile::parse(&db.file_text(root_file)).tree();
+    root.attrs()
+        .filter(|attr| attr.kind() == ast::AttrKind::Inner)
+        .filter_map(|attr| attr.as_simple_call())
+        .filter(|(name, _)| name == "feature")
+        .flat_map(|(_, tt)| {
+            tt.syntax()
+                .children_with_tokens()
+                .filter_map(|it| it.into_token())
+                .filter(|it| it.kind() == SyntaxKind::IDENT)
+                .filter_map(|it| ParserFeatures::from_name(it.text()))
+                .collect::<Vec<_>>()
+        })
+        .fold(ParserFeatures::EMPTY, ParserFeatures::union)
 }
 
 /// We don't want to give HIR knowledge of source roots, hence we extract these
@@ -42,6 +42,28 @@ impl fmt::Display for FlycheckConfig {
     }
 }
 
+/// What a flycheck run covers.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum FlycheckKind {
+    /// All packages of the workspace.
+    Workspace,
+    /// Only the package with the given name, as passed to `--package`.
+    ///
+    /// Custom commands always run as configured.
+    SingleCrate { package: String },
+}
+
+impl FlycheckKind {
+    /// Combines pending restarts into one covering both of them.
+    fn merge(self, other: FlycheckKind) -> FlycheckKind {
+        if self == other {
+            self
+        } else {
+            FlycheckKind::Workspace
+        }
+    }
+}
+
 /// Flycheck wraps the shared state and communication machinery used for
 /// running `cargo check` (or other compatible command) and providing
 /// diagnostics based on the output.
@@ -50,6 +72,7 @@ impl fmt::Display for FlycheckConfig {
 pub struct FlycheckHandle {
     // XXX: drop order is significant
     sender: Sender<Restart>,
+    id: usize,
     _thread: jod_thread::JoinHandle,
 }
 
@@ -66,12 +89,22 @@ impl FlycheckHandle {
             .name("Flycheck".to_owned())
             .spawn(move || actor.run(receiver))
             .expect("failed to spawn thread");
-        FlycheckHandle { sender, _thread: thread }
+        FlycheckHandle { sender, id, _thread: thread }
+    }
+
+    /// The id the worker was spawned with.
+    pub fn id(&self) -> usize {
+        self.id
     }
 
     /// Schedule a re-start of the cargo check worker.
     pub fn update(&self) {
-        self.sender.send(Restart).unwrap();
+        self.restart(FlycheckKind::Workspace);
+    }
+
+    /// Schedule a re-start of the cargo check worker, checking only what `kind` covers.
+    pub fn restart(&self, kind: FlycheckKind) {
+        self.sender.send(Restart(kind)).unwrap();
     }
 }
 
@@ -110,13 +143,15 @@ pub enum Progress {
     DidCancel,
 }
 
-struct Restart;
+struct Restart(FlycheckKind);
 
 struct FlycheckActor {
     id: usize,
     sender: Box<dyn Fn(Message) + Send>,
     config: FlycheckConfig,
     workspace_root: AbsPathBuf,
+    /// What the last restart asked to check.
+    kind: FlycheckKind,
     /// WatchThread exists to wrap around the communication needed to be able to
     /// run `cargo check` without blocking. Currently the Rust standard library
     /// doesn't provide a way to read sub-process output without blocking, so we
@@ -137,7 +172,14 @@ impl FlycheckActor {
         config: FlycheckConfig,
         workspace_root: AbsPathBuf,
     ) -> FlycheckActor {
-        FlycheckActor { id, sender, config, workspace_root, cargo_handle: None }
+        FlycheckActor {
+            id,
+            sender,
+            config,
+            workspace_root,
+            kind: FlycheckKind::Workspace,
+            cargo_handle: None,
+        }
     }
     fn progress(&self, progress: Progress) {
         self.send(Message::Progress { id: self.id, progress });
@@ -152,8 +194,11 @@ impl FlycheckActor {
     fn run(mut self, inbox: Receiver<Restart>) {
         while let Some(event) = self.next_event(&inbox) {
             match event {
-                Event::Restart(Restart) => {
-                    while let Ok(Restart) = inbox.recv_timeout(Duration::from_millis(50)) {}
+                Event::Restart(Restart(mut kind)) => {
+                    while let Ok(Restart(next)) = inbox.recv_timeout(Duration::from_millis(50)) {
+                        kind = kind.merge(next);
+                    }
+                    self.kind = kind;
 
                     self.cancel_check_process();
 
@@ -211,7 +256,13 @@ impl FlycheckActor {
                 let mut cmd = Command::new(toolchain::cargo());
                 cmd.arg(command);
                 cmd.current_dir(&self.workspace_root);
-                cmd.args(&["--workspace", "--message-format=json", "--manifest-path"])
+                match &self.kind {
+                    FlycheckKind::Workspace => cmd.arg("--workspace"),
+                    FlycheckKind::SingleCrate { package } => {
+                        cmd.args(["--package", package.as_str()])
+                    }
+                };
+                cmd.args(["--message-format=json", "--manifest-path"])
                     .arg(self.workspace_root.join("Cargo.toml").as_os_str());
 
                 if let Some(target) = target_triple {
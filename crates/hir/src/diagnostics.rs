@@ -33,6 +33,7 @@ diagnostics![
     InactiveCode,
     IncorrectCase,
     InvalidDeriveTarget,
+    IrrefutableLetPattern,
     MacroError,
     MalformedDerive,
     MismatchedArgCount,
@@ -167,10 +168,15 @@ pub struct MissingMatchArms {
     pub arms: AstPtr<ast::MatchArmList>,
 }
 
+#[derive(Debug)]
+pub struct IrrefutableLetPattern {
+    pub if_expr: InFile<AstPtr<ast::IfExpr>>,
+}
+
 #[derive(Debug)]
 pub struct AddReferenceHere {
     pub expr: InFile<AstPtr<ast::Expr>>,
     pub mutability: Mutability,
 }
 
-pub use hir_ty::diagnostics::IncorrectCase;
+pub use hir_ty::diagnostics::{CaseType, IncorrectCase};
@@ -40,12 +40,15 @@ use hir_def::{
     adt::{ReprKind, VariantData},
     body::{BodyDiagnostic, SyntheticSyntax},
     expr::{BindingAnnotation, LabelId, Pat, PatId},
+    generics::TypeParamProvenance,
     lang_item::LangItemTarget,
     nameres,
+    path::ImportAlias,
     per_ns::PerNs,
     resolver::{HasResolver, Resolver},
-    AttrDefId, ConstId, ConstParamId, EnumId, FunctionId, GenericDefId, HasModule, LifetimeParamId,
-    LocalEnumVariantId, LocalFieldId, StaticId, StructId, TypeAliasId, TypeParamId, UnionId,
+    AttrDefId, ConstId, ConstParamId, EnumId, ExternCrateId, FunctionId, GenericDefId, HasModule,
+    LifetimeParamId, LocalEnumVariantId, LocalFieldId, StaticId, StructId, TypeAliasId,
+    TypeParamId, UnionId,
 };
 use hir_expand::{name::name, MacroCallKind, MacroDefKind};
 use hir_ty::{
@@ -79,11 +82,11 @@ pub use crate::{
     attrs::{HasAttrs, Namespace},
     diagnostics::{
         AddReferenceHere, AnyDiagnostic, BreakOutsideOfLoop, InactiveCode, IncorrectCase,
-        InvalidDeriveTarget, MacroError, MalformedDerive, MismatchedArgCount, MissingFields,
-        MissingMatchArms, MissingOkOrSomeInTailExpr, MissingUnsafe, NoSuchField,
-        RemoveThisSemicolon, ReplaceFilterMapNextWithFindMap, UnimplementedBuiltinMacro,
-        UnresolvedExternCrate, UnresolvedImport, UnresolvedMacroCall, UnresolvedModule,
-        UnresolvedProcMacro,
+        InvalidDeriveTarget, IrrefutableLetPattern, MacroError, MalformedDerive,
+        MismatchedArgCount, MissingFields, MissingMatchArms, MissingOkOrSomeInTailExpr,
+        MissingUnsafe, NoSuchField, RemoveThisSemicolon, ReplaceFilterMapNextWithFindMap,
+        UnimplementedBuiltinMacro, UnresolvedExternCrate, UnresolvedImport, UnresolvedMacroCall,
+        UnresolvedModule, UnresolvedProcMacro,
     },
     has_source::HasSource,
     semantics::{PathResolution, Semantics, SemanticsScope, TypeInfo},
@@ -102,7 +105,7 @@ pub use {
     cfg::{CfgAtom, CfgExpr, CfgOptions},
     hir_def::{
         adt::StructKind,
-        attr::{Attr, Attrs, AttrsWithOwner, Documentation},
+        attr::{cfg_of_source, Attr, Attrs, AttrsWithOwner, Documentation},
         builtin_attr::AttributeTemplate,
         find_path::PrefixKind,
         import_map,
@@ -742,6 +745,11 @@ impl Module {
         def_map[self.id.local_id].scope.impls().map(Impl::from).collect()
     }
 
+    pub fn extern_crate_decls(self, db: &dyn HirDatabase) -> Vec<ExternCrateDecl> {
+        let def_map = self.id.def_map(db.upcast());
+        def_map[self.id.local_id].scope.extern_crate_decls().map(ExternCrateDecl::from).collect()
+    }
+
     /// Finds a path that can be used to refer to the given item from within
     /// this module, if possible.
     pub fn find_use_path(self, db: &dyn DefDatabase, item: impl Into<ItemInNs>) -> Option<ModPath> {
@@ -849,6 +857,10 @@ impl Struct {
         self.variant_data(db).kind()
     }
 
+    pub fn is_non_exhaustive(self, db: &dyn HirDatabase) -> bool {
+        db.attrs(self.id.into()).by_key("non_exhaustive").exists()
+    }
+
     fn variant_data(self, db: &dyn HirDatabase) -> Arc<VariantData> {
         db.struct_data(self.id).variant_data.clone()
     }
@@ -919,6 +931,10 @@ impl Enum {
     pub fn ty(self, db: &dyn HirDatabase) -> Type {
         Type::from_def(db, self.id.lookup(db.upcast()).container.krate(), self.id)
     }
+
+    pub fn is_non_exhaustive(self, db: &dyn HirDatabase) -> bool {
+        db.attrs(self.id.into()).by_key("non_exhaustive").exists()
+    }
 }
 
 impl HasVisibility for Enum {
@@ -1008,6 +1024,15 @@ impl Adt {
             Adt::Enum(e) => e.name(db),
         }
     }
+
+    /// Whether the ADT is marked `#[non_exhaustive]`. Unions can't be.
+    pub fn is_non_exhaustive(self, db: &dyn HirDatabase) -> bool {
+        match self {
+            Adt::Struct(s) => s.is_non_exhaustive(db),
+            Adt::Union(_) => false,
+            Adt::Enum(e) => e.is_non_exhaustive(db),
+        }
+    }
 }
 
 impl HasVisibility for Adt {
@@ -1289,6 +1314,19 @@ impl DefWithBody {
                         Err(SyntheticSyntax) => (),
                     }
                 }
+                BodyValidationDiagnostic::IrrefutableLetPattern { if_expr } => {
+                    if let Ok(source_ptr) = source_map.expr_syntax(if_expr) {
+                        let root = source_ptr.file_syntax(db.upcast());
+                        if let ast::Expr::IfExpr(if_expr) = &source_ptr.value.to_node(&root) {
+                            acc.push(
+                                IrrefutableLetPattern {
+                                    if_expr: InFile::new(source_ptr.file_id, AstPtr::new(if_expr)),
+                                }
+                                .into(),
+                            )
+                        }
+                    }
+                }
             }
         }
 
@@ -1392,6 +1430,25 @@ impl Function {
     }
 }
 
+/// How a binding pattern binds its value, after taking match ergonomics into account.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BindingMode {
+    Move,
+    Ref(Mutability),
+}
+
+impl From<hir_ty::BindingMode> for BindingMode {
+    fn from(mode: hir_ty::BindingMode) -> BindingMode {
+        match mode {
+            hir_ty::BindingMode::Move => BindingMode::Move,
+            hir_ty::BindingMode::Ref(hir_ty::Mutability::Not) => {
+                BindingMode::Ref(Mutability::Shared)
+            }
+            hir_ty::BindingMode::Ref(hir_ty::Mutability::Mut) => BindingMode::Ref(Mutability::Mut),
+        }
+    }
+}
+
 // Note: logically, this belongs to `hir_ty`, but we are not using it there yet.
 pub enum Access {
     Shared,
@@ -1435,6 +1492,14 @@ impl Param {
         self.source(db).and_then(|p| p.value.pat())
     }
 
+    /// The name of the parameter, or its pattern if it is destructured, like `(x, y): (i32, i32)`.
+    pub fn name_or_pattern(&self, db: &dyn HirDatabase) -> Option<Either<Name, ast::Pat>> {
+        match self.name(db) {
+            Some(name) => Some(Either::Left(name)),
+            None => self.pattern_source(db).map(Either::Right),
+        }
+    }
+
     pub fn source(&self, db: &dyn HirDatabase) -> Option<InFile<ast::Param>> {
         let InFile { file_id, value } = self.func.source(db)?;
         let params = value.param_list()?;
@@ -1622,6 +1687,15 @@ impl Trait {
     pub fn is_unsafe(&self, db: &dyn HirDatabase) -> bool {
         db.trait_data(self.id).is_unsafe
     }
+
+    /// The supertraits written in the declaration of the trait, like `Copy` in
+    /// `trait Marker: Copy {}`.
+    pub fn direct_supertraits(self, db: &dyn HirDatabase) -> Vec<Trait> {
+        hir_ty::direct_super_traits(db.upcast(), self.id)
+            .into_iter()
+            .map(|id| Trait { id })
+            .collect()
+    }
 }
 
 impl HasVisibility for Trait {
@@ -2186,6 +2260,17 @@ impl TypeParam {
             .collect()
     }
 
+    /// Is this type parameter implicitly introduced, e.g. by `impl Trait` in argument position?
+    pub fn is_implicit(self, db: &dyn HirDatabase) -> bool {
+        let params = db.generic_params(self.id.parent);
+        params.types[self.id.local_id].provenance != TypeParamProvenance::TypeParamList
+    }
+
+    pub fn has_default(self, db: &dyn HirDatabase) -> bool {
+        let params = db.generic_params(self.id.parent);
+        params.types[self.id.local_id].default.is_some()
+    }
+
     pub fn default(self, db: &dyn HirDatabase) -> Option<Type> {
         let params = db.generic_defaults(self.id.parent);
         let local_idx = hir_ty::param_idx(db, self.id)?;
@@ -2244,6 +2329,44 @@ impl ConstParam {
     }
 }
 
+/// An `extern crate` item.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ExternCrateDecl {
+    pub(crate) id: ExternCrateId,
+}
+
+impl ExternCrateDecl {
+    pub fn module(self, db: &dyn HirDatabase) -> Module {
+        self.id.lookup(db.upcast()).container.into()
+    }
+
+    /// The name of the crate as written, like `std` in `extern crate std as stdlib;`.
+    pub fn name(self, db: &dyn HirDatabase) -> Name {
+        let loc = self.id.lookup(db.upcast());
+        loc.id.item_tree(db.upcast())[loc.id.value].name.clone()
+    }
+
+    /// The name given with `as`, like `stdlib` in `extern crate std as stdlib;`. `as _` doesn't
+    /// give the crate a name, so it is `None` as well.
+    pub fn rename(self, db: &dyn HirDatabase) -> Option<Name> {
+        let loc = self.id.lookup(db.upcast());
+        match &loc.id.item_tree(db.upcast())[loc.id.value].alias {
+            Some(ImportAlias::Alias(name)) => Some(name.clone()),
+            Some(ImportAlias::Underscore) | None => None,
+        }
+    }
+
+    /// The crate this item refers to, if it is a dependency of the current crate.
+    pub fn krate(self, db: &dyn HirDatabase) -> Option<Crate> {
+        let krate = self.module(db).krate();
+        let name = self.name(db);
+        if name == known::SELF_PARAM {
+            return Some(krate);
+        }
+        krate.dependencies(db).into_iter().find(|dep| dep.name == name).map(|dep| dep.krate)
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct Impl {
     pub(crate) id: ImplId,
@@ -2426,6 +2549,12 @@ impl Type {
         matches!(self.ty.kind(Interner), TyKind::Never)
     }
 
+    /// Whether a value of this type can exist. `!`, empty enums and structs containing such types
+    /// are uninhabited.
+    pub fn is_inhabited(&self, db: &dyn HirDatabase) -> bool {
+        !hir_ty::is_ty_uninhabited(db, &self.ty)
+    }
+
     pub fn is_mutable_reference(&self) -> bool {
         matches!(self.ty.kind(Interner), TyKind::Ref(hir_ty::Mutability::Mut, ..))
     }
@@ -2501,6 +2630,11 @@ impl Type {
         )
     }
 
+    /// Checks whether this type implements `trait_`, with `args` as the trait's own generic
+    /// arguments.
+    ///
+    /// The solution is memoized by the `trait_solve_query` query, so calling this repeatedly
+    /// with the same arguments within one revision doesn't invoke the trait solver again.
     pub fn impls_trait(&self, db: &dyn HirDatabase, trait_: Trait, args: &[Type]) -> bool {
         let trait_ref = TyBuilder::trait_ref(db, trait_.id)
             .push(self.ty.clone())
@@ -2628,6 +2762,9 @@ impl Type {
         }
     }
 
+    /// Returns the fields of a struct or union type, named or positional, together with their
+    /// types with the type's generic arguments substituted. Returns no fields for enums and
+    /// non-ADT types.
     pub fn fields(&self, db: &dyn HirDatabase) -> Vec<(Field, Type)> {
         let (variant_id, substs) = match self.ty.kind(Interner) {
             TyKind::Adt(hir_ty::AdtId(AdtId::StructId(s)), substs) => ((*s).into(), substs),
@@ -3051,6 +3188,33 @@ impl Callable {
     pub fn return_type(&self) -> Type {
         self.ty.derived(self.sig.ret().clone())
     }
+    /// The inferred arguments for the type parameters declared on the called function itself,
+    /// i.e. the ones a turbofish would specify.
+    ///
+    /// Returns `None` if this is not a function call, or if the function has `impl Trait`
+    /// parameters and so can't be called with a turbofish.
+    pub fn turbofish_args(&self, db: &dyn HirDatabase) -> Option<Vec<Type>> {
+        let func = match self.def {
+            Some(CallableDefId::FunctionId(it)) => it,
+            _ => return None,
+        };
+        let subst = match self.ty.ty.kind(Interner) {
+            TyKind::FnDef(_, subst) => subst,
+            _ => return None,
+        };
+        let params = db.generic_params(func.into());
+        if params.types.iter().any(|(_, it)| it.provenance != TypeParamProvenance::TypeParamList) {
+            return None;
+        }
+        let own_args = subst.len(Interner).checked_sub(params.types.len())?;
+        let args = subst
+            .iter(Interner)
+            .skip(own_args)
+            .filter_map(|arg| arg.ty(Interner))
+            .map(|ty| self.ty.derived(ty.clone()))
+            .collect();
+        Some(args)
+    }
 }
 
 /// For IDE only
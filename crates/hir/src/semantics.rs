@@ -25,9 +25,9 @@ use crate::{
     db::HirDatabase,
     semantics::source_to_def::{ChildContainer, SourceToDefCache, SourceToDefCtx},
     source_analyzer::{resolve_hir_path, resolve_hir_path_as_macro, SourceAnalyzer},
-    Access, AssocItem, BuiltinAttr, Callable, ConstParam, Crate, Field, Function, HasSource,
-    HirFileId, Impl, InFile, Label, LifetimeParam, Local, MacroDef, Module, ModuleDef, Name, Path,
-    ScopeDef, ToolModule, Trait, Type, TypeAlias, TypeParam, VariantDef,
+    Access, AssocItem, BindingMode, BuiltinAttr, Callable, ConstParam, Crate, Field, Function,
+    HasSource, HirFileId, Impl, InFile, Label, LifetimeParam, Local, MacroDef, Module, ModuleDef,
+    Name, Path, ScopeDef, ToolModule, Trait, Type, TypeAlias, TypeParam, VariantDef,
 };
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -245,6 +245,20 @@ impl<'db, DB: HirDatabase> Semantics<'db, DB> {
         self.imp.ancestors_with_macros(node)
     }
 
+    /// Finds the nearest `impl` block containing `node`, climbing up macro expansions.
+    ///
+    /// For a node inside a macro expansion, the result can be in a different file than `node`.
+    pub fn enclosing_impl(&self, node: &SyntaxNode) -> Option<ast::Impl> {
+        self.ancestors_with_macros(node.clone()).find_map(ast::Impl::cast)
+    }
+
+    /// Finds the nearest function containing `node`, climbing up macro expansions.
+    ///
+    /// For a node inside a macro expansion, the result can be in a different file than `node`.
+    pub fn enclosing_fn(&self, node: &SyntaxNode) -> Option<ast::Fn> {
+        self.ancestors_with_macros(node.clone()).find_map(ast::Fn::cast)
+    }
+
     pub fn ancestors_at_offset_with_macros(
         &self,
         node: &SyntaxNode,
@@ -307,6 +321,12 @@ impl<'db, DB: HirDatabase> Semantics<'db, DB> {
         self.imp.type_of_self(param)
     }
 
+    /// Returns how `pat` binds its value, including the `ref` or `ref mut` that match ergonomics
+    /// implicitly add when matching on a reference.
+    pub fn binding_mode_of_pat(&self, pat: &ast::IdentPat) -> Option<BindingMode> {
+        self.imp.binding_mode_of_pat(pat)
+    }
+
     pub fn resolve_method_call(&self, call: &ast::MethodCallExpr) -> Option<Function> {
         self.imp.resolve_method_call(call).map(Function::from)
     }
@@ -315,10 +335,22 @@ impl<'db, DB: HirDatabase> Semantics<'db, DB> {
         self.imp.resolve_method_call_as_callable(call)
     }
 
+    /// Like [`Semantics::resolve_method_call`], but for calls of trait methods returns the method
+    /// of the impl that is called, or the trait's default method if the impl doesn't provide one.
+    /// Returns `None` if that impl isn't known statically, e.g. for trait objects.
+    pub fn resolve_impl_method(&self, call: &ast::MethodCallExpr) -> Option<Function> {
+        self.imp.resolve_impl_method(call).map(Function::from)
+    }
+
     pub fn resolve_field(&self, field: &ast::FieldExpr) -> Option<Field> {
         self.imp.resolve_field(field)
     }
 
+    /// Returns the `Range*` type from `core::ops` that a range expression desugars to.
+    pub fn resolve_range_expr(&self, range_expr: &ast::RangeExpr) -> Option<Type> {
+        self.imp.resolve_range_expr(range_expr)
+    }
+
     pub fn resolve_record_field(
         &self,
         field: &ast::RecordExprField,
@@ -394,6 +426,14 @@ impl<'db, DB: HirDatabase> Semantics<'db, DB> {
         self.imp.assert_contains_node(node)
     }
 
+    /// Returns `true` if the expression itself (not its subexpressions) is an operation that
+    /// requires an `unsafe` block: a call of an unsafe function, a dereference of a raw pointer,
+    /// a read of a union field or an access to a `static mut`. These are the operations the
+    /// missing-unsafe diagnostic reports.
+    pub fn is_unsafe_expr(&self, expr: &ast::Expr) -> bool {
+        self.imp.is_unsafe_expr(expr)
+    }
+
     pub fn is_unsafe_method_call(&self, method_call_expr: &ast::MethodCallExpr) -> bool {
         self.imp.is_unsafe_method_call(method_call_expr)
     }
@@ -829,6 +869,10 @@ impl<'db> SemanticsImpl<'db> {
             .map(|(ty, coerced)| TypeInfo { original: ty, adjusted: coerced })
     }
 
+    fn binding_mode_of_pat(&self, pat: &ast::IdentPat) -> Option<BindingMode> {
+        self.analyze(pat.syntax()).binding_mode_of_pat(self.db, pat)
+    }
+
     fn type_of_self(&self, param: &ast::SelfParam) -> Option<Type> {
         self.analyze(param.syntax()).type_of_self(self.db, param)
     }
@@ -837,6 +881,10 @@ impl<'db> SemanticsImpl<'db> {
         self.analyze(call.syntax()).resolve_method_call(self.db, call).map(|(id, _)| id)
     }
 
+    fn resolve_impl_method(&self, call: &ast::MethodCallExpr) -> Option<FunctionId> {
+        self.analyze(call.syntax()).resolve_impl_method(self.db, call)
+    }
+
     fn resolve_method_call_as_callable(&self, call: &ast::MethodCallExpr) -> Option<Callable> {
         let (func, subst) = self.analyze(call.syntax()).resolve_method_call(self.db, call)?;
         let ty = self.db.value_ty(func.into()).substitute(Interner, &subst);
@@ -851,6 +899,11 @@ impl<'db> SemanticsImpl<'db> {
         self.analyze(field.syntax()).resolve_field(self.db, field)
     }
 
+    fn resolve_range_expr(&self, range_expr: &ast::RangeExpr) -> Option<Type> {
+        let expr = ast::Expr::from(range_expr.clone());
+        self.analyze(range_expr.syntax()).type_of_expr(self.db, &expr).map(|(ty, _)| ty)
+    }
+
     fn resolve_record_field(
         &self,
         field: &ast::RecordExprField,
@@ -1027,6 +1080,10 @@ impl<'db> SemanticsImpl<'db> {
         InFile::new(file_id, node)
     }
 
+    fn is_unsafe_expr(&self, expr: &ast::Expr) -> bool {
+        self.analyze(expr.syntax()).is_unsafe_expr(self.db, expr)
+    }
+
     fn is_unsafe_method_call(&self, method_call_expr: &ast::MethodCallExpr) -> bool {
         method_call_expr
             .receiver()
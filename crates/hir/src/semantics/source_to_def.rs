@@ -86,10 +86,11 @@
 //! syntax nodes against this specific crate.
 
 use base_db::FileId;
+use either::Either;
 use hir_def::{
     child_by_source::ChildBySource,
     dyn_map::DynMap,
-    expr::{LabelId, PatId},
+    expr::{Expr, LabelId, PatId},
     keys::{self, Key},
     AdtId, ConstId, ConstParamId, DefWithBodyId, EnumId, EnumVariantId, FieldId, FunctionId,
     GenericDefId, GenericParamId, ImplId, LifetimeParamId, ModuleId, StaticId, StructId, TraitId,
@@ -134,13 +135,23 @@ impl SourceToDefCtx<'_, '_> {
         let parent_declaration =
             src.syntax().ancestors_with_macros_skip_attr_item(self.db.upcast()).skip(1).find_map(
                 |it| {
-                    let m = ast::Module::cast(it.value.clone())?;
-                    Some(it.with_value(m))
+                    match_ast! {
+                        match (it.value) {
+                            ast::Module(m) => Some(it.with_value(Either::Left(m))),
+                            ast::BlockExpr(block) => Some(it.with_value(Either::Right(block))),
+                            _ => None,
+                        }
+                    }
                 },
             );
 
         let parent_module = match parent_declaration {
-            Some(parent_declaration) => self.module_to_def(parent_declaration),
+            Some(InFile { file_id, value: Either::Left(module) }) => {
+                self.module_to_def(InFile::new(file_id, module))
+            }
+            Some(InFile { file_id, value: Either::Right(block) }) => {
+                self.block_to_def(InFile::new(file_id, block))
+            }
             None => {
                 let file_id = src.file_id.original_file(self.db.upcast());
                 self.file_to_def(file_id).get(0).copied()
@@ -153,6 +164,20 @@ impl SourceToDefCtx<'_, '_> {
         Some(def_map.module_id(child_id))
     }
 
+    /// The root module of the `DefMap` of the items declared in `src`, so that modules declared
+    /// in a block are looked up there rather than in the module containing the block.
+    fn block_to_def(&mut self, src: InFile<ast::BlockExpr>) -> Option<ModuleId> {
+        let container = self.find_pat_or_label_container(src.syntax())?;
+        let (body, source_map) = self.db.body_with_source_map(container);
+        let expr = ast::Expr::from(src.value.clone());
+        let block_id = match body[source_map.node_expr(src.with_value(&expr))?] {
+            Expr::Block { id, .. } => id,
+            _ => return None,
+        };
+        let def_map = self.db.block_def_map(block_id)?;
+        Some(def_map.module_id(def_map.root()))
+    }
+
     pub(super) fn source_file_to_def(&mut self, src: InFile<ast::SourceFile>) -> Option<ModuleId> {
         let _p = profile::span("source_file_to_def");
         let file_id = src.file_id.original_file(self.db.upcast());
@@ -16,12 +16,16 @@ use hir_def::{
     expr::{ExprId, Pat, PatId},
     path::{ModPath, Path, PathKind},
     resolver::{resolver_for_scope, Resolver, TypeNs, ValueNs},
-    AsMacroCall, DefWithBodyId, FieldId, FunctionId, LocalFieldId, ModuleDefId, VariantId,
+    AsMacroCall, DefWithBodyId, FieldId, FunctionId, ItemContainerId, LocalFieldId, Lookup,
+    ModuleDefId, VariantId,
 };
 use hir_expand::{hygiene::Hygiene, name::AsName, HirFileId, InFile};
 use hir_ty::{
-    diagnostics::{record_literal_missing_fields, record_pattern_missing_fields},
-    InferenceResult, Interner, Substitution, TyExt, TyLoweringContext,
+    diagnostics::{
+        record_literal_missing_fields, record_pattern_missing_fields, unsafe_expressions,
+    },
+    method_resolution, InferenceResult, Interner, Substitution, TraitEnvironment, TyExt,
+    TyLoweringContext,
 };
 use syntax::{
     ast::{self, AstNode},
@@ -29,9 +33,9 @@ use syntax::{
 };
 
 use crate::{
-    db::HirDatabase, semantics::PathResolution, Adt, BuiltinAttr, BuiltinType, Const, Field,
-    Function, Local, MacroDef, ModuleDef, Static, Struct, ToolModule, Trait, Type, TypeAlias,
-    TypeParam, Variant,
+    db::HirDatabase, semantics::PathResolution, Adt, BindingMode, BuiltinAttr, BuiltinType, Const,
+    Field, Function, Local, MacroDef, ModuleDef, Static, Struct, ToolModule, Trait, Type,
+    TypeAlias, TypeParam, Variant,
 };
 use base_db::CrateId;
 
@@ -168,6 +172,16 @@ impl SourceAnalyzer {
         mk_ty(ty).zip(Some(coerced.and_then(mk_ty)))
     }
 
+    pub(crate) fn binding_mode_of_pat(
+        &self,
+        _db: &dyn HirDatabase,
+        pat: &ast::IdentPat,
+    ) -> Option<BindingMode> {
+        let pat_id = self.pat_id(&pat.clone().into())?;
+        let infer = self.infer.as_ref()?;
+        infer.pat_binding_modes.get(&pat_id).map(|&mode| mode.into())
+    }
+
     pub(crate) fn type_of_self(
         &self,
         db: &dyn HirDatabase,
@@ -188,6 +202,33 @@ impl SourceAnalyzer {
         self.infer.as_ref()?.method_resolution(expr_id)
     }
 
+    pub(crate) fn is_unsafe_expr(&self, db: &dyn HirDatabase, expr: &ast::Expr) -> bool {
+        let is_unsafe = || -> Option<bool> {
+            let expr_id = self.expr_id(db, expr)?;
+            let def = self.resolver.body_owner()?;
+            let unsafe_exprs = unsafe_expressions(db, self.infer.as_ref()?, def);
+            Some(unsafe_exprs.iter().any(|it| it.expr == expr_id))
+        };
+        is_unsafe().unwrap_or(false)
+    }
+
+    pub(crate) fn resolve_impl_method(
+        &self,
+        db: &dyn HirDatabase,
+        call: &ast::MethodCallExpr,
+    ) -> Option<FunctionId> {
+        let (func, subst) = self.resolve_method_call(db, call)?;
+        if !matches!(func.lookup(db.upcast()).container, ItemContainerId::TraitId(_)) {
+            return Some(func);
+        }
+        let krate = self.resolver.krate()?;
+        let env = self
+            .resolver
+            .generic_def()
+            .map_or_else(|| Arc::new(TraitEnvironment::empty(krate)), |d| db.trait_environment(d));
+        method_resolution::lookup_impl_method(db, env, func, &subst)
+    }
+
     pub(crate) fn resolve_field(
         &self,
         db: &dyn HirDatabase,
@@ -283,6 +283,37 @@ impl Attrs {
         }
     }
 
+    /// Returns the names given by `#[doc(alias = "name")]` and `#[doc(alias("a", "b"))]`.
+    pub fn doc_aliases(&self) -> Vec<SmolStr> {
+        let mut res = Vec::new();
+        for tt in self.by_key("doc").tt_values() {
+            let mut trees = tt.token_trees.iter();
+            while let Some(tree) = trees.next() {
+                match tree {
+                    tt::TokenTree::Leaf(tt::Leaf::Ident(ident)) if ident.text == "alias" => {}
+                    _ => continue,
+                }
+                match trees.next() {
+                    Some(tt::TokenTree::Leaf(tt::Leaf::Punct(punct))) if punct.char == '=' => {
+                        if let Some(tt::TokenTree::Leaf(tt::Leaf::Literal(lit))) = trees.next() {
+                            res.push(lit.text.trim_matches('"').into());
+                        }
+                    }
+                    Some(tt::TokenTree::Subtree(list)) => {
+                        res.extend(list.token_trees.iter().filter_map(|tree| match tree {
+                            tt::TokenTree::Leaf(tt::Leaf::Literal(lit)) => {
+                                Some(lit.text.trim_matches('"').into())
+                            }
+                            _ => None,
+                        }));
+                    }
+                    _ => {}
+                }
+            }
+        }
+        res
+    }
+
     pub fn has_doc_hidden(&self) -> bool {
         self.by_key("doc").tt_values().any(|tt| {
             tt.delimiter_kind() == Some(DelimiterKind::Parenthesis) &&
@@ -291,6 +322,22 @@ impl Attrs {
     }
 }
 
+/// Parses the `#[cfg]` attributes written on `owner`, combining them like [`Attrs::cfg`]. Unlike
+/// [`Attrs`], this also works for items which are disabled and thus never lowered.
+pub fn cfg_of_source(owner: &dyn ast::HasAttrs) -> Option<CfgExpr> {
+    let mut cfgs = owner
+        .attrs()
+        .filter_map(|attr| attr.as_simple_call())
+        .filter(|(name, _)| name == "cfg")
+        .map(|(_, tt)| CfgExpr::parse(&syntax_node_to_token_tree(tt.syntax()).0))
+        .collect::<Vec<_>>();
+    match cfgs.len() {
+        0 => None,
+        1 => Some(cfgs.pop().unwrap()),
+        _ => Some(CfgExpr::All(cfgs)),
+    }
+}
+
 impl AttrsWithOwner {
     pub(crate) fn attrs_query(db: &dyn DefDatabase, def: AttrDefId) -> Self {
         // FIXME: this should use `Trace` to avoid duplication in `source_map` below
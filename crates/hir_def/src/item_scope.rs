@@ -14,7 +14,7 @@ use syntax::ast;
 
 use crate::{
     attr::AttrId, db::DefDatabase, per_ns::PerNs, visibility::Visibility, AdtId, BuiltinType,
-    ConstId, ImplId, LocalModuleId, MacroDefId, ModuleDefId, ModuleId, TraitId,
+    ConstId, ExternCrateId, ImplId, LocalModuleId, MacroDefId, ModuleDefId, ModuleId, TraitId,
 };
 
 #[derive(Copy, Clone)]
@@ -48,6 +48,8 @@ pub struct ItemScope {
 
     impls: Vec<ImplId>,
     unnamed_consts: Vec<ConstId>,
+    /// `extern crate` items declared in this scope.
+    extern_crate_decls: Vec<ExternCrateId>,
     /// Traits imported via `use Trait as _;`.
     unnamed_trait_imports: FxHashMap<TraitId, Visibility>,
     /// Macros visible in current module in legacy textual scope
@@ -121,6 +123,10 @@ impl ItemScope {
         self.unnamed_consts.iter().copied()
     }
 
+    pub fn extern_crate_decls(&self) -> impl Iterator<Item = ExternCrateId> + '_ {
+        self.extern_crate_decls.iter().copied()
+    }
+
     /// Iterate over all module scoped macros
     pub(crate) fn macros<'a>(&'a self) -> impl Iterator<Item = (&'a Name, MacroDefId)> + 'a {
         self.entries().filter_map(|(name, def)| def.take_macros().map(|macro_| (name, macro_)))
@@ -185,6 +191,10 @@ impl ItemScope {
         self.unnamed_consts.push(konst);
     }
 
+    pub(crate) fn define_extern_crate_decl(&mut self, extern_crate: ExternCrateId) {
+        self.extern_crate_decls.push(extern_crate);
+    }
+
     pub(crate) fn define_legacy_macro(&mut self, name: Name, mac: MacroDefId) {
         self.legacy_macros.insert(name, mac);
     }
@@ -350,6 +360,7 @@ impl ItemScope {
             macro_declarations,
             impls,
             unnamed_consts,
+            extern_crate_decls,
             unnamed_trait_imports,
             legacy_macros,
             attr_macros,
@@ -363,6 +374,7 @@ impl ItemScope {
         macro_declarations.shrink_to_fit();
         impls.shrink_to_fit();
         unnamed_consts.shrink_to_fit();
+        extern_crate_decls.shrink_to_fit();
         unnamed_trait_imports.shrink_to_fit();
         legacy_macros.shrink_to_fit();
         attr_macros.shrink_to_fit();
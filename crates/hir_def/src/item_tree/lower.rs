@@ -457,7 +457,9 @@ impl<'a> Ctx<'a> {
         // as if it was an non-trait impl. Ideally we want to create a unique missing ref that only
         // equals itself.
         let target_trait = impl_def.trait_().and_then(|tr| self.lower_trait_ref(&tr));
-        let self_ty = self.lower_type_ref(&impl_def.self_ty()?);
+        // An impl with a missing self type, like `impl Trait for {}`, is still lowered so that
+        // its items are analyzed.
+        let self_ty = self.lower_type_ref_opt(impl_def.self_ty());
         let is_negative = impl_def.excl_token().is_some();
 
         // We cannot use `assoc_items()` here as that does not include macro calls.
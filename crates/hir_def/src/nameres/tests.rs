@@ -9,7 +9,7 @@ use std::sync::Arc;
 use base_db::{fixture::WithFixture, SourceDatabase};
 use expect_test::{expect, Expect};
 
-use crate::{db::DefDatabase, test_db::TestDB};
+use crate::{db::DefDatabase, test_db::TestDB, Lookup};
 
 use super::DefMap;
 
@@ -384,6 +384,39 @@ pub struct Arc;
     );
 }
 
+#[test]
+fn extern_crate_decls_are_recorded() {
+    let db = TestDB::with_files(
+        r#"
+//- /main.rs crate:main deps:std
+extern crate std;
+extern crate std as stdlib;
+mod m {
+    extern crate self as this;
+}
+
+//- /std.rs crate:std
+"#,
+    );
+    let krate = db.crate_graph().iter().find(|&it| db.crate_graph()[it].dependencies.len() == 1);
+    let def_map = db.crate_def_map(krate.unwrap());
+    let mut actual = String::new();
+    for (_, module) in def_map.modules() {
+        for extern_crate in module.scope.extern_crate_decls() {
+            let loc = extern_crate.lookup(&db);
+            let it = &loc.id.item_tree(&db)[loc.id.value];
+            let alias = it.alias.as_ref().map(|alias| format!(" as {}", alias)).unwrap_or_default();
+            actual += &format!("extern crate {}{};\n", it.name, alias);
+        }
+    }
+    expect![[r#"
+        extern crate std;
+        extern crate std as stdlib;
+        extern crate self as this;
+    "#]]
+    .assert_eq(&actual);
+}
+
 #[test]
 fn extern_crate_rename_2015_edition() {
     check(
@@ -1,6 +1,6 @@
 use std::sync::Arc;
 
-use base_db::SourceDatabaseExt;
+use base_db::{CrateName, Dependency, SourceDatabase, SourceDatabaseExt};
 
 use crate::{AdtId, ModuleDefId};
 
@@ -234,3 +234,93 @@ pub type Ty = ();
         assert_eq!(n_reparsed_files, 0);
     }
 }
+
+#[test]
+fn adding_an_unrelated_dependency_should_not_reexpand_macros() {
+    let (mut db, _) = TestDB::with_position(
+        r#"
+//- /main.rs crate:main deps:lib
+$0
+//- /lib.rs crate:lib
+macro_rules! m {
+    ($ident:ident) => {
+        pub fn $ident() {}
+    }
+}
+m!(f);
+m!(g);
+//- /other.rs crate:other
+"#,
+    );
+    let crate_graph = db.crate_graph();
+    let find_crate = |name: &str| {
+        crate_graph
+            .iter()
+            .find(|&krate| crate_graph[krate].display_name.as_deref() == Some(name))
+            .unwrap()
+    };
+    let (main, lib, other) = (find_crate("main"), find_crate("lib"), find_crate("other"));
+    {
+        let events = db.log_executed(|| {
+            let crate_def_map = db.crate_def_map(lib);
+            assert_eq!(crate_def_map[crate_def_map.root()].scope.resolutions().count(), 2);
+        });
+        let n_expanded_macros = events.iter().filter(|it| it.contains("macro_expand(")).count();
+        assert_eq!(n_expanded_macros, 2);
+    }
+
+    let mut new_crate_graph = (*crate_graph).clone();
+    new_crate_graph
+        .add_dep(main, Dependency::new(CrateName::new("other").unwrap(), other))
+        .unwrap();
+    db.set_crate_graph(Arc::new(new_crate_graph));
+
+    {
+        let events = db.log_executed(|| {
+            let crate_def_map = db.crate_def_map(lib);
+            assert_eq!(crate_def_map[crate_def_map.root()].scope.resolutions().count(), 2);
+        });
+        let n_expanded_macros = events.iter().filter(|it| it.contains("macro_expand(")).count();
+        assert_eq!(n_expanded_macros, 0, "{:#?}", events);
+        let n_reparsed_macros =
+            events.iter().filter(|it| it.contains("parse_macro_expansion")).count();
+        assert_eq!(n_reparsed_macros, 0, "{:#?}", events);
+    }
+}
+
+#[test]
+fn saving_a_file_should_not_reexpand_proc_macros() {
+    let (mut db, pos) = TestDB::with_position(
+        r#"
+//- proc_macros: identity
+//- /lib.rs
+#[proc_macros::identity]
+fn f() {}
+fn g() { 1$0 }
+"#,
+    );
+    let crate_graph = db.crate_graph();
+    let krate = crate_graph.iter().find(|&it| crate_graph[it].root_file_id == pos.file_id).unwrap();
+    {
+        let events = db.log_executed(|| {
+            db.crate_def_map(krate);
+        });
+        let n_expanded_macros = events.iter().filter(|it| it.contains("expand_proc_macro")).count();
+        assert_eq!(n_expanded_macros, 1, "{:#?}", events);
+    }
+
+    let new_text = r#"
+#[proc_macros::identity]
+fn f() {}
+fn g() { 92 }
+"#;
+    db.set_file_text(pos.file_id, Arc::new(new_text.to_string()));
+
+    {
+        let events = db.log_executed(|| {
+            db.crate_def_map(krate);
+        });
+        let n_expanded_macros = events.iter().filter(|it| it.contains("expand_proc_macro")).count();
+        assert_eq!(n_expanded_macros, 0, "{:#?}", events);
+    }
+}
@@ -58,6 +58,9 @@ impl ProcMacroExpander {
                         | ProcMacroExpansionError::Panic(text) => {
                             ExpandResult::only_err(mbe::ExpandError::Other(text))
                         }
+                        ProcMacroExpansionError::Reported { value, message } => {
+                            ExpandResult { value, err: Some(mbe::ExpandError::Other(message)) }
+                        }
                     },
                 }
             }
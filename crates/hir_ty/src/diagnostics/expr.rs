@@ -57,6 +57,9 @@ pub enum BodyValidationDiagnostic {
         arg_expr: ExprId,
         mutability: Mutability,
     },
+    IrrefutableLetPattern {
+        if_expr: ExprId,
+    },
 }
 
 impl BodyValidationDiagnostic {
@@ -99,6 +102,7 @@ impl ExprValidator {
             match expr {
                 Expr::Match { expr, arms } => {
                     self.validate_match(id, *expr, arms, db, self.infer.clone());
+                    self.validate_if_let(id, arms, &body);
                 }
                 Expr::Call { .. } | Expr::MethodCall { .. } => {
                     self.validate_call(db, id, expr);
@@ -346,6 +350,17 @@ impl ExprValidator {
         }
     }
 
+    fn validate_if_let(&mut self, id: ExprId, arms: &[MatchArm], body: &Body) {
+        // `if let` is desugared into a `match` with a missing pattern in the second arm.
+        let then_arm = match arms {
+            [then_arm, else_arm] if matches!(body[else_arm.pat], Pat::Missing) => then_arm,
+            _ => return,
+        };
+        if is_irrefutable(then_arm.pat, body, &self.infer) {
+            self.diagnostics.push(BodyValidationDiagnostic::IrrefutableLetPattern { if_expr: id });
+        }
+    }
+
     fn lower_pattern<'p>(
         &self,
         cx: &MatchCheckCtx<'_, 'p>,
@@ -484,6 +499,34 @@ pub fn record_pattern_missing_fields(
     Some((variant_def, missed_fields, exhaustive))
 }
 
+/// Checks whether `pat` matches every value of its type. This only recognizes bindings, wildcards,
+/// references, boxes, tuples and structs made out of such patterns.
+fn is_irrefutable(pat: PatId, body: &Body, infer: &InferenceResult) -> bool {
+    match &body[pat] {
+        Pat::Wild => true,
+        Pat::Bind { subpat, .. } => {
+            subpat.map_or(true, |subpat| is_irrefutable(subpat, body, infer))
+        }
+        Pat::Ref { pat, .. } | Pat::Box { inner: pat } => is_irrefutable(*pat, body, infer),
+        Pat::Tuple { args, .. } => args.iter().all(|&arg| is_irrefutable(arg, body, infer)),
+        Pat::TupleStruct { args, .. } => {
+            matches!(infer.variant_resolution_for_pat(pat), Some(VariantId::StructId(_)))
+                && args.iter().all(|&arg| is_irrefutable(arg, body, infer))
+        }
+        Pat::Record { args, .. } => {
+            matches!(infer.variant_resolution_for_pat(pat), Some(VariantId::StructId(_)))
+                && args.iter().all(|field| is_irrefutable(field.pat, body, infer))
+        }
+        Pat::Missing
+        | Pat::Or(_)
+        | Pat::Range { .. }
+        | Pat::Slice { .. }
+        | Pat::Path(_)
+        | Pat::Lit(_)
+        | Pat::ConstBlock(_) => false,
+    }
+}
+
 fn types_of_subpatterns_do_match(pat: PatId, body: &Body, infer: &InferenceResult) -> bool {
     fn walk(pat: PatId, body: &Body, infer: &InferenceResult, has_type_mismatches: &mut bool) {
         match infer.type_mismatch_for_pat(pat) {
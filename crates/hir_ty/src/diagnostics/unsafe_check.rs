@@ -3,12 +3,12 @@
 
 use hir_def::{
     body::Body,
-    expr::{Expr, ExprId, UnaryOp},
+    expr::{BinaryOp, Expr, ExprId, UnaryOp},
     resolver::{resolver_for_expr, ResolveValueResult, ValueNs},
     DefWithBodyId,
 };
 
-use crate::{db::HirDatabase, InferenceResult, Interner, TyExt, TyKind};
+use crate::{db::HirDatabase, AdtId, InferenceResult, Interner, TyExt, TyKind};
 
 pub fn missing_unsafe(db: &dyn HirDatabase, def: DefWithBodyId) -> Vec<ExprId> {
     let infer = db.infer(def);
@@ -28,12 +28,13 @@ pub fn missing_unsafe(db: &dyn HirDatabase, def: DefWithBodyId) -> Vec<ExprId> {
         .collect()
 }
 
-struct UnsafeExpr {
-    pub(crate) expr: ExprId,
-    pub(crate) inside_unsafe_block: bool,
+pub struct UnsafeExpr {
+    pub expr: ExprId,
+    pub inside_unsafe_block: bool,
 }
 
-fn unsafe_expressions(
+/// Returns the expressions of the body that need an `unsafe` block, whether they are in one or not.
+pub fn unsafe_expressions(
     db: &dyn HirDatabase,
     infer: &InferenceResult,
     def: DefWithBodyId,
@@ -86,6 +87,19 @@ fn walk_unsafe(
                 unsafe_exprs.push(UnsafeExpr { expr: current, inside_unsafe_block });
             }
         }
+        Expr::Field { expr, .. } if is_union(infer, *expr) => {
+            unsafe_exprs.push(UnsafeExpr { expr: current, inside_unsafe_block });
+        }
+        &Expr::BinaryOp { lhs, rhs, op: Some(BinaryOp::Assignment { op: None }) } => {
+            // Assigning to a union field doesn't read it, so it's safe.
+            if let Expr::Field { expr, .. } = &body.exprs[lhs] {
+                if is_union(infer, *expr) {
+                    walk_unsafe(unsafe_exprs, db, infer, def, body, *expr, inside_unsafe_block);
+                    walk_unsafe(unsafe_exprs, db, infer, def, body, rhs, inside_unsafe_block);
+                    return;
+                }
+            }
+        }
         Expr::Unsafe { body: child } => {
             return walk_unsafe(unsafe_exprs, db, infer, def, body, *child, true);
         }
@@ -96,3 +110,10 @@ fn walk_unsafe(
         walk_unsafe(unsafe_exprs, db, infer, def, body, child, inside_unsafe_block);
     });
 }
+
+fn is_union(infer: &InferenceResult, expr: ExprId) -> bool {
+    matches!(
+        infer[expr].strip_references().kind(Interner),
+        TyKind::Adt(AdtId(hir_def::AdtId::UnionId(_)), _)
+    )
+}
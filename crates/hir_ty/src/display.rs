@@ -100,6 +100,28 @@ pub trait HirDisplay {
         }
     }
 
+    /// Returns a `Display`able type that is human-readable and names types by paths starting at
+    /// a crate, like `crate::shapes::Circle` or `std::collections::HashMap`, as seen from the
+    /// given module. Items in the prelude keep their short name.
+    /// Use this for showing types to the user where types with the same name need to be told
+    /// apart.
+    fn display_fully_qualified<'a>(
+        &'a self,
+        db: &'a dyn HirDatabase,
+        module_id: ModuleId,
+    ) -> HirDisplayWrapper<'a, Self>
+    where
+        Self: Sized,
+    {
+        HirDisplayWrapper {
+            db,
+            t: self,
+            max_size: None,
+            omit_verbose_types: false,
+            display_target: DisplayTarget::FullyQualified { module_id },
+        }
+    }
+
     /// Returns a String representation of `self` that can be inserted into the given module.
     /// Use this when generating code (e.g. assists)
     fn display_source_code<'a>(
@@ -188,6 +210,9 @@ pub enum DisplayTarget {
     /// Display types for inserting them in source files.
     /// The generated code should compile, so paths need to be qualified.
     SourceCode { module_id: ModuleId },
+    /// Display types for showing them to the user with paths starting at a crate.
+    /// Like `Diagnostics`, this falls back to plain names and can't fail.
+    FullyQualified { module_id: ModuleId },
     /// Only for test purpose to keep real types
     Test,
 }
@@ -504,15 +529,30 @@ impl HirDisplay for Ty {
                 }
             }
             TyKind::Adt(AdtId(def_id), parameters) => {
+                let name = || match *def_id {
+                    hir_def::AdtId::StructId(it) => f.db.struct_data(it).name.clone(),
+                    hir_def::AdtId::UnionId(it) => f.db.union_data(it).name.clone(),
+                    hir_def::AdtId::EnumId(it) => f.db.enum_data(it).name.clone(),
+                };
                 match f.display_target {
                     DisplayTarget::Diagnostics | DisplayTarget::Test => {
-                        let name = match *def_id {
-                            hir_def::AdtId::StructId(it) => f.db.struct_data(it).name.clone(),
-                            hir_def::AdtId::UnionId(it) => f.db.union_data(it).name.clone(),
-                            hir_def::AdtId::EnumId(it) => f.db.enum_data(it).name.clone(),
-                        };
+                        let name = name();
                         write!(f, "{}", name)?;
                     }
+                    DisplayTarget::FullyQualified { module_id } => {
+                        match find_path::find_path_prefixed(
+                            f.db.upcast(),
+                            ItemInNs::Types((*def_id).into()),
+                            module_id,
+                            find_path::PrefixKind::ByCrate,
+                        ) {
+                            Some(path) => write!(f, "{}", path)?,
+                            None => {
+                                let name = name();
+                                write!(f, "{}", name)?
+                            }
+                        }
+                    }
                     DisplayTarget::SourceCode { module_id } => {
                         if let Some(path) = find_path::find_path(
                             f.db.upcast(),
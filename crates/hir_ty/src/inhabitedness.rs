@@ -0,0 +1,60 @@
+//! Checks whether a type is uninhabited, i.e. has no values at all.
+
+use hir_def::{AdtId, EnumVariantId, VariantId};
+use rustc_hash::FxHashSet;
+
+use crate::{db::HirDatabase, Interner, Substitution, Ty, TyKind};
+
+/// Returns `true` if no value of type `ty` can exist: `!`, enums without (inhabited) variants,
+/// and structs and tuples with an uninhabited field.
+///
+/// This errs on the side of inhabitedness: references, pointers, arrays, unions and recursive
+/// types are all considered inhabited, and visibility of fields is not taken into account.
+pub fn is_ty_uninhabited(db: &dyn HirDatabase, ty: &Ty) -> bool {
+    UninhabitedCtx { db, adts_in_progress: FxHashSet::default() }.ty(ty)
+}
+
+struct UninhabitedCtx<'a> {
+    db: &'a dyn HirDatabase,
+    adts_in_progress: FxHashSet<AdtId>,
+}
+
+impl UninhabitedCtx<'_> {
+    fn ty(&mut self, ty: &Ty) -> bool {
+        match ty.kind(Interner) {
+            TyKind::Never => true,
+            TyKind::Tuple(_, subst) => {
+                subst.iter(Interner).filter_map(|it| it.ty(Interner)).any(|ty| self.ty(ty))
+            }
+            TyKind::Adt(chalk_ir::AdtId(adt), subst) => self.adt(*adt, subst),
+            _ => false,
+        }
+    }
+
+    fn adt(&mut self, adt: AdtId, subst: &Substitution) -> bool {
+        if !self.adts_in_progress.insert(adt) {
+            // A recursive type: if it is uninhabited, some other field or variant makes it so.
+            return false;
+        }
+        let res = match adt {
+            AdtId::StructId(it) => self.variant(it.into(), subst),
+            AdtId::UnionId(_) => false,
+            AdtId::EnumId(it) => {
+                let enum_data = self.db.enum_data(it);
+                let res = enum_data.variants.iter().all(|(local_id, _)| {
+                    self.variant(EnumVariantId { parent: it, local_id }.into(), subst)
+                });
+                res
+            }
+        };
+        self.adts_in_progress.remove(&adt);
+        res
+    }
+
+    fn variant(&mut self, variant: VariantId, subst: &Substitution) -> bool {
+        let field_types = self.db.field_types(variant);
+        let res =
+            field_types.iter().any(|(_, ty)| self.ty(&ty.clone().substitute(Interner, subst)));
+        res
+    }
+}
@@ -966,6 +966,61 @@ fn is_valid_candidate(
     }
 }
 
+/// Finds the function that a call of the trait method `func` with the generic arguments
+/// `fn_subst` dispatches to: the method of the matching impl, or the trait's default method if
+/// the impl doesn't override it. Returns `None` if `func` isn't a trait method, if the `Self`
+/// type is a trait object or unknown, or if no single impl matches.
+pub fn lookup_impl_method(
+    db: &dyn HirDatabase,
+    env: Arc<TraitEnvironment>,
+    func: FunctionId,
+    fn_subst: &Substitution,
+) -> Option<FunctionId> {
+    let trait_id = match func.lookup(db.upcast()).container {
+        ItemContainerId::TraitId(id) => id,
+        _ => return None,
+    };
+    let self_ty = fn_subst.at(Interner, 0).ty(Interner)?;
+    if self_ty.dyn_trait().is_some() {
+        return None;
+    }
+    let fingerprint = TyFingerprint::for_trait_impl(self_ty)?;
+    let self_ty = Canonical { binders: CanonicalVarKinds::empty(Interner), value: self_ty.clone() };
+
+    let impls = db.trait_impls_in_deps(env.krate);
+    let mut matching = impls.for_trait_and_self_ty(trait_id, fingerprint).filter(|&impl_id| {
+        let impl_subst = match inherent_impl_substs(db, env.clone(), impl_id, &self_ty) {
+            Some(it) => it,
+            None => return false,
+        };
+        let trait_ref = match db.impl_trait(impl_id) {
+            Some(it) => it.substitute(Interner, &impl_subst),
+            None => return false,
+        };
+        // For generic traits like `From<T>`, the trait arguments have to match as well. The
+        // method's own generic arguments come after the trait's, so `zip` skips them.
+        trait_ref.substitution.iter(Interner).zip(fn_subst.iter(Interner)).all(
+            |(impl_arg, call_arg)| match (impl_arg.ty(Interner), call_arg.ty(Interner)) {
+                (Some(impl_ty), Some(call_ty)) => {
+                    impl_ty.is_unknown() || call_ty.is_unknown() || impl_ty == call_ty
+                }
+                _ => true,
+            },
+        )
+    });
+    let impl_id = matching.next()?;
+    if matching.next().is_some() {
+        return None;
+    }
+
+    let name = &db.function_data(func).name;
+    let impl_fn = db.impl_data(impl_id).items.iter().find_map(|&item| match item {
+        AssocItemId::FunctionId(f) if db.function_data(f).name == *name => Some(f),
+        _ => None,
+    });
+    Some(impl_fn.unwrap_or(func))
+}
+
 pub(crate) fn inherent_impl_substs(
     db: &dyn HirDatabase,
     env: Arc<TraitEnvironment>,
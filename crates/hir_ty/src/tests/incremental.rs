@@ -49,3 +49,55 @@ fn typing_whitespace_inside_a_function_should_not_invalidate_types() {
         assert!(!format!("{:?}", events).contains("infer"), "{:#?}", events)
     }
 }
+
+#[test]
+fn editing_a_function_body_should_only_reinfer_that_function() {
+    let (mut db, pos) = TestDB::with_position(
+        "
+        //- /lib.rs
+        fn foo() -> i32 {
+            1 + 1
+        }
+
+        fn bar() -> i32 {
+            $02 + 2
+        }
+    ",
+    );
+    let infer_all = |db: &TestDB| {
+        let module = db.module_for_file(pos.file_id);
+        let crate_def_map = module.def_map(db);
+        visit_module(db, &crate_def_map, module.local_id, &mut |def| {
+            db.infer(def);
+        });
+    };
+    {
+        let events = db.log_executed(|| infer_all(&db));
+        assert_eq!(
+            events.iter().filter(|it| it.contains("infer_query")).count(),
+            2,
+            "{:#?}",
+            events
+        )
+    }
+
+    let new_text = "
+        fn foo() -> i32 {
+            1 + 1
+        }
+
+        fn bar() -> i32 {
+            let x = 2;
+            x * 2
+        }
+    "
+    .to_string();
+
+    db.set_file_text(pos.file_id, Arc::new(new_text));
+
+    {
+        let events = db.log_executed(|| infer_all(&db));
+        let reinferred: Vec<_> = events.iter().filter(|it| it.contains("infer_query")).collect();
+        assert_eq!(reinferred, ["infer_query(FunctionId(FunctionId(1)))"], "{:#?}", events);
+    }
+}
@@ -3545,3 +3545,167 @@ fn f<F: Foo>() {
         "#]],
     );
 }
+
+#[test]
+fn method_call_normalizes_projection_from_where_clause() {
+    check_types(
+        r#"
+//- minicore: iterator
+struct S;
+fn test<T>(mut t: T) where T: Iterator<Item = S> {
+    t.next();
+  //^^^^^^^^ Option<S>
+}
+"#,
+    );
+}
+
+#[test]
+fn future_output_normalized_from_where_clause() {
+    check_types(
+        r#"
+//- minicore: future
+struct S;
+async fn test<F>(f: F) where F: core::future::Future<Output = S> {
+    f.await;
+  //^^^^^^^ S
+}
+"#,
+    );
+}
+
+#[test]
+fn user_defined_assoc_type_normalized_from_where_clause() {
+    check_types(
+        r#"
+trait Assoc {
+    type Item;
+    fn get(&self) -> Self::Item;
+}
+struct S;
+fn test<T: Assoc<Item = S>>(t: T) {
+    t.get();
+  //^^^^^^^ S
+}
+fn test2<T>(t: T) where T: Assoc<Item = u32> {
+    let x = t.get();
+      //^ u32
+}
+"#,
+    );
+}
+
+#[test]
+fn infer_parse_target_from_expected_type() {
+    check_types(
+        r#"
+enum Result<T, E> {
+    Ok(T),
+    Err(E),
+}
+trait FromStr: Sized {
+    type Err;
+    fn from_str(s: &str) -> Result<Self, Self::Err>;
+}
+struct ParseIntError;
+impl FromStr for i32 {
+    type Err = ParseIntError;
+    fn from_str(s: &str) -> Result<i32, ParseIntError> { loop {} }
+}
+#[lang = "str"]
+impl str {
+    fn parse<F: FromStr>(&self) -> Result<F, F::Err> { loop {} }
+}
+impl<T, E> Result<T, E> {
+    fn unwrap(self) -> T { loop {} }
+}
+fn test() {
+    let x: i32 = "42".parse().unwrap();
+              // ^^^^^^^^^^^^ Result<i32, ParseIntError>
+    let r: Result<i32, _> = "42".parse();
+     // ^ Result<i32, ParseIntError>
+    let p = "42".parse::<i32>();
+     // ^ Result<i32, ParseIntError>
+    let y: i32 = FromStr::from_str("42").unwrap();
+              // ^^^^^^^^^^^^^^^^^^^^^^^ Result<i32, ParseIntError>
+    let z = <i32 as FromStr>::from_str("42");
+     // ^ Result<i32, ParseIntError>
+}
+"#,
+    );
+}
+
+#[test]
+fn infer_collect_target_from_expected_type() {
+    check_types(
+        r#"
+//- minicore: iterator
+trait FromIterator<A>: Sized {
+    fn from_iter<T: IntoIterator<Item = A>>(iter: T) -> Self;
+}
+trait IteratorExt: Iterator {
+    fn collect<B: FromIterator<Self::Item>>(self) -> B { loop {} }
+}
+impl<I: Iterator> IteratorExt for I {}
+struct Vec<T>(T);
+impl<T> FromIterator<T> for Vec<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self { loop {} }
+}
+struct Iter;
+impl Iterator for Iter {
+    type Item = u8;
+    fn next(&mut self) -> Option<u8> { loop {} }
+}
+fn test() {
+    let v: Vec<_> = Iter.collect();
+     // ^ Vec<u8>
+}
+"#,
+    );
+}
+
+#[test]
+fn infer_collect_turbofish_with_placeholders() {
+    check_types(
+        r#"
+//- minicore: iterator
+trait FromIterator<A>: Sized {
+    fn from_iter<T: IntoIterator<Item = A>>(iter: T) -> Self;
+}
+trait IteratorExt: Iterator {
+    fn collect<B: FromIterator<Self::Item>>(self) -> B { loop {} }
+}
+impl<I: Iterator> IteratorExt for I {}
+struct Vec<T>(T);
+impl<T> FromIterator<T> for Vec<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self { loop {} }
+}
+struct HashSet<T>(T);
+impl<T> FromIterator<T> for HashSet<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self { loop {} }
+}
+struct String;
+impl FromIterator<char> for String {
+    fn from_iter<I: IntoIterator<Item = char>>(iter: I) -> Self { loop {} }
+}
+struct Bytes;
+impl Iterator for Bytes {
+    type Item = u8;
+    fn next(&mut self) -> Option<u8> { loop {} }
+}
+struct Chars;
+impl Iterator for Chars {
+    type Item = char;
+    fn next(&mut self) -> Option<char> { loop {} }
+}
+fn test() {
+    let v = Bytes.collect::<Vec<_>>();
+     // ^ Vec<u8>
+    let s = Bytes.collect::<HashSet<_>>();
+     // ^ HashSet<u8>
+    let t = Chars.collect::<String>();
+     // ^ String
+}
+"#,
+    );
+}
@@ -52,7 +52,7 @@ pub(crate) fn annotations(
 
     if config.annotate_runnables {
         for runnable in runnables(db, file_id) {
-            if should_skip_runnable(&runnable.kind, config.binary_target) {
+            if should_skip_runnable(&runnable, config.binary_target) {
                 continue;
             }
 
@@ -179,9 +179,11 @@ pub(crate) fn resolve_annotation(db: &RootDatabase, mut annotation: Annotation)
     annotation
 }
 
-fn should_skip_runnable(kind: &RunnableKind, binary_target: bool) -> bool {
-    match kind {
+fn should_skip_runnable(runnable: &Runnable, binary_target: bool) -> bool {
+    match runnable.kind {
         RunnableKind::Bin => !binary_target,
+        // Without crate docs, there's nothing to put the lens on.
+        RunnableKind::CargoDoc => runnable.nav.focus_range.is_none(),
         _ => false,
     }
 }
@@ -841,4 +843,39 @@ m!();
             "#]],
         );
     }
+
+    #[test]
+    fn cargo_doc_annotation_on_crate_docs() {
+        check(
+            r#"
+//- /lib.rs
+//! Crate docs.
+
+mod m {}
+"#,
+            expect![[r#"
+                [
+                    Annotation {
+                        range: 0..15,
+                        kind: Runnable(
+                            Runnable {
+                                use_name_in_title: false,
+                                nav: NavigationTarget {
+                                    file_id: FileId(
+                                        0,
+                                    ),
+                                    full_range: 0..26,
+                                    focus_range: 0..15,
+                                    name: "",
+                                    kind: Module,
+                                },
+                                kind: CargoDoc,
+                                cfg: None,
+                            },
+                        ),
+                    },
+                ]
+            "#]],
+        );
+    }
 }
@@ -13,6 +13,7 @@ use url::Url;
 
 use hir::{db::HirDatabase, Adt, AsAssocItem, AssocItem, AssocItemContainer, Crate, HasAttrs};
 use ide_db::{
+    base_db::{CrateOrigin, FileId, SourceDatabaseExt},
     defs::{Definition, NameClass, NameRefClass},
     helpers::pick_best_token,
     RootDatabase,
@@ -26,12 +27,30 @@ use syntax::{
 
 use crate::{
     doc_links::intra_doc_links::{parse_intra_doc_link, strip_prefixes_suffixes},
-    FilePosition, Semantics,
+    FilePosition, NavigationTarget, Semantics, TryToNav,
 };
 
 /// Weblink to an item's documentation.
 pub(crate) type DocumentationLink = String;
 
+/// A clickable link inside of a doc comment.
+#[derive(Debug)]
+pub struct DocLink {
+    pub range: TextRange,
+    pub target: DocLinkTarget,
+}
+
+#[derive(Debug)]
+pub enum DocLinkTarget {
+    /// A `[label](url)` link to an external resource.
+    Url(String),
+    /// An intra-doc link to an item, like ``[`Item`]``.
+    Def(NavigationTarget),
+}
+
+/// The crates whose documentation is hosted on doc.rust-lang.org.
+const STD_CRATES: &[&str] = &["core", "std", "alloc", "proc_macro", "test"];
+
 const MARKDOWN_OPTIONS: Options =
     Options::ENABLE_FOOTNOTES.union(Options::ENABLE_TABLES).union(Options::ENABLE_TASKLISTS);
 
@@ -113,6 +132,61 @@ pub(crate) fn external_docs(
     position: &FilePosition,
 ) -> Option<DocumentationLink> {
     let sema = &Semantics::new(db);
+    let definition = definition_at(sema, position)?;
+    get_doc_link(db, definition)
+}
+
+/// The documentation of an item, see [`show_documentation`].
+#[derive(Debug)]
+pub enum ItemDocumentation {
+    /// A link to the documentation generated by rustdoc.
+    Url(String),
+    /// The doc comments of the item as markdown, for items of crates whose documentation is not
+    /// hosted anywhere, like the crates of the current workspace.
+    Markdown(String),
+}
+
+// Feature: Show Documentation
+//
+// Shows the documentation of the item under the cursor: the rustdoc documentation hosted online
+// for items of the standard library and of crates from crates.io, or the doc comments of the item
+// otherwise.
+//
+// |===
+// | Editor  | Action Name
+//
+// | VS Code | **Rust Analyzer: Show Documentation**
+// |===
+pub(crate) fn show_documentation(
+    db: &RootDatabase,
+    position: &FilePosition,
+) -> Option<ItemDocumentation> {
+    let sema = &Semantics::new(db);
+    let definition = definition_at(sema, position)?;
+    let krate = crate_of_def(db, definition)?;
+    if is_hosted(db, krate) {
+        return get_doc_link(db, definition).map(ItemDocumentation::Url);
+    }
+    // Links are left as they are, as rewriting them would point them to docs.rs as well.
+    let docs = docs_of_def(db, definition)?;
+    Some(ItemDocumentation::Markdown(docs.into()))
+}
+
+/// Whether the documentation of the crate is published online, rather than only generated
+/// locally. The crates of the workspace never are: Cargo gives every package, including the
+/// members, the crates.io origin.
+pub(crate) fn is_hosted(db: &RootDatabase, krate: Crate) -> bool {
+    let is_workspace_member = !db.source_root(db.file_source_root(krate.root_file(db))).is_library;
+    !is_workspace_member
+        && (matches!(krate.origin(db), CrateOrigin::CratesIo { .. } | CrateOrigin::Lang)
+            || krate.get_html_root_url(db).is_some()
+            || krate.display_name(db).map_or(false, |it| STD_CRATES.contains(&&**it.crate_name())))
+}
+
+pub(crate) fn definition_at(
+    sema: &Semantics<RootDatabase>,
+    position: &FilePosition,
+) -> Option<Definition> {
     let file = sema.parse(position.file_id).syntax().clone();
     let token = pick_best_token(file.token_at_offset(position.offset), |kind| match kind {
         IDENT | INT_NUMBER | T![self] => 3,
@@ -138,8 +212,67 @@ pub(crate) fn external_docs(
             _ => return None,
         }
     };
+    Some(definition)
+}
 
-    get_doc_link(db, definition)
+fn docs_of_def(db: &RootDatabase, def: Definition) -> Option<hir::Documentation> {
+    match def {
+        Definition::Macro(it) => it.docs(db),
+        Definition::Field(it) => it.docs(db),
+        Definition::Module(it) => it.docs(db),
+        Definition::Function(it) => it.docs(db),
+        Definition::Adt(it) => it.docs(db),
+        Definition::Variant(it) => it.docs(db),
+        Definition::Const(it) => it.docs(db),
+        Definition::Static(it) => it.docs(db),
+        Definition::Trait(it) => it.docs(db),
+        Definition::TypeAlias(it) => it.docs(db),
+        Definition::GenericParam(it) => it.docs(db),
+        Definition::BuiltinType(_)
+        | Definition::SelfType(_)
+        | Definition::Local(_)
+        | Definition::Label(_)
+        | Definition::BuiltinAttr(_)
+        | Definition::ToolModule(_) => None,
+    }
+}
+
+// Feature: Document Links
+//
+// Makes web links and intra-doc links to items in documentation comments clickable.
+pub(crate) fn doc_links(db: &RootDatabase, file_id: FileId) -> Vec<DocLink> {
+    let sema = Semantics::new(db);
+    let source_file = sema.parse(file_id);
+
+    let mut res = Vec::new();
+    for node in source_file.syntax().descendants() {
+        let (attrs, def) = match doc_attributes(&sema, &node) {
+            Some(it) => it,
+            None => continue,
+        };
+        let (docs, doc_mapping) = match attrs.docs_with_rangemap(db) {
+            Some(it) => it,
+            None => continue,
+        };
+        for (range, link, ns) in extract_definitions_from_docs(&docs) {
+            // Docs of out-of-line modules are partially located in the parent module's file.
+            let range = match doc_mapping.map(range) {
+                Some(it) if it.file_id == file_id.into() => it.value,
+                _ => continue,
+            };
+            let target = if link.contains("://") {
+                DocLinkTarget::Url(link)
+            } else {
+                match resolve_doc_path_for_def(db, def, &link, ns).and_then(|it| it.try_to_nav(db))
+                {
+                    Some(nav) => DocLinkTarget::Def(nav),
+                    None => continue,
+                }
+            };
+            res.push(DocLink { range, target });
+        }
+    }
+    res
 }
 
 /// Extracts all links from a given markdown text returning the definition text range, link-text
@@ -298,7 +431,7 @@ fn broken_link_clone_cb<'a, 'b>(link: BrokenLink<'a>) -> Option<(CowStr<'b>, Cow
 //
 // This should cease to be a problem if RFC2988 (Stable Rustdoc URLs) is implemented
 // https://github.com/rust-lang/rfcs/pull/2988
-fn get_doc_link(db: &RootDatabase, def: Definition) -> Option<String> {
+pub(crate) fn get_doc_link(db: &RootDatabase, def: Definition) -> Option<String> {
     let (target, file, frag) = filename_and_frag_for_def(db, def)?;
 
     let krate = crate_of_def(db, target)?;
@@ -309,7 +442,10 @@ fn get_doc_link(db: &RootDatabase, def: Definition) -> Option<String> {
     }
 
     url = url.join(&file).ok()?;
-    url.set_fragment(frag.as_deref());
+    // Variants already come with their fragment in `file`.
+    if frag.is_some() {
+        url.set_fragment(frag.as_deref());
+    }
 
     Some(url.into())
 }
@@ -356,7 +492,7 @@ fn rewrite_url_link(db: &RootDatabase, def: Definition, target: &str) -> Option<
     url.join(target).ok().map(Into::into)
 }
 
-fn crate_of_def(db: &RootDatabase, def: Definition) -> Option<Crate> {
+pub(crate) fn crate_of_def(db: &RootDatabase, def: Definition) -> Option<Crate> {
     let krate = match def {
         // Definition::module gives back the parent module, we don't want that as it fails for root modules
         Definition::Module(module) => module.krate(),
@@ -365,7 +501,7 @@ fn crate_of_def(db: &RootDatabase, def: Definition) -> Option<Crate> {
     Some(krate)
 }
 
-fn mod_path_of_def(db: &RootDatabase, def: Definition) -> Option<String> {
+pub(crate) fn mod_path_of_def(db: &RootDatabase, def: Definition) -> Option<String> {
     def.canonical_module_path(db).map(|it| {
         let mut path = String::new();
         it.flat_map(|it| it.name(db)).for_each(|name| format_to!(path, "{}/", name));
@@ -420,7 +556,7 @@ fn get_doc_base_url(db: &RootDatabase, krate: &Crate) -> Option<Url> {
     let base = match &**display_name.crate_name() {
         // std and co do not specify `html_root_url` any longer so we gotta handwrite this ourself.
         // FIXME: Use the toolchains channel instead of nightly
-        name @ ("core" | "std" | "alloc" | "proc_macro" | "test") => {
+        name if STD_CRATES.contains(&name) => {
             format!("https://doc.rust-lang.org/nightly/{}", name)
         }
         _ => {
@@ -448,7 +584,7 @@ fn get_doc_base_url(db: &RootDatabase, krate: &Crate) -> Option<Url> {
 /// https://doc.rust-lang.org/std/iter/trait.Iterator.html#tymethod.next
 ///                                    ^^^^^^^^^^^^^^^^^^^
 /// ```
-fn filename_and_frag_for_def(
+pub(crate) fn filename_and_frag_for_def(
     db: &dyn HirDatabase,
     def: Definition,
 ) -> Option<(Definition, String, Option<String>)> {
@@ -10,7 +10,7 @@ use syntax::{ast, match_ast, AstNode, SyntaxNode};
 
 use crate::{
     doc_links::{extract_definitions_from_docs, resolve_doc_path_for_def, rewrite_links},
-    fixture, TryToNav,
+    fixture, DocLinkTarget, TryToNav,
 };
 
 fn check_external_docs(ra_fixture: &str, expect: Expect) {
@@ -20,6 +20,12 @@ fn check_external_docs(ra_fixture: &str, expect: Expect) {
     expect.assert_eq(&url)
 }
 
+fn check_show_documentation(ra_fixture: &str, expect: Expect) {
+    let (analysis, position) = fixture::position(ra_fixture);
+    let docs = analysis.show_documentation(position).unwrap().expect("no documentation found");
+    expect.assert_debug_eq(&docs)
+}
+
 fn check_rewrite(ra_fixture: &str, expect: Expect) {
     let (analysis, position) = fixture::position(ra_fixture);
     let sema = &Semantics::new(&*analysis.db);
@@ -51,6 +57,24 @@ fn check_doc_links(ra_fixture: &str) {
     assert_eq!(expected, actual);
 }
 
+fn check_doc_link_targets(ra_fixture: &str, expect: Expect) {
+    let (analysis, position) = fixture::position(ra_fixture);
+    let links = analysis.doc_links(position.file_id).unwrap();
+    let actual = links
+        .into_iter()
+        .map(|link| {
+            let target = match link.target {
+                DocLinkTarget::Url(url) => url,
+                DocLinkTarget::Def(nav) => {
+                    format!("{:?} {:?}", nav.file_id, nav.focus_or_full_range())
+                }
+            };
+            format!("{:?} {}\n", link.range, target)
+        })
+        .collect::<String>();
+    expect.assert_eq(&actual)
+}
+
 fn def_under_cursor(
     sema: &Semantics<RootDatabase>,
     position: &FilePosition,
@@ -120,6 +144,77 @@ use self$0;
     );
 }
 
+#[test]
+fn show_documentation_std_fn() {
+    check_show_documentation(
+        r#"
+//- /main.rs crate:main deps:std
+fn main() {
+    std::mem::swap$0(&mut 0, &mut 1);
+}
+//- /std.rs crate:std new_source_root:library
+pub mod mem {
+    /// Swaps the values at two mutable locations.
+    pub fn swap<T>(x: &mut T, y: &mut T) {}
+}
+"#,
+        expect![[r#"
+            Url(
+                "https://doc.rust-lang.org/nightly/std/mem/fn.swap.html",
+            )
+        "#]],
+    );
+}
+
+#[test]
+fn show_documentation_crates_io_struct() {
+    check_show_documentation(
+        r#"
+//- /main.rs crate:main deps:foo
+use foo::Foo$0;
+//- /lib.rs crate:foo@CratesIo:0.1.0,https://github.com/foo/foo new_source_root:library
+/// A foo.
+pub struct Foo;
+"#,
+        expect![[r#"
+            Url(
+                "https://docs.rs/foo/0.1.0/foo/struct.Foo.html",
+            )
+        "#]],
+    );
+}
+
+#[test]
+fn show_documentation_local_item() {
+    check_show_documentation(
+        r#"
+//- /main.rs crate:main
+/// Adds one, see [`two`].
+fn one$0() {}
+fn two() {}
+"#,
+        expect![[r#"
+            Markdown(
+                "Adds one, see [`two`].",
+            )
+        "#]],
+    );
+    check_show_documentation(
+        r#"
+//- /main.rs crate:main deps:foo
+use foo::Foo$0;
+//- /lib.rs crate:foo@CratesIo:0.1.0,https://github.com/foo/foo
+/// A foo of the workspace.
+pub struct Foo;
+"#,
+        expect![[r#"
+            Markdown(
+                "A foo of the workspace.",
+            )
+        "#]],
+    );
+}
+
 #[test]
 fn external_docs_doc_url_struct() {
     check_external_docs(
@@ -409,3 +504,27 @@ pub struct $0Foo;
             []: https://docs.rs/foo/*/foo/struct.Foo.html"#]],
     );
 }
+
+#[test]
+fn doc_link_targets() {
+    check_doc_link_targets(
+        r#"
+//- /main.rs crate:main deps:foo
+//! See [`Bar`], [`foo::Foo`] and [the book](https://doc.rust-lang.org/book/).
+
+/// Links to [itself](Bar)$0 and to [`Baz`].
+pub struct Bar;
+/// [`Missing`] and [relative](../index.html) links are skipped.
+pub struct Baz;
+//- /lib.rs crate:foo
+pub struct Foo;
+"#,
+        expect![[r#"
+            8..15 FileId(0) 134..137
+            17..29 FileId(1) 11..14
+            34..77 https://doc.rust-lang.org/book/
+            93..106 FileId(0) 134..137
+            114..121 FileId(0) 215..218
+        "#]],
+    );
+}
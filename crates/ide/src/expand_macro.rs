@@ -4,13 +4,20 @@ use ide_db::{
     RootDatabase,
 };
 use itertools::Itertools;
-use syntax::{ast, ted, AstNode, SyntaxKind, SyntaxNode};
+use rustc_hash::FxHashMap;
+use syntax::{ast, ted, AstNode, SyntaxKind, SyntaxNode, SyntaxToken, TextRange};
 
 use crate::FilePosition;
 
 pub struct ExpandedMacro {
     pub name: String,
     pub expansion: String,
+    /// Pairs of ranges of tokens in the macro call and of the tokens they became in
+    /// [`ExpandedMacro::expansion`].
+    ///
+    /// Only tokens substituted into the expansion of the outermost macro call are mapped, the
+    /// ones passed on to nested macro calls are not.
+    pub mappings: Vec<(TextRange, TextRange)>,
 }
 
 // Feature: Expand Macro Recursively
@@ -51,6 +58,7 @@ pub(crate) fn expand_macro(db: &RootDatabase, position: FilePosition) -> Option<
             Some(ExpandedMacro {
                 name: tt,
                 expansion: expansions.into_iter().map(insert_ws_into).join(""),
+                mappings: Vec::new(),
             })
         } else {
             None
@@ -65,17 +73,24 @@ pub(crate) fn expand_macro(db: &RootDatabase, position: FilePosition) -> Option<
     // currently we only recursively expand one of the two types
     let mut expanded = None;
     let mut name = None;
+    let mut origins = Vec::new();
     for node in tok.ancestors() {
         if let Some(item) = ast::Item::cast(node.clone()) {
             if let Some(def) = sema.resolve_attr_macro_call(&item) {
                 name = def.name(db).map(|name| name.to_string());
-                expanded = expand_attr_macro_recur(&sema, &item);
+                let expansion = sema.expand_attr_macro(&item)?;
+                let first_level = expansion.clone_for_update();
+                origins = token_origins(&sema, item.syntax(), &expansion, &first_level);
+                expanded = expand(&sema, first_level, ast::Item::cast, expand_attr_macro_recur);
                 break;
             }
         }
         if let Some(mac) = ast::MacroCall::cast(node) {
             name = Some(mac.path()?.segment()?.name_ref()?.to_string());
-            expanded = expand_macro_recur(&sema, &mac);
+            let expansion = sema.expand(&mac)?;
+            let first_level = expansion.clone_for_update();
+            origins = token_origins(&sema, mac.token_tree()?.syntax(), &expansion, &first_level);
+            expanded = expand(&sema, first_level, ast::MacroCall::cast, expand_macro_recur);
             break;
         }
     }
@@ -83,8 +98,55 @@ pub(crate) fn expand_macro(db: &RootDatabase, position: FilePosition) -> Option<
     // FIXME:
     // macro expansion may lose all white space information
     // But we hope someday we can use ra_fmt for that
-    let expansion = insert_ws_into(expanded?).to_string();
-    Some(ExpandedMacro { name: name.unwrap_or_else(|| "???".to_owned()), expansion })
+    let expanded = expanded?;
+    let formatted = insert_ws_into(expanded.clone());
+    // Formatting only adds whitespace, so the other tokens of both trees line up. Tokens change
+    // their position when nested calls get expanded, so they are only hashed after that.
+    let formatted_tokens: FxHashMap<SyntaxToken, TextRange> = non_whitespace_tokens(&expanded)
+        .zip(non_whitespace_tokens(&formatted).map(|it| it.text_range()))
+        .collect();
+    let mappings = origins
+        .into_iter()
+        .filter(|(token, _)| token.ancestors().last().as_ref() == Some(&expanded))
+        .filter_map(|(token, source)| Some((source, *formatted_tokens.get(&token)?)))
+        .collect();
+    Some(ExpandedMacro {
+        name: name.unwrap_or_else(|| "???".to_owned()),
+        expansion: formatted.to_string(),
+        mappings,
+    })
+}
+
+/// Finds the tokens of `source` which the tokens of `expansion` come from, returning the
+/// corresponding tokens of its mutable copy `first_level`.
+fn token_origins(
+    sema: &Semantics<RootDatabase>,
+    source: &SyntaxNode,
+    expansion: &SyntaxNode,
+    first_level: &SyntaxNode,
+) -> Vec<(SyntaxToken, TextRange)> {
+    let indices: FxHashMap<SyntaxToken, usize> =
+        tokens(expansion).enumerate().map(|(idx, token)| (token, idx)).collect();
+    let mutable_tokens: Vec<_> = tokens(first_level).collect();
+    tokens(source)
+        .filter(|token| !token.kind().is_trivia())
+        .flat_map(|token| {
+            let range = token.text_range();
+            sema.descend_into_macros(token)
+                .into_iter()
+                .filter_map(|descended| indices.get(&descended).copied())
+                .map(move |idx| (idx, range))
+        })
+        .map(|(idx, range)| (mutable_tokens[idx].clone(), range))
+        .collect()
+}
+
+fn tokens(node: &SyntaxNode) -> impl Iterator<Item = SyntaxToken> {
+    node.descendants_with_tokens().filter_map(|it| it.into_token())
+}
+
+fn non_whitespace_tokens(node: &SyntaxNode) -> impl Iterator<Item = SyntaxToken> {
+    tokens(node).filter(|it| it.kind() != SyntaxKind::WHITESPACE)
 }
 
 fn expand_macro_recur(
@@ -127,6 +189,8 @@ fn expand<T: AstNode>(
 mod tests {
     use expect_test::{expect, Expect};
 
+    use itertools::Itertools;
+
     use crate::fixture;
 
     #[track_caller]
@@ -137,6 +201,21 @@ mod tests {
         expect.assert_eq(&actual);
     }
 
+    #[track_caller]
+    fn check_mappings(ra_fixture: &str, expect: Expect) {
+        let (analysis, pos) = fixture::position(ra_fixture);
+        let source = analysis.file_text(pos.file_id).unwrap();
+        let expansion = analysis.expand_macro(pos).unwrap().unwrap();
+        let actual = expansion
+            .mappings
+            .iter()
+            .map(|&(from, to)| {
+                format!("{:?} {} -> {:?} {}", from, &source[from], to, &expansion.expansion[to])
+            })
+            .join("\n");
+        expect.assert_eq(&actual);
+    }
+
     #[test]
     fn macro_expand_as_keyword() {
         check(
@@ -361,4 +440,48 @@ struct Foo {}
             "#]],
         );
     }
+
+    #[test]
+    fn macro_expand_mappings() {
+        check_mappings(
+            r#"
+macro_rules! vec {
+    ($($item:expr),*) => {{
+        let mut v = Vec::new();
+        $(v.push($item);)*
+        v
+    }};
+}
+fn main() {
+    let v = v$0ec![1, 2 + 3, four()];
+}
+"#,
+            expect![[r#"
+                155..156 1 -> 37..38 1
+                158..159 2 -> 51..52 2
+                160..161 + -> 52..53 +
+                162..163 3 -> 53..54 3
+                165..169 four -> 68..72 four
+                169..170 ( -> 72..73 (
+                170..171 ) -> 73..74 )"#]],
+        );
+    }
+
+    #[test]
+    fn macro_expand_mappings_skip_nested_calls() {
+        check_mappings(
+            r#"
+macro_rules! inner {
+    ($e:expr) => { $e };
+}
+macro_rules! outer {
+    ($a:expr, $b:expr) => { $a + inner!($b) };
+}
+fn main() {
+    let x = out$0er!(1, 2);
+}
+"#,
+            expect![[r#"149..150 1 -> 0..1 1"#]],
+        );
+    }
 }
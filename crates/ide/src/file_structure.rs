@@ -576,4 +576,90 @@ fn g() {}
             "#]],
         );
     }
+
+    #[test]
+    fn test_nested_impl_in_module() {
+        check(
+            r#"
+mod m {
+    struct S;
+    impl S {
+        fn new() -> S { S }
+        fn get(&self) {}
+    }
+}
+"#,
+            expect![[r#"
+                [
+                    StructureNode {
+                        parent: None,
+                        label: "m",
+                        navigation_range: 5..6,
+                        node_range: 1..96,
+                        kind: SymbolKind(
+                            Module,
+                        ),
+                        detail: None,
+                        deprecated: false,
+                    },
+                    StructureNode {
+                        parent: Some(
+                            0,
+                        ),
+                        label: "S",
+                        navigation_range: 20..21,
+                        node_range: 13..22,
+                        kind: SymbolKind(
+                            Struct,
+                        ),
+                        detail: None,
+                        deprecated: false,
+                    },
+                    StructureNode {
+                        parent: Some(
+                            0,
+                        ),
+                        label: "impl S",
+                        navigation_range: 32..33,
+                        node_range: 27..94,
+                        kind: SymbolKind(
+                            Impl,
+                        ),
+                        detail: None,
+                        deprecated: false,
+                    },
+                    StructureNode {
+                        parent: Some(
+                            2,
+                        ),
+                        label: "new",
+                        navigation_range: 47..50,
+                        node_range: 44..63,
+                        kind: SymbolKind(
+                            Function,
+                        ),
+                        detail: Some(
+                            "fn() -> S",
+                        ),
+                        deprecated: false,
+                    },
+                    StructureNode {
+                        parent: Some(
+                            2,
+                        ),
+                        label: "get",
+                        navigation_range: 75..78,
+                        node_range: 72..88,
+                        kind: SymbolKind(
+                            Function,
+                        ),
+                        detail: Some(
+                            "fn(&self)",
+                        ),
+                        deprecated: false,
+                    },
+                ]
+            "#]],
+        );
+    }
 }
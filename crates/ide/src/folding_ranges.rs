@@ -2,10 +2,10 @@ use ide_db::helpers::node_ext::vis_eq;
 use rustc_hash::FxHashSet;
 
 use syntax::{
-    ast::{self, AstNode, AstToken},
+    ast::{self, AstNode, AstToken, HasAttrs},
     match_ast, Direction, NodeOrToken, SourceFile,
     SyntaxKind::{self, *},
-    TextRange, TextSize,
+    SyntaxNode, TextRange, TextSize,
 };
 
 use std::hash::Hash;
@@ -26,6 +26,7 @@ pub enum FoldKind {
     Array,
     WhereClause,
     ReturnType,
+    Cfg,
 }
 
 #[derive(Debug)]
@@ -37,7 +38,7 @@ pub struct Fold {
 // Feature: Folding
 //
 // Defines folding regions for curly braced blocks, runs of consecutive use, mod, const or static
-// items, and `region` / `endregion` comment markers.
+// items, multi-line items gated by `#[cfg(...)]`, and `region` / `endregion` comment markers.
 pub(crate) fn folding_ranges(file: &SourceFile) -> Vec<Fold> {
     let mut res = vec![];
     let mut visited_comments = FxHashSet::default();
@@ -87,6 +88,9 @@ pub(crate) fn folding_ranges(file: &SourceFile) -> Vec<Fold> {
                 }
             }
             NodeOrToken::Node(node) => {
+                if let Some(range) = fold_range_for_cfg_item(&node) {
+                    res.push(Fold { range, kind: FoldKind::Cfg });
+                }
                 match_ast! {
                     match node {
                         ast::Module(module) => {
@@ -266,6 +270,27 @@ fn fold_range_for_where_clause(where_clause: ast::WhereClause) -> Option<TextRan
     None
 }
 
+fn fold_range_for_cfg_item(node: &SyntaxNode) -> Option<TextRange> {
+    let item = ast::Item::cast(node.clone())?;
+    let has_cfg = item.attrs().any(|attr| attr.simple_name().map_or(false, |name| name == "cfg"));
+    if !has_cfg {
+        return None;
+    }
+    // Only fold the item if it spans multiple lines without its attributes, so that
+    // `#[cfg(test)] mod tests;` on separate lines is left alone.
+    let body_start = node
+        .children_with_tokens()
+        .find(|it| !matches!(it.kind(), ATTR | COMMENT | WHITESPACE))?
+        .text_range()
+        .start();
+    let body = TextRange::new(body_start, node.text_range().end()) - node.text_range().start();
+    if node.text().slice(body).contains_char('\n') {
+        Some(node.text_range())
+    } else {
+        None
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use test_utils::extract_tags;
@@ -301,6 +326,7 @@ mod tests {
                 FoldKind::Array => "array",
                 FoldKind::WhereClause => "whereclause",
                 FoldKind::ReturnType => "returntype",
+                FoldKind::Cfg => "cfg",
             };
             assert_eq!(kind, &attr.unwrap());
         }
@@ -575,6 +601,25 @@ fn foo()<fold returntype>-> (
 )</fold> { (true, true) }
 
 fn bar() -> (bool, bool) { (true, true) }
+"#,
+        )
+    }
+
+    #[test]
+    fn fold_cfg_items() {
+        check(
+            r#"
+<fold cfg>#[cfg(test)]
+mod tests <fold block>{
+    #[cfg(feature = "foo")] fn foo() {}
+    <fold cfg>#[cfg(unix)]
+    fn bar() <fold block>{
+    }</fold></fold>
+}</fold></fold>
+
+#[inline]
+fn baz() <fold block>{
+}</fold>
 "#,
         )
     }
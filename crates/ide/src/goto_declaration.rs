@@ -1,15 +1,25 @@
-use hir::Semantics;
+use hir::{ScopeDef, Semantics};
 use ide_db::{
     defs::{Definition, NameClass, NameRefClass},
     RootDatabase,
 };
-use syntax::{ast, match_ast, AstNode, SyntaxKind::*, T};
+use syntax::{algo::find_node_at_range, ast, match_ast, AstNode, SyntaxKind::*, SyntaxNode, T};
 
-use crate::{FilePosition, NavigationTarget, RangeInfo};
+use crate::{
+    navigation_target::{ToNav, TryToNav},
+    FilePosition, NavigationTarget, RangeInfo,
+};
 
 // Feature: Go to Declaration
 //
 // Navigates to the declaration of an identifier.
+//
+// This differs from go to definition for
+//
+// * modules, where it goes to the `mod` item that declares the module
+// * paths in `use` items, where it goes to where the path starts: the `extern crate` item or the
+//   root of the crate it comes from, or the module or item named by its first segment
+// * local variables that shadow another variable, where it goes to the original variable
 pub(crate) fn goto_declaration(
     db: &RootDatabase,
     position: FilePosition,
@@ -27,9 +37,14 @@ pub(crate) fn goto_declaration(
             let parent = token.parent()?;
             let def = match_ast! {
                 match parent {
-                    ast::NameRef(name_ref) => match NameRefClass::classify(&sema, &name_ref)? {
-                        NameRefClass::Definition(it) => Some(it),
-                        _ => None
+                    ast::NameRef(name_ref) => {
+                        if let Some(nav) = use_path_origin(&sema, &name_ref) {
+                            return Some(nav);
+                        }
+                        match NameRefClass::classify(&sema, &name_ref)? {
+                            NameRefClass::Definition(it) => Some(it),
+                            _ => None
+                        }
                     },
                     ast::Name(name) => match NameClass::classify(&sema, &name)? {
                         NameClass::Definition(it) => Some(it),
@@ -42,14 +57,104 @@ pub(crate) fn goto_declaration(
                 Definition::Module(module) => {
                     Some(NavigationTarget::from_module_to_decl(db, module))
                 }
+                Definition::Local(local) => Some(original_local(&sema, local).to_nav(db)),
                 _ => None,
             }
         })
         .collect();
 
+    if info.is_empty() {
+        return None;
+    }
     Some(RangeInfo::new(range, info))
 }
 
+/// For a path in a `use` item, navigates to where the path starts.
+fn use_path_origin(
+    sema: &Semantics<RootDatabase>,
+    name_ref: &ast::NameRef,
+) -> Option<NavigationTarget> {
+    let db = sema.db;
+    let path = name_ref.syntax().parent().and_then(ast::PathSegment::cast)?.parent_path();
+    let mut use_tree = path.top_path().syntax().parent().and_then(ast::UseTree::cast)?;
+    let mut first_path = use_tree.path()?;
+    while let Some(list) = use_tree.syntax().parent().and_then(ast::UseTreeList::cast) {
+        use_tree = list.parent_use_tree();
+        if let Some(path) = use_tree.path() {
+            first_path = path;
+        }
+    }
+    let first_segment = first_path.first_segment()?.name_ref()?;
+
+    let def = match NameRefClass::classify(sema, &first_segment)? {
+        NameRefClass::Definition(it) => it,
+        NameRefClass::FieldShorthand { .. } => return None,
+    };
+    let module = match def {
+        Definition::Module(it) => it,
+        def => return def.try_to_nav(db),
+    };
+
+    let from = sema.scope(name_ref.syntax()).module()?;
+    let krate = module.krate();
+    if krate != from.krate() && module == krate.root_module(db) {
+        let extern_crate = [from, from.krate().root_module(db)]
+            .into_iter()
+            .flat_map(|it| it.extern_crate_decls(db))
+            .find(|decl| {
+                decl.krate(db) == Some(krate)
+                    && decl.rename(db).unwrap_or_else(|| decl.name(db)).to_smol_str()
+                        == first_segment.text().as_str()
+            });
+        if let Some(nav) = extern_crate.and_then(|it| it.try_to_nav(db)) {
+            return Some(nav);
+        }
+    }
+    Some(NavigationTarget::from_module_to_decl(db, module))
+}
+
+/// Follows a chain of shadowing `let`s (or other bindings) back to the first variable of that name.
+fn original_local(sema: &Semantics<RootDatabase>, mut local: hir::Local) -> hir::Local {
+    while let Some(shadowed) = shadowed_local(sema, local) {
+        local = shadowed;
+    }
+    local
+}
+
+fn shadowed_local(sema: &Semantics<RootDatabase>, local: hir::Local) -> Option<hir::Local> {
+    let name = local.name(sema.db)?;
+    let src = local.source(sema.db);
+    let pat = src.value.left()?;
+    let root = sema.parse_or_expand(src.file_id)?;
+    let pat = find_node_at_range::<ast::IdentPat>(&root, pat.syntax().text_range())?;
+
+    let mut res = None;
+    sema.scope(&binding_site(&pat)?).process_all_names(&mut |it, def| match def {
+        ScopeDef::Local(shadowed) if res.is_none() && it == name && shadowed != local => {
+            res = Some(shadowed)
+        }
+        _ => (),
+    });
+    res
+}
+
+/// A node from which the bindings introduced by `pat` aren't visible yet.
+fn binding_site(pat: &ast::IdentPat) -> Option<SyntaxNode> {
+    pat.syntax().ancestors().find_map(|node| {
+        let expr = match_ast! {
+            match node {
+                ast::LetStmt(it) => it.initializer(),
+                ast::Condition(it) => it.expr(),
+                ast::MatchArm(it) => it.syntax().ancestors().find_map(ast::MatchExpr::cast)?.expr(),
+                ast::ForExpr(it) => it.iterable(),
+                ast::ClosureExpr(it) => Some(it.into()),
+                _ => return None,
+            }
+        };
+        expr.map(|it| it.syntax().clone())
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use ide_db::base_db::FileRange;
@@ -106,4 +211,121 @@ mod foo {
 "#,
         )
     }
+
+    #[test]
+    fn goto_decl_use_path_to_extern_crate() {
+        check(
+            r#"
+//- /main.rs crate:main deps:std
+extern crate std as stdlib;
+                 // ^^^^^^
+use stdlib::collections::HashMap$0;
+//- /std.rs crate:std
+pub mod collections {
+    pub struct HashMap;
+}
+"#,
+        )
+    }
+
+    #[test]
+    fn goto_decl_nested_use_path_to_extern_crate() {
+        check(
+            r#"
+//- /main.rs crate:main deps:std
+extern crate std;
+          // ^^^
+use std::{collections::{HashMap$0}, fmt};
+//- /std.rs crate:std
+pub mod collections {
+    pub struct HashMap;
+}
+pub mod fmt {}
+"#,
+        )
+    }
+
+    #[test]
+    fn goto_decl_use_path_to_crate_root() {
+        check(
+            r#"
+//- /main.rs crate:main deps:dep
+use dep::Thing$0;
+//- /dep.rs crate:dep
+pub struct Thing;
+//^file
+"#,
+        )
+    }
+
+    #[test]
+    fn goto_decl_use_path_to_module() {
+        check(
+            r#"
+mod foo {
+ // ^^^
+    pub mod bar {
+        pub struct Baz;
+    }
+}
+use foo::bar::Baz$0;
+"#,
+        )
+    }
+
+    #[test]
+    fn goto_decl_shadowed_local() {
+        check(
+            r#"
+fn main() {
+    let x = 0;
+     // ^
+    let x = x + 1;
+    let x = x * 2;
+    x$0;
+}
+"#,
+        );
+        check(
+            r#"
+fn main() {
+    let x = 0;
+     // ^
+    let x$0 = x + 1;
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn goto_decl_shadowed_local_in_pattern() {
+        check(
+            r#"
+//- minicore: option
+fn f(x: Option<u32>) {
+  // ^
+    if let Some(x) = x {
+        match Some(x) {
+            Some(x) => { x$0; }
+            None => {}
+        }
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn goto_decl_unshadowed_local() {
+        check(
+            r#"
+fn main() {
+    let x = 0;
+     // ^
+    let y = 0;
+    x$0 + y;
+}
+"#,
+        );
+    }
 }
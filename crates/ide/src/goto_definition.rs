@@ -31,6 +31,8 @@ pub(crate) fn goto_definition(
     let original_token =
         pick_best_token(file.token_at_offset(position.offset), |kind| match kind {
             IDENT | INT_NUMBER | LIFETIME_IDENT | T![self] | T![super] | T![crate] | COMMENT => 2,
+            // The `!` of a macro call, which is preferred over the following delimiter.
+            T![!] => 2,
             kind if kind.is_trivia() => 0,
             _ => 1,
         })?;
@@ -40,6 +42,9 @@ pub(crate) fn goto_definition(
             Some(RangeInfo::new(original_token.text_range(), vec![nav]))
         });
     }
+    if let Some(navs) = try_lookup_macro_call_bang(sema, &original_token) {
+        return Some(RangeInfo::new(original_token.text_range(), navs));
+    }
     let navs = sema
         .descend_into_macros(original_token.clone())
         .into_iter()
@@ -96,6 +101,19 @@ fn try_lookup_include_path(
     }])
 }
 
+/// Handles the cursor being on the `!` of a macro call, which isn't part of its path.
+fn try_lookup_macro_call_bang(
+    sema: &Semantics<RootDatabase>,
+    token: &SyntaxToken,
+) -> Option<Vec<NavigationTarget>> {
+    if token.kind() != T![!] {
+        return None;
+    }
+    let macro_call = token.parent().and_then(ast::MacroCall::cast)?;
+    let mac = sema.resolve_macro_call(&macro_call)?;
+    Some(def_to_nav(sema.db, Definition::Macro(mac)))
+}
+
 /// finds the trait definition of an impl'd item
 /// e.g.
 /// ```rust
@@ -291,6 +309,19 @@ fn bar() {
         );
     }
 
+    #[test]
+    fn goto_def_for_macros_from_bang() {
+        check(
+            r#"
+macro_rules! foo { () => { () } }
+           //^^^
+fn bar() {
+    foo!$0();
+}
+"#,
+        );
+    }
+
     #[test]
     fn goto_def_for_macros_from_other_crates() {
         check(
@@ -309,6 +340,55 @@ macro_rules! foo { () => { () } }
         );
     }
 
+    #[test]
+    fn goto_def_for_renamed_macros_from_other_crates() {
+        check(
+            r#"
+//- /lib.rs crate:main deps:foo
+use foo::foo as baz;
+fn bar() {
+    baz$0!();
+}
+
+//- /foo/lib.rs crate:foo
+#[macro_export]
+macro_rules! foo { () => { () } }
+           //^^^
+"#,
+        );
+        check(
+            r#"
+//- /lib.rs crate:main deps:foo
+use foo::foo as baz;
+fn bar() {
+    baz!$0();
+}
+
+//- /foo/lib.rs crate:foo
+#[macro_export]
+macro_rules! foo { () => { () } }
+           //^^^
+"#,
+        );
+    }
+
+    #[test]
+    fn goto_def_for_macros_reexported_with_use() {
+        check(
+            r#"
+mod m {
+    macro_rules! foo { () => { () } }
+               //^^^
+    pub(crate) use foo;
+}
+use m::foo;
+fn bar() {
+    foo$0!();
+}
+"#,
+        );
+    }
+
     #[test]
     fn goto_def_for_macros_in_use_tree() {
         check(
@@ -424,6 +504,125 @@ macro_rules! foo { () => { () } }
         );
     }
 
+    #[test]
+    fn goto_def_for_use_self_path() {
+        check(
+            r#"
+mod outer {
+    use self::sibling$0;
+    mod sibling {}
+      //^^^^^^^
+}
+"#,
+        );
+        check(
+            r#"
+mod outer {
+    use self::sibling::Item$0;
+    mod sibling {
+        pub struct Item;
+                 //^^^^
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn goto_def_for_use_super_path() {
+        check(
+            r#"
+fn parent() {}
+ //^^^^^^
+mod child {
+    use super::parent$0;
+}
+"#,
+        );
+        check(
+            r#"
+//- /lib.rs
+mod a;
+pub struct Root;
+         //^^^^
+//- /a.rs
+mod b;
+//- /a/b.rs
+use super::super::Root$0;
+"#,
+        );
+        check(
+            r#"
+mod a {
+  //^
+    pub mod b {
+        use super$0::super::Root;
+    }
+}
+pub struct Root;
+"#,
+        );
+        check(
+            r#"
+//- /lib.rs
+//^file
+mod a {
+    pub mod b {
+        use super::super$0::Root;
+    }
+}
+pub struct Root;
+"#,
+        );
+    }
+
+    #[test]
+    fn goto_def_for_relative_use_tree() {
+        check(
+            r#"
+fn parent() {}
+ //^^^^^^
+mod child {
+    use super::{parent$0, sibling};
+    mod sibling {}
+}
+"#,
+        );
+        check(
+            r#"
+mod outer {
+    use self::sibling::{self$0};
+    mod sibling {}
+      //^^^^^^^
+}
+"#,
+        );
+        check(
+            r#"
+mod outer {
+  //^^^^^
+    use self$0::sibling;
+    mod sibling {}
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn goto_def_for_use_super_in_block_module() {
+        check(
+            r#"
+struct Root;
+     //^^^^
+fn f() {
+    mod m {
+        use super::Root$0;
+    }
+}
+"#,
+        );
+    }
+
     #[test]
     fn goto_def_for_methods() {
         check(
@@ -1236,6 +1435,23 @@ use mac::fn_macro;
 
 fn_macro$0!();
 
+//- /mac.rs crate:mac
+#[proc_macro]
+fn fn_macro() {}
+ //^^^^^^^^
+            "#,
+        )
+    }
+
+    #[test]
+    fn goto_renamed_proc_macro_from_bang() {
+        check(
+            r#"
+//- /main.rs crate:main deps:mac
+use mac::fn_macro as m;
+
+m!$0();
+
 //- /mac.rs crate:mac
 #[proc_macro]
 fn fn_macro() {}
@@ -5,13 +5,17 @@ use ide_db::{
     RootDatabase,
 };
 use itertools::Itertools;
-use syntax::{ast, AstNode, SyntaxKind::*, T};
+use syntax::{ast, AstNode, SyntaxKind::*, SyntaxToken, T};
 
 use crate::{FilePosition, NavigationTarget, RangeInfo, TryToNav};
 
 // Feature: Go to Implementation
 //
 // Navigates to the impl block of structs, enums or traits. Also implemented as a code lens.
+// On a method call with a known receiver type, navigates to the method of the impl that is called.
+//
+// In VS Code, **Rust Analyzer: Navigate to Implementations** lists the implementing types and
+// their files in a quick pick instead of the peek view.
 //
 // |===
 // | Editor  | Shortcut
@@ -34,6 +38,9 @@ pub(crate) fn goto_implementation(
             _ => 0,
         })?;
     let range = original_token.text_range();
+    if let Some(navs) = impl_method_of_call(&sema, &original_token) {
+        return Some(RangeInfo { range, info: navs });
+    }
     let navs = sema
         .descend_into_macros(original_token)
         .into_iter()
@@ -89,6 +96,24 @@ pub(crate) fn goto_implementation(
     Some(RangeInfo { range, info: navs })
 }
 
+fn impl_method_of_call(
+    sema: &Semantics<RootDatabase>,
+    token: &SyntaxToken,
+) -> Option<Vec<NavigationTarget>> {
+    let navs: Vec<_> = sema
+        .descend_into_macros(token.clone())
+        .into_iter()
+        .filter_map(|token| {
+            let name_ref = token.parent().and_then(ast::NameRef::cast)?;
+            let call = name_ref.syntax().parent().and_then(ast::MethodCallExpr::cast)?;
+            let func = sema.resolve_impl_method(&call)?;
+            func.as_assoc_item(sema.db)?.containing_trait_impl(sema.db)?;
+            func.try_to_nav(sema.db)
+        })
+        .collect();
+    (!navs.is_empty()).then(|| navs)
+}
+
 fn impls_for_ty(sema: &Semantics<RootDatabase>, ty: hir::Type) -> Vec<NavigationTarget> {
     Impl::all_for_type(sema.db, ty).into_iter().filter_map(|imp| imp.try_to_nav(sema.db)).collect()
 }
@@ -324,6 +349,83 @@ impl Tr for S {
         );
     }
 
+    #[test]
+    fn goto_implementation_of_method_call_on_concrete_type() {
+        check(
+            r#"
+trait Tr {
+    fn f(&self);
+}
+
+struct S;
+impl Tr for S {
+    fn f(&self) {}
+     //^
+}
+
+struct T;
+impl Tr for T {
+    fn f(&self) {}
+}
+
+fn main() {
+    S.f$0();
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn goto_implementation_of_method_call_uses_generic_trait_args() {
+        check(
+            r#"
+trait Convert<T> {
+    fn convert(&self) -> T;
+}
+
+struct S;
+impl Convert<u32> for S {
+    fn convert(&self) -> u32 { 0 }
+}
+impl Convert<bool> for S {
+    fn convert(&self) -> bool { false }
+     //^^^^^^^
+}
+
+fn main() {
+    let b: bool = S.convert$0();
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn goto_implementation_of_method_call_on_trait_object() {
+        check(
+            r#"
+trait Tr {
+    fn f(&self);
+}
+
+struct S;
+impl Tr for S {
+    fn f(&self) {}
+     //^
+}
+
+struct T;
+impl Tr for T {
+    fn f(&self) {}
+     //^
+}
+
+fn main(tr: &dyn Tr) {
+    tr.f$0();
+}
+"#,
+        );
+    }
+
     #[test]
     fn goto_implementation_trait_assoc_const() {
         check(
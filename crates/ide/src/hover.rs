@@ -10,11 +10,14 @@ use hir::{HasSource, Semantics};
 use ide_db::{
     base_db::FileRange,
     defs::Definition,
+    format_args::FormatArgs,
     helpers::{pick_best_token, FamousDefs},
     FxIndexSet, RootDatabase,
 };
 use itertools::Itertools;
-use syntax::{ast, match_ast, AstNode, SyntaxKind::*, SyntaxNode, SyntaxToken, T};
+use syntax::{
+    ast, match_ast, AstNode, AstToken, SyntaxKind::*, SyntaxNode, SyntaxToken, TextSize, T,
+};
 
 use crate::{
     doc_links::token_as_doc_comment,
@@ -115,6 +118,10 @@ pub(crate) fn hover(
         });
     }
 
+    if let res @ Some(_) = hover_format_placeholder(sema, config, &original_token, offset) {
+        return res;
+    }
+
     let descended = sema.descend_into_macros(original_token.clone());
 
     // FIXME: Definition should include known lints and the like instead of having this special case here
@@ -146,6 +153,11 @@ pub(crate) fn hover(
         if let Some(res) = render::keyword(sema, config, &original_token) {
             return Some(RangeInfo::new(original_token.text_range(), res));
         }
+        if let res @ Some(_) =
+            descended.iter().find_map(|token| hover_range_expr(sema, config, token))
+        {
+            return res;
+        }
         if let res @ Some(_) =
             descended.iter().find_map(|token| hover_type_fallback(sema, config, token))
         {
@@ -252,6 +264,42 @@ fn hover_type_fallback(
     Some(RangeInfo::new(range, res))
 }
 
+fn hover_range_expr(
+    sema: &Semantics<RootDatabase>,
+    config: &HoverConfig,
+    token: &SyntaxToken,
+) -> Option<RangeInfo<HoverResult>> {
+    if !matches!(token.kind(), T![..] | T![..=]) {
+        return None;
+    }
+    let range_expr = token.parent().and_then(ast::RangeExpr::cast)?;
+    let ty = sema.resolve_range_expr(&range_expr)?;
+    let res = render::range_expr(sema, config, &ty);
+    let range = sema.original_range(range_expr.syntax()).range;
+    Some(RangeInfo::new(range, res))
+}
+
+fn hover_format_placeholder(
+    sema: &Semantics<RootDatabase>,
+    config: &HoverConfig,
+    token: &SyntaxToken,
+    offset: TextSize,
+) -> Option<RangeInfo<HoverResult>> {
+    if token.kind() != STRING {
+        return None;
+    }
+    let macro_call = token.parent()?.parent().and_then(ast::MacroCall::cast)?;
+    let format_args = FormatArgs::from_macro_call(&macro_call)?;
+    if format_args.format_string.syntax() != token {
+        return None;
+    }
+    let placeholder =
+        format_args.placeholders().into_iter().find(|it| it.range.contains_inclusive(offset))?;
+    let ty = format_args.arg_type(sema, &placeholder);
+    let res = render::format_placeholder(sema, config, &placeholder, ty);
+    Some(RangeInfo::new(placeholder.range, res))
+}
+
 fn show_implementations_action(db: &RootDatabase, def: Definition) -> Option<HoverAction> {
     fn to_action(nav_target: NavigationTarget) -> HoverAction {
         HoverAction::Implementation(FilePosition {
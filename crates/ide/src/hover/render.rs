@@ -6,6 +6,7 @@ use hir::{AsAssocItem, AttributeTemplate, HasAttrs, HasSource, HirDisplay, Seman
 use ide_db::{
     base_db::SourceDatabase,
     defs::Definition,
+    format_args::{FormatArgRef, FormatPlaceholder},
     helpers::{
         generated_lints::{CLIPPY_LINTS, DEFAULT_LINTS, FEATURES},
         FamousDefs,
@@ -74,6 +75,69 @@ pub(super) fn type_info(
     Some(res)
 }
 
+pub(super) fn range_expr(
+    sema: &Semantics<RootDatabase>,
+    config: &HoverConfig,
+    ty: &hir::Type,
+) -> HoverResult {
+    let mut res = HoverResult::default();
+    let mut targets: Vec<hir::ModuleDef> = Vec::new();
+    walk_and_push_ty(sema.db, ty, &mut |item| {
+        if !targets.contains(&item) {
+            targets.push(item);
+        }
+    });
+    res.markup = if config.markdown() {
+        Markup::fenced_block(&ty.display(sema.db))
+    } else {
+        ty.display(sema.db).to_string().into()
+    };
+    res.actions.push(HoverAction::goto_type_from_targets(sema.db, targets));
+    res
+}
+
+pub(super) fn format_placeholder(
+    sema: &Semantics<RootDatabase>,
+    config: &HoverConfig,
+    placeholder: &FormatPlaceholder,
+    ty: Option<hir::Type>,
+) -> HoverResult {
+    let mut res = HoverResult::default();
+    let arg = match &placeholder.arg {
+        FormatArgRef::Index(idx) => idx.to_string(),
+        FormatArgRef::Name(name) => name.to_string(),
+    };
+    let required = format!("{}: impl {}", arg, placeholder.trait_.name());
+    res.markup = if config.markdown() { Markup::fenced_block(&required) } else { required.into() };
+    if let Some(ty) = ty {
+        let mut targets: Vec<hir::ModuleDef> = Vec::new();
+        walk_and_push_ty(sema.db, &ty, &mut |item| {
+            if !targets.contains(&item) {
+                targets.push(item);
+            }
+        });
+        let ty = ty.display(sema.db).to_string();
+        res.markup = if config.markdown() {
+            format!(
+                "{}
+___
+Argument type: `{}`",
+                res.markup, ty
+            )
+            .into()
+        } else {
+            format!(
+                "{}
+Argument type: {}",
+                res.markup, ty
+            )
+            .into()
+        };
+        res.actions.push(HoverAction::goto_type_from_targets(sema.db, targets));
+    }
+    res
+}
+
 pub(super) fn try_expr(
     sema: &Semantics<RootDatabase>,
     config: &HoverConfig,
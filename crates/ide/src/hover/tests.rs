@@ -592,6 +592,21 @@ const foo$0: u32 = {
             ```
         "#]],
     );
+
+    check(
+        r#"static mut bar$0: u32 = 789;"#,
+        expect![[r#"
+            *bar*
+
+            ```rust
+            test
+            ```
+
+            ```rust
+            static mut bar: u32 = 789
+            ```
+        "#]],
+    );
 }
 
 #[test]
@@ -4485,3 +4500,162 @@ pub struct Foo;
         "##]],
     );
 }
+
+#[test]
+fn hover_range_expr_operators() {
+    check(
+        r#"
+//- minicore: range
+fn f() { let _ = 0.$0.10; }
+"#,
+        expect![[r#"
+            *0..10*
+            ```rust
+            Range<i32>
+            ```
+        "#]],
+    );
+    check(
+        r#"
+//- minicore: range
+fn f() { let _ = 0..$0=10; }
+"#,
+        expect![[r#"
+            *0..=10*
+            ```rust
+            RangeInclusive<i32>
+            ```
+        "#]],
+    );
+    check(
+        r#"
+//- minicore: range
+fn f() { let _ = .$0.10; }
+"#,
+        expect![[r#"
+            *..10*
+            ```rust
+            RangeTo<i32>
+            ```
+        "#]],
+    );
+    check(
+        r#"
+//- minicore: range
+fn f() { let _ = ..$0=10; }
+"#,
+        expect![[r#"
+            *..=10*
+            ```rust
+            RangeToInclusive<i32>
+            ```
+        "#]],
+    );
+    check(
+        r#"
+//- minicore: range
+fn f() { let _ = 0.$0.; }
+"#,
+        expect![[r#"
+            *0..*
+            ```rust
+            RangeFrom<i32>
+            ```
+        "#]],
+    );
+    check(
+        r#"
+//- minicore: range
+fn f() { let _ = .$0.; }
+"#,
+        expect![[r#"
+            *..*
+            ```rust
+            RangeFull
+            ```
+        "#]],
+    );
+}
+
+#[test]
+fn hover_format_placeholder_of_named_arg() {
+    check(
+        r#"
+#[rustc_builtin_macro]
+macro_rules! format_args {}
+fn main() {
+    let value = 1.5f64;
+    format_args!("{x} {na$0me:.2}", x = 1, name = value);
+}
+"#,
+        expect![[r#"
+            *{name:.2}*
+            ```rust
+            name: impl Display
+            ```
+            ___
+            Argument type: `f64`
+        "#]],
+    );
+}
+
+#[test]
+fn hover_format_placeholder_of_captured_variable() {
+    check(
+        r#"
+#[rustc_builtin_macro]
+macro_rules! format_args {}
+struct S;
+fn main() {
+    let name = S;
+    let _ = format_args!("{name:$0?}");
+}
+"#,
+        expect![[r#"
+            *{name:?}*
+            ```rust
+            name: impl Debug
+            ```
+            ___
+            Argument type: `S`
+        "#]],
+    );
+}
+
+#[test]
+fn hover_format_placeholder_of_positional_arg() {
+    check(
+        r#"
+#[rustc_builtin_macro]
+macro_rules! format_args {}
+macro_rules! format {
+    ($($arg:tt)*) => { format_args!($($arg)*) };
+}
+fn main() {
+    format!("{} {$0:#x}", "a", 2u8);
+}
+"#,
+        expect![[r##"
+            *{:#x}*
+            ```rust
+            1: impl LowerHex
+            ```
+            ___
+            Argument type: `u8`
+        "##]],
+    );
+    check_hover_no_markdown(
+        r#"
+macro_rules! println {
+    ($($arg:tt)*) => {};
+}
+fn main() {
+    println!("{}$0", missing);
+}
+"#,
+        expect![[r#"
+            *{}*
+            0: impl Display
+        "#]],
+    );
+}
@@ -1,3 +1,5 @@
+use std::iter;
+
 use either::Either;
 use hir::{known, Callable, HasVisibility, HirDisplay, Semantics, TypeInfo};
 use ide_db::{base_db::FileRange, helpers::FamousDefs, RootDatabase};
@@ -5,18 +7,37 @@ use itertools::Itertools;
 use stdx::to_lower_snake_case;
 use syntax::{
     ast::{self, AstNode, HasArgList, HasName, UnaryOp},
-    match_ast, Direction, NodeOrToken, SmolStr, SyntaxKind, TextRange, T,
+    match_ast, NodeOrToken, SmolStr, TextRange,
 };
 
 use crate::FileId;
 
+mod binding_mode;
+mod borrow_lifetime;
+mod cfg;
+mod chaining;
+mod generic_args;
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct InlayHintsConfig {
     pub type_hints: bool,
     pub parameter_hints: bool,
     pub chaining_hints: bool,
+    /// Whether type hints are also shown for closure parameters.
+    pub closure_param_type_hints: bool,
+    /// Whether to show the `ref` or `ref mut` that match ergonomics implicitly add to bindings.
+    pub binding_mode_hints: bool,
+    /// Whether to show the inferred type arguments of generic function calls.
+    pub generic_parameter_hints: bool,
+    /// Whether to show if the `#[cfg]` conditions of items are enabled.
+    pub cfg_hints: bool,
+    /// Whether to show the lifetime of borrows which don't borrow a local directly.
+    pub lifetime_hints: bool,
     pub hide_named_constructor_hints: bool,
     pub max_length: Option<usize>,
+    /// Whether to compute tooltips right away instead of leaving them to
+    /// [`inlay_hint_tooltip`].
+    pub render_tooltips: bool,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -24,6 +45,10 @@ pub enum InlayKind {
     TypeHint,
     ParameterHint,
     ChainingHint,
+    BindingModeHint,
+    GenericArgsHint,
+    CfgHint,
+    LifetimeHint,
 }
 
 #[derive(Debug)]
@@ -31,6 +56,7 @@ pub struct InlayHint {
     pub range: TextRange,
     pub kind: InlayKind,
     pub label: SmolStr,
+    pub tooltip: Option<String>,
 }
 
 // Feature: Inlay Hints
@@ -42,7 +68,11 @@ pub struct InlayHint {
 //
 // * types of local variables
 // * names of function arguments
-// * types of chained expressions
+// * types of chained expressions, where they change along the chain
+// * implicit `ref` and `ref mut` binding modes in patterns
+// * inferred type arguments of generic function calls
+// * whether the `#[cfg]` conditions of items are enabled
+// * the named lifetime of borrows of fields, elements and referents
 //
 // **Note:** VS Code does not have native support for inlay hints https://github.com/microsoft/vscode/issues/16221[yet] and the hints are implemented using decorations.
 // This approach has limitations, the caret movement and bracket highlighting near the edges of the hint may be weird:
@@ -69,7 +99,9 @@ pub(crate) fn inlay_hints(
 
     for node in file.descendants() {
         if let Some(expr) = ast::Expr::cast(node.clone()) {
-            get_chaining_hints(&mut res, &sema, config, &expr);
+            chaining::hints(&mut res, &sema, config, &expr);
+            generic_args::hints(&mut res, &sema, config, &expr);
+            borrow_lifetime::hints(&mut res, &sema, config, &expr);
             match expr {
                 ast::Expr::CallExpr(it) => {
                     get_param_name_hints(&mut res, &sema, config, ast::Expr::from(it));
@@ -80,73 +112,15 @@ pub(crate) fn inlay_hints(
                 _ => (),
             }
         } else if let Some(it) = ast::IdentPat::cast(node.clone()) {
+            binding_mode::hints(&mut res, &sema, config, &it);
             get_bind_pat_hints(&mut res, &sema, config, &it);
+        } else if let Some(it) = ast::Item::cast(node.clone()) {
+            cfg::hints(&mut res, &sema, config, &it);
         }
     }
     res
 }
 
-fn get_chaining_hints(
-    acc: &mut Vec<InlayHint>,
-    sema: &Semantics<RootDatabase>,
-    config: &InlayHintsConfig,
-    expr: &ast::Expr,
-) -> Option<()> {
-    if !config.chaining_hints {
-        return None;
-    }
-
-    if matches!(expr, ast::Expr::RecordExpr(_)) {
-        return None;
-    }
-
-    let descended = sema.descend_node_into_attributes(expr.clone()).pop();
-    let desc_expr = descended.as_ref().unwrap_or(expr);
-    let krate = sema.scope(desc_expr.syntax()).module().map(|it| it.krate());
-    let famous_defs = FamousDefs(sema, krate);
-
-    let mut tokens = expr
-        .syntax()
-        .siblings_with_tokens(Direction::Next)
-        .filter_map(NodeOrToken::into_token)
-        .filter(|t| match t.kind() {
-            SyntaxKind::WHITESPACE if !t.text().contains('\n') => false,
-            SyntaxKind::COMMENT => false,
-            _ => true,
-        });
-
-    // Chaining can be defined as an expression whose next sibling tokens are newline and dot
-    // Ignoring extra whitespace and comments
-    let next = tokens.next()?.kind();
-    if next == SyntaxKind::WHITESPACE {
-        let mut next_next = tokens.next()?.kind();
-        while next_next == SyntaxKind::WHITESPACE {
-            next_next = tokens.next()?.kind();
-        }
-        if next_next == T![.] {
-            let ty = sema.type_of_expr(desc_expr)?.original;
-            if ty.is_unknown() {
-                return None;
-            }
-            if matches!(expr, ast::Expr::PathExpr(_)) {
-                if let Some(hir::Adt::Struct(st)) = ty.as_adt() {
-                    if st.fields(sema.db).is_empty() {
-                        return None;
-                    }
-                }
-            }
-            acc.push(InlayHint {
-                range: expr.syntax().text_range(),
-                kind: InlayKind::ChainingHint,
-                label: hint_iterator(sema, &famous_defs, config, &ty).unwrap_or_else(|| {
-                    ty.display_truncated(sema.db, config.max_length).to_string().into()
-                }),
-            });
-        }
-    }
-    Some(())
-}
-
 fn get_param_name_hints(
     acc: &mut Vec<InlayHint>,
     sema: &Semantics<RootDatabase>,
@@ -158,18 +132,36 @@ fn get_param_name_hints(
     }
 
     let (callable, arg_list) = get_callable(sema, &expr)?;
+    let fn_params = match callable.kind() {
+        hir::CallableKind::Function(func) => {
+            let params = func.assoc_fn_params(sema.db);
+            // The receiver of a method call is not one of the arguments.
+            let n_receivers = params.len().saturating_sub(callable.n_params());
+            params.into_iter().skip(n_receivers).map(Some).collect()
+        }
+        _ => Vec::new(),
+    };
     let hints = callable
         .params(sema.db)
         .into_iter()
+        .zip(fn_params.into_iter().chain(iter::repeat(None)))
         .zip(arg_list.args())
-        .filter_map(|((param, _ty), arg)| {
+        .filter_map(|(((param, _ty), fn_param), arg)| {
             // Only annotate hints for expressions that exist in the original file
             let range = sema.original_range_opt(arg.syntax())?;
             let param_name = match param? {
                 Either::Left(_) => "self".to_string(),
-                Either::Right(pat) => match pat {
-                    ast::Pat::IdentPat(it) => it.name()?.to_string(),
-                    _ => return None,
+                Either::Right(_) => match fn_param?.name_or_pattern(sema.db)? {
+                    Either::Left(name) => name.to_string(),
+                    Either::Right(ast::Pat::WildcardPat(_)) => return None,
+                    // Destructuring patterns like `(x, y)` are shown as they are written.
+                    Either::Right(pat) => {
+                        let text = pat.syntax().text().to_string();
+                        if text.contains('\n') {
+                            return None;
+                        }
+                        text
+                    }
                 },
             };
             Some((param_name, arg, range))
@@ -181,6 +173,7 @@ fn get_param_name_hints(
             range,
             kind: InlayKind::ParameterHint,
             label: param_name.into(),
+            tooltip: None,
         });
 
     acc.extend(hints);
@@ -196,6 +189,9 @@ fn get_bind_pat_hints(
     if !config.type_hints {
         return None;
     }
+    if !config.closure_param_type_hints && is_closure_param(pat) {
+        return None;
+    }
 
     let descended = sema.descend_node_into_attributes(pat.clone()).pop();
     let desc_pat = descended.as_ref().unwrap_or(pat);
@@ -229,11 +225,61 @@ fn get_bind_pat_hints(
         },
         kind: InlayKind::TypeHint,
         label,
+        tooltip: config.render_tooltips.then(|| type_tooltip(sema, &ty)),
     });
 
     Some(())
 }
 
+// Computing the tooltip of a hint repeats the type inference lookups done for its label, so
+// editors that support it can resolve the tooltip only once a hint is hovered.
+pub(crate) fn inlay_hint_tooltip(
+    db: &RootDatabase,
+    file_id: FileId,
+    range: TextRange,
+    kind: InlayKind,
+) -> Option<String> {
+    let sema = Semantics::new(db);
+    let file = sema.parse(file_id);
+    let mut ancestors = match file.syntax().covering_element(range) {
+        NodeOrToken::Node(node) => node.ancestors(),
+        NodeOrToken::Token(token) => token.parent()?.ancestors(),
+    };
+
+    let ty = match kind {
+        InlayKind::TypeHint => {
+            let pat = ancestors.find_map(ast::IdentPat::cast)?;
+            let pat = sema.descend_node_into_attributes(pat.clone()).pop().unwrap_or(pat);
+            sema.type_of_pat(&pat.into())?.original
+        }
+        InlayKind::ChainingHint => {
+            let expr = ancestors.filter(|it| it.text_range() == range).find_map(ast::Expr::cast)?;
+            let expr = sema.descend_node_into_attributes(expr.clone()).pop().unwrap_or(expr);
+            chained_expr_ty(&sema, &expr)?
+        }
+        InlayKind::ParameterHint
+        | InlayKind::BindingModeHint
+        | InlayKind::GenericArgsHint
+        | InlayKind::CfgHint
+        | InlayKind::LifetimeHint => return None,
+    };
+    Some(type_tooltip(&sema, &ty))
+}
+
+fn chained_expr_ty(sema: &Semantics<RootDatabase>, expr: &ast::Expr) -> Option<hir::Type> {
+    let ty = sema.type_of_expr(expr)?.original;
+    if ty.is_unknown() {
+        None
+    } else {
+        Some(ty)
+    }
+}
+
+/// Unlike the label, the tooltip is neither truncated nor replaced by `impl Iterator`.
+fn type_tooltip(sema: &Semantics<RootDatabase>, ty: &hir::Type) -> String {
+    ty.display(sema.db).to_string()
+}
+
 fn is_named_constructor(
     sema: &Semantics<RootDatabase>,
     pat: &ast::IdentPat,
@@ -353,6 +399,14 @@ fn pat_is_enum_variant(db: &RootDatabase, bind_pat: &ast::IdentPat, pat_ty: &hir
     }
 }
 
+fn is_closure_param(pat: &ast::IdentPat) -> bool {
+    pat.syntax()
+        .ancestors()
+        .find_map(ast::Param::cast)
+        .and_then(|param| param.syntax().parent()?.parent())
+        .map_or(false, |it| ast::ClosureExpr::can_cast(it.kind()))
+}
+
 fn should_not_display_type_hint(
     sema: &Semantics<RootDatabase>,
     bind_pat: &ast::IdentPat,
@@ -553,12 +607,18 @@ mod tests {
 
     use crate::{fixture, inlay_hints::InlayHintsConfig};
 
-    const TEST_CONFIG: InlayHintsConfig = InlayHintsConfig {
+    pub(super) const TEST_CONFIG: InlayHintsConfig = InlayHintsConfig {
         type_hints: true,
         parameter_hints: true,
         chaining_hints: true,
+        closure_param_type_hints: true,
+        binding_mode_hints: false,
+        generic_parameter_hints: false,
+        cfg_hints: false,
+        lifetime_hints: false,
         hide_named_constructor_hints: false,
         max_length: None,
+        render_tooltips: false,
     };
 
     #[track_caller]
@@ -573,8 +633,14 @@ mod tests {
                 parameter_hints: true,
                 type_hints: false,
                 chaining_hints: false,
+                closure_param_type_hints: true,
+                binding_mode_hints: false,
+                generic_parameter_hints: false,
+                cfg_hints: false,
+                lifetime_hints: false,
                 hide_named_constructor_hints: false,
                 max_length: None,
+                render_tooltips: false,
             },
             ra_fixture,
         );
@@ -587,29 +653,21 @@ mod tests {
                 parameter_hints: false,
                 type_hints: true,
                 chaining_hints: false,
+                closure_param_type_hints: true,
+                binding_mode_hints: false,
+                generic_parameter_hints: false,
+                cfg_hints: false,
+                lifetime_hints: false,
                 hide_named_constructor_hints: false,
                 max_length: None,
+                render_tooltips: false,
             },
             ra_fixture,
         );
     }
 
     #[track_caller]
-    fn check_chains(ra_fixture: &str) {
-        check_with_config(
-            InlayHintsConfig {
-                parameter_hints: false,
-                type_hints: false,
-                chaining_hints: true,
-                hide_named_constructor_hints: false,
-                max_length: None,
-            },
-            ra_fixture,
-        );
-    }
-
-    #[track_caller]
-    fn check_with_config(config: InlayHintsConfig, ra_fixture: &str) {
+    pub(super) fn check_with_config(config: InlayHintsConfig, ra_fixture: &str) {
         let (analysis, file_id) = fixture::file(ra_fixture);
         let expected = extract_annotations(&*analysis.file_text(file_id).unwrap());
         let inlay_hints = analysis.inlay_hints(&config, file_id).unwrap();
@@ -619,12 +677,35 @@ mod tests {
     }
 
     #[track_caller]
-    fn check_expect(config: InlayHintsConfig, ra_fixture: &str, expect: Expect) {
+    pub(super) fn check_expect(config: InlayHintsConfig, ra_fixture: &str, expect: Expect) {
         let (analysis, file_id) = fixture::file(ra_fixture);
         let inlay_hints = analysis.inlay_hints(&config, file_id).unwrap();
         expect.assert_debug_eq(&inlay_hints)
     }
 
+    #[track_caller]
+    fn check_tooltips(ra_fixture: &str, expect: Expect) {
+        let (analysis, file_id) = fixture::file(ra_fixture);
+        let config = InlayHintsConfig { max_length: Some(8), render_tooltips: true, ..TEST_CONFIG };
+        let lazy_hints = analysis
+            .inlay_hints(&InlayHintsConfig { render_tooltips: false, ..config.clone() }, file_id)
+            .unwrap();
+        assert!(lazy_hints.iter().all(|it| it.tooltip.is_none()));
+
+        let inlay_hints = analysis.inlay_hints(&config, file_id).unwrap();
+        assert_eq!(lazy_hints.len(), inlay_hints.len());
+        for hint in &inlay_hints {
+            let resolved =
+                analysis.inlay_hint_tooltip(file_id, hint.range, hint.kind.clone()).unwrap();
+            assert_eq!(hint.tooltip, resolved, "resolved tooltip differs for {:?}", hint);
+        }
+        let actual = inlay_hints
+            .iter()
+            .map(|it| format!("{:?} {}: {:?}\n", it.kind, it.label, it.tooltip))
+            .collect::<String>();
+        expect.assert_eq(&actual)
+    }
+
     #[test]
     fn hints_disabled() {
         check_with_config(
@@ -632,8 +713,14 @@ mod tests {
                 type_hints: false,
                 parameter_hints: false,
                 chaining_hints: false,
+                closure_param_type_hints: true,
+                binding_mode_hints: false,
+                generic_parameter_hints: false,
+                cfg_hints: false,
+                lifetime_hints: false,
                 hide_named_constructor_hints: false,
                 max_length: None,
+                render_tooltips: false,
             },
             r#"
 fn foo(a: i32, b: i32) -> i32 { a + b }
@@ -661,6 +748,20 @@ fn main() {
         );
     }
 
+    #[test]
+    fn param_hints_for_destructuring_patterns() {
+        check_params(
+            r#"
+struct Point { x: i32, y: i32 }
+fn foo((a, b): (i32, i32), Point { x, .. }: Point, _: i32) {}
+fn main() {
+    foo((1, 2), Point { x: 3, y: 4 }, 5);
+      //^^^^^^ (a, b)
+              //^^^^^^^^^^^^^^^^^^^^ Point { x, .. }
+}"#,
+        );
+    }
+
     #[test]
     fn param_name_similar_to_fn_name_still_hints() {
         check_params(
@@ -907,6 +1008,7 @@ fn main() {
       //^^^^^^^^^^^ param_eter
 
     non_ident_pat((0, 0));
+                //^^^^^^ (a, b)
 }"#,
         );
     }
@@ -1347,8 +1449,14 @@ fn main() {
                 type_hints: true,
                 parameter_hints: true,
                 chaining_hints: true,
+                closure_param_type_hints: true,
+                binding_mode_hints: false,
+                generic_parameter_hints: false,
+                cfg_hints: false,
+                lifetime_hints: false,
                 hide_named_constructor_hints: true,
                 max_length: None,
+                render_tooltips: false,
             },
             r#"
 //- minicore: try
@@ -1463,230 +1571,69 @@ fn main() {
     }
 
     #[test]
-    fn hint_truncation() {
-        check_with_config(
-            InlayHintsConfig { max_length: Some(8), ..TEST_CONFIG },
-            r#"
-struct Smol<T>(T);
-
-struct VeryLongOuterName<T>(T);
-
-fn main() {
-    let a = Smol(0u32);
-      //^ Smol<u32>
-    let b = VeryLongOuterName(0usize);
-      //^ VeryLongOuterName<…>
-    let c = Smol(Smol(0u32))
-      //^ Smol<Smol<…>>
-}"#,
-        );
-    }
-
-    // Chaining hint tests
-
-    #[test]
-    fn chaining_hints_ignore_comments() {
-        check_expect(
-            InlayHintsConfig {
-                parameter_hints: false,
-                type_hints: false,
-                chaining_hints: true,
-                hide_named_constructor_hints: false,
-                max_length: None,
-            },
+    fn closure_param_types_from_context() {
+        check_types(
             r#"
-struct A(B);
-impl A { fn into_b(self) -> B { self.0 } }
-struct B(C);
-impl B { fn into_c(self) -> C { self.0 } }
-struct C;
-
-fn main() {
-    let c = A(B(C))
-        .into_b() // This is a comment
-        // This is another comment
-        .into_c();
+//- minicore: fn
+struct Vec<T>(T);
+impl<T> Vec<T> {
+    fn retain(&mut self, f: impl FnMut(&T) -> bool) {}
 }
-"#,
-            expect![[r#"
-                [
-                    InlayHint {
-                        range: 147..172,
-                        kind: ChainingHint,
-                        label: "B",
-                    },
-                    InlayHint {
-                        range: 147..154,
-                        kind: ChainingHint,
-                        label: "A",
-                    },
-                ]
-            "#]],
-        );
-    }
-
-    #[test]
-    fn chaining_hints_without_newlines() {
-        check_chains(
-            r#"
-struct A(B);
-impl A { fn into_b(self) -> B { self.0 } }
-struct B(C);
-impl B { fn into_c(self) -> C { self.0 } }
-struct C;
-
-fn main() {
-    let c = A(B(C)).into_b().into_c();
-}"#,
-        );
-    }
-
-    #[test]
-    fn struct_access_chaining_hints() {
-        check_expect(
-            InlayHintsConfig {
-                parameter_hints: false,
-                type_hints: false,
-                chaining_hints: true,
-                hide_named_constructor_hints: false,
-                max_length: None,
-            },
-            r#"
-struct A { pub b: B }
-struct B { pub c: C }
-struct C(pub bool);
-struct D;
-
-impl D {
-    fn foo(&self) -> i32 { 42 }
+enum Option<T> {
+    Some(T),
+    None,
 }
-
-fn main() {
-    let x = A { b: B { c: C(true) } }
-        .b
-        .c
-        .0;
-    let x = D
-        .foo();
-}"#,
-            expect![[r#"
-                [
-                    InlayHint {
-                        range: 143..190,
-                        kind: ChainingHint,
-                        label: "C",
-                    },
-                    InlayHint {
-                        range: 143..179,
-                        kind: ChainingHint,
-                        label: "B",
-                    },
-                ]
-            "#]],
+impl<T> Option<T> {
+    fn map<U>(self, f: impl FnOnce(T) -> U) -> Option<U> { loop {} }
+}
+fn apply(f: impl Fn(&str) -> usize) {}
+fn main(mut v: Vec<u32>, o: Option<u8>) {
+    apply(|s| 0);
+         //^ &str
+    v.retain(|x| true);
+            //^ &u32
+    o.map(|x| x);
+         //^ u8
+}
+"#,
         );
     }
 
     #[test]
-    fn generic_chaining_hints() {
-        check_expect(
-            InlayHintsConfig {
-                parameter_hints: false,
-                type_hints: false,
-                chaining_hints: true,
-                hide_named_constructor_hints: false,
-                max_length: None,
-            },
+    fn closure_param_type_hints_can_be_disabled() {
+        check_with_config(
+            InlayHintsConfig { closure_param_type_hints: false, ..TEST_CONFIG },
             r#"
-struct A<T>(T);
-struct B<T>(T);
-struct C<T>(T);
-struct X<T,R>(T, R);
-
-impl<T> A<T> {
-    fn new(t: T) -> Self { A(t) }
-    fn into_b(self) -> B<T> { B(self.0) }
-}
-impl<T> B<T> {
-    fn into_c(self) -> C<T> { C(self.0) }
-}
+//- minicore: fn
+fn apply(f: impl Fn(&str) -> usize) {}
 fn main() {
-    let c = A::new(X(42, true))
-        .into_b()
-        .into_c();
+    apply(|s| {
+        let n = 0usize;
+          //^ usize
+        n
+    });
 }
 "#,
-            expect![[r#"
-                [
-                    InlayHint {
-                        range: 246..283,
-                        kind: ChainingHint,
-                        label: "B<X<i32, bool>>",
-                    },
-                    InlayHint {
-                        range: 246..265,
-                        kind: ChainingHint,
-                        label: "A<X<i32, bool>>",
-                    },
-                ]
-            "#]],
         );
     }
 
     #[test]
-    fn shorten_iterator_chaining_hints() {
-        check_expect(
-            InlayHintsConfig {
-                parameter_hints: false,
-                type_hints: false,
-                chaining_hints: true,
-                hide_named_constructor_hints: false,
-                max_length: None,
-            },
+    fn hint_truncation() {
+        check_with_config(
+            InlayHintsConfig { max_length: Some(8), ..TEST_CONFIG },
             r#"
-//- minicore: iterators
-use core::iter;
-
-struct MyIter;
+struct Smol<T>(T);
 
-impl Iterator for MyIter {
-    type Item = ();
-    fn next(&mut self) -> Option<Self::Item> {
-        None
-    }
-}
+struct VeryLongOuterName<T>(T);
 
 fn main() {
-    let _x = MyIter.by_ref()
-        .take(5)
-        .by_ref()
-        .take(5)
-        .by_ref();
-}
-"#,
-            expect![[r#"
-                [
-                    InlayHint {
-                        range: 174..241,
-                        kind: ChainingHint,
-                        label: "impl Iterator<Item = ()>",
-                    },
-                    InlayHint {
-                        range: 174..224,
-                        kind: ChainingHint,
-                        label: "impl Iterator<Item = ()>",
-                    },
-                    InlayHint {
-                        range: 174..206,
-                        kind: ChainingHint,
-                        label: "impl Iterator<Item = ()>",
-                    },
-                    InlayHint {
-                        range: 174..189,
-                        kind: ChainingHint,
-                        label: "&mut MyIter",
-                    },
-                ]
-            "#]],
+    let a = Smol(0u32);
+      //^ Smol<u32>
+    let b = VeryLongOuterName(0usize);
+      //^ VeryLongOuterName<…>
+    let c = Smol(Smol(0u32))
+      //^ Smol<Smol<…>>
+}"#,
         );
     }
 
@@ -1718,24 +1665,52 @@ fn main() {
                         range: 124..130,
                         kind: TypeHint,
                         label: "Struct",
-                    },
-                    InlayHint {
-                        range: 145..185,
-                        kind: ChainingHint,
-                        label: "Struct",
+                        tooltip: None,
                     },
                     InlayHint {
                         range: 145..168,
                         kind: ChainingHint,
                         label: "Struct",
+                        tooltip: None,
                     },
                     InlayHint {
                         range: 222..228,
                         kind: ParameterHint,
                         label: "self",
+                        tooltip: None,
                     },
                 ]
             "#]],
         );
     }
+
+    #[test]
+    fn resolved_tooltips_match_eager_ones() {
+        check_tooltips(
+            r#"
+//- minicore: iterators
+struct VeryLongName<T>(T);
+impl<T> VeryLongName<T> {
+    fn get(self) -> T { self.0 }
+}
+
+fn takes(value: u32) {}
+
+fn main() {
+    let long = VeryLongName(VeryLongName(0u32));
+    let iter = core::iter::repeat(long);
+    let value = VeryLongName(0u32)
+        .get();
+    takes(value);
+}
+"#,
+            expect![[r#"
+                TypeHint VeryLongName<…>: Some("VeryLongName<VeryLongName<u32>>")
+                TypeHint impl Iterator<Item = …>: Some("Repeat<VeryLongName<VeryLongName<u32>>>")
+                ParameterHint elt: None
+                TypeHint u32: Some("u32")
+                ChainingHint VeryLongName<…>: Some("VeryLongName<u32>")
+            "#]],
+        );
+    }
 }
@@ -0,0 +1,148 @@
+//! Inlay hints for the implicit `ref` and `ref mut` of bindings under match ergonomics.
+use hir::{BindingMode, Mutability, Semantics};
+use ide_db::RootDatabase;
+use syntax::ast::{self, AstNode};
+
+use crate::{InlayHint, InlayHintsConfig, InlayKind};
+
+pub(super) fn hints(
+    acc: &mut Vec<InlayHint>,
+    sema: &Semantics<RootDatabase>,
+    config: &InlayHintsConfig,
+    pat: &ast::IdentPat,
+) -> Option<()> {
+    if !config.binding_mode_hints {
+        return None;
+    }
+    if pat.ref_token().is_some() {
+        return None;
+    }
+
+    let descended = sema.descend_node_into_attributes(pat.clone()).pop();
+    let desc_pat = descended.as_ref().unwrap_or(pat);
+    let label = match sema.binding_mode_of_pat(desc_pat)? {
+        BindingMode::Move => return None,
+        BindingMode::Ref(Mutability::Shared) => "ref",
+        BindingMode::Ref(Mutability::Mut) => "ref mut",
+    };
+
+    acc.push(InlayHint {
+        range: pat.syntax().text_range(),
+        kind: InlayKind::BindingModeHint,
+        label: label.into(),
+        tooltip: None,
+    });
+
+    Some(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::inlay_hints::tests::{check_with_config, TEST_CONFIG};
+    use crate::InlayHintsConfig;
+
+    #[track_caller]
+    fn check_binding_modes(ra_fixture: &str) {
+        check_with_config(
+            InlayHintsConfig {
+                type_hints: false,
+                parameter_hints: false,
+                chaining_hints: false,
+                binding_mode_hints: true,
+                ..TEST_CONFIG
+            },
+            ra_fixture,
+        );
+    }
+
+    #[test]
+    fn shared_ref_binding() {
+        check_binding_modes(
+            r#"
+//- minicore: option
+fn main() {
+    match &Some(0) {
+        Some(x) => {}
+           //^ ref
+        None => {}
+    }
+    if let Some(y) = &Some(1) {}
+              //^ ref
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn mut_ref_binding() {
+        check_binding_modes(
+            r#"
+//- minicore: option
+fn main() {
+    let mut o = Some(0);
+    match &mut o {
+        Some(x) => {}
+           //^ ref mut
+        None => {}
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn nested_patterns() {
+        check_binding_modes(
+            r#"
+//- minicore: option
+struct S { a: u32, b: (u32, Option<u32>) }
+fn f(s: &S, t: &mut (u32, Option<u32>)) {
+    match s {
+        S {
+            a,
+          //^ ref
+            b: (
+                c,
+              //^ ref
+                Some(d),
+                   //^ ref
+            ),
+        } => {}
+    }
+    match t {
+        (
+            e,
+          //^ ref mut
+            Some(f),
+               //^ ref mut
+        ) => {}
+        _ => {}
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn no_hints_for_explicit_or_move_bindings() {
+        check_binding_modes(
+            r#"
+//- minicore: option
+fn main() {
+    match &Some(0) {
+        Some(ref x) => {}
+        None => {}
+    }
+    match Some(0) {
+        Some(x) => {}
+        None => {}
+    }
+    match &Some(0) {
+        &Some(x) => {}
+        &None => {}
+    }
+}
+"#,
+        );
+    }
+}
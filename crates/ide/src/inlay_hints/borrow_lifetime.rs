@@ -0,0 +1,169 @@
+//! Inlay hints naming the lifetime of borrows which don't borrow a local directly, like
+//! `&vec[0]` or `&*arg`.
+//!
+//! Type inference erases lifetimes, so instead of regions, the hint follows the borrowed place
+//! back to the local or the static it starts from. Only lifetimes which have a name in the
+//! source are shown: `'static`, or the lifetime of a reference parameter spelled out in the
+//! signature.
+use either::Either;
+use hir::{PathResolution, Semantics};
+use ide_db::RootDatabase;
+use syntax::ast::{self, AstNode, UnaryOp};
+
+use crate::{InlayHint, InlayHintsConfig, InlayKind};
+
+pub(super) fn hints(
+    acc: &mut Vec<InlayHint>,
+    sema: &Semantics<RootDatabase>,
+    config: &InlayHintsConfig,
+    expr: &ast::Expr,
+) -> Option<()> {
+    if !config.lifetime_hints {
+        return None;
+    }
+    let ref_expr = match expr {
+        ast::Expr::RefExpr(it) if it.raw_token().is_none() => it,
+        _ => return None,
+    };
+    let amp = ref_expr.amp_token()?;
+    let label = borrowed_lifetime(sema, ref_expr.expr()?)?;
+
+    acc.push(InlayHint {
+        range: amp.text_range(),
+        kind: InlayKind::LifetimeHint,
+        label: label.into(),
+        tooltip: None,
+    });
+
+    Some(())
+}
+
+fn borrowed_lifetime(sema: &Semantics<RootDatabase>, mut place: ast::Expr) -> Option<String> {
+    // Whether the borrow is of a part of the root, rather than of the root itself.
+    let mut projected = false;
+    let path = loop {
+        place = match place {
+            ast::Expr::ParenExpr(it) => it.expr()?,
+            ast::Expr::FieldExpr(it) => {
+                projected = true;
+                it.expr()?
+            }
+            ast::Expr::IndexExpr(it) => {
+                projected = true;
+                it.base()?
+            }
+            ast::Expr::PrefixExpr(it) if it.op_kind() == Some(UnaryOp::Deref) => {
+                projected = true;
+                it.expr()?
+            }
+            // Borrows of literals are promoted to constants.
+            ast::Expr::Literal(_) => return Some("'static".to_string()),
+            ast::Expr::PathExpr(it) => break it.path()?,
+            _ => return None,
+        }
+    };
+
+    let local = match sema.resolve_path(&path)? {
+        PathResolution::Local(local) => local,
+        PathResolution::Def(hir::ModuleDef::Static(_) | hir::ModuleDef::Const(_)) => {
+            return Some("'static".to_string())
+        }
+        _ => return None,
+    };
+    // `&local` already says where it borrows from, and borrows of other locals have no
+    // lifetime we could name.
+    if !projected || !local.is_param(sema.db) || !local.ty(sema.db).is_reference() {
+        return None;
+    }
+    // Going through a reference parameter, the borrow lives as long as its referent.
+    let lifetime = match local.source(sema.db).value {
+        Either::Left(pat) => match pat.syntax().parent().and_then(ast::Param::cast)?.ty()? {
+            ast::Type::RefType(it) => it.lifetime(),
+            _ => None,
+        },
+        Either::Right(self_param) => self_param.lifetime(),
+    }?;
+    Some(lifetime.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::inlay_hints::tests::{check_with_config, TEST_CONFIG};
+    use crate::InlayHintsConfig;
+
+    #[track_caller]
+    fn check_lifetimes(ra_fixture: &str) {
+        check_with_config(
+            InlayHintsConfig {
+                type_hints: false,
+                parameter_hints: false,
+                chaining_hints: false,
+                lifetime_hints: true,
+                ..TEST_CONFIG
+            },
+            ra_fixture,
+        );
+    }
+
+    #[test]
+    fn borrow_from_local() {
+        check_lifetimes(
+            r#"
+struct Pair { first: u32, second: [u32; 2] }
+fn main() {
+    let pair = Pair { first: 0, second: [1, 2] };
+    let whole = &pair;
+    let first = &pair.first;
+    let second = &(pair.second)[1];
+    let p = &pair;
+    let through = &p.first;
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn borrow_from_argument() {
+        check_lifetimes(
+            r#"
+struct S { values: [u32; 4] }
+impl S {
+    fn first(&self) -> &u32 {
+        &self.values[0]
+    }
+    fn named<'a>(&'a self) -> &'a u32 {
+        &self.values[1]
+      //^ 'a
+    }
+}
+fn named<'a>(s: &'a S, n: u32) -> &'a u32 {
+    let n = &n;
+    &s.values[*n as usize]
+  //^ 'a
+}
+fn elided(s: &S) -> &S {
+    &*s
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn static_borrow() {
+        check_lifetimes(
+            r#"
+static TABLE: [u8; 2] = [0, 1];
+const LIMIT: u32 = 10;
+fn main() {
+    let table = &TABLE;
+              //^ 'static
+    let limit = &LIMIT;
+              //^ 'static
+    let zero = &0;
+             //^ 'static
+    let temporary = &(1 + 2);
+}
+"#,
+        );
+    }
+}
@@ -0,0 +1,120 @@
+//! Inlay hints telling whether the `#[cfg]` conditions of an item hold in the current
+//! configuration.
+use cfg::DnfExpr;
+use hir::Semantics;
+use ide_db::RootDatabase;
+use syntax::ast::{self, AstNode, HasAttrs, HasName};
+
+use crate::{InlayHint, InlayHintsConfig, InlayKind};
+
+pub(super) fn hints(
+    acc: &mut Vec<InlayHint>,
+    sema: &Semantics<RootDatabase>,
+    config: &InlayHintsConfig,
+    item: &ast::Item,
+) -> Option<()> {
+    if !config.cfg_hints {
+        return None;
+    }
+
+    let cfg = hir::cfg_of_source(item)?;
+    let cfg_options = sema.scope(item.syntax()).krate()?.cfg(sema.db);
+    let (label, tooltip) = match cfg_options.check(&cfg)? {
+        true => ("✓", "`#[cfg]` conditions are enabled".to_string()),
+        false => {
+            let inactive = DnfExpr::new(cfg).why_inactive(&cfg_options)?;
+            ("✗", format!("code is inactive due to #[cfg] directives: {}", inactive))
+        }
+    };
+
+    // Items without a name, like impls, get the hint after their last `#[cfg]`.
+    let range = match ast::AnyHasName::cast(item.syntax().clone()).and_then(|it| it.name()) {
+        Some(name) => name.syntax().text_range(),
+        None => item
+            .attrs()
+            .filter(|attr| attr.simple_name().as_deref() == Some("cfg"))
+            .last()?
+            .syntax()
+            .text_range(),
+    };
+    acc.push(InlayHint {
+        range,
+        kind: InlayKind::CfgHint,
+        label: label.into(),
+        tooltip: Some(tooltip),
+    });
+
+    Some(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::inlay_hints::tests::{check_with_config, TEST_CONFIG};
+    use crate::InlayHintsConfig;
+
+    #[track_caller]
+    fn check_cfg(ra_fixture: &str) {
+        check_with_config(
+            InlayHintsConfig {
+                type_hints: false,
+                parameter_hints: false,
+                chaining_hints: false,
+                cfg_hints: true,
+                ..TEST_CONFIG
+            },
+            ra_fixture,
+        );
+    }
+
+    #[test]
+    fn enabled_feature() {
+        check_cfg(
+            r#"
+//- /lib.rs crate:foo cfg:feature=std
+#[cfg(feature = "std")]
+pub fn with_std() {}
+     //^^^^^^^^ ✓
+#[cfg(all(feature = "std", not(feature = "alloc")))]
+mod imp {}
+  //^^^ ✓
+fn not_gated() {}
+"#,
+        );
+    }
+
+    #[test]
+    fn disabled_feature() {
+        check_cfg(
+            r#"
+//- /lib.rs crate:foo cfg:feature=std
+#[cfg(feature = "alloc")]
+struct Vec;
+     //^^^ ✗
+mod m {
+    #[cfg(feature = "std")]
+    #[cfg(feature = "alloc")]
+  //^^^^^^^^^^^^^^^^^^^^^^^^^ ✗
+    impl super::Vec {}
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn target_specific_cfg() {
+        check_cfg(
+            r#"
+//- /lib.rs crate:foo cfg:target_os=linux,unix
+#[cfg(target_os = "linux")]
+fn linux() {}
+ //^^^^^ ✓
+#[cfg(target_os = "windows")]
+fn windows() {}
+ //^^^^^^^ ✗
+#[cfg(unix)]
+const SEP: char = '/';
+    //^^^ ✓
+"#,
+        );
+    }
+}
@@ -0,0 +1,570 @@
+//! Inlay hints for the types of intermediate expressions in method chains.
+use std::iter;
+
+use hir::{HirDisplay, Semantics};
+use ide_db::{helpers::FamousDefs, RootDatabase};
+use syntax::{
+    ast::{self, AstNode},
+    Direction, NodeOrToken, SmolStr, SyntaxKind, T,
+};
+
+use crate::{InlayHint, InlayHintsConfig, InlayKind};
+
+use super::{chained_expr_ty, hint_iterator, type_tooltip};
+
+pub(super) fn hints(
+    acc: &mut Vec<InlayHint>,
+    sema: &Semantics<RootDatabase>,
+    config: &InlayHintsConfig,
+    expr: &ast::Expr,
+) -> Option<()> {
+    if !config.chaining_hints {
+        return None;
+    }
+
+    if matches!(expr, ast::Expr::RecordExpr(_)) {
+        return None;
+    }
+
+    let descended = sema.descend_node_into_attributes(expr.clone()).pop();
+    let desc_expr = descended.as_ref().unwrap_or(expr);
+    let krate = sema.scope(desc_expr.syntax()).module().map(|it| it.krate());
+    let famous_defs = FamousDefs(sema, krate);
+
+    if !is_chain_link(expr) {
+        return Some(());
+    }
+    let ty = chained_expr_ty(sema, desc_expr)?;
+    if is_fieldless_struct_path(sema, expr, &ty) {
+        return None;
+    }
+    let label = ty_label(sema, &famous_defs, config, &ty);
+
+    // Only show the type if it changed since the last hint of the chain. Types which differ but
+    // render the same, like two `impl Iterator<Item = i32>`, look like a repetition as well.
+    let receivers = iter::successors(chain_receiver(expr), chain_receiver)
+        .zip(iter::successors(chain_receiver(desc_expr), chain_receiver));
+    for (receiver, desc_receiver) in receivers {
+        if !is_chain_link(&receiver) {
+            continue;
+        }
+        let receiver_ty = match chained_expr_ty(sema, &desc_receiver) {
+            Some(it) if !is_fieldless_struct_path(sema, &receiver, &it) => it,
+            _ => continue,
+        };
+        if ty_label(sema, &famous_defs, config, &receiver_ty) == label {
+            return None;
+        }
+        break;
+    }
+
+    acc.push(InlayHint {
+        range: expr.syntax().text_range(),
+        kind: InlayKind::ChainingHint,
+        label,
+        tooltip: config.render_tooltips.then(|| type_tooltip(sema, &ty)),
+    });
+    Some(())
+}
+
+/// Whether `expr` is followed by a newline and a dot, ignoring extra whitespace and comments.
+/// Only these steps of a chain get a hint.
+fn is_chain_link(expr: &ast::Expr) -> bool {
+    let mut tokens = expr
+        .syntax()
+        .siblings_with_tokens(Direction::Next)
+        .filter_map(NodeOrToken::into_token)
+        .filter(|t| match t.kind() {
+            SyntaxKind::WHITESPACE if !t.text().contains('\n') => false,
+            SyntaxKind::COMMENT => false,
+            _ => true,
+        });
+    if tokens.next().map(|it| it.kind()) != Some(SyntaxKind::WHITESPACE) {
+        return false;
+    }
+    tokens.find(|it| it.kind() != SyntaxKind::WHITESPACE).map_or(false, |it| it.kind() == T![.])
+}
+
+/// Unit-like structs, like the `D` in `D.foo()`, are obvious from their name.
+fn is_fieldless_struct_path(
+    sema: &Semantics<RootDatabase>,
+    expr: &ast::Expr,
+    ty: &hir::Type,
+) -> bool {
+    matches!(expr, ast::Expr::PathExpr(_))
+        && matches!(ty.as_adt(), Some(hir::Adt::Struct(st)) if st.fields(sema.db).is_empty())
+}
+
+fn ty_label(
+    sema: &Semantics<RootDatabase>,
+    famous_defs: &FamousDefs,
+    config: &InlayHintsConfig,
+    ty: &hir::Type,
+) -> SmolStr {
+    hint_iterator(sema, famous_defs, config, ty)
+        .unwrap_or_else(|| ty.display_truncated(sema.db, config.max_length).to_string().into())
+}
+
+/// The previous step of a chain.
+fn chain_receiver(expr: &ast::Expr) -> Option<ast::Expr> {
+    match expr {
+        ast::Expr::MethodCallExpr(it) => it.receiver(),
+        ast::Expr::FieldExpr(it) => it.expr(),
+        ast::Expr::TryExpr(it) => it.expr(),
+        ast::Expr::AwaitExpr(it) => it.expr(),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::expect;
+
+    use crate::inlay_hints::tests::{check_expect, check_with_config, TEST_CONFIG};
+    use crate::InlayHintsConfig;
+
+    #[track_caller]
+    fn check_chains(ra_fixture: &str) {
+        check_with_config(
+            InlayHintsConfig {
+                parameter_hints: false,
+                type_hints: false,
+                chaining_hints: true,
+                closure_param_type_hints: true,
+                binding_mode_hints: false,
+                generic_parameter_hints: false,
+                cfg_hints: false,
+                lifetime_hints: false,
+                hide_named_constructor_hints: false,
+                max_length: None,
+                render_tooltips: false,
+            },
+            ra_fixture,
+        );
+    }
+
+    #[test]
+    fn chaining_hints_ignore_comments() {
+        check_expect(
+            InlayHintsConfig {
+                parameter_hints: false,
+                type_hints: false,
+                chaining_hints: true,
+                closure_param_type_hints: true,
+                binding_mode_hints: false,
+                generic_parameter_hints: false,
+                cfg_hints: false,
+                lifetime_hints: false,
+                hide_named_constructor_hints: false,
+                max_length: None,
+                render_tooltips: false,
+            },
+            r#"
+struct A(B);
+impl A { fn into_b(self) -> B { self.0 } }
+struct B(C);
+impl B { fn into_c(self) -> C { self.0 } }
+struct C;
+
+fn main() {
+    let c = A(B(C))
+        .into_b() // This is a comment
+        // This is another comment
+        .into_c();
+}
+"#,
+            expect![[r#"
+                [
+                    InlayHint {
+                        range: 147..172,
+                        kind: ChainingHint,
+                        label: "B",
+                        tooltip: None,
+                    },
+                    InlayHint {
+                        range: 147..154,
+                        kind: ChainingHint,
+                        label: "A",
+                        tooltip: None,
+                    },
+                ]
+            "#]],
+        );
+    }
+
+    #[test]
+    fn chaining_hints_without_newlines() {
+        check_chains(
+            r#"
+struct A(B);
+impl A { fn into_b(self) -> B { self.0 } }
+struct B(C);
+impl B { fn into_c(self) -> C { self.0 } }
+struct C;
+
+fn main() {
+    let c = A(B(C)).into_b().into_c();
+}"#,
+        );
+    }
+
+    #[test]
+    fn chaining_hints_after_receiver_on_the_same_line() {
+        check_chains(
+            r#"
+struct Foo;
+impl Foo {
+    fn bar(self) -> Foo { self }
+    fn baz(self) -> u32 { 0 }
+}
+
+fn main() {
+    let foo = Foo;
+    let _ = foo.bar()
+          //^^^^^^^^^ Foo
+        .baz();
+}"#,
+        );
+    }
+
+    #[test]
+    fn builder_chaining_hints() {
+        check_chains(
+            r#"
+struct Builder;
+struct Built;
+impl Builder {
+    fn new() -> Builder { Builder }
+    fn x(self) -> Builder { self }
+    fn y(self) -> Builder { self }
+    fn build(self) -> Built { Built }
+}
+
+fn main() {
+    let _ = Builder::new().x()
+          //^^^^^^^^^^^^^^^^^^ Builder
+        .y()
+        .build();
+}"#,
+        );
+        // The unit struct itself gets no hint, so the first step shows its type.
+        check_expect(
+            InlayHintsConfig { type_hints: false, ..TEST_CONFIG },
+            r#"
+struct Builder;
+struct Built;
+impl Builder {
+    fn x(self) -> Builder { self }
+    fn y(self) -> Builder { self }
+    fn build(self) -> Built { Built }
+}
+
+fn main() {
+    let _ = Builder
+        .x()
+        .y()
+        .build();
+}"#,
+            expect![[r#"
+                [
+                    InlayHint {
+                        range: 180..200,
+                        kind: ChainingHint,
+                        label: "Builder",
+                        tooltip: None,
+                    },
+                ]
+            "#]],
+        );
+    }
+
+    #[test]
+    fn struct_access_chaining_hints() {
+        check_expect(
+            InlayHintsConfig {
+                parameter_hints: false,
+                type_hints: false,
+                chaining_hints: true,
+                closure_param_type_hints: true,
+                binding_mode_hints: false,
+                generic_parameter_hints: false,
+                cfg_hints: false,
+                lifetime_hints: false,
+                hide_named_constructor_hints: false,
+                max_length: None,
+                render_tooltips: false,
+            },
+            r#"
+struct A { pub b: B }
+struct B { pub c: C }
+struct C(pub bool);
+struct D;
+
+impl D {
+    fn foo(&self) -> i32 { 42 }
+}
+
+fn main() {
+    let x = A { b: B { c: C(true) } }
+        .b
+        .c
+        .0;
+    let x = D
+        .foo();
+}"#,
+            expect![[r#"
+                [
+                    InlayHint {
+                        range: 143..190,
+                        kind: ChainingHint,
+                        label: "C",
+                        tooltip: None,
+                    },
+                    InlayHint {
+                        range: 143..179,
+                        kind: ChainingHint,
+                        label: "B",
+                        tooltip: None,
+                    },
+                ]
+            "#]],
+        );
+    }
+
+    #[test]
+    fn generic_chaining_hints() {
+        check_expect(
+            InlayHintsConfig {
+                parameter_hints: false,
+                type_hints: false,
+                chaining_hints: true,
+                closure_param_type_hints: true,
+                binding_mode_hints: false,
+                generic_parameter_hints: false,
+                cfg_hints: false,
+                lifetime_hints: false,
+                hide_named_constructor_hints: false,
+                max_length: None,
+                render_tooltips: false,
+            },
+            r#"
+struct A<T>(T);
+struct B<T>(T);
+struct C<T>(T);
+struct X<T,R>(T, R);
+
+impl<T> A<T> {
+    fn new(t: T) -> Self { A(t) }
+    fn into_b(self) -> B<T> { B(self.0) }
+}
+impl<T> B<T> {
+    fn into_c(self) -> C<T> { C(self.0) }
+}
+fn main() {
+    let c = A::new(X(42, true))
+        .into_b()
+        .into_c();
+}
+"#,
+            expect![[r#"
+                [
+                    InlayHint {
+                        range: 246..283,
+                        kind: ChainingHint,
+                        label: "B<X<i32, bool>>",
+                        tooltip: None,
+                    },
+                    InlayHint {
+                        range: 246..265,
+                        kind: ChainingHint,
+                        label: "A<X<i32, bool>>",
+                        tooltip: None,
+                    },
+                ]
+            "#]],
+        );
+    }
+
+    #[test]
+    fn shorten_iterator_chaining_hints() {
+        check_expect(
+            InlayHintsConfig {
+                parameter_hints: false,
+                type_hints: false,
+                chaining_hints: true,
+                closure_param_type_hints: true,
+                binding_mode_hints: false,
+                generic_parameter_hints: false,
+                cfg_hints: false,
+                lifetime_hints: false,
+                hide_named_constructor_hints: false,
+                max_length: None,
+                render_tooltips: false,
+            },
+            r#"
+//- minicore: iterators
+use core::iter;
+
+struct MyIter;
+
+impl Iterator for MyIter {
+    type Item = ();
+    fn next(&mut self) -> Option<Self::Item> {
+        None
+    }
+}
+
+fn main() {
+    let _x = MyIter.by_ref()
+        .take(5)
+        .by_ref()
+        .take(5)
+        .by_ref();
+}
+"#,
+            expect![[r#"
+                [
+                    InlayHint {
+                        range: 174..206,
+                        kind: ChainingHint,
+                        label: "impl Iterator<Item = ()>",
+                        tooltip: None,
+                    },
+                    InlayHint {
+                        range: 174..189,
+                        kind: ChainingHint,
+                        label: "&mut MyIter",
+                        tooltip: None,
+                    },
+                ]
+            "#]],
+        );
+    }
+
+    #[test]
+    fn iterator_chaining_hints() {
+        check_expect(
+            TEST_CONFIG,
+            r#"
+//- minicore: iterators
+struct Numbers;
+impl Iterator for Numbers {
+    type Item = i32;
+    fn next(&mut self) -> Option<i32> {
+        None
+    }
+}
+
+fn main() {
+    let mut numbers = Numbers;
+    numbers
+        .by_ref()
+        .take(3)
+        .by_ref()
+        .next();
+}
+"#,
+            expect![[r#"
+                [
+                    InlayHint {
+                        range: 151..158,
+                        kind: TypeHint,
+                        label: "Numbers",
+                        tooltip: None,
+                    },
+                    InlayHint {
+                        range: 174..216,
+                        kind: ChainingHint,
+                        label: "impl Iterator<Item = i32>",
+                        tooltip: None,
+                    },
+                    InlayHint {
+                        range: 174..199,
+                        kind: ChainingHint,
+                        label: "&mut Numbers",
+                        tooltip: None,
+                    },
+                ]
+            "#]],
+        );
+    }
+
+    #[test]
+    fn option_chaining_hints() {
+        check_expect(
+            TEST_CONFIG,
+            r#"
+//- minicore: option
+trait OptionExt<T> {
+    fn checked(self) -> Option<T>;
+    fn len(self) -> Option<usize>;
+}
+impl<T> OptionExt<T> for Option<T> {
+    fn checked(self) -> Option<T> { self }
+    fn len(self) -> Option<usize> { None }
+}
+
+fn main() {
+    Some("text")
+        .checked()
+        .len()
+        .checked()
+        .unwrap();
+}
+"#,
+            expect![[r#"
+                [
+                    InlayHint {
+                        range: 235..281,
+                        kind: ChainingHint,
+                        label: "Option<usize>",
+                        tooltip: None,
+                    },
+                    InlayHint {
+                        range: 235..247,
+                        kind: ChainingHint,
+                        label: "Option<&str>",
+                        tooltip: None,
+                    },
+                ]
+            "#]],
+        );
+    }
+
+    #[test]
+    fn chaining_hints_in_attr_call() {
+        check_expect(
+            TEST_CONFIG,
+            r#"
+//- proc_macros: identity
+struct A;
+impl A { fn into_b(self) -> B { B } }
+struct B;
+impl B { fn into_a(self) -> A { A } }
+
+#[proc_macros::identity]
+fn main() {
+    A.into_b()
+        .into_a()
+        .into_b();
+}
+"#,
+            expect![[r#"
+                [
+                    InlayHint {
+                        range: 138..166,
+                        kind: ChainingHint,
+                        label: "A",
+                        tooltip: None,
+                    },
+                    InlayHint {
+                        range: 138..148,
+                        kind: ChainingHint,
+                        label: "B",
+                        tooltip: None,
+                    },
+                ]
+            "#]],
+        );
+    }
+}
@@ -0,0 +1,158 @@
+//! Inlay hints for the inferred type arguments of generic function calls, rendered as a
+//! turbofish after the function name.
+use hir::{HirDisplay, Semantics};
+use ide_db::RootDatabase;
+use itertools::Itertools;
+use syntax::ast::{self, AstNode};
+
+use crate::{InlayHint, InlayHintsConfig, InlayKind};
+
+use super::get_callable;
+
+/// Calls of functions with more type parameters than this get no hint, as it would be too noisy.
+const MAX_TYPE_ARGS: usize = 3;
+
+pub(super) fn hints(
+    acc: &mut Vec<InlayHint>,
+    sema: &Semantics<RootDatabase>,
+    config: &InlayHintsConfig,
+    expr: &ast::Expr,
+) -> Option<()> {
+    if !config.generic_parameter_hints {
+        return None;
+    }
+
+    let (name_ref, has_explicit_args) = match expr {
+        ast::Expr::MethodCallExpr(it) => (it.name_ref()?, it.generic_arg_list().is_some()),
+        ast::Expr::CallExpr(it) => match it.expr()? {
+            ast::Expr::PathExpr(path) => {
+                let segment = path.path()?.segment()?;
+                (segment.name_ref()?, segment.generic_arg_list().is_some())
+            }
+            _ => return None,
+        },
+        _ => return None,
+    };
+    if has_explicit_args || is_typed_let_initializer(expr) {
+        return None;
+    }
+
+    let (callable, _) = get_callable(sema, expr)?;
+    let args = callable.turbofish_args(sema.db)?;
+    if args.is_empty() || args.len() > MAX_TYPE_ARGS {
+        return None;
+    }
+    if args.iter().any(|ty| ty.is_unknown() || ty.is_closure()) {
+        return None;
+    }
+
+    let args = args.iter().map(|ty| ty.display_truncated(sema.db, config.max_length)).join(", ");
+    acc.push(InlayHint {
+        range: name_ref.syntax().text_range(),
+        kind: InlayKind::GenericArgsHint,
+        label: format!("::<{}>", args).into(),
+        tooltip: None,
+    });
+
+    Some(())
+}
+
+/// In `let x: T = f();` the type arguments of `f` follow from the annotation.
+fn is_typed_let_initializer(expr: &ast::Expr) -> bool {
+    expr.syntax()
+        .parent()
+        .and_then(ast::LetStmt::cast)
+        .map_or(false, |it| it.ty().is_some() && it.initializer().as_ref() == Some(expr))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::inlay_hints::tests::{check_with_config, TEST_CONFIG};
+    use crate::InlayHintsConfig;
+
+    #[track_caller]
+    fn check_generic_args(ra_fixture: &str) {
+        check_with_config(
+            InlayHintsConfig {
+                type_hints: false,
+                parameter_hints: false,
+                chaining_hints: false,
+                generic_parameter_hints: true,
+                ..TEST_CONFIG
+            },
+            ra_fixture,
+        );
+    }
+
+    #[test]
+    fn function_and_method_calls() {
+        check_generic_args(
+            r#"
+struct S;
+impl S {
+    fn get<I>(&self, index: I) -> I { index }
+}
+fn pair<A, B>(a: A, b: B) -> (A, B) { (a, b) }
+
+fn main() {
+    let p = pair(1u8, "two");
+          //^^^^ ::<u8, &str>
+    let i = S.get(0usize);
+            //^^^ ::<usize>
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn generic_args_of_nested_calls() {
+        check_generic_args(
+            r#"
+fn id<T>(t: T) -> T { t }
+
+fn main() {
+    let x = id(id(0u32));
+          //^^ ::<u32>
+             //^^ ::<u32>
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn no_hints_for_explicit_or_annotated_args() {
+        check_generic_args(
+            r#"
+struct S;
+impl S {
+    fn get<I>(&self, index: I) -> I { index }
+}
+fn id<T>(t: T) -> T { t }
+fn default<T>() -> T { loop {} }
+
+fn main() {
+    let a = id::<u32>(0);
+    let b = S.get::<u8>(0);
+    let c: u64 = default();
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn no_hints_for_non_generic_or_large_generic_fns() {
+        check_generic_args(
+            r#"
+fn plain(x: u32) {}
+fn many<A, B, C, D>(a: A, b: B, c: C, d: D) {}
+fn with_impl_trait<T>(t: T, u: impl Copy) {}
+
+fn main() {
+    plain(0);
+    many(1u8, 2u16, 3u32, 4u64);
+    with_impl_trait(0u8, 1u8);
+}
+"#,
+        );
+    }
+}
@@ -39,19 +39,25 @@ mod goto_type_definition;
 mod hover;
 mod inlay_hints;
 mod join_lines;
+mod linked_editing_range;
 mod markdown_remove;
 mod matching_brace;
 mod moniker;
 mod move_item;
+mod move_item_to_module;
+mod open_docs;
+mod parent_expression;
 mod parent_module;
 mod references;
 mod rename;
 mod runnables;
+mod show_type;
 mod ssr;
 mod static_index;
 mod status;
 mod syntax_highlighting;
 mod syntax_tree;
+mod type_hierarchy;
 mod typing;
 mod view_crate_graph;
 mod view_hir;
@@ -77,6 +83,7 @@ pub use crate::{
     annotations::{Annotation, AnnotationConfig, AnnotationKind},
     call_hierarchy::CallItem,
     call_info::CallInfo,
+    doc_links::{DocLink, DocLinkTarget, ItemDocumentation},
     expand_macro::ExpandedMacro,
     file_structure::{StructureNode, StructureNodeKind},
     folding_ranges::{Fold, FoldKind},
@@ -87,7 +94,9 @@ pub use crate::{
     markup::Markup,
     moniker::{MonikerKind, MonikerResult, PackageInformation},
     move_item::Direction,
+    move_item_to_module::MovedItem,
     navigation_target::NavigationTarget,
+    open_docs::DocsLocation,
     prime_caches::PrimeCachesProgress,
     references::ReferenceSearchResult,
     rename::RenameError,
@@ -95,7 +104,7 @@ pub use crate::{
     static_index::{StaticIndex, StaticIndexedFile, TokenId, TokenStaticData},
     syntax_highlighting::{
         tags::{Highlight, HlMod, HlMods, HlOperator, HlPunct, HlTag},
-        HlRange,
+        HighlightConfig, HlRange,
     },
 };
 pub use hir::{Documentation, Semantics};
@@ -245,11 +254,11 @@ impl Analysis {
         self.with_db(|db| status::status(&*db, file_id))
     }
 
-    pub fn prime_caches<F>(&self, cb: F) -> Cancellable<()>
+    pub fn prime_caches<F>(&self, num_worker_threads: usize, cb: F) -> Cancellable<()>
     where
         F: Fn(PrimeCachesProgress) + Sync + std::panic::UnwindSafe,
     {
-        self.with_db(move |db| prime_caches::prime_caches(db, &cb))
+        self.with_db(move |db| prime_caches::prime_caches(db, num_worker_threads, &cb))
     }
 
     /// Gets the text of the source file.
@@ -289,6 +298,27 @@ impl Analysis {
         })
     }
 
+    /// Returns the range of the expression or statement enclosing `frange`.
+    pub fn parent_expression(&self, frange: FileRange) -> Cancellable<Option<TextRange>> {
+        self.with_db(|db| {
+            let file = db.parse(frange.file_id).tree();
+            parent_expression::parent_expression(&file, frange.range)
+        })
+    }
+
+    /// Returns the ranges of the delimiter under the cursor and of its counterpart, which
+    /// should be edited together.
+    pub fn linked_editing_range(
+        &self,
+        position: FilePosition,
+    ) -> Cancellable<Option<Vec<TextRange>>> {
+        self.with_db(|db| {
+            let parse = db.parse(position.file_id);
+            let file = parse.tree();
+            linked_editing_range::linked_editing_range(&file, position.offset)
+        })
+    }
+
     /// Returns a syntax tree represented as `String`, for debug purposes.
     // FIXME: use a better name here.
     pub fn syntax_tree(
@@ -363,6 +393,16 @@ impl Analysis {
         self.with_db(|db| inlay_hints::inlay_hints(db, file_id, config))
     }
 
+    /// Computes the tooltip of the inlay hint of the given kind at `range`.
+    pub fn inlay_hint_tooltip(
+        &self,
+        file_id: FileId,
+        range: TextRange,
+        kind: InlayKind,
+    ) -> Cancellable<Option<String>> {
+        self.with_db(|db| inlay_hints::inlay_hint_tooltip(db, file_id, range, kind))
+    }
+
     /// Returns the set of folding ranges.
     pub fn folding_ranges(&self, file_id: FileId) -> Cancellable<Vec<Fold>> {
         self.with_db(|db| folding_ranges::folding_ranges(&db.parse(file_id).tree()))
@@ -433,6 +473,11 @@ impl Analysis {
         self.with_db(|db| hover::hover(db, range, config))
     }
 
+    /// Returns the type of the expression or pattern at position, with its paths qualified.
+    pub fn show_type(&self, position: FilePosition) -> Cancellable<Option<String>> {
+        self.with_db(|db| show_type::show_type(db, position))
+    }
+
     /// Returns moniker of symbol at position.
     pub fn moniker(
         &self,
@@ -449,6 +494,20 @@ impl Analysis {
         self.with_db(|db| doc_links::external_docs(db, &position))
     }
 
+    /// Returns the documentation of the symbol under the cursor: a link to it if it is hosted
+    /// online, its doc comments otherwise.
+    pub fn show_documentation(
+        &self,
+        position: FilePosition,
+    ) -> Cancellable<Option<ItemDocumentation>> {
+        self.with_db(|db| doc_links::show_documentation(db, &position))
+    }
+
+    /// Returns the links in the doc comments of the given file.
+    pub fn doc_links(&self, file_id: FileId) -> Cancellable<Vec<DocLink>> {
+        self.with_db(|db| doc_links::doc_links(db, file_id))
+    }
+
     /// Computes parameter information for the given call expression.
     pub fn call_info(&self, position: FilePosition) -> Cancellable<Option<CallInfo>> {
         self.with_db(|db| call_info::call_info(db, position))
@@ -472,6 +531,29 @@ impl Analysis {
         self.with_db(|db| call_hierarchy::outgoing_calls(db, position))
     }
 
+    /// Computes type hierarchy candidates for the given file position.
+    pub fn type_hierarchy(
+        &self,
+        position: FilePosition,
+    ) -> Cancellable<Option<RangeInfo<Vec<NavigationTarget>>>> {
+        self.with_db(|db| type_hierarchy::type_hierarchy(db, position))
+    }
+
+    /// Computes the supertypes of the type at the given file position.
+    pub fn supertypes(&self, position: FilePosition) -> Cancellable<Option<Vec<NavigationTarget>>> {
+        self.with_db(|db| type_hierarchy::supertypes(db, position))
+    }
+
+    /// Computes the subtypes of the type at the given file position.
+    pub fn subtypes(&self, position: FilePosition) -> Cancellable<Option<Vec<NavigationTarget>>> {
+        self.with_db(|db| type_hierarchy::subtypes(db, position))
+    }
+
+    /// Returns where the documentation of the item at `position` can be found.
+    pub fn open_docs(&self, position: FilePosition) -> Cancellable<Option<DocsLocation>> {
+        self.with_db(|db| open_docs::open_docs(db, position))
+    }
+
     /// Returns a `mod name;` declaration which created the current module.
     pub fn parent_module(&self, position: FilePosition) -> Cancellable<Vec<NavigationTarget>> {
         self.with_db(|db| parent_module::parent_module(db, position))
@@ -507,8 +589,8 @@ impl Analysis {
     }
 
     /// Computes syntax highlighting for the given file
-    pub fn highlight(&self, file_id: FileId) -> Cancellable<Vec<HlRange>> {
-        self.with_db(|db| syntax_highlighting::highlight(db, file_id, None, false))
+    pub fn highlight(&self, config: HighlightConfig, file_id: FileId) -> Cancellable<Vec<HlRange>> {
+        self.with_db(|db| syntax_highlighting::highlight(db, config, file_id, None, false))
     }
 
     /// Computes all ranges to highlight for a given item in a file.
@@ -523,9 +605,13 @@ impl Analysis {
     }
 
     /// Computes syntax highlighting for the given file range.
-    pub fn highlight_range(&self, frange: FileRange) -> Cancellable<Vec<HlRange>> {
+    pub fn highlight_range(
+        &self,
+        config: HighlightConfig,
+        frange: FileRange,
+    ) -> Cancellable<Vec<HlRange>> {
         self.with_db(|db| {
-            syntax_highlighting::highlight(db, frange.file_id, Some(frange.range), false)
+            syntax_highlighting::highlight(db, config, frange.file_id, Some(frange.range), false)
         })
     }
 
@@ -582,7 +668,10 @@ impl Analysis {
             let diagnostic_assists = if include_fixes {
                 ide_diagnostics::diagnostics(db, diagnostics_config, &resolve, frange.file_id)
                     .into_iter()
-                    .flat_map(|it| it.fixes.unwrap_or_default())
+                    .flat_map(|it| {
+                        let suppress = ide_diagnostics::suppress_fix(db, frange.file_id, &it);
+                        it.fixes.unwrap_or_default().into_iter().chain(suppress)
+                    })
                     .filter(|it| it.target.intersect(frange.range).is_some())
                     .collect()
             } else {
@@ -661,6 +750,15 @@ impl Analysis {
         self.with_db(|db| move_item::move_item(db, range, direction))
     }
 
+    /// Moves the item at `position` to the module defined by `target`.
+    pub fn move_item_to_module(
+        &self,
+        position: FilePosition,
+        target: FileId,
+    ) -> Cancellable<Option<MovedItem>> {
+        self.with_db(|db| move_item_to_module::move_item_to_module(db, position, target))
+    }
+
     /// Performs an operation on the database that may be canceled.
     ///
     /// rust-analyzer needs to be able to answer semantic questions about the
@@ -0,0 +1,87 @@
+use syntax::{AstNode, SourceFile, SyntaxKind, SyntaxToken, TextRange, TextSize};
+
+// Feature: Linked Editing Range
+//
+// If the cursor is on the quotes of a string, or on the hashes of a raw string, editing them also
+// edits their counterpart. Brackets aren't linked: the client would type the same text into
+// both, turning the closing `)` into a second `(`.
+pub(crate) fn linked_editing_range(file: &SourceFile, offset: TextSize) -> Option<Vec<TextRange>> {
+    file.syntax().token_at_offset(offset).find_map(|token| match token.kind() {
+        SyntaxKind::STRING | SyntaxKind::BYTE_STRING => string_delimiters(&token, offset),
+        _ => None,
+    })
+}
+
+fn string_delimiters(token: &SyntaxToken, offset: TextSize) -> Option<Vec<TextRange>> {
+    let text = token.text();
+    let range = token.text_range();
+    let prefix_len = text.find('"')?;
+    let hashes = text[..prefix_len].chars().filter(|&c| c == '#').count();
+    let suffix = format!("\"{}", "#".repeat(hashes));
+    if text.len() < prefix_len + suffix.len() || !text[prefix_len + 1..].ends_with(&suffix) {
+        // Unterminated string.
+        return None;
+    }
+
+    let open_quote = range.start() + TextSize::from(prefix_len as u32);
+    let close_quote = range.end() - TextSize::of(suffix.as_str());
+    let open_quote = TextRange::at(open_quote, TextSize::of('"'));
+    let close_quote = TextRange::at(close_quote, TextSize::of('"'));
+
+    if text[..prefix_len].contains('r') {
+        let hash_len = TextSize::from(hashes as u32);
+        let open_hashes = TextRange::at(open_quote.start() - hash_len, hash_len);
+        let close_hashes = TextRange::at(close_quote.end(), hash_len);
+        if open_hashes.contains_inclusive(offset) || close_hashes.contains_inclusive(offset) {
+            return Some(vec![open_hashes, close_hashes]);
+        }
+    }
+    if open_quote.contains_inclusive(offset) || close_quote.contains_inclusive(offset) {
+        return Some(vec![open_quote, close_quote]);
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use test_utils::{assert_eq_text, extract_offset};
+
+    use super::*;
+
+    fn check(before: &str, after: &str) {
+        let (offset, before) = extract_offset(before);
+        let file = SourceFile::parse(&before).tree();
+        let mut actual = before.clone();
+        if let Some(mut ranges) = linked_editing_range(&file, offset) {
+            ranges.sort_by_key(|range| std::cmp::Reverse(range.start()));
+            for range in ranges {
+                actual.insert_str(range.end().into(), "$0");
+                actual.insert_str(range.start().into(), "$0");
+            }
+        }
+        assert_eq_text!(after, &actual);
+    }
+
+    #[test]
+    fn brackets_are_not_linked() {
+        check("fn main() $0{ foo(92); }", "fn main() { foo(92); }");
+        check("fn main() { foo(92)$0; }", "fn main() { foo(92); }");
+        check("fn f<$0T>() {}", "fn f<T>() {}");
+        check("m!$0(a, (b));", "m!(a, (b));");
+    }
+
+    #[test]
+    fn strings() {
+        check(r#"fn f() { "$0abc"; }"#, r#"fn f() { $0"$0abc$0"$0; }"#);
+        check(r#"fn f() { b"abc$0"; }"#, r#"fn f() { b$0"$0abc$0"$0; }"#);
+        check(r#"fn f() { "a$0bc"; }"#, r#"fn f() { "abc"; }"#);
+        check(r#"fn f() { "abc$0 }"#, r#"fn f() { "abc }"#);
+    }
+
+    #[test]
+    fn raw_string_hashes() {
+        check(r##"fn f() { r#$0"abc"#; }"##, r##"fn f() { r$0#$0"abc"$0#$0; }"##);
+        check(r###"fn f() { br##"abc"##$0; }"###, r###"fn f() { br$0##$0"abc"$0##$0; }"###);
+        check(r#"fn f() { r$0"abc"; }"#, r#"fn f() { r$0$0"abc"$0$0; }"#);
+    }
+}
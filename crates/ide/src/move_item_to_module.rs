@@ -0,0 +1,845 @@
+use std::iter;
+
+use hir::{Module, ModuleSource, Semantics};
+use ide_db::{
+    base_db::{FileId, FilePosition, SourceDatabaseExt},
+    defs::{Definition, NameClass, NameRefClass},
+    helpers::mod_path_to_ast,
+    source_change::SourceChange,
+    RootDatabase,
+};
+use rustc_hash::{FxHashMap, FxHashSet};
+use syntax::{
+    algo::find_node_at_offset,
+    ast::{
+        self,
+        edit::{AstNodeEdit, IndentLevel},
+        HasName, HasVisibility,
+    },
+    AstNode, SyntaxKind, SyntaxNode, TextRange, TextSize,
+};
+use text_edit::{TextEdit, TextEditBuilder};
+
+#[derive(Debug)]
+pub struct MovedItem {
+    pub source_change: SourceChange,
+    /// Things that will probably need manual fixing after the move, like names the item uses that
+    /// are not in scope in the target module.
+    pub warnings: Vec<String>,
+}
+
+// Feature: Move Item to Module
+//
+// Moves the function, type or other module-level item under the cursor to the module defined by
+// another file, and updates the paths that refer to it.
+//
+// The item is made `pub(crate)` if it is used outside of the target module. Names used by the item
+// that are not in scope in the target module are reported, but don't prevent the move.
+//
+// |===
+// | Editor  | Action Name
+//
+// | VS Code | **Rust Analyzer: Move item to module**
+// |===
+pub(crate) fn move_item_to_module(
+    db: &RootDatabase,
+    position: FilePosition,
+    target_file: FileId,
+) -> Option<MovedItem> {
+    let sema = Semantics::new(db);
+    let source_file = sema.parse(position.file_id);
+
+    let item = find_node_at_offset::<ast::Item>(source_file.syntax(), position.offset)?
+        .syntax()
+        .ancestors()
+        .filter_map(ast::Item::cast)
+        .find(|it| is_module_level(it.syntax()))?;
+    let (name, visibility) = name_and_visibility(&item)?;
+    let def = NameClass::classify(&sema, &name)?.defined()?;
+
+    let source_module = containing_module(&sema, position.file_id, item.syntax())?;
+    let target_module = sema.to_module_def(target_file)?;
+    if source_module == target_module || source_module.krate() != target_module.krate() {
+        return None;
+    }
+
+    let item_range = item.syntax().text_range();
+    let mut warnings = dependency_warnings(&sema, &item, def, target_file);
+    let mut edits: FxHashMap<FileId, TextEditBuilder> = FxHashMap::default();
+    let mut needs_visibility = false;
+
+    let mut references = Vec::new();
+    let usages = def.usages(&sema).all();
+    for (file_id, file_references) in usages.references {
+        let root = sema.parse(file_id).syntax().clone();
+        for reference in file_references {
+            if file_id == position.file_id && item_range.contains_range(reference.range) {
+                continue;
+            }
+            let name_ref = match reference.name {
+                ast::NameLike::NameRef(name_ref) => name_ref,
+                _ => continue,
+            };
+            if name_ref.syntax().ancestors().last().as_ref() != Some(&root) {
+                warnings.push(format!(
+                    "The reference to `{}` in a macro call could not be updated",
+                    name.text()
+                ));
+                continue;
+            }
+            if let Some(usage_module) = containing_module(&sema, file_id, name_ref.syntax()) {
+                references.push((file_id, name_ref, usage_module));
+            }
+        }
+    }
+    // The modules importing the item by name. Unqualified uses in the other modules come from
+    // glob imports.
+    let imported_by_name = references
+        .iter()
+        .filter(|(_, name_ref, _)| {
+            name_ref.syntax().ancestors().any(|it| ast::UseTree::can_cast(it.kind()))
+        })
+        .map(|&(_, _, usage_module)| usage_module)
+        .collect::<FxHashSet<_>>();
+
+    for (file_id, name_ref, usage_module) in references {
+        if !is_in_subtree(db, usage_module, target_module) {
+            needs_visibility = true;
+        }
+        let modules = Modules {
+            source: source_module,
+            usage: usage_module,
+            target: target_module,
+            usage_imports_by_name: imported_by_name.contains(&usage_module),
+        };
+        let edit = edits.entry(file_id).or_insert_with(TextEdit::builder);
+        if !update_reference(db, edit, &name_ref, modules) {
+            warnings.push(format!("A reference to `{}` could not be updated", name.text()));
+        }
+    }
+
+    let moved_item = item.dedent(IndentLevel::from_node(item.syntax()));
+    let moved_start = moved_item.syntax().text_range().start();
+    let mut text = moved_item.to_string();
+    // `pub(super)` and the like mean something else in the new module, so these are either
+    // widened to `pub(crate)` or dropped if all uses are in the target module anyway.
+    let restricted_range = name_and_visibility(&moved_item)
+        .and_then(|(_, vis)| vis)
+        .filter(|vis| !matches!(vis.syntax().text().to_string().as_str(), "pub" | "pub(crate)"))
+        .map(|vis| range_with_trailing_ws(vis.syntax()) - moved_start);
+    match (restricted_range, needs_visibility) {
+        (Some(range), true) => text.replace_range(range_to_usize(range), "pub(crate) "),
+        (Some(range), false) => text.replace_range(range_to_usize(range), ""),
+        (None, true) if visibility.is_none() => {
+            let offset = visibility_offset(moved_item.syntax()) - moved_start;
+            text.insert_str(offset.into(), "pub(crate) ");
+        }
+        (None, _) => (),
+    }
+    if needs_visibility {
+        if let ast::Item::Struct(strukt) = &item {
+            if has_private_fields(strukt) {
+                warnings.push(format!(
+                    "The private fields of `{}` might no longer be accessible",
+                    name.text()
+                ));
+            }
+        }
+    }
+
+    let source_edit = edits.entry(position.file_id).or_insert_with(TextEdit::builder);
+    source_edit.delete(range_with_trailing_ws(item.syntax()));
+
+    let target_text = db.file_text(target_file);
+    let insert = if target_text.trim().is_empty() {
+        format!("{}\n", text)
+    } else if target_text.ends_with('\n') {
+        format!("\n{}\n", text)
+    } else {
+        format!("\n\n{}\n", text)
+    };
+    edits
+        .entry(target_file)
+        .or_insert_with(TextEdit::builder)
+        .insert(TextSize::of(&*target_text), insert);
+
+    let source_change = edits
+        .into_iter()
+        .map(|(file_id, edit)| (file_id, edit.finish()))
+        .collect::<FxHashMap<_, _>>()
+        .into();
+    Some(MovedItem { source_change, warnings })
+}
+
+fn is_module_level(item: &SyntaxNode) -> bool {
+    match item.parent() {
+        Some(parent) if ast::SourceFile::can_cast(parent.kind()) => true,
+        Some(parent) if ast::ItemList::can_cast(parent.kind()) => {
+            parent.parent().map_or(false, |it| ast::Module::can_cast(it.kind()))
+        }
+        _ => false,
+    }
+}
+
+fn name_and_visibility(item: &ast::Item) -> Option<(ast::Name, Option<ast::Visibility>)> {
+    let res = match item {
+        ast::Item::Const(it) => (it.name()?, it.visibility()),
+        ast::Item::Enum(it) => (it.name()?, it.visibility()),
+        ast::Item::Fn(it) => (it.name()?, it.visibility()),
+        ast::Item::Static(it) => (it.name()?, it.visibility()),
+        ast::Item::Struct(it) => (it.name()?, it.visibility()),
+        ast::Item::Trait(it) => (it.name()?, it.visibility()),
+        ast::Item::TypeAlias(it) => (it.name()?, it.visibility()),
+        ast::Item::Union(it) => (it.name()?, it.visibility()),
+        _ => return None,
+    };
+    Some(res)
+}
+
+/// Returns the innermost module, not counting block expressions, that contains `node`.
+fn containing_module(
+    sema: &Semantics<RootDatabase>,
+    file_id: FileId,
+    node: &SyntaxNode,
+) -> Option<Module> {
+    match node.ancestors().skip(1).filter_map(ast::Module::cast).find(|it| it.item_list().is_some())
+    {
+        Some(module) => sema.to_def(&module),
+        None => sema.to_module_def(file_id),
+    }
+}
+
+fn is_in_subtree(db: &RootDatabase, module: Module, root: Module) -> bool {
+    iter::successors(Some(module), |it| it.parent(db)).any(|it| it == root)
+}
+
+struct Modules {
+    source: Module,
+    usage: Module,
+    target: Module,
+    /// Whether the usage module imports the item by its name, rather than through a glob import.
+    usage_imports_by_name: bool,
+}
+
+/// Rewrites the path `name_ref` is part of to refer to the item in its new module. Returns `false`
+/// if that wasn't possible.
+fn update_reference(
+    db: &RootDatabase,
+    edit: &mut TextEditBuilder,
+    name_ref: &ast::NameRef,
+    modules: Modules,
+) -> bool {
+    let Modules { source: source_module, usage: usage_module, target: target_module, .. } = modules;
+    let path = match name_ref.syntax().parent().and_then(ast::PathSegment::cast) {
+        Some(segment) => segment.parent_path(),
+        None => return false,
+    };
+    let qualifier_range =
+        TextRange::new(path.syntax().text_range().start(), name_ref.syntax().text_range().start());
+    let new_qualifier = || -> Option<String> {
+        if usage_module == target_module {
+            return Some("self".to_string());
+        }
+        let path = usage_module.find_use_path(db, hir::ModuleDef::Module(target_module))?;
+        Some(mod_path_to_ast(&path).to_string())
+    };
+
+    let tree = match path.syntax().parent().and_then(ast::UseTree::cast) {
+        Some(tree) => tree,
+        // Outside of the source module the item can only be used unqualified through an import.
+        // Imports by name get updated on their own, but glob imports of the source module no
+        // longer bring the item into scope.
+        None if path.qualifier().is_none() => {
+            let is_glob_imported = !modules.usage_imports_by_name && usage_module != target_module;
+            if usage_module == source_module || is_glob_imported {
+                match new_qualifier() {
+                    Some(qualifier) => {
+                        edit.insert(qualifier_range.start(), format!("{}::", qualifier))
+                    }
+                    None => return false,
+                }
+            }
+            return true;
+        }
+        None => {
+            if usage_module == target_module {
+                edit.delete(qualifier_range);
+            } else {
+                match new_qualifier() {
+                    Some(qualifier) => edit.replace(qualifier_range, format!("{}::", qualifier)),
+                    None => return false,
+                }
+            }
+            return true;
+        }
+    };
+    if tree.use_tree_list().is_some() || tree.star_token().is_some() {
+        return false;
+    }
+    // Importing the item into the module it is defined in would conflict with it.
+    let is_redundant = usage_module == target_module && tree.rename().is_none();
+
+    let in_group = tree.syntax().parent().map_or(false, |it| ast::UseTreeList::can_cast(it.kind()));
+    if !in_group {
+        if is_redundant {
+            remove_use_tree(edit, &tree);
+        } else {
+            match new_qualifier() {
+                Some(qualifier) => edit.replace(qualifier_range, format!("{}::", qualifier)),
+                None => return false,
+            }
+        }
+        return true;
+    }
+
+    // Part of a group import, like `use foo::{item, other}`: pull it out into its own `use` item.
+    let use_item = match tree.syntax().ancestors().find_map(ast::Use::cast) {
+        Some(it) => it,
+        None => return false,
+    };
+    if !is_redundant {
+        let qualifier = match new_qualifier() {
+            Some(it) => it,
+            None => return false,
+        };
+        let visibility = match use_item.visibility() {
+            Some(vis) => format!("{} ", vis),
+            None => String::new(),
+        };
+        let rename = match tree.rename() {
+            Some(rename) => format!(" {}", rename),
+            None => String::new(),
+        };
+        edit.insert(
+            use_item.syntax().text_range().end(),
+            format!(
+                "\n{}{}use {}::{}{};",
+                IndentLevel::from_node(use_item.syntax()),
+                visibility,
+                qualifier,
+                name_ref,
+                rename
+            ),
+        );
+    }
+    remove_use_tree(edit, &tree);
+    true
+}
+
+/// Removes `tree`, either with its separating comma if it is part of a group, or together with the
+/// whole `use` item.
+fn remove_use_tree(edit: &mut TextEditBuilder, tree: &ast::UseTree) {
+    let list = match tree.syntax().parent().and_then(ast::UseTreeList::cast) {
+        Some(it) => it,
+        None => {
+            if let Some(use_item) = tree.syntax().parent().and_then(ast::Use::cast) {
+                edit.delete(range_with_trailing_ws(use_item.syntax()));
+            }
+            return;
+        }
+    };
+    let parent_tree = list.parent_use_tree();
+    let mut rest = list.use_trees().filter(|it| it != tree);
+    match (rest.next(), rest.next(), parent_tree.path()) {
+        (None, _, _) => return remove_use_tree(edit, &parent_tree),
+        // Don't leave a group with a single tree behind, like `use foo::{self};`.
+        (Some(remaining), None, Some(prefix)) => {
+            let is_self = remaining.is_simple_path()
+                && remaining.path().map_or(false, |it| it.syntax().text() == "self");
+            let replacement = if is_self {
+                remaining.rename().map_or_else(String::new, |it| format!(" {}", it))
+            } else {
+                format!("::{}", remaining)
+            };
+            let range = TextRange::new(
+                prefix.syntax().text_range().end(),
+                list.syntax().text_range().end(),
+            );
+            edit.replace(range, replacement);
+            return;
+        }
+        _ => (),
+    }
+    let range = tree.syntax().text_range();
+    let next_comma = tree
+        .syntax()
+        .siblings_with_tokens(syntax::Direction::Next)
+        .skip(1)
+        .find(|it| !it.kind().is_trivia())
+        .filter(|it| it.kind() == SyntaxKind::COMMA);
+    let prev_comma = tree
+        .syntax()
+        .siblings_with_tokens(syntax::Direction::Prev)
+        .skip(1)
+        .find(|it| !it.kind().is_trivia())
+        .filter(|it| it.kind() == SyntaxKind::COMMA);
+    let range = match (next_comma, prev_comma) {
+        (Some(comma), _) => {
+            let end = match comma.next_sibling_or_token() {
+                Some(ws) if ws.kind() == SyntaxKind::WHITESPACE => ws.text_range().end(),
+                _ => comma.text_range().end(),
+            };
+            TextRange::new(range.start(), end)
+        }
+        (None, Some(comma)) => TextRange::new(comma.text_range().start(), range.end()),
+        (None, None) => range,
+    };
+    edit.delete(range);
+}
+
+/// The range of `node` along with the whitespace separating it from the next item, or from the
+/// previous one if it is the last item.
+fn range_with_trailing_ws(node: &SyntaxNode) -> TextRange {
+    let range = node.text_range();
+    let next_ws = node.next_sibling_or_token().filter(|it| it.kind() == SyntaxKind::WHITESPACE);
+    match next_ws {
+        Some(ws) if ws.next_sibling_or_token().is_some() => range.cover(ws.text_range()),
+        _ => match node.prev_sibling_or_token() {
+            Some(ws) if ws.kind() == SyntaxKind::WHITESPACE => range.cover(ws.text_range()),
+            _ => range,
+        },
+    }
+}
+
+fn range_to_usize(range: TextRange) -> std::ops::Range<usize> {
+    range.start().into()..range.end().into()
+}
+
+fn visibility_offset(item: &SyntaxNode) -> TextSize {
+    item.children_with_tokens()
+        .find(|it| {
+            !matches!(it.kind(), SyntaxKind::ATTR | SyntaxKind::COMMENT) && !it.kind().is_trivia()
+        })
+        .map_or_else(|| item.text_range().start(), |it| it.text_range().start())
+}
+
+fn has_private_fields(strukt: &ast::Struct) -> bool {
+    match strukt.field_list() {
+        Some(ast::FieldList::RecordFieldList(fields)) => {
+            fields.fields().any(|it| it.visibility().is_none())
+        }
+        Some(ast::FieldList::TupleFieldList(fields)) => {
+            fields.fields().any(|it| it.visibility().is_none())
+        }
+        None => false,
+    }
+}
+
+/// Collects the names used by `item` that won't be in scope after moving it to `target_file`.
+fn dependency_warnings(
+    sema: &Semantics<RootDatabase>,
+    item: &ast::Item,
+    moved: Definition,
+    target_file: FileId,
+) -> Vec<String> {
+    let target_scope = sema.scope(sema.parse(target_file).syntax());
+    let mut in_scope = FxHashSet::default();
+    target_scope.process_all_names(&mut |_, def| match def {
+        hir::ScopeDef::ModuleDef(def) => {
+            in_scope.insert(Definition::from(def));
+        }
+        hir::ScopeDef::MacroDef(mac) => {
+            in_scope.insert(Definition::Macro(mac));
+        }
+        _ => (),
+    });
+
+    let mut res = Vec::new();
+    let mut seen = FxHashSet::default();
+    for path in item.syntax().descendants().filter_map(ast::Path::cast) {
+        // The visibility is adjusted separately.
+        if path.qualifier().is_some()
+            || path.syntax().ancestors().any(|it| ast::Visibility::can_cast(it.kind()))
+        {
+            continue;
+        }
+        let segment = match path.segment() {
+            Some(it) => it,
+            None => continue,
+        };
+        if segment.super_token().is_some() || segment.self_token().is_some() {
+            if seen.insert(segment.syntax().text().to_string()) {
+                res.push(format!(
+                    "The relative path `{}` might need to be updated",
+                    path.top_path().syntax().text()
+                ));
+            }
+            continue;
+        }
+        let name_ref = match segment.name_ref() {
+            Some(it) => it,
+            None => continue,
+        };
+        let def = match NameRefClass::classify(sema, &name_ref) {
+            Some(NameRefClass::Definition(def)) => def,
+            _ => continue,
+        };
+        let is_item = matches!(
+            def,
+            Definition::Macro(_)
+                | Definition::Module(_)
+                | Definition::Function(_)
+                | Definition::Adt(_)
+                | Definition::Variant(_)
+                | Definition::Const(_)
+                | Definition::Static(_)
+                | Definition::Trait(_)
+                | Definition::TypeAlias(_)
+        );
+        if !is_item || def == moved || in_scope.contains(&def) {
+            continue;
+        }
+        // Items declared in a block of the moved item move along with it.
+        let in_block = def.module(sema.db).map_or(false, |module| {
+            matches!(module.definition_source(sema.db).value, ModuleSource::BlockExpr(_))
+        });
+        if !in_block && seen.insert(name_ref.text().to_string()) {
+            res.push(format!("`{}` is not in scope in the target module", name_ref.text()));
+        }
+    }
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::{expect, Expect};
+    use ide_db::base_db::{FileId, SourceDatabaseExt};
+    use stdx::format_to;
+
+    use crate::fixture;
+
+    fn check(ra_fixture: &str, target: &str, expect: Expect) {
+        let (analysis, position) = fixture::position(ra_fixture);
+        let target = find_file(&analysis, position.file_id, target);
+        let moved = analysis.move_item_to_module(position, target).unwrap().expect("not movable");
+
+        let mut actual = String::new();
+        let mut edits = moved.source_change.source_file_edits.into_iter().collect::<Vec<_>>();
+        edits.sort_by_key(|(file_id, _)| *file_id);
+        for (file_id, edit) in edits {
+            let mut text = analysis.file_text(file_id).unwrap().to_string();
+            edit.apply(&mut text);
+            format_to!(actual, "//- {}\n{}", path_of(&analysis, file_id), text);
+        }
+        for warning in moved.warnings {
+            format_to!(actual, "// warning: {}\n", warning);
+        }
+        expect.assert_eq(&actual);
+    }
+
+    fn check_not_applicable(ra_fixture: &str, target: &str) {
+        let (analysis, position) = fixture::position(ra_fixture);
+        let target = find_file(&analysis, position.file_id, target);
+        assert!(analysis.move_item_to_module(position, target).unwrap().is_none());
+    }
+
+    fn find_file(analysis: &crate::Analysis, anchor: FileId, path: &str) -> FileId {
+        analysis
+            .with_db(|db| {
+                let source_root = db.source_root(db.file_source_root(anchor));
+                let file_id = source_root.iter().find(|&file_id| {
+                    source_root.path_for_file(&file_id).map_or(false, |it| it.to_string() == path)
+                });
+                file_id
+            })
+            .unwrap()
+            .unwrap()
+    }
+
+    fn path_of(analysis: &crate::Analysis, file_id: FileId) -> String {
+        analysis
+            .with_db(|db| {
+                let source_root = db.source_root(db.file_source_root(file_id));
+                source_root.path_for_file(&file_id).unwrap().to_string()
+            })
+            .unwrap()
+    }
+
+    #[test]
+    fn move_fn_to_sibling_module() {
+        check(
+            r#"
+//- /main.rs
+mod a;
+mod b;
+
+fn main() {
+    a::foo();
+}
+//- /a.rs
+pub fn foo$0() {}
+
+pub fn bar() {
+    foo();
+}
+//- /b.rs
+pub fn baz() {}
+"#,
+            "/b.rs",
+            expect![[r#"
+                //- /main.rs
+                mod a;
+                mod b;
+
+                fn main() {
+                    b::foo();
+                }
+                //- /a.rs
+                pub fn bar() {
+                    crate::b::foo();
+                }
+                //- /b.rs
+                pub fn baz() {}
+
+                pub fn foo() {}
+            "#]],
+        );
+    }
+
+    #[test]
+    fn move_private_struct_adds_visibility() {
+        check(
+            r#"
+//- /main.rs
+mod b;
+
+/// Docs.
+struct S$0 {
+    field: u32,
+}
+
+fn main() {
+    let _ = S { field: 0 };
+}
+//- /b.rs
+"#,
+            "/b.rs",
+            expect![[r#"
+                //- /main.rs
+                mod b;
+
+                fn main() {
+                    let _ = b::S { field: 0 };
+                }
+                //- /b.rs
+                /// Docs.
+                pub(crate) struct S {
+                    field: u32,
+                }
+                // warning: The private fields of `S` might no longer be accessible
+            "#]],
+        );
+    }
+
+    #[test]
+    fn move_updates_use_items() {
+        check(
+            r#"
+//- /main.rs
+mod a;
+mod b;
+mod c;
+//- /a.rs
+pub type Alias$0 = u32;
+//- /b.rs
+use crate::a::Alias;
+pub fn f(_: Alias) {}
+//- /c.rs
+use crate::a::{self, Alias};
+pub use crate::a::Alias as Other;
+pub fn g(_: Alias, _: a::Alias) {}
+"#,
+            "/b.rs",
+            expect![[r#"
+                //- /a.rs
+
+                //- /b.rs
+                pub fn f(_: Alias) {}
+
+                pub type Alias = u32;
+                //- /c.rs
+                use crate::a;
+                use crate::b::Alias;
+                pub use crate::b::Alias as Other;
+                pub fn g(_: Alias, _: crate::b::Alias) {}
+            "#]],
+        );
+    }
+
+    #[test]
+    fn move_collapses_use_groups() {
+        check(
+            r#"
+//- /main.rs
+mod a;
+mod b;
+mod c;
+//- /a.rs
+pub struct S$0;
+pub struct T;
+//- /b.rs
+//- /c.rs
+use crate::{a::{S}, b};
+use crate::a::{S as Renamed, T};
+pub fn g(_: S, _: Renamed, _: T) {}
+"#,
+            "/b.rs",
+            expect![[r#"
+                //- /a.rs
+                pub struct T;
+                //- /b.rs
+                pub struct S;
+                //- /c.rs
+                use crate::b;
+                use b::S;
+                use crate::a::T;
+                use b::S as Renamed;
+                pub fn g(_: S, _: Renamed, _: T) {}
+            "#]],
+        );
+    }
+
+    #[test]
+    fn move_qualifies_uses_through_glob_imports() {
+        check(
+            r#"
+//- /main.rs
+mod a;
+mod b;
+//- /a.rs
+pub fn foo$0() {}
+
+mod tests {
+    use super::*;
+
+    fn test_foo() {
+        foo();
+    }
+}
+//- /b.rs
+use crate::a::*;
+
+fn bar() {
+    foo();
+}
+"#,
+            "/b.rs",
+            expect![[r#"
+                //- /a.rs
+                mod tests {
+                    use super::*;
+
+                    fn test_foo() {
+                        crate::b::foo();
+                    }
+                }
+                //- /b.rs
+                use crate::a::*;
+
+                fn bar() {
+                    foo();
+                }
+
+                pub fn foo() {}
+            "#]],
+        );
+    }
+
+    #[test]
+    fn move_warns_about_dependencies() {
+        check(
+            r#"
+//- /main.rs
+mod b;
+
+struct Helper;
+mod inner {
+    pub fn g() {}
+}
+
+fn foo$0() -> Helper {
+    struct Local;
+    let _ = Local;
+    inner::g();
+    Helper
+}
+//- /b.rs
+"#,
+            "/b.rs",
+            expect![[r#"
+                //- /main.rs
+                mod b;
+
+                struct Helper;
+                mod inner {
+                    pub fn g() {}
+                }
+                //- /b.rs
+                fn foo() -> Helper {
+                    struct Local;
+                    let _ = Local;
+                    inner::g();
+                    Helper
+                }
+                // warning: `Helper` is not in scope in the target module
+                // warning: `inner` is not in scope in the target module
+            "#]],
+        );
+    }
+
+    #[test]
+    fn move_into_parent_module() {
+        check(
+            r#"
+//- /main.rs
+mod a;
+
+fn main() {
+    a::foo();
+}
+//- /a.rs
+pub(super) fn foo$0() {}
+"#,
+            "/main.rs",
+            expect![[r#"
+                //- /main.rs
+                mod a;
+
+                fn main() {
+                    foo();
+                }
+
+                fn foo() {}
+                //- /a.rs
+
+            "#]],
+        );
+    }
+
+    #[test]
+    fn not_applicable_to_assoc_items() {
+        check_not_applicable(
+            r#"
+//- /main.rs
+mod b;
+struct S;
+impl S {
+    fn f$0() {}
+}
+//- /b.rs
+"#,
+            "/b.rs",
+        );
+    }
+
+    #[test]
+    fn not_applicable_to_own_module() {
+        check_not_applicable(
+            r#"
+//- /main.rs
+fn f$0() {}
+"#,
+            "/main.rs",
+        );
+    }
+}
@@ -322,6 +322,32 @@ impl TryToNav for hir::Impl {
     }
 }
 
+impl TryToNav for hir::ExternCrateDecl {
+    fn try_to_nav(&self, db: &RootDatabase) -> Option<NavigationTarget> {
+        let src = self.source(db)?;
+        let InFile { file_id, value } = &src;
+        let focus = value
+            .rename()
+            .and_then(|it| it.name())
+            .map(|it| it.syntax().clone())
+            .or_else(|| value.name_ref().map(|it| it.syntax().clone()));
+        let focus_range = focus
+            .and_then(|it| InFile::new(*file_id, &it).original_file_range_opt(db))
+            .map(|it| it.range);
+        let FileRange { file_id, range: full_range } =
+            src.as_ref().map(|it| it.syntax()).original_file_range(db);
+
+        let name = self.rename(db).unwrap_or_else(|| self.name(db)).to_smol_str();
+        Some(NavigationTarget::from_syntax(
+            file_id,
+            name,
+            focus_range,
+            full_range,
+            SymbolKind::Module,
+        ))
+    }
+}
+
 impl TryToNav for hir::Field {
     fn try_to_nav(&self, db: &RootDatabase) -> Option<NavigationTarget> {
         let src = self.source(db)?;
@@ -0,0 +1,210 @@
+//! Finds the page of the rustdoc documentation of an item, to open it in a browser.
+
+use hir::Semantics;
+use ide_db::{
+    base_db::{FileId, FilePosition},
+    RootDatabase,
+};
+use stdx::format_to;
+
+use crate::doc_links::{
+    crate_of_def, definition_at, filename_and_frag_for_def, get_doc_link, is_hosted,
+    mod_path_of_def,
+};
+
+/// Where the documentation of an item can be found.
+#[derive(Debug, PartialEq, Eq)]
+pub enum DocsLocation {
+    /// The documentation is published online, on docs.rs for crates from crates.io.
+    Remote(String),
+    /// The documentation has to be generated by rustdoc first, like for the crates of the
+    /// workspace.
+    Local {
+        /// The root file of the crate to document.
+        crate_root: FileId,
+        /// The URL of the page relative to the `doc` directory rustdoc writes to.
+        path: String,
+    },
+}
+
+// Feature: Open Docs
+//
+// Opens the documentation of the item under the cursor in the browser. Items of the standard
+// library and of crates from crates.io link to their documentation on doc.rust-lang.org and
+// docs.rs, the other crates are documented with `cargo doc` first. Re-exported items open the
+// page of their definition.
+//
+// |===
+// | Editor  | Action Name
+//
+// | VS Code | **Rust Analyzer: Open docs under cursor**
+// |===
+pub(crate) fn open_docs(db: &RootDatabase, position: FilePosition) -> Option<DocsLocation> {
+    let sema = Semantics::new(db);
+    let def = definition_at(&sema, &position)?;
+    let krate = crate_of_def(db, def)?;
+    if is_hosted(db, krate) {
+        return get_doc_link(db, def).map(DocsLocation::Remote);
+    }
+
+    let (target, file, frag) = filename_and_frag_for_def(db, def)?;
+    let mut path = format!("{}/", krate.display_name(db)?.crate_name());
+    if let Some(mod_path) = mod_path_of_def(db, target) {
+        path.push_str(&mod_path);
+    }
+    path.push_str(&file);
+    if let Some(frag) = frag {
+        format_to!(path, "#{}", frag);
+    }
+    Some(DocsLocation::Local { crate_root: krate.root_file(db), path })
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::{expect, Expect};
+
+    use crate::fixture;
+
+    fn check(ra_fixture: &str, expect: Expect) {
+        let (analysis, position) = fixture::position(ra_fixture);
+        let location = analysis.open_docs(position).unwrap().expect("no documentation found");
+        expect.assert_debug_eq(&location)
+    }
+
+    #[test]
+    fn crates_io_items() {
+        check(
+            r#"
+//- /main.rs crate:main deps:foo
+fn f(foo: foo::Foo) {
+    foo.bar$0();
+}
+//- /lib.rs crate:foo@CratesIo:0.1.0,https://github.com/foo/foo new_source_root:library
+pub struct Foo;
+impl Foo {
+    pub fn bar(&self) {}
+}
+"#,
+            expect![[r##"
+                Remote(
+                    "https://docs.rs/foo/0.1.0/foo/struct.Foo.html#method.bar",
+                )
+            "##]],
+        );
+        check(
+            r#"
+//- /main.rs crate:main deps:foo
+use foo::Kind::Small$0;
+//- /lib.rs crate:foo@CratesIo:0.1.0,https://github.com/foo/foo new_source_root:library
+pub enum Kind { Small, Large }
+"#,
+            expect![[r##"
+                Remote(
+                    "https://docs.rs/foo/0.1.0/foo/enum.Kind.html#variant.Small",
+                )
+            "##]],
+        );
+    }
+
+    #[test]
+    fn std_items() {
+        check(
+            r#"
+//- /main.rs crate:main deps:std
+fn f(it: impl std::iter::Iterator$0) {}
+//- /std.rs crate:std new_source_root:library
+pub mod iter {
+    pub trait Iterator {}
+}
+"#,
+            expect![[r#"
+                Remote(
+                    "https://doc.rust-lang.org/nightly/std/iter/trait.Iterator.html",
+                )
+            "#]],
+        );
+    }
+
+    #[test]
+    fn reexported_item() {
+        check(
+            r#"
+//- /main.rs crate:main deps:foo
+use foo::Foo$0;
+//- /lib.rs crate:foo@CratesIo:0.2.0,https://github.com/foo/foo new_source_root:library
+pub mod imp {
+    pub mod types {
+        pub struct Foo;
+    }
+}
+pub use imp::types::Foo;
+"#,
+            expect![[r#"
+                Remote(
+                    "https://docs.rs/foo/0.2.0/foo/imp/types/struct.Foo.html",
+                )
+            "#]],
+        );
+    }
+
+    #[test]
+    fn workspace_member_from_crates_io() {
+        check(
+            r#"
+//- /main.rs crate:main deps:foo
+use foo::Foo$0;
+//- /lib.rs crate:foo@CratesIo:0.1.0,https://github.com/foo/foo
+pub struct Foo;
+"#,
+            expect![[r#"
+                Local {
+                    crate_root: FileId(
+                        1,
+                    ),
+                    path: "foo/struct.Foo.html",
+                }
+            "#]],
+        );
+    }
+
+    #[test]
+    fn local_items() {
+        check(
+            r#"
+//- /main.rs crate:main
+mod shapes {
+    pub trait Shape {
+        const SIDES: u32;
+    }
+}
+fn f<S: shapes::Shape>() -> u32 {
+    S::SIDES$0
+}
+"#,
+            expect![[r##"
+                Local {
+                    crate_root: FileId(
+                        0,
+                    ),
+                    path: "main/shapes/trait.Shape.html#associatedconstant.SIDES",
+                }
+            "##]],
+        );
+        check(
+            r#"
+//- /main.rs crate:main
+mod shapes$0;
+//- /shapes.rs
+pub struct Circle;
+"#,
+            expect![[r#"
+                Local {
+                    crate_root: FileId(
+                        0,
+                    ),
+                    path: "main/shapes/index.html",
+                }
+            "#]],
+        );
+    }
+}
@@ -0,0 +1,94 @@
+use ide_db::helpers::pick_best_token;
+use syntax::{
+    ast::{self, AstNode},
+    SourceFile,
+    SyntaxKind::{self, EXPR_STMT, LET_STMT},
+    TextRange,
+};
+
+// Feature: Go to Parent Expression
+//
+// Selects the expression or statement enclosing the current selection, or the expression
+// under the cursor. Invoking it again selects the next enclosing one, so with the cursor on
+// `x` in `foo(x + y)`, it selects `x + y`, then `foo(x + y)`.
+//
+// |===
+// | Editor  | Action Name
+//
+// | VS Code | **Rust Analyzer: Go to parent expression**
+// |===
+pub(crate) fn parent_expression(file: &SourceFile, range: TextRange) -> Option<TextRange> {
+    // Without a selection, start from the token under the cursor, so that the expression it
+    // makes up counts as already selected.
+    let range = if range.is_empty() {
+        let token =
+            pick_best_token(file.syntax().token_at_offset(range.start()), |kind| match kind {
+                _ if kind.is_trivia() => 0,
+                _ if kind.is_punct() => 1,
+                _ => 2,
+            })?;
+        token.text_range()
+    } else {
+        range
+    };
+
+    let node = match file.syntax().covering_element(range) {
+        syntax::NodeOrToken::Node(node) => node,
+        syntax::NodeOrToken::Token(token) => token.parent()?,
+    };
+    node.ancestors()
+        .filter(|it| is_expr_or_stmt(it.kind()))
+        .map(|it| it.text_range())
+        .find(|it| it.contains_range(range) && *it != range)
+}
+
+fn is_expr_or_stmt(kind: SyntaxKind) -> bool {
+    ast::Expr::can_cast(kind) || matches!(kind, EXPR_STMT | LET_STMT)
+}
+
+#[cfg(test)]
+mod tests {
+    use syntax::TextRange;
+    use test_utils::extract_range_or_offset;
+
+    use super::*;
+
+    fn check(ra_fixture: &str, expected: &[&str]) {
+        let (range_or_offset, text) = extract_range_or_offset(ra_fixture);
+        let file = SourceFile::parse(&text).tree();
+        let mut range: TextRange = range_or_offset.into();
+        let mut actual = Vec::new();
+        while let Some(parent) = parent_expression(&file, range) {
+            actual.push(&text[parent]);
+            range = parent;
+        }
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn parents_of_cursor() {
+        check(
+            "fn f() { foo($0x + y); }",
+            &["x + y", "foo(x + y)", "foo(x + y);", "{ foo(x + y); }"],
+        );
+        check(
+            "fn f() { let a = foo(x$0); }",
+            &["foo(x)", "let a = foo(x);", "{ let a = foo(x); }"],
+        );
+    }
+
+    #[test]
+    fn parents_of_selection() {
+        check("fn f() { if $0a.b(c)$0 { d } }", &["if a.b(c) { d }", "{ if a.b(c) { d } }"]);
+        check(
+            "fn f() { [1, $02 +$0 3]; }",
+            &["2 + 3", "[1, 2 + 3]", "[1, 2 + 3];", "{ [1, 2 + 3]; }"],
+        );
+    }
+
+    #[test]
+    fn no_parent_outside_of_bodies() {
+        check("struct S$0;", &[]);
+        check("fn f() $0{}$0", &[]);
+    }
+}
@@ -23,7 +23,8 @@ use crate::NavigationTarget;
 //
 // image::https://user-images.githubusercontent.com/48062697/113065580-04c21800-91b1-11eb-9a32-00086161c0bd.gif[]
 
-/// This returns `Vec` because a module may be included from several places.
+/// This returns `Vec` because a module may be included from several places. It is empty for the
+/// crate root.
 pub(crate) fn parent_module(db: &RootDatabase, position: FilePosition) -> Vec<NavigationTarget> {
     let sema = Semantics::new(db);
     let source_file = sema.parse(position.file_id);
@@ -49,6 +50,8 @@ pub(crate) fn parent_module(db: &RootDatabase, position: FilePosition) -> Vec<Na
             .collect(),
         None => sema
             .to_module_defs(position.file_id)
+            // The crate root has no parent module to navigate to.
+            .filter(|module| module.parent(db).is_some())
             .map(|module| NavigationTarget::from_module_to_decl(db, module))
             .collect(),
     }
@@ -107,6 +110,33 @@ mod $0bar;
         );
     }
 
+    #[test]
+    fn test_resolve_parent_module_two_levels() {
+        check(
+            r#"
+//- /lib.rs
+mod foo;
+//- /foo.rs
+mod bar;
+  //^^^
+//- /foo/bar.rs
+fn f() {$0}
+"#,
+        );
+    }
+
+    #[test]
+    fn test_resolve_parent_module_of_crate_root() {
+        check(
+            r#"
+//- /lib.rs
+mod foo;
+fn f() {$0}
+//- /foo.rs
+"#,
+        );
+    }
+
     #[test]
     fn test_resolve_parent_module_for_inline() {
         check(
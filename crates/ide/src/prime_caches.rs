@@ -3,9 +3,19 @@
 //! request takes longer to compute. This modules implemented prepopulation of
 //! various caches, it's not really advanced at the moment.
 
+use std::{
+    any::Any,
+    panic::{self, AssertUnwindSafe},
+    time::Duration,
+};
+
+use crossbeam_channel::RecvTimeoutError;
 use hir::db::DefDatabase;
-use ide_db::base_db::{SourceDatabase, SourceDatabaseExt};
-use rustc_hash::FxHashSet;
+use ide_db::base_db::{
+    salsa::{Database, ParallelDatabase},
+    CrateId, SourceDatabase, SourceDatabaseExt,
+};
+use rustc_hash::{FxHashMap, FxHashSet};
 
 use crate::RootDatabase;
 
@@ -17,7 +27,21 @@ pub struct PrimeCachesProgress {
     pub n_total: usize,
 }
 
-pub(crate) fn prime_caches(db: &RootDatabase, cb: &(dyn Fn(PrimeCachesProgress) + Sync)) {
+/// Sent by the workers. After a `Panicked`, which includes getting cancelled, the worker stops.
+enum WorkerProgress {
+    BeginCrate { crate_name: String },
+    EndCrate { crate_id: CrateId },
+    Panicked(Box<dyn Any + Send>),
+}
+
+/// Computes the `ImportMap`s of the dependencies of the workspace crates, handing a crate to one
+/// of `num_worker_threads` workers as soon as the ones it depends on are done, so that
+/// independent crates are processed in parallel.
+pub(crate) fn prime_caches(
+    db: &RootDatabase,
+    num_worker_threads: usize,
+    cb: &(dyn Fn(PrimeCachesProgress) + Sync),
+) {
     let _p = profile::span("prime_caches");
     let graph = db.crate_graph();
     // We're only interested in the workspace crates and the `ImportMap`s of their direct
@@ -34,15 +58,183 @@ pub(crate) fn prime_caches(db: &RootDatabase, cb: &(dyn Fn(PrimeCachesProgress)
         .flat_map(|id| graph[id].dependencies.iter().map(|krate| krate.crate_id))
         .collect();
 
-    // FIXME: This would be easy to parallelize, since it's in the ideal ordering for that.
-    // Unfortunately rayon prevents panics from propagation out of a `scope`, which breaks
-    // cancellation, so we cannot use rayon.
-    let n_total = to_prime.len();
-    for (n_done, &crate_id) in to_prime.iter().enumerate() {
-        let crate_name = graph[crate_id].display_name.as_deref().unwrap_or_default().to_string();
+    // Other dependencies are computed on demand by the worker which needs them.
+    let mut pending_deps: FxHashMap<CrateId, usize> = FxHashMap::default();
+    let mut dependents: FxHashMap<CrateId, Vec<CrateId>> = FxHashMap::default();
+    for &id in &to_prime {
+        let deps: FxHashSet<_> = graph[id]
+            .dependencies
+            .iter()
+            .map(|dep| dep.crate_id)
+            .filter(|dep| to_prime.contains(dep))
+            .collect();
+        for &dep in &deps {
+            dependents.entry(dep).or_default().push(id);
+        }
+        pending_deps.insert(id, deps.len());
+    }
+    let mut ready: Vec<_> =
+        pending_deps.iter().filter(|(_, &n)| n == 0).map(|(&id, _)| id).collect();
+
+    let (work_sender, work_receiver) = crossbeam_channel::unbounded();
+    let (progress_sender, progress_receiver) = crossbeam_channel::unbounded();
+    for _ in 0..num_worker_threads.max(1) {
+        let work_receiver = work_receiver.clone();
+        let progress_sender = progress_sender.clone();
+        let db = db.snapshot();
+        std::thread::Builder::new()
+            .name("PrimeCaches".to_owned())
+            .spawn(move || {
+                // The loop ends once the main thread drops the sender, which also happens when
+                // it gets cancelled.
+                while let Ok((crate_id, crate_name)) = work_receiver.recv() {
+                    if progress_sender.send(WorkerProgress::BeginCrate { crate_name }).is_err() {
+                        break;
+                    }
+                    // Without its `EndCrate`, the main thread would wait for this crate forever,
+                    // so panics are sent over to be resumed there.
+                    let progress = match panic::catch_unwind(AssertUnwindSafe(|| {
+                        db.import_map(crate_id);
+                    })) {
+                        Ok(()) => WorkerProgress::EndCrate { crate_id },
+                        Err(payload) => WorkerProgress::Panicked(payload),
+                    };
+                    let panicked = matches!(progress, WorkerProgress::Panicked(_));
+                    if progress_sender.send(progress).is_err() || panicked {
+                        break;
+                    }
+                }
+            })
+            .expect("failed to spawn thread");
+    }
+    drop(progress_sender);
+
+    let n_total = pending_deps.len();
+    let mut n_done = 0;
+    let mut n_in_flight = 0;
+    loop {
+        for crate_id in ready.drain(..) {
+            let crate_name =
+                graph[crate_id].display_name.as_deref().unwrap_or_default().to_string();
+            work_sender.send((crate_id, crate_name)).ok();
+            n_in_flight += 1;
+        }
+        if n_in_flight == 0 {
+            break;
+        }
+
+        // Rayon would be the natural fit here, but it doesn't propagate panics out of a
+        // `scope`, which breaks cancellation. So we poll for it instead.
+        let progress = match progress_receiver.recv_timeout(Duration::from_millis(10)) {
+            Ok(it) => it,
+            Err(RecvTimeoutError::Timeout) => {
+                db.unwind_if_cancelled();
+                continue;
+            }
+            Err(RecvTimeoutError::Disconnected) => {
+                // Workers only stop early after reporting a panic.
+                db.unwind_if_cancelled();
+                break;
+            }
+        };
+        match progress {
+            WorkerProgress::BeginCrate { crate_name } => {
+                cb(PrimeCachesProgress { on_crate: crate_name, n_done, n_total });
+            }
+            WorkerProgress::EndCrate { crate_id } => {
+                n_done += 1;
+                n_in_flight -= 1;
+                for &dependent in dependents.get(&crate_id).into_iter().flatten() {
+                    let pending = pending_deps.get_mut(&dependent).unwrap();
+                    *pending -= 1;
+                    if *pending == 0 {
+                        ready.push(dependent);
+                    }
+                }
+            }
+            // This drops the sender, so the other workers stop after their current crate.
+            WorkerProgress::Panicked(payload) => panic::resume_unwind(payload),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        fmt::Write,
+        time::{Duration, Instant},
+    };
+
+    use ide_db::base_db::fixture::ChangeFixture;
+    use test_utils::{bench, skip_slow_tests};
+
+    use crate::{fixture, AnalysisHost};
+
+    /// A workspace crate depending on `n_crates` independent crates, which contain
+    /// `n_modules` modules each.
+    fn many_crates(n_crates: usize, n_modules: usize) -> String {
+        let mut fixture = String::new();
+        let deps: Vec<_> = (0..n_crates).map(|i| format!("dep{}", i)).collect();
+        writeln!(fixture, "//- /main.rs crate:main deps:{}", deps.join(",")).unwrap();
+        writeln!(fixture, "fn main() {{}}").unwrap();
+        for krate in &deps {
+            writeln!(fixture, "//- /{}/lib.rs crate:{}", krate, krate).unwrap();
+            for module in 0..n_modules {
+                writeln!(fixture, "pub mod m{} {{", module).unwrap();
+                writeln!(fixture, "    pub struct S;").unwrap();
+                writeln!(fixture, "    pub fn f() -> S {{ S }}").unwrap();
+                writeln!(fixture, "    macro_rules! m {{ () => {{ pub struct T; }} }}").unwrap();
+                writeln!(fixture, "    m!();").unwrap();
+                writeln!(fixture, "}}").unwrap();
+            }
+        }
+        fixture
+    }
+
+    /// Returns the number of crates which got primed.
+    fn prime(fixture: &str, num_worker_threads: usize, label: &'static str) -> usize {
+        let (analysis, _) = fixture::file(fixture);
+        let n_started = std::sync::atomic::AtomicUsize::new(0);
+        {
+            let _b = bench(label);
+            analysis
+                .prime_caches(num_worker_threads, |_| {
+                    n_started.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+                })
+                .unwrap();
+        }
+        n_started.into_inner()
+    }
+
+    #[test]
+    fn benchmark_prime_caches() {
+        if skip_slow_tests() {
+            return;
+        }
+
+        let fixture = many_crates(200, 10);
+        let sequential = prime(&fixture, 1, "prime caches, 1 thread");
+        let parallel = prime(&fixture, 8, "prime caches, 8 threads");
+        assert_eq!(sequential, 200);
+        assert_eq!(parallel, 200);
+    }
+
+    #[test]
+    fn prime_caches_is_cancellable() {
+        if skip_slow_tests() {
+            return;
+        }
+
+        let mut host = AnalysisHost::default();
+        host.raw_database_mut().apply_change(ChangeFixture::parse(&many_crates(200, 10)).change);
+        let analysis = host.analysis();
+        let worker = std::thread::spawn(move || analysis.prime_caches(2, |_| ()));
+        std::thread::sleep(Duration::from_millis(100));
 
-        cb(PrimeCachesProgress { on_crate: crate_name, n_done, n_total });
-        // This also computes the DefMap
-        db.import_map(crate_id);
+        let start = Instant::now();
+        host.request_cancellation();
+        let elapsed = start.elapsed();
+        assert!(worker.join().unwrap().is_err());
+        assert!(elapsed < Duration::from_secs(1), "cancellation took {:?}", elapsed);
     }
 }
@@ -270,9 +270,12 @@ fn is_lit_name_ref(name_ref: &ast::NameRef) -> bool {
 
 #[cfg(test)]
 mod tests {
+    use std::fmt::Write;
+
     use expect_test::{expect, Expect};
     use ide_db::{base_db::FileId, search::ReferenceCategory};
     use stdx::format_to;
+    use test_utils::{bench, skip_slow_tests};
 
     use crate::{fixture, SearchScope};
 
@@ -723,7 +726,7 @@ pub struct Foo {
             expect![[r#"
                 foo Module FileId(0) 0..8 4..7
 
-                FileId(0) 14..17
+                FileId(0) 14..17 Import
             "#]],
         );
     }
@@ -784,7 +787,7 @@ pub(super) struct Foo$0 {
             expect![[r#"
                 Foo Struct FileId(2) 0..41 18..21
 
-                FileId(1) 20..23
+                FileId(1) 20..23 Import
                 FileId(1) 47..50
             "#]],
         );
@@ -947,12 +950,90 @@ fn g() { f(); }
             expect![[r#"
                 f Function FileId(0) 22..31 25..26
 
-                FileId(1) 11..12
+                FileId(1) 11..12 Import
                 FileId(1) 24..25
             "#]],
         );
     }
 
+    #[test]
+    fn test_find_all_refs_in_macro_calls_across_files() {
+        check(
+            r#"
+//- /lib.rs
+macro_rules! id { ($($t:tt)*) => { $($t)* } }
+mod foo;
+pub fn f$0() {}
+fn g() { id!(f()); }
+//- /foo.rs
+macro_rules! id { ($($t:tt)*) => { $($t)* } }
+fn h() {
+    crate::f();
+    id!(crate::f());
+}
+"#,
+            expect![[r#"
+                f Function FileId(0) 55..68 62..63
+
+                FileId(0) 82..83
+                FileId(1) 66..67
+                FileId(1) 86..87
+            "#]],
+        );
+    }
+
+    #[test]
+    fn test_find_all_refs_in_use_items() {
+        check(
+            r#"
+mod m {
+    pub static mut COUNTER$0: u32 = 0;
+}
+use m::COUNTER;
+use m::{self, COUNTER as C};
+
+fn f() {
+    unsafe {
+        COUNTER = 1;
+        let _ = m::COUNTER;
+    }
+}
+"#,
+            expect![[r#"
+                COUNTER Static FileId(0) 12..44 27..34
+
+                FileId(0) 54..61 Import
+                FileId(0) 77..84 Import
+                FileId(0) 123..130 Write
+                FileId(0) 155..162 Read
+            "#]],
+        );
+    }
+
+    #[test]
+    fn test_find_all_refs_static_mut_write() {
+        check(
+            r#"
+static mut COUNTER$0: u32 = 0;
+
+fn f() {
+    unsafe {
+        COUNTER = 1;
+        COUNTER += 1;
+        let _ = COUNTER;
+    }
+}
+"#,
+            expect![[r#"
+                COUNTER Static FileId(0) 0..28 11..18
+
+                FileId(0) 60..67 Write
+                FileId(0) 81..88 Write
+                FileId(0) 111..118 Read
+            "#]],
+        );
+    }
+
     #[test]
     fn test_find_all_refs_struct_pat() {
         check(
@@ -1073,6 +1154,35 @@ impl Foo {
         check_with_scope(ra_fixture, None, expect)
     }
 
+    #[test]
+    fn benchmark_find_all_refs_across_crates() {
+        if skip_slow_tests() {
+            return;
+        }
+
+        let mut fixture = String::new();
+        writeln!(fixture, "//- /tr/lib.rs crate:tr").unwrap();
+        writeln!(fixture, "pub trait Tr {{ fn meth$0od(&self) -> u32; }}").unwrap();
+        for krate in 0..10 {
+            writeln!(fixture, "//- /c{0}/lib.rs crate:c{0} deps:tr", krate).unwrap();
+            writeln!(fixture, "use tr::Tr;").unwrap();
+            writeln!(fixture, "pub struct S;").unwrap();
+            writeln!(fixture, "impl Tr for S {{ fn method(&self) -> u32 {{ 92 }} }}").unwrap();
+            for f in 0..20 {
+                writeln!(fixture, "pub fn f{}(s: S) -> u32 {{ let x = s.method(); x }}", f)
+                    .unwrap();
+            }
+        }
+        let (analysis, pos) = fixture::position(&fixture);
+
+        let n_refs = {
+            let _b = bench("find all refs across crates");
+            let refs = analysis.find_all_refs(pos, None).unwrap().unwrap();
+            refs.iter().flat_map(|refs| refs.references.values()).map(|it| it.len()).sum::<usize>()
+        };
+        assert_eq!(n_refs, 210);
+    }
+
     fn check_with_scope(ra_fixture: &str, search_scope: Option<SearchScope>, expect: Expect) {
         let (analysis, pos) = fixture::position(ra_fixture);
         let refs = analysis.find_all_refs(pos, search_scope).unwrap().unwrap();
@@ -1225,6 +1335,51 @@ fn foo<'a>() -> &'a () {
         );
     }
 
+    #[test]
+    fn test_find_shadowed_labels() {
+        check(
+            r#"
+fn foo() {
+    'a: loop {
+        'a: for _ in 0..10 {
+            break 'a;
+            continue 'a;
+        }
+        break 'a$0;
+    }
+}
+"#,
+            expect![[r#"
+                'a Label FileId(0) 15..18 15..17
+
+                FileId(0) 126..128
+            "#]],
+        );
+    }
+
+    #[test]
+    fn test_find_block_labels() {
+        check(
+            r#"
+fn foo() {
+    'outer: loop {
+        let _ = 'block$0: {
+            if true {
+                break 'block 1;
+            }
+            break 'outer;
+        };
+    }
+}
+"#,
+            expect![[r#"
+                'block Label FileId(0) 46..53 46..52
+
+                FileId(0) 100..106
+            "#]],
+        );
+    }
+
     #[test]
     fn test_find_const_param() {
         check(
@@ -1405,9 +1560,9 @@ pub use level1::Foo;
             expect![[r#"
                 Foo Struct FileId(0) 0..15 11..14
 
-                FileId(1) 16..19
-                FileId(2) 16..19
-                FileId(3) 16..19
+                FileId(1) 16..19 Import
+                FileId(2) 16..19 Import
+                FileId(3) 16..19 Import
             "#]],
         );
     }
@@ -1435,7 +1590,7 @@ lib::foo!();
             expect![[r#"
                 foo Macro FileId(1) 0..61 29..32
 
-                FileId(0) 46..49
+                FileId(0) 46..49 Import
                 FileId(2) 0..3
                 FileId(3) 5..8
             "#]],
@@ -15,8 +15,8 @@ use itertools::Itertools;
 use rustc_hash::{FxHashMap, FxHashSet};
 use stdx::{always, format_to};
 use syntax::{
-    ast::{self, AstNode, HasAttrs as _},
-    SmolStr, SyntaxNode,
+    ast::{self, AstNode, HasAttrs as _, HasDocComments},
+    AstToken, SmolStr, SyntaxNode,
 };
 
 use crate::{references, FileId, NavigationTarget, ToNav, TryToNav};
@@ -46,11 +46,22 @@ impl fmt::Display for TestId {
 
 #[derive(Debug, Clone, Hash, PartialEq, Eq)]
 pub enum RunnableKind {
-    Test { test_id: TestId, attr: TestAttr },
-    TestMod { path: String },
-    Bench { test_id: TestId },
-    DocTest { test_id: TestId },
+    Test {
+        test_id: TestId,
+        attr: TestAttr,
+    },
+    TestMod {
+        path: String,
+    },
+    Bench {
+        test_id: TestId,
+    },
+    DocTest {
+        test_id: TestId,
+    },
     Bin,
+    /// Builds and opens the documentation of the crate, offered in its root file.
+    CargoDoc,
 }
 
 #[cfg(test)]
@@ -61,6 +72,7 @@ enum RunnableTestKind {
     DocTest,
     Bench,
     Bin,
+    CargoDoc,
 }
 
 impl Runnable {
@@ -74,24 +86,28 @@ impl Runnable {
             RunnableKind::Bin => {
                 target.map_or_else(|| "run binary".to_string(), |t| format!("run {}", t))
             }
+            RunnableKind::CargoDoc => {
+                target.map_or_else(|| "open docs".to_string(), |t| format!("open docs {}", t))
+            }
         }
     }
 
     pub fn title(&self) -> String {
-        let mut s = String::from("▶\u{fe0e} Run ");
-        if self.use_name_in_title {
-            format_to!(s, "{}", self.nav.name);
-            if !matches!(self.kind, RunnableKind::Bin) {
-                s.push(' ');
-            }
-        }
         let suffix = match &self.kind {
             RunnableKind::TestMod { .. } => "Tests",
             RunnableKind::Test { .. } => "Test",
             RunnableKind::DocTest { .. } => "Doctest",
             RunnableKind::Bench { .. } => "Bench",
-            RunnableKind::Bin => return s,
+            RunnableKind::Bin => "",
+            RunnableKind::CargoDoc => return String::from("▶\u{fe0e} Open Docs"),
         };
+        let mut s = String::from("▶\u{fe0e} Run ");
+        if self.use_name_in_title {
+            format_to!(s, "{}", self.nav.name);
+            if !suffix.is_empty() {
+                s.push(' ');
+            }
+        }
         s.push_str(suffix);
         s
     }
@@ -104,6 +120,7 @@ impl Runnable {
             RunnableKind::DocTest { .. } => RunnableTestKind::DocTest,
             RunnableKind::Bench { .. } => RunnableTestKind::Bench,
             RunnableKind::Bin => RunnableTestKind::Bin,
+            RunnableKind::CargoDoc => RunnableTestKind::CargoDoc,
         }
     }
 }
@@ -175,6 +192,8 @@ pub(crate) fn runnables(db: &RootDatabase, file_id: FileId) -> Vec<Runnable> {
     sema.to_module_defs(file_id)
         .map(|it| runnable_mod_outline_definition(&sema, it))
         .for_each(|it| add_opt(it, None));
+    // A file can be the root of several crates, which are documented all the same.
+    add_opt(sema.to_module_defs(file_id).find_map(|it| runnable_crate_docs(&sema, it)), None);
 
     res.extend(in_macro_expansion.into_iter().flat_map(|(_, runnables)| {
         let use_name_in_title = runnables.len() != 1;
@@ -388,6 +407,24 @@ fn runnable_mod_outline_definition(
     }
 }
 
+/// Creates a runnable opening the docs of the crate for crate root files, which points at the
+/// crate docs if there are any.
+fn runnable_crate_docs(sema: &Semantics<RootDatabase>, def: hir::Module) -> Option<Runnable> {
+    if def.crate_root(sema.db) != def {
+        return None;
+    }
+    let mut nav = def.to_nav(sema.db);
+    nav.focus_range = match def.definition_source(sema.db).value {
+        hir::ModuleSource::SourceFile(file) => file
+            .doc_comments()
+            .filter(|it| it.is_inner())
+            .map(|it| it.syntax().text_range())
+            .reduce(|acc, it| acc.cover(it)),
+        _ => return None,
+    };
+    Some(Runnable { use_name_in_title: false, nav, kind: RunnableKind::CargoDoc, cfg: None })
+}
+
 fn module_def_doctest(db: &RootDatabase, def: Definition) -> Option<Runnable> {
     let attrs = match def {
         Definition::Module(it) => it.attrs(db),
@@ -539,7 +576,13 @@ mod tests {
         expect: Expect,
     ) {
         let (analysis, position) = fixture::position(ra_fixture);
-        let runnables = analysis.runnables(position.file_id).unwrap();
+        // Every crate root gets one, those are checked separately in `check_cargo_doc`.
+        let runnables: Vec<_> = analysis
+            .runnables(position.file_id)
+            .unwrap()
+            .into_iter()
+            .filter(|it| it.kind != RunnableKind::CargoDoc)
+            .collect();
         expect.assert_debug_eq(&runnables);
         assert_eq!(
             actions,
@@ -547,6 +590,17 @@ mod tests {
         );
     }
 
+    fn check_cargo_doc(ra_fixture: &str, expect: Expect) {
+        let (analysis, position) = fixture::position(ra_fixture);
+        let runnables: Vec<_> = analysis
+            .runnables(position.file_id)
+            .unwrap()
+            .into_iter()
+            .filter(|it| it.kind == RunnableKind::CargoDoc)
+            .collect();
+        expect.assert_debug_eq(&runnables);
+    }
+
     fn check_tests(ra_fixture: &str, expect: Expect) {
         let (analysis, position) = fixture::position(ra_fixture);
         let tests = analysis.related_tests(position, None).unwrap();
@@ -672,6 +726,84 @@ mod not_a_root {
         );
     }
 
+    #[test]
+    fn test_runnables_async_test_attributes() {
+        check(
+            r#"
+//- /lib.rs
+$0
+#[tokio::test]
+async fn tokio_test() {}
+
+#[async_std::test]
+#[ignore]
+async fn async_std_test() {}
+"#,
+            &[Test, Test, TestMod],
+            expect![[r#"
+                [
+                    Runnable {
+                        use_name_in_title: false,
+                        nav: NavigationTarget {
+                            file_id: FileId(
+                                0,
+                            ),
+                            full_range: 1..40,
+                            focus_range: 25..35,
+                            name: "tokio_test",
+                            kind: Function,
+                        },
+                        kind: Test {
+                            test_id: Path(
+                                "tokio_test",
+                            ),
+                            attr: TestAttr {
+                                ignore: false,
+                            },
+                        },
+                        cfg: None,
+                    },
+                    Runnable {
+                        use_name_in_title: false,
+                        nav: NavigationTarget {
+                            file_id: FileId(
+                                0,
+                            ),
+                            full_range: 42..99,
+                            focus_range: 80..94,
+                            name: "async_std_test",
+                            kind: Function,
+                        },
+                        kind: Test {
+                            test_id: Path(
+                                "async_std_test",
+                            ),
+                            attr: TestAttr {
+                                ignore: true,
+                            },
+                        },
+                        cfg: None,
+                    },
+                    Runnable {
+                        use_name_in_title: false,
+                        nav: NavigationTarget {
+                            file_id: FileId(
+                                0,
+                            ),
+                            full_range: 0..100,
+                            name: "",
+                            kind: Module,
+                        },
+                        kind: TestMod {
+                            path: "",
+                        },
+                        cfg: None,
+                    },
+                ]
+            "#]],
+        );
+    }
+
     #[test]
     fn test_runnables_doc_test() {
         check(
@@ -2075,4 +2207,82 @@ impl<T, U> Foo<T, U> {
             "#]],
         );
     }
+
+    #[test]
+    fn cargo_doc_in_library_crate_root() {
+        check_cargo_doc(
+            r#"
+//- /lib.rs
+//! Crate docs.
+//! More crate docs.
+$0
+/// Not crate docs.
+fn foo() {}
+"#,
+            expect![[r#"
+                [
+                    Runnable {
+                        use_name_in_title: false,
+                        nav: NavigationTarget {
+                            file_id: FileId(
+                                0,
+                            ),
+                            full_range: 0..70,
+                            focus_range: 0..36,
+                            name: "",
+                            kind: Module,
+                        },
+                        kind: CargoDoc,
+                        cfg: None,
+                    },
+                ]
+            "#]],
+        );
+    }
+
+    #[test]
+    fn cargo_doc_in_crate_root_without_docs() {
+        check_cargo_doc(
+            r#"
+//- /main.rs
+$0
+fn main() {}
+"#,
+            expect![[r#"
+                [
+                    Runnable {
+                        use_name_in_title: false,
+                        nav: NavigationTarget {
+                            file_id: FileId(
+                                0,
+                            ),
+                            full_range: 0..14,
+                            name: "",
+                            kind: Module,
+                        },
+                        kind: CargoDoc,
+                        cfg: None,
+                    },
+                ]
+            "#]],
+        );
+    }
+
+    #[test]
+    fn no_cargo_doc_outside_crate_root() {
+        check_cargo_doc(
+            r#"
+//- /lib.rs
+//! Crate docs.
+mod foo;
+//- /foo.rs
+//! Module docs.
+$0
+fn foo() {}
+"#,
+            expect![[r#"
+                []
+            "#]],
+        );
+    }
 }
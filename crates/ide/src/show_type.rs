@@ -0,0 +1,116 @@
+use hir::{HirDisplay, Semantics};
+use ide_db::{base_db::FilePosition, helpers::pick_best_token, RootDatabase};
+use syntax::{ast, match_ast, AstNode, SyntaxKind::*, T};
+
+// Feature: Show Type
+//
+// Shows the type of the expression or pattern under the cursor, without its documentation.
+// Unlike hover, the paths in the type start at a crate, like `crate::shapes::Circle` or
+// `std::collections::HashMap`, which helps telling apart types with the same name. Types
+// from the prelude, like `Option`, keep their short name.
+//
+// |===
+// | Editor  | Action Name
+//
+// | VS Code | **Rust Analyzer: Show type at cursor**
+// |===
+pub(crate) fn show_type(db: &RootDatabase, position: FilePosition) -> Option<String> {
+    let sema = Semantics::new(db);
+    let file = sema.parse(position.file_id);
+    let token =
+        pick_best_token(file.syntax().token_at_offset(position.offset), |kind| match kind {
+            IDENT | INT_NUMBER | T![self] => 2,
+            kind if kind.is_trivia() => 0,
+            _ => 1,
+        })?;
+
+    sema.descend_into_macros(token).into_iter().find_map(|token| {
+        let node = token
+            .ancestors()
+            .take_while(|it| !ast::Stmt::can_cast(it.kind()) && !ast::Item::can_cast(it.kind()))
+            .find(|it| ast::Expr::can_cast(it.kind()) || ast::Pat::can_cast(it.kind()))?;
+        let ty = match_ast! {
+            match node {
+                ast::Expr(it) => sema.type_of_expr(&it)?.original,
+                ast::Pat(it) => sema.type_of_pat(&it)?.original,
+                _ => return None,
+            }
+        };
+        let module = sema.scope(&node).module()?;
+        Some(ty.display_fully_qualified(db, module.into()).to_string())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::{expect, Expect};
+
+    use crate::fixture;
+
+    fn check(ra_fixture: &str, expect: Expect) {
+        let (analysis, position) = fixture::position(ra_fixture);
+        let ty = analysis.show_type(position).unwrap().unwrap_or_default();
+        expect.assert_eq(&ty)
+    }
+
+    #[test]
+    fn qualifies_paths() {
+        check(
+            r#"
+mod shapes {
+    pub struct Circle;
+    pub struct Pair<A, B>(pub A, pub B);
+}
+fn f() {
+    let pair$0 = shapes::Pair(shapes::Circle, 0u8);
+}
+"#,
+            expect![[r#"crate::shapes::Pair<crate::shapes::Circle, u8>"#]],
+        );
+    }
+
+    #[test]
+    fn qualifies_paths_into_other_crates() {
+        check(
+            r#"
+//- /main.rs crate:main deps:other
+use other::collections::Map;
+mod local {
+    pub struct Map;
+}
+fn f(a: Map, b: local::Map) {
+    let pair$0 = (a, b);
+}
+//- /other.rs crate:other
+pub mod collections {
+    pub struct Map;
+}
+"#,
+            expect![[r#"(other::collections::Map, crate::local::Map)"#]],
+        );
+    }
+
+    #[test]
+    fn type_of_inner_expression() {
+        check(
+            r#"
+struct Wrapper<T>(T);
+impl<T> Wrapper<T> {
+    fn get(&self) -> &T { &self.0 }
+}
+fn f(w: Wrapper<(u8, bool)>) {
+    let first = w.get$0().0;
+}
+"#,
+            expect![[r#"&(u8, bool)"#]],
+        );
+        check(
+            r#"
+fn f() {
+    let x = $0 ;
+}
+"#,
+            expect![[r#""#]],
+        );
+    }
+}
@@ -108,8 +108,14 @@ impl StaticIndex<'_> {
                     type_hints: true,
                     parameter_hints: true,
                     chaining_hints: true,
+                    closure_param_type_hints: true,
+                    binding_mode_hints: false,
+                    generic_parameter_hints: false,
+                    cfg_hints: false,
+                    lifetime_hints: false,
                     hide_named_constructor_hints: false,
                     max_length: Some(25),
+                    render_tooltips: false,
                 },
                 file_id,
             )
@@ -32,6 +32,12 @@ use crate::{
 
 pub(crate) use html::highlight_as_html;
 
+#[derive(Debug, Clone, Copy)]
+pub struct HighlightConfig {
+    /// Whether to highlight the format specifiers and argument names inside format strings.
+    pub format_string_literals: bool,
+}
+
 #[derive(Debug, Clone, Copy)]
 pub struct HlRange {
     pub range: TextRange,
@@ -145,8 +151,9 @@ pub struct HlRange {
 // library:: Emitted for items that are defined outside of the current crate.
 // mutable:: Emitted for mutable locals and statics as well as functions taking `&mut self`.
 // public:: Emitted for items that are from the current crate and are `pub`.
+// readonly:: Emitted for consts and `const fn`s, as well as their `const` keyword.
 // reference:: Emitted for locals behind a reference and functions taking `self` by reference.
-// static:: Emitted for "static" functions, also known as functions that do not take a `self` param, as well as statics and consts.
+// static:: Emitted for "static" functions, also known as functions that do not take a `self` param, as well as statics, consts and `const fn`s.
 // trait:: Emitted for associated trait items.
 // unsafe:: Emitted for unsafe operations, like unsafe function calls, as well as the `unsafe` token.
 //
@@ -155,6 +162,7 @@ pub struct HlRange {
 // image::https://user-images.githubusercontent.com/48062697/113187625-f7f50100-9250-11eb-825e-91c58f236071.png[]
 pub(crate) fn highlight(
     db: &RootDatabase,
+    config: HighlightConfig,
     file_id: FileId,
     range_to_highlight: Option<TextRange>,
     syntactic_name_ref_highlighting: bool,
@@ -182,6 +190,7 @@ pub(crate) fn highlight(
     traverse(
         &mut hl,
         &sema,
+        config,
         InFile::new(file_id.into(), &root),
         sema.scope(&root).krate(),
         range_to_highlight,
@@ -193,6 +202,7 @@ pub(crate) fn highlight(
 fn traverse(
     hl: &mut Highlights,
     sema: &Semantics<RootDatabase>,
+    config: HighlightConfig,
     root: InFile<&SyntaxNode>,
     krate: Option<hir::Crate>,
     range_to_highlight: TextRange,
@@ -277,7 +287,7 @@ fn traverse(
             WalkEvent::Enter(it) => it,
             WalkEvent::Leave(it) => {
                 if let Some(node) = it.as_node() {
-                    inject::doc_comment(hl, sema, root.with_value(node));
+                    inject::doc_comment(hl, sema, config, root.with_value(node));
                 }
                 continue;
             }
@@ -334,11 +344,13 @@ fn traverse(
             let string_to_highlight = ast::String::cast(token_to_highlight.clone());
             if let Some((string, expanded_string)) = string.zip(string_to_highlight) {
                 if string.is_raw() {
-                    if inject::ra_fixture(hl, sema, &string, &expanded_string).is_some() {
+                    if inject::ra_fixture(hl, sema, config, &string, &expanded_string).is_some() {
                         continue;
                     }
                 }
-                highlight_format_string(hl, &string, &expanded_string, range);
+                if config.format_string_literals {
+                    highlight_format_string(hl, &string, &expanded_string, range);
+                }
                 // Highlight escape sequences
                 if let Some(char_ranges) = string.char_ranges() {
                     for (piece_range, _) in char_ranges.iter().filter(|(_, char)| char.is_ok()) {
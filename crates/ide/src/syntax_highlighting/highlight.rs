@@ -176,6 +176,12 @@ fn token(
                         })
                         .map_or(h, |modifier| h | modifier),
                     T![async] => h | HlMod::Async,
+                    T![const]
+                        if parent_matches::<ast::Fn>(&token)
+                            || parent_matches::<ast::Const>(&token) =>
+                    {
+                        h | HlMod::Const
+                    }
                     _ => h,
                 }
             }
@@ -418,6 +424,9 @@ fn highlight_def(
             if func.is_async(db) {
                 h |= HlMod::Async;
             }
+            if func.is_const(db) {
+                h |= HlMod::Const;
+            }
 
             h
         }
@@ -432,7 +441,7 @@ fn highlight_def(
         }
         Definition::Variant(_) => Highlight::new(HlTag::Symbol(SymbolKind::Variant)),
         Definition::Const(konst) => {
-            let mut h = Highlight::new(HlTag::Symbol(SymbolKind::Const));
+            let mut h = HlTag::Symbol(SymbolKind::Const) | HlMod::Const;
 
             if let Some(item) = konst.as_assoc_item(db) {
                 h |= HlMod::Associated;
@@ -566,6 +575,9 @@ fn highlight_method_call(
     if func.is_async(sema.db) {
         h |= HlMod::Async;
     }
+    if func.is_const(sema.db) {
+        h |= HlMod::Const;
+    }
     if func.as_assoc_item(sema.db).and_then(|it| it.containing_trait(sema.db)).is_some() {
         h |= HlMod::Trait;
     }
@@ -16,12 +16,13 @@ use syntax::{
 use crate::{
     doc_links::{doc_attributes, extract_definitions_from_docs, resolve_doc_path_for_def},
     syntax_highlighting::{highlights::Highlights, injector::Injector},
-    Analysis, HlMod, HlRange, HlTag, RootDatabase,
+    Analysis, HighlightConfig, HlMod, HlRange, HlTag, RootDatabase,
 };
 
 pub(super) fn ra_fixture(
     hl: &mut Highlights,
     sema: &Semantics<RootDatabase>,
+    config: HighlightConfig,
     literal: &ast::String,
     expanded: &ast::String,
 ) -> Option<()> {
@@ -63,7 +64,7 @@ pub(super) fn ra_fixture(
 
     let (analysis, tmp_file_id) = Analysis::from_single_file(inj.text().to_string());
 
-    for mut hl_range in analysis.highlight(tmp_file_id).unwrap() {
+    for mut hl_range in analysis.highlight(config, tmp_file_id).unwrap() {
         for range in inj.map_range_up(hl_range.range) {
             if let Some(range) = literal.map_range_up(range) {
                 hl_range.range = range;
@@ -85,6 +86,7 @@ const RUSTDOC_FENCE: &'static str = "```";
 pub(super) fn doc_comment(
     hl: &mut Highlights,
     sema: &Semantics<RootDatabase>,
+    config: HighlightConfig,
     node: InFile<&SyntaxNode>,
 ) {
     let (attributes, def) = match doc_attributes(sema, node.value) {
@@ -199,7 +201,7 @@ pub(super) fn doc_comment(
     let (analysis, tmp_file_id) = Analysis::from_single_file(inj.text().to_string());
 
     for HlRange { range, highlight, binding_hash } in
-        analysis.with_db(|db| super::highlight(db, tmp_file_id, None, true)).unwrap()
+        analysis.with_db(|db| super::highlight(db, config, tmp_file_id, None, true)).unwrap()
     {
         for range in inj.map_range_up(range) {
             hl.add(HlRange { range, highlight: highlight | HlMod::Injected, binding_hash });
@@ -50,6 +50,8 @@ pub enum HlMod {
     Attribute,
     /// Callable item or value.
     Callable,
+    /// Used for const items and `const fn`s, as well as the `const` keyword of those.
+    Const,
     /// Value that is being consumed in a function call
     Consuming,
     /// Used with keywords like `if` and `break`.
@@ -196,6 +198,7 @@ impl HlMod {
         HlMod::Async,
         HlMod::Attribute,
         HlMod::Callable,
+        HlMod::Const,
         HlMod::Consuming,
         HlMod::ControlFlow,
         HlMod::CrateRoot,
@@ -219,6 +222,7 @@ impl HlMod {
             HlMod::Async => "async",
             HlMod::Attribute => "attribute",
             HlMod::Callable => "callable",
+            HlMod::Const => "const",
             HlMod::Consuming => "consuming",
             HlMod::ControlFlow => "control",
             HlMod::CrateRoot => "crate_root",
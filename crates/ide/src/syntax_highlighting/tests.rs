@@ -4,7 +4,9 @@ use expect_test::{expect_file, ExpectFile};
 use ide_db::SymbolKind;
 use test_utils::{bench, bench_fixture, skip_slow_tests, AssertLinear};
 
-use crate::{fixture, FileRange, HlTag, TextRange};
+use crate::{fixture, FileRange, HighlightConfig, HlTag, TextRange};
+
+const HL_CONFIG: HighlightConfig = HighlightConfig { format_string_literals: true };
 
 #[test]
 fn test_highlighting() {
@@ -319,7 +321,7 @@ fn benchmark_syntax_highlighting_long_struct() {
     let hash = {
         let _pt = bench("syntax highlighting long struct");
         analysis
-            .highlight(file_id)
+            .highlight(HL_CONFIG, file_id)
             .unwrap()
             .iter()
             .filter(|it| it.highlight.tag == HlTag::Symbol(SymbolKind::Struct))
@@ -345,7 +347,7 @@ fn syntax_highlighting_not_quadratic() {
             let time = Instant::now();
 
             let hash = analysis
-                .highlight(file_id)
+                .highlight(HL_CONFIG, file_id)
                 .unwrap()
                 .iter()
                 .filter(|it| it.highlight.tag == HlTag::Symbol(SymbolKind::Struct))
@@ -370,7 +372,7 @@ fn benchmark_syntax_highlighting_parser() {
     let hash = {
         let _pt = bench("syntax highlighting parser");
         analysis
-            .highlight(file_id)
+            .highlight(HL_CONFIG, file_id)
             .unwrap()
             .iter()
             .filter(|it| it.highlight.tag == HlTag::Symbol(SymbolKind::Function))
@@ -393,7 +395,10 @@ struct Foo {
 
     // The "x"
     let highlights = &analysis
-        .highlight_range(FileRange { file_id, range: TextRange::at(45.into(), 1.into()) })
+        .highlight_range(
+            HL_CONFIG,
+            FileRange { file_id, range: TextRange::at(45.into(), 1.into()) },
+        )
         .unwrap();
 
     assert_eq!(&highlights[0].highlight.to_string(), "field.declaration.public");
@@ -429,7 +434,33 @@ macro_rules! test {}
 }"#
         .trim(),
     );
-    let _ = analysis.highlight(file_id).unwrap();
+    let _ = analysis.highlight(HL_CONFIG, file_id).unwrap();
+}
+
+#[test]
+fn format_string_highlighting_can_be_disabled() {
+    let (analysis, file_id) = fixture::file(
+        r#"
+#[rustc_builtin_macro]
+macro_rules! format_args {}
+
+fn main() {
+    let name = 92;
+    format_args!("{name:.2}");
+}
+"#,
+    );
+    let format_specifiers = |config| {
+        analysis
+            .highlight(config, file_id)
+            .unwrap()
+            .iter()
+            .filter(|it| it.highlight.tag == HlTag::FormatSpecifier)
+            .count()
+    };
+
+    assert_eq!(format_specifiers(HL_CONFIG), 4);
+    assert_eq!(format_specifiers(HighlightConfig { format_string_literals: false }), 0);
 }
 
 #[test]
@@ -873,6 +904,30 @@ impl t for foo {
     )
 }
 
+#[test]
+fn test_const_highlighting() {
+    check_highlighting(
+        r#"
+const C: u32 = 0;
+const fn konst() -> u32 { C }
+
+struct S;
+impl S {
+    const ASSOC: u32 = 1;
+    const fn method(&self) -> u32 { Self::ASSOC }
+}
+
+fn main() {
+    const { konst() };
+    let _ = C + S.method();
+    let _: *const u32 = &C;
+}
+"#,
+        expect_file!["./test_data/highlight_const.html"],
+        false,
+    );
+}
+
 #[test]
 fn test_injection() {
     check_highlighting(
@@ -0,0 +1,204 @@
+//! Entry point for type-hierarchy
+
+use hir::{Impl, Semantics};
+use ide_db::{
+    defs::{Definition, NameClass, NameRefClass},
+    helpers::pick_best_token,
+    RootDatabase,
+};
+use itertools::Itertools;
+use syntax::{ast, AstNode, SyntaxKind::IDENT, TextRange};
+
+use crate::{FilePosition, NavigationTarget, RangeInfo, TryToNav};
+
+// Feature: Type Hierarchy
+//
+// Shows the traits a struct, enum or union implements and the supertraits of a trait as its
+// supertypes, and the types implementing a trait as its subtypes.
+//
+// |===
+// | Editor  | Action Name
+//
+// | VS Code | **Show Type Hierarchy**
+// |===
+pub(crate) fn type_hierarchy(
+    db: &RootDatabase,
+    position: FilePosition,
+) -> Option<RangeInfo<Vec<NavigationTarget>>> {
+    let sema = Semantics::new(db);
+    let (range, defs) = type_defs_at(&sema, position)?;
+    let navs = defs.into_iter().filter_map(|def| def.try_to_nav(db)).collect();
+    Some(RangeInfo::new(range, navs))
+}
+
+/// The traits implemented by the type at `position`, or the supertraits of the trait there.
+pub(crate) fn supertypes(
+    db: &RootDatabase,
+    position: FilePosition,
+) -> Option<Vec<NavigationTarget>> {
+    let sema = Semantics::new(db);
+    let (_, defs) = type_defs_at(&sema, position)?;
+    let navs = defs
+        .into_iter()
+        .flat_map(|def| match def {
+            Definition::Adt(adt) => Impl::all_for_type(db, adt.ty(db))
+                .into_iter()
+                .filter(|impl_| !impl_.is_negative(db))
+                .filter_map(|impl_| impl_.trait_(db))
+                .collect(),
+            Definition::Trait(trait_) => trait_.direct_supertraits(db),
+            _ => Vec::new(),
+        })
+        .unique()
+        .filter_map(|trait_| trait_.try_to_nav(db))
+        .collect();
+    Some(navs)
+}
+
+/// The types implementing the trait at `position`.
+pub(crate) fn subtypes(db: &RootDatabase, position: FilePosition) -> Option<Vec<NavigationTarget>> {
+    let sema = Semantics::new(db);
+    let (_, defs) = type_defs_at(&sema, position)?;
+    let navs = defs
+        .into_iter()
+        .flat_map(|def| match def {
+            Definition::Trait(trait_) => Impl::all_for_trait(db, trait_)
+                .into_iter()
+                .filter(|impl_| !impl_.is_negative(db))
+                .filter_map(|impl_| impl_.self_ty(db).as_adt())
+                .collect(),
+            _ => Vec::new(),
+        })
+        .unique()
+        .filter_map(|adt| adt.try_to_nav(db))
+        .collect();
+    Some(navs)
+}
+
+/// Finds the ADTs and traits named by the identifier at `position`.
+fn type_defs_at(
+    sema: &Semantics<RootDatabase>,
+    position: FilePosition,
+) -> Option<(TextRange, Vec<Definition>)> {
+    let file = sema.parse(position.file_id);
+    let token =
+        pick_best_token(file.syntax().token_at_offset(position.offset), |kind| match kind {
+            IDENT => 1,
+            _ => 0,
+        })?;
+    let range = token.text_range();
+    let defs = sema
+        .descend_into_macros(token)
+        .into_iter()
+        .filter_map(|token| token.parent().and_then(ast::NameLike::cast))
+        .filter_map(|node| match &node {
+            ast::NameLike::Name(name) => match NameClass::classify(sema, name)? {
+                NameClass::Definition(it) => Some(it),
+                _ => None,
+            },
+            ast::NameLike::NameRef(name_ref) => match NameRefClass::classify(sema, name_ref)? {
+                NameRefClass::Definition(it) => Some(it),
+                _ => None,
+            },
+            ast::NameLike::Lifetime(_) => None,
+        })
+        .map(|def| match def {
+            Definition::SelfType(impl_) => {
+                impl_.self_ty(sema.db).as_adt().map_or(def, Definition::Adt)
+            }
+            _ => def,
+        })
+        .filter(|def| matches!(def, Definition::Adt(_) | Definition::Trait(_)))
+        .unique()
+        .collect();
+    Some((range, defs))
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::{expect, Expect};
+    use ide_db::base_db::FilePosition;
+    use itertools::Itertools;
+
+    use crate::fixture;
+
+    fn check_hierarchy(
+        ra_fixture: &str,
+        expected: Expect,
+        expected_supertypes: Expect,
+        expected_subtypes: Expect,
+    ) {
+        let (analysis, pos) = fixture::position(ra_fixture);
+
+        let mut navs = analysis.type_hierarchy(pos).unwrap().unwrap().info;
+        assert_eq!(navs.len(), 1);
+        let nav = navs.pop().unwrap();
+        expected.assert_eq(&nav.debug_render());
+
+        let item_pos =
+            FilePosition { file_id: nav.file_id, offset: nav.focus_or_full_range().start() };
+        let supertypes = analysis.supertypes(item_pos).unwrap().unwrap();
+        expected_supertypes.assert_eq(&supertypes.iter().map(|it| it.debug_render()).join("\n"));
+
+        let subtypes = analysis.subtypes(item_pos).unwrap().unwrap();
+        expected_subtypes.assert_eq(&subtypes.iter().map(|it| it.debug_render()).join("\n"));
+    }
+
+    #[test]
+    fn type_hierarchy_of_struct() {
+        check_hierarchy(
+            r#"
+trait Named {}
+trait Shape: Named {}
+struct Circle;
+impl Named for Circle {}
+impl Shape for Circle {}
+impl Circle {}
+fn f(c: Circ$0le) {}
+"#,
+            expect![[r#"Circle Struct FileId(0) 37..51 44..50"#]],
+            expect![[r#"
+                Named Trait FileId(0) 0..14 6..11
+                Shape Trait FileId(0) 15..36 21..26"#]],
+            expect![[r#""#]],
+        );
+    }
+
+    #[test]
+    fn type_hierarchy_of_trait() {
+        check_hierarchy(
+            r#"
+trait Named {}
+trait Sha$0pe: Named {}
+struct Circle;
+enum Polygon { Square }
+impl Named for Circle {}
+impl Shape for Circle {}
+impl Named for Polygon {}
+impl Shape for Polygon {}
+"#,
+            expect![[r#"Shape Trait FileId(0) 15..36 21..26"#]],
+            expect![[r#"Named Trait FileId(0) 0..14 6..11"#]],
+            expect![[r#"
+                Circle Struct FileId(0) 37..51 44..50
+                Polygon Enum FileId(0) 52..75 57..64"#]],
+        );
+    }
+
+    #[test]
+    fn type_hierarchy_on_self_type() {
+        check_hierarchy(
+            r#"
+trait Named {}
+struct Circle;
+impl Named for Circle {}
+impl Circle {
+    fn new() -> Se$0lf { Circle }
+}
+"#,
+            expect![[r#"Circle Struct FileId(0) 15..29 22..28"#]],
+            expect![[r#"Named Trait FileId(0) 0..14 6..11"#]],
+            expect![[r#""#]],
+        );
+    }
+}
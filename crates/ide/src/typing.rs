@@ -23,8 +23,8 @@ use syntax::{
     algo::find_node_at_offset,
     ast::{self, edit::IndentLevel, AstToken},
     AstNode, Parse, SourceFile,
-    SyntaxKind::{self, FIELD_EXPR, METHOD_CALL_EXPR},
-    TextRange, TextSize,
+    SyntaxKind::{self, FIELD_EXPR, METHOD_CALL_EXPR, WHITESPACE},
+    SyntaxToken, TextRange, TextSize,
 };
 
 use text_edit::{Indel, TextEdit};
@@ -34,7 +34,7 @@ use crate::SourceChange;
 pub(crate) use on_enter::on_enter;
 
 // Don't forget to add new trigger characters to `server_capabilities` in `caps.rs`.
-pub(crate) const TRIGGER_CHARS: &str = ".=>{";
+pub(crate) const TRIGGER_CHARS: &str = ".=>{}";
 
 // Feature: On Typing Assists
 //
@@ -43,6 +43,8 @@ pub(crate) const TRIGGER_CHARS: &str = ".=>{";
 // - typing `let =` tries to smartly add `;` if `=` is followed by an existing expression
 // - typing `.` in a chain method call auto-indents
 // - typing `{` in front of an expression inserts a closing `}` after the expression
+// - typing `{` at the end of a line inserts a new, indented line
+// - typing `}` on an otherwise blank line dedents it to match the opening `{`
 //
 // VS Code::
 //
@@ -83,6 +85,7 @@ fn on_char_typed_inner(
         '=' => on_eq_typed(&file.tree(), offset),
         '>' => on_arrow_typed(&file.tree(), offset),
         '{' => on_opening_brace_typed(file, offset),
+        '}' => on_closing_brace_typed(&file.tree(), offset),
         _ => unreachable!(),
     }
 }
@@ -99,6 +102,10 @@ fn on_opening_brace_typed(file: &Parse<SourceFile>, offset: TextSize) -> Option<
         return None;
     }
 
+    if let Some(edit) = brace_at_line_end(&brace_token) {
+        return Some(edit);
+    }
+
     // Remove the `{` to get a better parse tree, and reparse.
     let range = brace_token.text_range();
     if !stdx::always!(range.len() == TextSize::of('{')) {
@@ -116,6 +123,18 @@ fn on_opening_brace_typed(file: &Parse<SourceFile>, offset: TextSize) -> Option<
 
     return None;
 
+    fn brace_at_line_end(brace_token: &SyntaxToken) -> Option<TextEdit> {
+        match brace_token.next_token() {
+            Some(next) if !(next.kind() == WHITESPACE && next.text().contains('\n')) => {
+                return None
+            }
+            _ => (),
+        }
+
+        let indent = IndentLevel::from_token(brace_token) + 1;
+        Some(TextEdit::insert(brace_token.text_range().end(), format!("\n{}", indent)))
+    }
+
     fn brace_use_path(file: &SourceFile, offset: TextSize) -> Option<TextEdit> {
         let segment: ast::PathSegment = find_node_at_offset(file.syntax(), offset)?;
         if segment.syntax().text_range().start() != offset {
@@ -161,6 +180,37 @@ fn on_opening_brace_typed(file: &Parse<SourceFile>, offset: TextSize) -> Option<
     }
 }
 
+/// Dedents a `}` typed on an otherwise blank line to the indentation of the line with the
+/// matching `{`.
+fn on_closing_brace_typed(file: &SourceFile, offset: TextSize) -> Option<TextEdit> {
+    if !stdx::always!(file.syntax().text().char_at(offset) == Some('}')) {
+        return None;
+    }
+    let brace_token = file.syntax().token_at_offset(offset).right_biased()?;
+    if brace_token.kind() != SyntaxKind::R_CURLY {
+        return None;
+    }
+    let whitespace = brace_token.prev_token().and_then(ast::Whitespace::cast)?;
+    let current_indent = {
+        let text = whitespace.text();
+        let newline = text.rfind('\n')?;
+        &text[newline + 1..]
+    };
+
+    let l_curly = brace_token
+        .parent()?
+        .children_with_tokens()
+        .filter_map(|it| it.into_token())
+        .find(|it| it.kind() == SyntaxKind::L_CURLY)?;
+    let target_indent = IndentLevel::from_token(&l_curly).to_string();
+    if current_indent == target_indent {
+        return None;
+    }
+
+    let current_indent_len = TextSize::of(current_indent);
+    Some(TextEdit::replace(TextRange::new(offset - current_indent_len, offset), target_indent))
+}
+
 /// Returns an edit which should be applied after `=` was typed. Primarily,
 /// this works when adding `let =`.
 // FIXME: use a snippet completion instead of this hack here.
@@ -661,4 +711,99 @@ use some::pa$0th::to::Item;
             "#,
         );
     }
+
+    #[test]
+    fn indents_after_brace_at_line_end() {
+        type_char(
+            '{',
+            r#"
+fn f() {
+    if true $0
+}
+"#,
+            "\nfn f() {\n    if true {\n        \n}\n",
+        );
+        type_char(
+            '{',
+            r#"
+fn f() $0"#,
+            r#"
+fn f() {
+    "#,
+        );
+        type_char_noop(
+            '{',
+            r#"
+fn f() {
+    let s = "$0
+    ";
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn dedents_closing_brace() {
+        type_char(
+            '}',
+            r#"
+fn f() {
+    if true {
+        92
+        $0
+}
+"#,
+            r#"
+fn f() {
+    if true {
+        92
+    }
+}
+"#,
+        );
+        type_char(
+            '}',
+            r#"
+mod m {
+    fn f() {}
+        $0
+"#,
+            r#"
+mod m {
+    fn f() {}
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn closing_brace_noop() {
+        type_char_noop(
+            '}',
+            r#"
+fn f() {
+    if true {
+        92
+    $0
+}
+"#,
+        );
+        type_char_noop(
+            '}',
+            r#"
+fn f() {
+    if true { 92 $0
+}
+"#,
+        );
+        type_char_noop(
+            '}',
+            r#"
+fn f() {
+    let s = "
+        $0";
+}
+"#,
+        );
+    }
 }
@@ -92,7 +92,20 @@ pub(crate) fn add_missing_match_arms(acc: &mut Assists, ctx: &AssistContext) ->
         } else {
             Box::new(missing_pats)
         };
-        missing_pats.peekable()
+
+        // Foreign `#[non_exhaustive]` enums can't be matched exhaustively without a wildcard.
+        let is_foreign_non_exhaustive = match enum_def {
+            ExtendedEnum::Enum(e) => {
+                e.is_non_exhaustive(ctx.db()) && e.module(ctx.db()).krate() != module.krate()
+            }
+            ExtendedEnum::Bool => false,
+        };
+        let has_catch_all_arm = match_arm_list
+            .arms()
+            .any(|arm| matches!(arm.pat(), Some(Pat::WildcardPat(_))) && !has_empty_expr(&arm));
+        let wildcard = (is_foreign_non_exhaustive && !has_catch_all_arm)
+            .then(|| ast::Pat::from(make::wildcard_pat()));
+        (Box::new(missing_pats.chain(wildcard)) as Box<dyn Iterator<Item = _>>).peekable()
     } else if let Some(enum_defs) = resolve_tuple_of_enum_def(&ctx.sema, &expr) {
         let mut n_arms = 1;
         let variants_of_enums: Vec<Vec<ExtendedVariant>> = enum_defs
@@ -145,14 +158,7 @@ pub(crate) fn add_missing_match_arms(acc: &mut Assists, ctx: &AssistContext) ->
                 .arms()
                 .find(|arm| matches!(arm.pat(), Some(ast::Pat::WildcardPat(_))));
             if let Some(arm) = catch_all_arm {
-                let is_empty_expr = arm.expr().map_or(true, |e| match e {
-                    ast::Expr::BlockExpr(b) => {
-                        b.statements().next().is_none() && b.tail_expr().is_none()
-                    }
-                    ast::Expr::TupleExpr(t) => t.fields().next().is_none(),
-                    _ => false,
-                });
-                if is_empty_expr {
+                if has_empty_expr(&arm) {
                     arm.remove();
                 } else {
                     cov_mark::hit!(add_missing_match_arms_empty_expr);
@@ -186,6 +192,14 @@ pub(crate) fn add_missing_match_arms(acc: &mut Assists, ctx: &AssistContext) ->
     )
 }
 
+fn has_empty_expr(arm: &MatchArm) -> bool {
+    arm.expr().map_or(true, |e| match e {
+        ast::Expr::BlockExpr(b) => b.statements().next().is_none() && b.tail_expr().is_none(),
+        ast::Expr::TupleExpr(t) => t.fields().next().is_none(),
+        _ => false,
+    })
+}
+
 fn cursor_at_trivial_match_arm_list(
     ctx: &AssistContext,
     match_expr: &MatchExpr,
@@ -1283,4 +1297,157 @@ fn foo(t: bool) {
 }"#,
         );
     }
+
+    #[test]
+    fn keeps_uninhabited_variants() {
+        // Without `exhaustive_patterns`, rustc and the match checker still require these arms.
+        check_assist(
+            add_missing_match_arms,
+            r#"
+enum Void {}
+enum A {
+    One,
+    Never(!),
+    Void(Void),
+    Pair { a: i32, void: (Void, i32) },
+    Two(i32),
+}
+fn foo(a: A) {
+    match $0a {
+    }
+}
+"#,
+            r#"
+enum Void {}
+enum A {
+    One,
+    Never(!),
+    Void(Void),
+    Pair { a: i32, void: (Void, i32) },
+    Two(i32),
+}
+fn foo(a: A) {
+    match a {
+        $0A::One => todo!(),
+        A::Never(_) => todo!(),
+        A::Void(_) => todo!(),
+        A::Pair { a, void } => todo!(),
+        A::Two(_) => todo!(),
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn keeps_variant_with_uninhabited_struct() {
+        check_assist(
+            add_missing_match_arms,
+            r#"
+enum Void {}
+struct Wrapper(Void);
+enum Outcome {
+    Value(u32),
+    Error(Wrapper),
+}
+fn foo(outcome: Outcome) {
+    match $0outcome {
+    }
+}
+"#,
+            r#"
+enum Void {}
+struct Wrapper(Void);
+enum Outcome {
+    Value(u32),
+    Error(Wrapper),
+}
+fn foo(outcome: Outcome) {
+    match outcome {
+        Outcome::Value(${0:_}) => todo!(),
+        Outcome::Error(_) => todo!(),
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn adds_wildcard_for_foreign_non_exhaustive_enum() {
+        check_assist(
+            add_missing_match_arms,
+            r#"
+//- /main.rs crate:main deps:e
+fn foo(t: e::E) {
+    match $0t {
+    }
+}
+//- /e.rs crate:e
+#[non_exhaustive]
+pub enum E { A, B }
+"#,
+            r#"
+fn foo(t: e::E) {
+    match t {
+        $0e::E::A => todo!(),
+        e::E::B => todo!(),
+        _ => todo!(),
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn adds_only_wildcard_for_covered_foreign_non_exhaustive_enum() {
+        check_assist(
+            add_missing_match_arms,
+            r#"
+//- /main.rs crate:main deps:e
+fn foo(t: e::E) {
+    match $0t {
+        e::E::A => {}
+        e::E::B => {}
+    }
+}
+//- /e.rs crate:e
+#[non_exhaustive]
+pub enum E { A, B }
+"#,
+            r#"
+fn foo(t: e::E) {
+    match t {
+        e::E::A => {}
+        e::E::B => {}
+        ${0:_} => todo!(),
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn no_wildcard_for_local_non_exhaustive_enum() {
+        check_assist(
+            add_missing_match_arms,
+            r#"
+#[non_exhaustive]
+enum E { A, B }
+fn foo(t: E) {
+    match $0t {
+    }
+}
+"#,
+            r#"
+#[non_exhaustive]
+enum E { A, B }
+fn foo(t: E) {
+    match t {
+        $0E::A => todo!(),
+        E::B => todo!(),
+    }
+}
+"#,
+        );
+    }
 }
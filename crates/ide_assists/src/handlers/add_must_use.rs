@@ -0,0 +1,275 @@
+use ide_db::helpers::FamousDefs;
+use syntax::{
+    ast::{self, edit::IndentLevel, AstNode, HasAttrs},
+    SyntaxNode, TextRange,
+};
+
+use crate::{utils::vis_offset, AssistContext, AssistId, AssistKind, Assists};
+
+// Assist: add_must_use
+//
+// Adds a `#[must_use]` attribute to a function returning `Result` or `Option`, or to a type
+// definition. As `Result` is already `#[must_use]`, functions returning it only get the variant
+// with a message.
+//
+// ```
+// //- minicore: option
+// fn $0find(id: u32) -> Option<u32> {
+//     None
+// }
+// ```
+// ->
+// ```
+// #[must_use]
+// fn find(id: u32) -> Option<u32> {
+//     None
+// }
+// ```
+pub(crate) fn add_must_use(acc: &mut Assists, ctx: &AssistContext) -> Option<()> {
+    let (node, target, needs_message) = match ctx.find_node_at_offset::<ast::Fn>() {
+        Some(fn_) => {
+            if has_must_use(&fn_) {
+                return None;
+            }
+            if fn_.body().map_or(false, |it| it.syntax().text_range().contains(ctx.offset())) {
+                return None;
+            }
+            let func = ctx.sema.to_def(&fn_)?;
+            let ret_adt = func.ret_type(ctx.db()).as_adt()?;
+            let famous_defs = FamousDefs(&ctx.sema, Some(func.module(ctx.db()).krate()));
+            let is_result =
+                famous_defs.core_result_Result().map_or(false, |it| hir::Adt::Enum(it) == ret_adt);
+            let is_option =
+                famous_defs.core_option_Option().map_or(false, |it| hir::Adt::Enum(it) == ret_adt);
+            if !is_result && !is_option {
+                return None;
+            }
+            let end = fn_
+                .ret_type()
+                .map(|it| it.syntax().text_range().end())
+                .or_else(|| fn_.param_list().map(|it| it.syntax().text_range().end()))?;
+            let target = TextRange::new(fn_.syntax().text_range().start(), end);
+            (fn_.syntax().clone(), target, is_result)
+        }
+        None => {
+            let adt = ctx.find_node_at_offset::<ast::Adt>()?;
+            if has_must_use(&adt) {
+                return None;
+            }
+            // Only offer the assist on the header, not everywhere inside the fields.
+            let body = match &adt {
+                ast::Adt::Struct(it) => it.field_list().map(|it| it.syntax().clone()),
+                ast::Adt::Enum(it) => it.variant_list().map(|it| it.syntax().clone()),
+                ast::Adt::Union(it) => it.record_field_list().map(|it| it.syntax().clone()),
+            };
+            let end = match body {
+                Some(body) if body.text_range().contains(ctx.offset()) => return None,
+                Some(body) => body.text_range().start(),
+                None => adt.syntax().text_range().end(),
+            };
+            (adt.syntax().clone(), TextRange::new(adt.syntax().text_range().start(), end), false)
+        }
+    };
+
+    // A plain `#[must_use]` on top of `Result`'s own one doesn't add anything.
+    if !needs_message {
+        acc.add(
+            AssistId("add_must_use", AssistKind::Refactor),
+            "Add `#[must_use]`",
+            target,
+            |builder| builder.insert(vis_offset(&node), attr_text(&node, "#[must_use]")),
+        );
+    }
+    if let Some(cap) = ctx.config.snippet_cap {
+        acc.add(
+            AssistId("add_must_use", AssistKind::Refactor),
+            "Add `#[must_use]` with a message",
+            target,
+            |builder| {
+                let attr = attr_text(&node, r#"#[must_use = "${0:message}"]"#);
+                builder.insert_snippet(cap, vis_offset(&node), attr)
+            },
+        );
+    }
+    Some(())
+}
+
+fn has_must_use(item: &impl HasAttrs) -> bool {
+    item.attrs().any(|attr| attr.simple_name().as_deref() == Some("must_use"))
+}
+
+fn attr_text(node: &SyntaxNode, attr: &str) -> String {
+    format!("{}\n{}", attr, IndentLevel::from_node(node))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::{check_assist, check_assist_by_label, check_assist_not_applicable};
+
+    use super::*;
+
+    #[test]
+    fn add_must_use_to_fn_returning_option() {
+        check_assist_by_label(
+            add_must_use,
+            r#"
+//- minicore: option
+/// Docs.
+pub fn $0find() -> Option<u32> {
+    None
+}
+"#,
+            r#"
+/// Docs.
+#[must_use]
+pub fn find() -> Option<u32> {
+    None
+}
+"#,
+            "Add `#[must_use]`",
+        );
+    }
+
+    #[test]
+    fn add_must_use_to_method_returning_result() {
+        check_assist(
+            add_must_use,
+            r#"
+//- minicore: result
+struct S;
+impl S {
+    fn parse(&self$0) -> Result<u32, ()> {
+        Ok(0)
+    }
+}
+"#,
+            r#"
+struct S;
+impl S {
+    #[must_use = "${0:message}"]
+    fn parse(&self) -> Result<u32, ()> {
+        Ok(0)
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn add_must_use_with_message() {
+        check_assist_by_label(
+            add_must_use,
+            r#"
+//- minicore: option
+fn $0find() -> Option<u32> { None }
+"#,
+            r#"
+#[must_use = "${0:message}"]
+fn find() -> Option<u32> { None }
+"#,
+            "Add `#[must_use]` with a message",
+        );
+    }
+
+    #[test]
+    fn add_must_use_to_adts() {
+        check_assist_by_label(
+            add_must_use,
+            r#"
+#[derive(Debug)]
+pub struct $0Guard {
+    id: u32,
+}
+"#,
+            r#"
+#[derive(Debug)]
+#[must_use]
+pub struct Guard {
+    id: u32,
+}
+"#,
+            "Add `#[must_use]`",
+        );
+        check_assist_by_label(
+            add_must_use,
+            r#"
+mod m {
+    enum$0 Handle { A, B }
+}
+"#,
+            r#"
+mod m {
+    #[must_use]
+    enum Handle { A, B }
+}
+"#,
+            "Add `#[must_use]`",
+        );
+    }
+
+    #[test]
+    fn not_applicable_to_fn_returning_unit() {
+        check_assist_not_applicable(
+            add_must_use,
+            r#"
+//- minicore: option
+fn $0f() {}
+"#,
+        );
+        check_assist_not_applicable(
+            add_must_use,
+            r#"
+//- minicore: option
+fn $0f() -> () {}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_to_other_return_types() {
+        check_assist_not_applicable(
+            add_must_use,
+            r#"
+//- minicore: option
+struct S;
+fn $0f() -> S { S }
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_when_already_must_use() {
+        check_assist_not_applicable(
+            add_must_use,
+            r#"
+//- minicore: option
+#[must_use]
+fn $0f() -> Option<()> { None }
+"#,
+        );
+        check_assist_not_applicable(
+            add_must_use,
+            r#"
+#[must_use = "guards must be held"]
+struct $0Guard;
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_inside_body_or_fields() {
+        check_assist_not_applicable(
+            add_must_use,
+            r#"
+//- minicore: option
+fn f() -> Option<()> { $0None }
+"#,
+        );
+        check_assist_not_applicable(
+            add_must_use,
+            r#"
+struct S { $0field: u32 }
+"#,
+        );
+    }
+}
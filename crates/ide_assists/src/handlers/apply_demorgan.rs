@@ -7,9 +7,9 @@ use crate::{utils::invert_boolean_expression, AssistContext, AssistId, AssistKin
 // Assist: apply_demorgan
 //
 // Apply https://en.wikipedia.org/wiki/De_Morgan%27s_laws[De Morgan's law].
-// This transforms expressions of the form `!l || !r` into `!(l && r)`.
-// This also works with `&&`. This assist can only be applied with the cursor
-// on either `||` or `&&`.
+// This transforms expressions of the form `!l || !r` into `!(l && r)`, and pushes the negation
+// of `!(l && r)` back inward into `!l || !r`. This also works with `&&`. This assist can only be
+// applied with the cursor on either `||` or `&&`, or on the `!` of a negated expression.
 //
 // ```
 // fn main() {
@@ -23,30 +23,33 @@ use crate::{utils::invert_boolean_expression, AssistContext, AssistId, AssistKin
 // }
 // ```
 pub(crate) fn apply_demorgan(acc: &mut Assists, ctx: &AssistContext) -> Option<()> {
-    let expr = ctx.find_node_at_offset::<ast::BinExpr>()?;
+    let expr = match ctx.find_node_at_offset::<ast::BinExpr>() {
+        Some(expr)
+            if expr
+                .op_token()
+                .map_or(false, |it| it.text_range().contains_range(ctx.selection_trimmed())) =>
+        {
+            expr
+        }
+        _ => negated_bin_expr(ctx)?,
+    };
     let op = expr.op_kind()?;
     let op_range = expr.op_token()?.text_range();
 
-    let opposite_op = match op {
-        ast::BinaryOp::LogicOp(ast::LogicOp::And) => "||",
-        ast::BinaryOp::LogicOp(ast::LogicOp::Or) => "&&",
+    let (opposite_op, opposite_logic_op) = match op {
+        ast::BinaryOp::LogicOp(ast::LogicOp::And) => ("||", ast::LogicOp::Or),
+        ast::BinaryOp::LogicOp(ast::LogicOp::Or) => ("&&", ast::LogicOp::And),
         _ => return None,
     };
 
-    let cursor_in_range = op_range.contains_range(ctx.selection_trimmed());
-    if !cursor_in_range {
-        return None;
-    }
-
     let mut expr = expr;
 
     // Walk up the tree while we have the same binary operator
     while let Some(parent_expr) = expr.syntax().parent().and_then(ast::BinExpr::cast) {
-        if let Some(parent_op) = expr.op_kind() {
-            if parent_op == op {
-                expr = parent_expr
-            }
+        if parent_expr.op_kind() != Some(op) {
+            break;
         }
+        expr = parent_expr;
     }
 
     let mut expr_stack = vec![expr.clone()];
@@ -76,70 +79,105 @@ pub(crate) fn apply_demorgan(acc: &mut Assists, ctx: &AssistContext) -> Option<(
         traverse_bin_expr_arm(expr.rhs()?);
     }
 
-    acc.add(
-        AssistId("apply_demorgan", AssistKind::RefactorRewrite),
-        "Apply De Morgan's law",
-        op_range,
-        |edit| {
-            terms.sort_by_key(|t| t.syntax().text_range().start());
-            let mut terms = VecDeque::from(terms);
+    let paren_expr = expr.syntax().parent().and_then(ast::ParenExpr::cast);
+    let neg_expr = paren_expr
+        .clone()
+        .and_then(|paren_expr| paren_expr.syntax().parent())
+        .and_then(ast::PrefixExpr::cast)
+        .filter(|prefix_expr| prefix_expr.op_kind() == Some(ast::UnaryOp::Not));
+
+    let label = if neg_expr.is_some() {
+        "Apply De Morgan's law"
+    } else {
+        "Factor out negation using De Morgan's law"
+    };
+    acc.add(AssistId("apply_demorgan", AssistKind::RefactorRewrite), label, op_range, |edit| {
+        terms.sort_by_key(|t| t.syntax().text_range().start());
+        let mut terms = VecDeque::from(terms);
 
-            let paren_expr = expr.syntax().parent().and_then(ast::ParenExpr::cast);
+        for op_range in op_ranges {
+            edit.replace(op_range, opposite_op);
+        }
 
-            let neg_expr = paren_expr
-                .clone()
-                .and_then(|paren_expr| paren_expr.syntax().parent())
-                .and_then(ast::PrefixExpr::cast)
-                .and_then(|prefix_expr| {
-                    if prefix_expr.op_kind().unwrap() == ast::UnaryOp::Not {
-                        Some(prefix_expr)
-                    } else {
-                        None
-                    }
-                });
+        if let Some(paren_expr) = paren_expr {
+            for term in terms {
+                let range = term.syntax().text_range();
+                let not_term = invert_boolean_expression(term);
 
-            for op_range in op_ranges {
-                edit.replace(op_range, opposite_op);
+                edit.replace(range, not_term.syntax().text());
             }
 
-            if let Some(paren_expr) = paren_expr {
-                for term in terms {
-                    let range = term.syntax().text_range();
-                    let not_term = invert_boolean_expression(term);
-
-                    edit.replace(range, not_term.syntax().text());
-                }
-
-                if let Some(neg_expr) = neg_expr {
-                    cov_mark::hit!(demorgan_double_negation);
-                    edit.replace(neg_expr.op_token().unwrap().text_range(), "");
-                } else {
-                    cov_mark::hit!(demorgan_double_parens);
-                    edit.replace(paren_expr.l_paren_token().unwrap().text_range(), "!(");
+            if let Some(neg_expr) = neg_expr {
+                cov_mark::hit!(demorgan_double_negation);
+                edit.delete(neg_expr.op_token().unwrap().text_range());
+                if !needs_parens(&neg_expr, opposite_logic_op) {
+                    cov_mark::hit!(demorgan_removes_parens);
+                    edit.delete(paren_expr.l_paren_token().unwrap().text_range());
+                    edit.delete(paren_expr.r_paren_token().unwrap().text_range());
                 }
             } else {
-                if let Some(lhs) = terms.pop_front() {
-                    let lhs_range = lhs.syntax().text_range();
-                    let not_lhs = invert_boolean_expression(lhs);
+                cov_mark::hit!(demorgan_double_parens);
+                edit.replace(paren_expr.l_paren_token().unwrap().text_range(), "!(");
+            }
+        } else {
+            if let Some(lhs) = terms.pop_front() {
+                let lhs_range = lhs.syntax().text_range();
+                let not_lhs = invert_boolean_expression(lhs);
 
-                    edit.replace(lhs_range, format!("!({}", not_lhs.syntax().text()));
-                }
+                edit.replace(lhs_range, format!("!({}", not_lhs.syntax().text()));
+            }
 
-                if let Some(rhs) = terms.pop_back() {
-                    let rhs_range = rhs.syntax().text_range();
-                    let not_rhs = invert_boolean_expression(rhs);
+            if let Some(rhs) = terms.pop_back() {
+                let rhs_range = rhs.syntax().text_range();
+                let not_rhs = invert_boolean_expression(rhs);
 
-                    edit.replace(rhs_range, format!("{})", not_rhs.syntax().text()));
-                }
+                edit.replace(rhs_range, format!("{})", not_rhs.syntax().text()));
+            }
 
-                for term in terms {
-                    let term_range = term.syntax().text_range();
-                    let not_term = invert_boolean_expression(term);
-                    edit.replace(term_range, not_term.syntax().text());
-                }
+            for term in terms {
+                let term_range = term.syntax().text_range();
+                let not_term = invert_boolean_expression(term);
+                edit.replace(term_range, not_term.syntax().text());
             }
+        }
+    })
+}
+
+/// Finds the operand of a `!(..)` expression with the cursor on its `!`.
+fn negated_bin_expr(ctx: &AssistContext) -> Option<ast::BinExpr> {
+    let prefix_expr = ctx.find_node_at_offset::<ast::PrefixExpr>()?;
+    if prefix_expr.op_kind()? != ast::UnaryOp::Not
+        || !prefix_expr.op_token()?.text_range().contains_range(ctx.selection_trimmed())
+    {
+        return None;
+    }
+    match prefix_expr.expr()? {
+        ast::Expr::ParenExpr(paren_expr) => match paren_expr.expr()? {
+            ast::Expr::BinExpr(bin_expr) => Some(bin_expr),
+            _ => None,
         },
-    )
+        _ => None,
+    }
+}
+
+/// Whether replacing `expr` with a chain of `op`s has to keep the chain parenthesized.
+fn needs_parens(expr: &ast::PrefixExpr, op: ast::LogicOp) -> bool {
+    let parent = match expr.syntax().parent() {
+        Some(it) => it,
+        None => return false,
+    };
+    if let Some(bin_expr) = ast::BinExpr::cast(parent.clone()) {
+        return match bin_expr.op_kind() {
+            Some(ast::BinaryOp::LogicOp(parent_op)) => parent_op != op,
+            Some(ast::BinaryOp::Assignment { op: None }) => {
+                bin_expr.lhs().map_or(true, |lhs| lhs.syntax() == expr.syntax())
+            }
+            _ => true,
+        };
+    }
+    ast::Expr::can_cast(parent.kind())
+        && !ast::ParenExpr::can_cast(parent.kind())
+        && !ast::ReturnExpr::can_cast(parent.kind())
 }
 
 #[cfg(test)]
@@ -212,7 +250,56 @@ fn f() { !(S <= S || S < S) }
     #[test]
     fn demorgan_doesnt_double_negation() {
         cov_mark::check!(demorgan_double_negation);
-        check_assist(apply_demorgan, "fn f() { !(x ||$0 x) }", "fn f() { (!x && !x) }")
+        check_assist(apply_demorgan, "fn f() { !(x ||$0 x) }", "fn f() { !x && !x }")
+    }
+
+    #[test]
+    fn demorgan_pushes_negation_inward() {
+        cov_mark::check!(demorgan_removes_parens);
+        check_assist(apply_demorgan, "fn f() { !(a &&$0 b) }", "fn f() { !a || !b }");
+        check_assist(apply_demorgan, "fn f() { !(a ||$0 b) }", "fn f() { !a && !b }");
+        check_assist(apply_demorgan, "fn f() { !(a && b &&$0 c) }", "fn f() { !a || !b || !c }");
+        check_assist(apply_demorgan, "fn f() { !(a ||$0 b || c) }", "fn f() { !a && !b && !c }");
+    }
+
+    #[test]
+    fn demorgan_factors_out_negation() {
+        check_assist(apply_demorgan, "fn f() { !a ||$0 !b }", "fn f() { !(a && b) }");
+        check_assist(apply_demorgan, "fn f() { !a &&$0 !b }", "fn f() { !(a || b) }");
+        check_assist(apply_demorgan, "fn f() { !a && !b &&$0 !c }", "fn f() { !(a || b || c) }");
+        check_assist(apply_demorgan, "fn f() { !a ||$0 !b || !c }", "fn f() { !(a && b && c) }");
+    }
+
+    #[test]
+    fn demorgan_applies_with_cursor_on_negation() {
+        check_assist(apply_demorgan, "fn f() { $0!(a && b) }", "fn f() { !a || !b }");
+        check_assist(apply_demorgan, "fn f() { c && $0!(a && b) }", "fn f() { c && (!a || !b) }");
+        check_assist_not_applicable(apply_demorgan, "fn f() { $0!(a + b) }");
+        check_assist_not_applicable(apply_demorgan, "fn f() { $0!a }");
+    }
+
+    #[test]
+    fn demorgan_keeps_parens_when_needed() {
+        check_assist(apply_demorgan, "fn f() { !(a &&$0 b) && c }", "fn f() { (!a || !b) && c }");
+        check_assist(apply_demorgan, "fn f() { !(a &&$0 b) || c }", "fn f() { !a || !b || c }");
+        check_assist(apply_demorgan, "fn f() { !(a ||$0 b) as u8 }", "fn f() { (!a && !b) as u8 }");
+        check_assist(apply_demorgan, "fn f() { x = !(a ||$0 b) }", "fn f() { x = !a && !b }");
+        check_assist(
+            apply_demorgan,
+            "fn f() { let x = !(a ||$0 b); }",
+            "fn f() { let x = !a && !b; }",
+        );
+    }
+
+    #[test]
+    fn demorgan_doesnt_distribute_over_other_operators() {
+        check_assist(apply_demorgan, "fn f() { !(a ||$0 b && c) }", "fn f() { !a && !(b && c) }");
+        check_assist(
+            apply_demorgan,
+            "fn f() { a &&$0 b || c || d }",
+            "fn f() { !(!a || !b) || c || d }",
+        );
+        check_assist_not_applicable(apply_demorgan, "fn f() { !(a &$0 b) }");
     }
 
     #[test]
@@ -0,0 +1,536 @@
+use either::Either;
+use ide_db::{base_db::FileId, defs::Definition};
+use rustc_hash::FxHashMap;
+use stdx::format_to;
+use syntax::{
+    ast::{self, AstNode, HasGenericParams, HasName, HasVisibility},
+    match_ast, SyntaxNode, TextRange,
+};
+
+use crate::{assist_context::AssistBuilder, AssistContext, AssistId, AssistKind, Assists};
+
+// Assist: convert_named_struct_to_tuple_struct
+//
+// Converts struct with named fields to tuple struct, and analogously for enum variants with named
+// fields.
+//
+// ```
+// struct Point$0 { x: f32, y: f32 }
+//
+// impl Point {
+//     pub fn new(x: f32, y: f32) -> Self {
+//         Point { x, y }
+//     }
+//
+//     pub fn x(&self) -> f32 {
+//         self.x
+//     }
+// }
+// ```
+// ->
+// ```
+// struct Point(f32, f32);
+//
+// impl Point {
+//     pub fn new(x: f32, y: f32) -> Self {
+//         Point(x, y)
+//     }
+//
+//     pub fn x(&self) -> f32 {
+//         self.0
+//     }
+// }
+// ```
+pub(crate) fn convert_named_struct_to_tuple_struct(
+    acc: &mut Assists,
+    ctx: &AssistContext,
+) -> Option<()> {
+    let strukt = ctx
+        .find_node_at_offset::<ast::Struct>()
+        .map(Either::Left)
+        .or_else(|| ctx.find_node_at_offset::<ast::Variant>().map(Either::Right))?;
+    let field_list = strukt.as_ref().either(|s| s.field_list(), |v| v.field_list())?;
+    let record_fields = match field_list {
+        ast::FieldList::RecordFieldList(it) => it,
+        ast::FieldList::TupleFieldList(_) => return None,
+    };
+    let strukt_def = match &strukt {
+        Either::Left(s) => Either::Left(ctx.sema.to_def(s)?),
+        Either::Right(v) => Either::Right(ctx.sema.to_def(v)?),
+    };
+    let target = strukt.as_ref().either(|s| s.syntax(), |v| v.syntax()).text_range();
+
+    acc.add(
+        AssistId("convert_named_struct_to_tuple_struct", AssistKind::RefactorRewrite),
+        "Convert to tuple struct",
+        target,
+        |edit| {
+            let names: Vec<_> =
+                record_fields.fields().filter_map(|it| Some(it.name()?.to_string())).collect();
+            let mut edits = FxHashMap::default();
+            edit_field_references(ctx, &mut edits, record_fields.fields());
+            edit_struct_references(ctx, &mut edits, strukt_def, &names);
+            for (file_id, file_edits) in edits {
+                edit.edit_file(file_id);
+                for (range, replacement) in file_edits.0 {
+                    edit.replace(range, replacement);
+                }
+            }
+            edit_struct_def(ctx, edit, &strukt, record_fields);
+        },
+    )
+}
+
+fn edit_struct_def(
+    ctx: &AssistContext,
+    edit: &mut AssistBuilder,
+    strukt: &Either<ast::Struct, ast::Variant>,
+    record_fields: ast::RecordFieldList,
+) {
+    let tuple_fields = record_fields
+        .fields()
+        .filter_map(|f| Some(ast::make::tuple_field(f.visibility(), f.ty()?)));
+    let tuple_fields = ast::make::tuple_field_list(tuple_fields);
+    let record_fields_text_range = record_fields.syntax().text_range();
+
+    edit.edit_file(ctx.file_id());
+
+    // Everything between the name (or the generic parameters) and the end of the fields is
+    // replaced, for structs this includes the where clause which goes after tuple fields.
+    let (header, where_clause) = match strukt {
+        Either::Left(s) => (
+            s.generic_param_list()
+                .map(|it| it.syntax().clone())
+                .or_else(|| s.name().map(|it| it.syntax().clone())),
+            s.where_clause(),
+        ),
+        Either::Right(v) => (v.name().map(|it| it.syntax().clone()), None),
+    };
+    let start = header.map_or(record_fields_text_range.start(), |it| it.text_range().end());
+
+    let mut replacement = tuple_fields.to_string();
+    if let Some(w) = where_clause {
+        let w = w.syntax().text().to_string();
+        format_to!(
+            replacement,
+            "\n{}",
+            w.trim_end_matches(|c: char| c == ',' || c.is_whitespace())
+        );
+    }
+    if strukt.is_left() {
+        replacement.push(';');
+    }
+    edit.replace(TextRange::new(start, record_fields_text_range.end()), replacement);
+}
+
+/// Replacements in one file which don't overlap, so that rewriting a record literal or pattern can
+/// take over the edits inside of it.
+#[derive(Default)]
+struct FileEdits(Vec<(TextRange, String)>);
+
+impl FileEdits {
+    /// The text of `node` with the edits inside it applied. These edits are removed, as `node`
+    /// is about to be part of a replacement itself.
+    fn take_text(&mut self, node: &SyntaxNode) -> String {
+        let range = node.text_range();
+        let mut inner = self.take_edits_in(range);
+        inner.sort_by_key(|(range, _)| std::cmp::Reverse(range.start()));
+        let mut text = node.text().to_string();
+        for (edit_range, replacement) in inner {
+            let edit_range = edit_range - range.start();
+            text.replace_range(std::ops::Range::<usize>::from(edit_range), &replacement);
+        }
+        text
+    }
+
+    fn take_edits_in(&mut self, range: TextRange) -> Vec<(TextRange, String)> {
+        let (inner, rest) = self.0.drain(..).partition(|(it, _)| range.contains_range(*it));
+        self.0 = rest;
+        inner
+    }
+
+    /// Replaces `range`, dropping the edits in it that haven't been taken over.
+    fn replace(&mut self, range: TextRange, replacement: String) {
+        self.take_edits_in(range);
+        self.0.push((range, replacement));
+    }
+}
+
+fn edit_struct_references(
+    ctx: &AssistContext,
+    edits: &mut FxHashMap<FileId, FileEdits>,
+    strukt: Either<hir::Struct, hir::Variant>,
+    names: &[String],
+) {
+    let strukt_def = match strukt {
+        Either::Left(s) => Definition::Adt(hir::Adt::Struct(s)),
+        Either::Right(v) => Definition::Variant(v),
+    };
+    let usages = strukt_def.usages(&ctx.sema).include_self_refs().all();
+
+    let edit_node = |edits: &mut FileEdits, node: SyntaxNode| -> Option<()> {
+        match_ast! {
+            match node {
+                ast::RecordPat(record_pat) => {
+                    let path = record_pat.path()?;
+                    let field_list = record_pat.record_pat_field_list()?;
+                    let fields: Vec<_> = field_list
+                        .fields()
+                        .map(|it| Some((it.field_name()?.to_string(), it.pat()?)))
+                        .collect::<Option<_>>()?;
+                    let pats = names
+                        .iter()
+                        .map(|name| match fields.iter().find(|(field, _)| field == name) {
+                            Some((_, pat)) => edits.take_text(pat.syntax()),
+                            None => "_".to_string(),
+                        })
+                        .collect::<Vec<_>>();
+                    edits.replace(
+                        record_pat.syntax().text_range(),
+                        format!("{}({})", path, pats.join(", ")),
+                    );
+                },
+                // for struct creations like `Foo { bar: 42 }`
+                ast::RecordExpr(record_expr) => {
+                    let path = record_expr.path()?;
+                    let field_list = record_expr.record_expr_field_list()?;
+                    let fields: Vec<_> = field_list
+                        .fields()
+                        .map(|it| Some((it.field_name()?.to_string(), it.expr()?)))
+                        .collect::<Option<_>>()?;
+                    if let Some(spread) = field_list.spread() {
+                        // Functional update syntax works with tuple structs as well, but the fields
+                        // have to be referred to by index.
+                        let mut fields: Vec<_> = fields
+                            .iter()
+                            .filter_map(|(field, expr)| {
+                                let idx = names.iter().position(|it| it == field)?;
+                                Some(format!("{}: {}", idx, edits.take_text(expr.syntax())))
+                            })
+                            .collect();
+                        fields.push(format!("..{}", edits.take_text(spread.syntax())));
+                        edits.replace(
+                            record_expr.syntax().text_range(),
+                            format!("{} {{ {} }}", path, fields.join(", ")),
+                        );
+                        return Some(());
+                    }
+                    let args = names
+                        .iter()
+                        .map(|name| fields.iter().find(|(field, _)| field == name))
+                        .collect::<Option<Vec<_>>>()?
+                        .into_iter()
+                        .map(|(_, expr)| edits.take_text(expr.syntax()))
+                        .collect::<Vec<_>>();
+                    edits.replace(
+                        record_expr.syntax().text_range(),
+                        format!("{}({})", path, args.join(", ")),
+                    );
+                },
+                _ => return None,
+            }
+        }
+        Some(())
+    };
+
+    for (file_id, refs) in usages {
+        // Only the literal or pattern the usage is the path of gets rewritten, innermost first so
+        // that the outer ones take over the edits of the nested ones.
+        let mut nodes: Vec<SyntaxNode> = refs
+            .iter()
+            .filter_map(|r| {
+                let path = r.name.syntax().ancestors().find_map(ast::Path::cast)?;
+                let node = path.syntax().parent()?;
+                let is_record_path = match_ast! {
+                    match (&node) {
+                        ast::RecordExpr(it) => it.path() == Some(path),
+                        ast::RecordPat(it) => it.path() == Some(path),
+                        _ => false,
+                    }
+                };
+                is_record_path.then(|| node)
+            })
+            .collect();
+        nodes.sort_by_key(|node| node.text_range().len());
+        let file_edits = edits.entry(file_id).or_default();
+        for node in nodes {
+            edit_node(file_edits, node);
+        }
+    }
+}
+
+fn edit_field_references(
+    ctx: &AssistContext,
+    edits: &mut FxHashMap<FileId, FileEdits>,
+    fields: impl Iterator<Item = ast::RecordField>,
+) {
+    for (idx, field) in fields.enumerate() {
+        let field = match ctx.sema.to_def(&field) {
+            Some(it) => it,
+            None => continue,
+        };
+        let def = Definition::Field(field);
+        let usages = def.usages(&ctx.sema).all();
+        for (file_id, refs) in usages {
+            let file_edits = edits.entry(file_id).or_default();
+            for r in refs {
+                // Record literals and patterns are rewritten as a whole, only field accesses are
+                // left to fix up here.
+                if let Some(name_ref) = r.name.as_name_ref() {
+                    if name_ref.syntax().parent().and_then(ast::FieldExpr::cast).is_some() {
+                        file_edits.0.push((name_ref.syntax().text_range(), idx.to_string()));
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::{check_assist, check_assist_not_applicable};
+
+    use super::*;
+
+    #[test]
+    fn not_applicable_other_than_record_struct() {
+        check_assist_not_applicable(convert_named_struct_to_tuple_struct, r#"struct Foo$0(u32);"#);
+        check_assist_not_applicable(convert_named_struct_to_tuple_struct, r#"struct Foo$0;"#);
+    }
+
+    #[test]
+    fn convert_simple_struct() {
+        check_assist(
+            convert_named_struct_to_tuple_struct,
+            r#"
+struct Inner;
+struct A$0 { inner: Inner }
+
+impl A {
+    fn new(inner: Inner) -> A {
+        A { inner }
+    }
+
+    fn new_with_default() -> A {
+        A::new(Inner)
+    }
+
+    fn into_inner(self) -> Inner {
+        self.inner
+    }
+}
+"#,
+            r#"
+struct Inner;
+struct A(Inner);
+
+impl A {
+    fn new(inner: Inner) -> A {
+        A(inner)
+    }
+
+    fn new_with_default() -> A {
+        A::new(Inner)
+    }
+
+    fn into_inner(self) -> Inner {
+        self.0
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn convert_struct_with_visibility_and_patterns() {
+        check_assist(
+            convert_named_struct_to_tuple_struct,
+            r#"
+pub struct $0Pair {
+    pub first: u32,
+    second: u32,
+}
+
+impl Pair {
+    fn f(self) -> u32 {
+        let Pair { second: s, first } = self;
+        match self {
+            Pair { first: 0, .. } => s,
+            Self { second, .. } => second + first,
+        }
+    }
+}
+
+fn g() -> Pair {
+    Pair { second: 1, first: 2 }
+}
+"#,
+            r#"
+pub struct Pair(pub u32, u32);
+
+impl Pair {
+    fn f(self) -> u32 {
+        let Pair(first, s) = self;
+        match self {
+            Pair(0, _) => s,
+            Self(_, second) => second + first,
+        }
+    }
+}
+
+fn g() -> Pair {
+    Pair(2, 1)
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn convert_struct_with_functional_update() {
+        check_assist(
+            convert_named_struct_to_tuple_struct,
+            r#"
+#[derive(Default)]
+struct S$0 { a: u32, b: u32 }
+
+fn f() -> S {
+    S { b: 1, ..S::default() }
+}
+"#,
+            r#"
+#[derive(Default)]
+struct S(u32, u32);
+
+fn f() -> S {
+    S { 1: 1, ..S::default() }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn convert_struct_with_field_access_in_literal() {
+        check_assist(
+            convert_named_struct_to_tuple_struct,
+            r#"
+struct P$0 { x: u32, y: u32 }
+
+fn f(p: P) -> P {
+    P { x: p.x, y: P { x: 0, y: p.y }.y }
+}
+"#,
+            r#"
+struct P(u32, u32);
+
+fn f(p: P) -> P {
+    P(p.0, P(0, p.1).1)
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn convert_struct_keeps_enclosing_literal() {
+        check_assist(
+            convert_named_struct_to_tuple_struct,
+            r#"
+struct P$0 { x: u32, y: u32 }
+struct Outer { p: P }
+
+fn f() -> Outer {
+    Outer { p: P { x: 0 } }
+}
+"#,
+            r#"
+struct P(u32, u32);
+struct Outer { p: P }
+
+fn f() -> Outer {
+    Outer { p: P { x: 0 } }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn convert_struct_with_where_clause() {
+        check_assist(
+            convert_named_struct_to_tuple_struct,
+            r#"
+struct Wrap$0<T>
+where
+    T: Clone,
+{
+    value: T,
+}
+"#,
+            r#"
+struct Wrap<T>(T)
+where
+    T: Clone;
+"#,
+        );
+    }
+
+    #[test]
+    fn convert_variant() {
+        check_assist(
+            convert_named_struct_to_tuple_struct,
+            r#"
+enum E {
+    $0V { x: i32, y: i32 },
+}
+
+fn f(e: E) -> i32 {
+    let v = E::V { x: 1, y: 2 };
+    match e {
+        E::V { x, y } => x + y,
+    }
+}
+"#,
+            r#"
+enum E {
+    V(i32, i32),
+}
+
+fn f(e: E) -> i32 {
+    let v = E::V(1, 2);
+    match e {
+        E::V(x, y) => x + y,
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn convert_struct_referenced_in_other_file() {
+        check_assist(
+            convert_named_struct_to_tuple_struct,
+            r#"
+//- /main.rs
+mod foo;
+use foo::Foo;
+
+fn main() {
+    let foo = Foo { bar: 1 };
+    let _ = foo.bar;
+}
+//- /foo.rs
+pub struct $0Foo { pub bar: u32 }
+"#,
+            r#"
+//- /main.rs
+mod foo;
+use foo::Foo;
+
+fn main() {
+    let foo = Foo(1);
+    let _ = foo.0;
+}
+//- /foo.rs
+pub struct Foo(pub u32);
+"#,
+        );
+    }
+}
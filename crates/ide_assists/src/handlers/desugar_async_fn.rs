@@ -0,0 +1,266 @@
+use hir::ModuleDef;
+use ide_db::helpers::{mod_path_to_ast, FamousDefs};
+use rustc_hash::FxHashSet;
+use syntax::{
+    ast::{self, edit::AstNodeEdit, edit::IndentLevel, AstNode},
+    TextRange,
+};
+
+use crate::{AssistContext, AssistId, AssistKind, Assists};
+
+// Assist: desugar_async_fn
+//
+// Rewrites an `async fn` into a plain `fn` returning `impl Future`.
+//
+// ```
+// //- minicore: future
+// async fn $0fetch(id: u32) -> String {
+//     format!("{}", id)
+// }
+// ```
+// ->
+// ```
+// fn fetch(id: u32) -> impl core::future::Future<Output = String> {
+//     async move {
+//         format!("{}", id)
+//     }
+// }
+// ```
+pub(crate) fn desugar_async_fn(acc: &mut Assists, ctx: &AssistContext) -> Option<()> {
+    let fn_ = ctx.find_node_at_offset::<ast::Fn>()?;
+    let async_token = fn_.async_token()?;
+    let body = fn_.body()?;
+    if body.syntax().text_range().contains_inclusive(ctx.offset()) {
+        return None;
+    }
+    let param_list = fn_.param_list()?;
+
+    // The returned future captures all the input lifetimes, which we can only spell out if there's
+    // at most one of them.
+    let lifetime_bound = match captured_lifetimes(&param_list) {
+        (0, named) if named.is_empty() => String::new(),
+        (1, named) if named.is_empty() => " + '_".to_string(),
+        (0, named) if named.len() == 1 => format!(" + {}", named.into_iter().next()?),
+        _ => {
+            cov_mark::hit!(desugar_async_fn_multiple_lifetimes);
+            return None;
+        }
+    };
+
+    let module = ctx.sema.scope(fn_.syntax()).module()?;
+    let future = FamousDefs(&ctx.sema, Some(module.krate())).core_future_Future()?;
+    let future_path = mod_path_to_ast(&module.find_use_path(ctx.db(), ModuleDef::Trait(future))?);
+
+    let target =
+        TextRange::new(fn_.syntax().text_range().start(), param_list.syntax().text_range().end());
+    acc.add(
+        AssistId("desugar_async_fn", AssistKind::RefactorRewrite),
+        "Desugar `async fn` into `fn` returning `impl Future`",
+        target,
+        |builder| {
+            let async_end = async_token
+                .next_token()
+                .filter(|it| it.kind().is_trivia())
+                .map_or(async_token.text_range().end(), |it| it.text_range().end());
+            builder.delete(TextRange::new(async_token.text_range().start(), async_end));
+
+            match fn_.ret_type().and_then(|it| it.ty()) {
+                Some(ty) => builder.replace(
+                    ty.syntax().text_range(),
+                    format!("impl {}<Output = {}>{}", future_path, ty, lifetime_bound),
+                ),
+                None => builder.insert(
+                    param_list.syntax().text_range().end(),
+                    format!(" -> impl {}<Output = ()>{}", future_path, lifetime_bound),
+                ),
+            }
+
+            let indent = IndentLevel::from_node(fn_.syntax());
+            let new_body = format!(
+                "{{\n{}async move {}\n{}}}",
+                indent + 1,
+                body.indent(IndentLevel(1)),
+                indent
+            );
+            builder.replace(body.syntax().text_range(), new_body);
+        },
+    )
+}
+
+/// Returns the number of elided lifetimes and the set of named lifetimes in the parameters.
+fn captured_lifetimes(param_list: &ast::ParamList) -> (usize, FxHashSet<String>) {
+    let mut elided = 0;
+    let mut named = FxHashSet::default();
+    if let Some(self_param) = param_list.self_param() {
+        if self_param.amp_token().is_some() && self_param.lifetime().is_none() {
+            elided += 1;
+        }
+    }
+    for node in param_list.syntax().descendants() {
+        if let Some(lifetime) = ast::Lifetime::cast(node.clone()) {
+            match lifetime.text().as_str() {
+                "'_" => elided += 1,
+                "'static" => (),
+                name => {
+                    named.insert(name.to_string());
+                }
+            }
+        } else if let Some(ref_type) = ast::RefType::cast(node) {
+            if ref_type.lifetime().is_none() {
+                elided += 1;
+            }
+        }
+    }
+    (elided, named)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::{check_assist, check_assist_not_applicable};
+
+    use super::*;
+
+    #[test]
+    fn desugar_simple_async_fn() {
+        check_assist(
+            desugar_async_fn,
+            r#"
+//- minicore: future
+$0async fn f() {
+    work();
+}
+"#,
+            r#"
+fn f() -> impl core::future::Future<Output = ()> {
+    async move {
+        work();
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn desugar_preserves_visibility_unsafe_and_where_clause() {
+        check_assist(
+            desugar_async_fn,
+            r#"
+//- minicore: future
+mod m {
+    pub async unsafe fn $0run<T>(value: T) -> T
+    where
+        T: Clone,
+    {
+        value.clone()
+    }
+}
+"#,
+            r#"
+mod m {
+    pub unsafe fn run<T>(value: T) -> impl core::future::Future<Output = T>
+    where
+        T: Clone,
+    {
+        async move {
+            value.clone()
+        }
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn desugar_moves_captured_params() {
+        check_assist(
+            desugar_async_fn,
+            r#"
+//- minicore: future
+async fn add$0(x: u32, y: u32) -> u32 {
+    let z = x + y;
+    z
+}
+"#,
+            r#"
+fn add(x: u32, y: u32) -> impl core::future::Future<Output = u32> {
+    async move {
+        let z = x + y;
+        z
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn desugar_captures_single_lifetime() {
+        check_assist(
+            desugar_async_fn,
+            r#"
+//- minicore: future
+struct S;
+impl S {
+    async fn $0name(&self) -> usize {
+        0
+    }
+}
+"#,
+            r#"
+struct S;
+impl S {
+    fn name(&self) -> impl core::future::Future<Output = usize> + '_ {
+        async move {
+            0
+        }
+    }
+}
+"#,
+        );
+        check_assist(
+            desugar_async_fn,
+            r#"
+//- minicore: future
+async fn $0first<'a>(xs: &'a [u8], _: &'static str) -> &'a u8 {
+    &xs[0]
+}
+"#,
+            r#"
+fn first<'a>(xs: &'a [u8], _: &'static str) -> impl core::future::Future<Output = &'a u8> + 'a {
+    async move {
+        &xs[0]
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_with_multiple_lifetimes() {
+        cov_mark::check!(desugar_async_fn_multiple_lifetimes);
+        check_assist_not_applicable(
+            desugar_async_fn,
+            r#"
+//- minicore: future
+async fn $0f(a: &u8, b: &u8) {}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_to_sync_fn_or_in_body() {
+        check_assist_not_applicable(
+            desugar_async_fn,
+            r#"
+//- minicore: future
+fn $0f() {}
+"#,
+        );
+        check_assist_not_applicable(
+            desugar_async_fn,
+            r#"
+//- minicore: future
+async fn f() { $0 }
+"#,
+        );
+    }
+}
@@ -1,11 +1,13 @@
 use hir::{db::HirDatabase, HasSource, HasVisibility, PathResolution};
-use ide_db::base_db::FileId;
+use ide_db::{base_db::FileId, helpers::SnippetCap};
 use syntax::{
     ast::{self, HasVisibility as _},
     AstNode, TextRange, TextSize,
 };
 
-use crate::{utils::vis_offset, AssistContext, AssistId, AssistKind, Assists};
+use crate::{
+    assist_context::AssistBuilder, utils::vis_offset, AssistContext, AssistId, AssistKind, Assists,
+};
 
 // FIXME: this really should be a fix for diagnostic, rather than an assist.
 
@@ -37,49 +39,69 @@ pub(crate) fn fix_visibility(acc: &mut Assists, ctx: &AssistContext) -> Option<(
 
 fn add_vis_to_referenced_module_def(acc: &mut Assists, ctx: &AssistContext) -> Option<()> {
     let path: ast::Path = ctx.find_node_at_offset()?;
-    let path_res = ctx.sema.resolve_path(&path)?;
-    let def = match path_res {
-        PathResolution::Def(def) => def,
-        _ => return None,
-    };
-
     let current_module = ctx.sema.scope(path.syntax()).module()?;
-    let target_module = def.module(ctx.db())?;
-
-    if def.visibility(ctx.db()).is_visible_from(ctx.db(), current_module.into()) {
-        return None;
-    };
-
-    let (offset, current_visibility, target, target_file, target_name) =
-        target_data_for_def(ctx.db(), def)?;
-
-    let missing_visibility =
-        if current_module.krate() == target_module.krate() { "pub(crate)" } else { "pub" };
-
-    let assist_label = match target_name {
-        None => format!("Change visibility to {}", missing_visibility),
-        Some(name) => format!("Change visibility of {} to {}", name, missing_visibility),
-    };
 
-    acc.add(AssistId("fix_visibility", AssistKind::QuickFix), assist_label, target, |builder| {
-        builder.edit_file(target_file);
-        match ctx.config.snippet_cap {
-            Some(cap) => match current_visibility {
-                Some(current_visibility) => builder.replace_snippet(
-                    cap,
-                    current_visibility.syntax().text_range(),
-                    format!("$0{}", missing_visibility),
-                ),
-                None => builder.insert_snippet(cap, offset, format!("$0{} ", missing_visibility)),
-            },
-            None => match current_visibility {
-                Some(current_visibility) => {
-                    builder.replace(current_visibility.syntax().text_range(), missing_visibility)
+    // The item can only be reached if all modules on the way to it are visible as well, so fix
+    // those alongside the item itself.
+    let mut defs = Vec::new();
+    let mut next = Some(path.clone());
+    while let Some(segment_path) = next {
+        next = segment_path.qualifier();
+        let def = match ctx.sema.resolve_path(&segment_path) {
+            Some(PathResolution::Def(def)) => def,
+            _ if segment_path == path => return None,
+            _ => continue,
+        };
+        if segment_path != path && !matches!(def, hir::ModuleDef::Module(_)) {
+            continue;
+        }
+        if !def.visibility(ctx.db()).is_visible_from(ctx.db(), current_module.into()) {
+            defs.push(def);
+        }
+    }
+    let target_module = defs.first()?.module(ctx.db())?;
+
+    let targets: Vec<_> =
+        defs.into_iter().rev().filter_map(|def| target_data_for_def(ctx.db(), def)).collect();
+    // The referenced item itself (or the innermost module) is where the cursor ends up.
+    let (_, _, target, _, _) = targets.last()?;
+    let target = *target;
+    let names: Vec<_> = targets
+        .iter()
+        .filter_map(|(_, _, _, _, name)| name.as_ref())
+        .map(|it| it.to_string())
+        .collect();
+
+    for missing_visibility in missing_visibilities(current_module, target_module) {
+        let assist_label = if names.is_empty() {
+            format!("Change visibility to {}", missing_visibility)
+        } else {
+            format!("Change visibility of {} to {}", names.join(", "), missing_visibility)
+        };
+
+        acc.add(
+            AssistId("fix_visibility", AssistKind::QuickFix),
+            assist_label,
+            target,
+            |builder| {
+                let last = targets.len() - 1;
+                for (idx, (offset, current_visibility, _, target_file, _)) in
+                    targets.iter().enumerate()
+                {
+                    builder.edit_file(*target_file);
+                    let snippet_cap = if idx == last { ctx.config.snippet_cap } else { None };
+                    change_visibility(
+                        builder,
+                        snippet_cap,
+                        *offset,
+                        current_visibility.as_ref(),
+                        missing_visibility,
+                    );
                 }
-                None => builder.insert(offset, format!("{} ", missing_visibility)),
             },
-        }
-    })
+        );
+    }
+    Some(())
 }
 
 fn add_vis_to_referenced_record_field(acc: &mut Assists, ctx: &AssistContext) -> Option<()> {
@@ -108,33 +130,69 @@ fn add_vis_to_referenced_record_field(acc: &mut Assists, ctx: &AssistContext) ->
         }
     };
 
-    let missing_visibility =
-        if current_module.krate() == target_module.krate() { "pub(crate)" } else { "pub" };
     let target_file = in_file_source.file_id.original_file(ctx.db());
-
     let target_name = record_field_def.name(ctx.db());
-    let assist_label =
-        format!("Change visibility of {}.{} to {}", parent_name, target_name, missing_visibility);
-
-    acc.add(AssistId("fix_visibility", AssistKind::QuickFix), assist_label, target, |builder| {
-        builder.edit_file(target_file);
-        match ctx.config.snippet_cap {
-            Some(cap) => match current_visibility {
-                Some(current_visibility) => builder.replace_snippet(
-                    cap,
-                    current_visibility.syntax().text_range(),
-                    format!("$0{}", missing_visibility),
-                ),
-                None => builder.insert_snippet(cap, offset, format!("$0{} ", missing_visibility)),
-            },
-            None => match current_visibility {
-                Some(current_visibility) => {
-                    builder.replace(current_visibility.syntax().text_range(), missing_visibility)
-                }
-                None => builder.insert(offset, format!("{} ", missing_visibility)),
+
+    for missing_visibility in missing_visibilities(current_module, target_module) {
+        let assist_label = format!(
+            "Change visibility of {}.{} to {}",
+            parent_name, target_name, missing_visibility
+        );
+
+        acc.add(
+            AssistId("fix_visibility", AssistKind::QuickFix),
+            assist_label,
+            target,
+            |builder| {
+                builder.edit_file(target_file);
+                change_visibility(
+                    builder,
+                    ctx.config.snippet_cap,
+                    offset,
+                    current_visibility.as_ref(),
+                    missing_visibility,
+                );
             },
-        }
-    })
+        );
+    }
+    Some(())
+}
+
+/// Within the same crate `pub(crate)` is enough, but plain `pub` is offered too, after it.
+fn missing_visibilities(
+    current_module: hir::Module,
+    target_module: hir::Module,
+) -> &'static [&'static str] {
+    if current_module.krate() == target_module.krate() {
+        &["pub(crate)", "pub"]
+    } else {
+        &["pub"]
+    }
+}
+
+fn change_visibility(
+    builder: &mut AssistBuilder,
+    snippet_cap: Option<SnippetCap>,
+    offset: TextSize,
+    current_visibility: Option<&ast::Visibility>,
+    missing_visibility: &str,
+) {
+    match snippet_cap {
+        Some(cap) => match current_visibility {
+            Some(current_visibility) => builder.replace_snippet(
+                cap,
+                current_visibility.syntax().text_range(),
+                format!("$0{}", missing_visibility),
+            ),
+            None => builder.insert_snippet(cap, offset, format!("$0{} ", missing_visibility)),
+        },
+        None => match current_visibility {
+            Some(current_visibility) => {
+                builder.replace(current_visibility.syntax().text_range(), missing_visibility)
+            }
+            None => builder.insert(offset, format!("{} ", missing_visibility)),
+        },
+    }
 }
 
 fn target_data_for_def(
@@ -208,18 +266,19 @@ fn target_data_for_def(
 
 #[cfg(test)]
 mod tests {
-    use crate::tests::{check_assist, check_assist_not_applicable};
+    use crate::tests::{check_assist, check_assist_by_label, check_assist_not_applicable};
 
     use super::*;
 
     #[test]
     fn fix_visibility_of_fn() {
-        check_assist(
+        check_assist_by_label(
             fix_visibility,
             r"mod foo { fn foo() {} }
               fn main() { foo::foo$0() } ",
             r"mod foo { $0pub(crate) fn foo() {} }
               fn main() { foo::foo() } ",
+            "Change visibility of foo to pub(crate)",
         );
         check_assist_not_applicable(
             fix_visibility,
@@ -230,36 +289,39 @@ mod tests {
 
     #[test]
     fn fix_visibility_of_adt_in_submodule() {
-        check_assist(
+        check_assist_by_label(
             fix_visibility,
             r"mod foo { struct Foo; }
               fn main() { foo::Foo$0 } ",
             r"mod foo { $0pub(crate) struct Foo; }
               fn main() { foo::Foo } ",
+            "Change visibility of Foo to pub(crate)",
         );
         check_assist_not_applicable(
             fix_visibility,
             r"mod foo { pub struct Foo; }
               fn main() { foo::Foo$0 } ",
         );
-        check_assist(
+        check_assist_by_label(
             fix_visibility,
             r"mod foo { enum Foo; }
               fn main() { foo::Foo$0 } ",
             r"mod foo { $0pub(crate) enum Foo; }
               fn main() { foo::Foo } ",
+            "Change visibility of Foo to pub(crate)",
         );
         check_assist_not_applicable(
             fix_visibility,
             r"mod foo { pub enum Foo; }
               fn main() { foo::Foo$0 } ",
         );
-        check_assist(
+        check_assist_by_label(
             fix_visibility,
             r"mod foo { union Foo; }
               fn main() { foo::Foo$0 } ",
             r"mod foo { $0pub(crate) union Foo; }
               fn main() { foo::Foo } ",
+            "Change visibility of Foo to pub(crate)",
         );
         check_assist_not_applicable(
             fix_visibility,
@@ -270,7 +332,7 @@ mod tests {
 
     #[test]
     fn fix_visibility_of_adt_in_other_file() {
-        check_assist(
+        check_assist_by_label(
             fix_visibility,
             r"
 //- /main.rs
@@ -282,19 +344,21 @@ struct Foo;
 ",
             r"$0pub(crate) struct Foo;
 ",
+            "Change visibility of Foo to pub(crate)",
         );
     }
 
     #[test]
     fn fix_visibility_of_struct_field() {
-        check_assist(
+        check_assist_by_label(
             fix_visibility,
             r"mod foo { pub struct Foo { bar: (), } }
               fn main() { foo::Foo { $0bar: () }; } ",
             r"mod foo { pub struct Foo { $0pub(crate) bar: (), } }
               fn main() { foo::Foo { bar: () }; } ",
+            "Change visibility of Foo.bar to pub(crate)",
         );
-        check_assist(
+        check_assist_by_label(
             fix_visibility,
             r"
 //- /lib.rs
@@ -305,6 +369,7 @@ pub struct Foo { bar: () }
 ",
             r"pub struct Foo { $0pub(crate) bar: () }
 ",
+            "Change visibility of Foo.bar to pub(crate)",
         );
         check_assist_not_applicable(
             fix_visibility,
@@ -361,14 +426,15 @@ pub struct Foo { pub bar: () }
 
     #[test]
     fn fix_visibility_of_union_field() {
-        check_assist(
+        check_assist_by_label(
             fix_visibility,
             r"mod foo { pub union Foo { bar: (), } }
               fn main() { foo::Foo { $0bar: () }; } ",
             r"mod foo { pub union Foo { $0pub(crate) bar: (), } }
               fn main() { foo::Foo { bar: () }; } ",
+            "Change visibility of Foo.bar to pub(crate)",
         );
-        check_assist(
+        check_assist_by_label(
             fix_visibility,
             r"
 //- /lib.rs
@@ -379,6 +445,7 @@ pub union Foo { bar: () }
 ",
             r"pub union Foo { $0pub(crate) bar: () }
 ",
+            "Change visibility of Foo.bar to pub(crate)",
         );
         check_assist_not_applicable(
             fix_visibility,
@@ -399,12 +466,13 @@ pub union Foo { pub bar: () }
 
     #[test]
     fn fix_visibility_of_const() {
-        check_assist(
+        check_assist_by_label(
             fix_visibility,
             r"mod foo { const FOO: () = (); }
               fn main() { foo::FOO$0 } ",
             r"mod foo { $0pub(crate) const FOO: () = (); }
               fn main() { foo::FOO } ",
+            "Change visibility of FOO to pub(crate)",
         );
         check_assist_not_applicable(
             fix_visibility,
@@ -415,12 +483,13 @@ pub union Foo { pub bar: () }
 
     #[test]
     fn fix_visibility_of_static() {
-        check_assist(
+        check_assist_by_label(
             fix_visibility,
             r"mod foo { static FOO: () = (); }
               fn main() { foo::FOO$0 } ",
             r"mod foo { $0pub(crate) static FOO: () = (); }
               fn main() { foo::FOO } ",
+            "Change visibility of FOO to pub(crate)",
         );
         check_assist_not_applicable(
             fix_visibility,
@@ -431,12 +500,13 @@ pub union Foo { pub bar: () }
 
     #[test]
     fn fix_visibility_of_trait() {
-        check_assist(
+        check_assist_by_label(
             fix_visibility,
             r"mod foo { trait Foo { fn foo(&self) {} } }
               fn main() { let x: &dyn foo::$0Foo; } ",
             r"mod foo { $0pub(crate) trait Foo { fn foo(&self) {} } }
               fn main() { let x: &dyn foo::Foo; } ",
+            "Change visibility of Foo to pub(crate)",
         );
         check_assist_not_applicable(
             fix_visibility,
@@ -447,12 +517,13 @@ pub union Foo { pub bar: () }
 
     #[test]
     fn fix_visibility_of_type_alias() {
-        check_assist(
+        check_assist_by_label(
             fix_visibility,
             r"mod foo { type Foo = (); }
               fn main() { let x: foo::Foo$0; } ",
             r"mod foo { $0pub(crate) type Foo = (); }
               fn main() { let x: foo::Foo; } ",
+            "Change visibility of Foo to pub(crate)",
         );
         check_assist_not_applicable(
             fix_visibility,
@@ -463,15 +534,16 @@ pub union Foo { pub bar: () }
 
     #[test]
     fn fix_visibility_of_module() {
-        check_assist(
+        check_assist_by_label(
             fix_visibility,
             r"mod foo { mod bar { fn bar() {} } }
               fn main() { foo::bar$0::bar(); } ",
             r"mod foo { $0pub(crate) mod bar { fn bar() {} } }
               fn main() { foo::bar::bar(); } ",
+            "Change visibility of bar to pub(crate)",
         );
 
-        check_assist(
+        check_assist_by_label(
             fix_visibility,
             r"
 //- /main.rs
@@ -487,6 +559,7 @@ mod bar {
     pub fn baz() {}
 }
 ",
+            "Change visibility of bar to pub(crate)",
         );
 
         check_assist_not_applicable(
@@ -496,9 +569,68 @@ mod bar {
         );
     }
 
+    #[test]
+    fn fix_visibility_of_modules_on_path() {
+        check_assist_by_label(
+            fix_visibility,
+            r"mod foo { mod bar { fn baz() {} } }
+              fn main() { foo::bar::baz$0(); } ",
+            r"mod foo { pub(crate) mod bar { $0pub(crate) fn baz() {} } }
+              fn main() { foo::bar::baz(); } ",
+            "Change visibility of bar, baz to pub(crate)",
+        );
+        check_assist_by_label(
+            fix_visibility,
+            r"mod foo { mod bar { mod baz { pub fn f() {} } } }
+              fn main() { foo::bar::baz::f$0(); } ",
+            r"mod foo { pub(crate) mod bar { $0pub(crate) mod baz { pub fn f() {} } } }
+              fn main() { foo::bar::baz::f(); } ",
+            "Change visibility of bar, baz to pub(crate)",
+        );
+        check_assist_by_label(
+            fix_visibility,
+            r"
+//- /main.rs
+mod foo;
+fn main() { foo::bar::Baz$0; }
+//- /foo.rs
+mod bar;
+//- /foo/bar.rs
+struct Baz;
+",
+            r"
+//- /foo.rs
+pub(crate) mod bar;
+//- /foo/bar.rs
+$0pub(crate) struct Baz;
+",
+            "Change visibility of bar, Baz to pub(crate)",
+        );
+    }
+
+    #[test]
+    fn fix_visibility_to_pub_in_same_crate() {
+        check_assist_by_label(
+            fix_visibility,
+            r"mod foo { mod bar { fn baz() {} } }
+              fn main() { foo::bar::baz$0(); } ",
+            r"mod foo { pub mod bar { $0pub fn baz() {} } }
+              fn main() { foo::bar::baz(); } ",
+            "Change visibility of bar, baz to pub",
+        );
+        check_assist_by_label(
+            fix_visibility,
+            r"mod foo { pub struct Foo { bar: () } }
+              fn main() { foo::Foo { $0bar: () }; } ",
+            r"mod foo { pub struct Foo { $0pub bar: () } }
+              fn main() { foo::Foo { bar: () }; } ",
+            "Change visibility of Foo.bar to pub",
+        );
+    }
+
     #[test]
     fn fix_visibility_of_inline_module_in_other_file() {
-        check_assist(
+        check_assist_by_label(
             fix_visibility,
             r"
 //- /main.rs
@@ -512,12 +644,13 @@ pub fn baz() {}
 ",
             r"$0pub(crate) mod bar;
 ",
+            "Change visibility of bar to pub(crate)",
         );
     }
 
     #[test]
     fn fix_visibility_of_module_declaration_in_other_file() {
-        check_assist(
+        check_assist_by_label(
             fix_visibility,
             r"
 //- /main.rs
@@ -533,6 +666,7 @@ mod bar {
     pub fn baz() {}
 }
 ",
+            "Change visibility of bar to pub(crate)",
         );
     }
 
@@ -583,7 +717,7 @@ pub struct Foo { pub(crate) bar: () }
     fn fix_visibility_of_reexport() {
         // FIXME: broken test, this should fix visibility of the re-export
         // rather than the struct.
-        check_assist(
+        check_assist_by_label(
             fix_visibility,
             r#"
 mod foo {
@@ -599,6 +733,7 @@ mod foo {
 }
 foo::Baz
 "#,
+            "Change visibility of Baz to pub(crate)",
         )
     }
 }
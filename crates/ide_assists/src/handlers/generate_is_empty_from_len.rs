@@ -50,7 +50,7 @@ pub(crate) fn generate_is_empty_from_len(acc: &mut Assists, ctx: &AssistContext)
         return None;
     }
 
-    let impl_ = fn_node.syntax().ancestors().find_map(ast::Impl::cast)?;
+    let impl_ = ctx.sema.enclosing_impl(fn_node.syntax())?;
     let len_fn = get_impl_method(ctx, &impl_, &known::len)?;
     if !len_fn.ret_type(ctx.sema.db).is_usize() {
         cov_mark::hit!(len_fn_different_return_type);
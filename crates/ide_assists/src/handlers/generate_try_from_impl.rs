@@ -0,0 +1,358 @@
+use hir::ModuleDef;
+use ide_db::helpers::{mod_path_to_ast, FamousDefs};
+use itertools::Itertools;
+use syntax::ast::{self, AstNode, HasAttrs, HasName};
+
+use crate::{utils::generate_trait_impl_text, AssistContext, AssistId, AssistKind, Assists};
+
+const INT_TYPES: &[&str] =
+    &["u8", "u16", "u32", "u64", "u128", "usize", "i8", "i16", "i32", "i64", "i128", "isize"];
+
+// Assist: generate_try_from_impl
+//
+// Generates a `TryFrom` impl converting integers into a fieldless enum with explicit discriminants.
+//
+// ```
+// //- minicore: try_from
+// #[repr(u8)]
+// enum $0Color {
+//     Red = 1,
+//     Green,
+//     Blue = 4,
+// }
+// ```
+// ->
+// ```
+// #[repr(u8)]
+// enum Color {
+//     Red = 1,
+//     Green,
+//     Blue = 4,
+// }
+//
+// impl core::convert::TryFrom<u8> for Color {
+//     type Error = u8;
+//
+//     fn try_from(value: u8) -> Result<Self, Self::Error> {
+//         match value {
+//             1 => Ok(Self::Red),
+//             2 => Ok(Self::Green),
+//             4 => Ok(Self::Blue),
+//             _ => Err(value),
+//         }
+//     }
+// }
+// ```
+pub(crate) fn generate_try_from_impl(acc: &mut Assists, ctx: &AssistContext) -> Option<()> {
+    let enum_ = ctx.find_node_at_offset::<ast::Enum>()?;
+    let name = enum_.name()?;
+    let variants: Vec<_> = enum_.variant_list()?.variants().collect();
+    if variants.is_empty() || variants.iter().any(|it| it.field_list().is_some()) {
+        return None;
+    }
+
+    let repr = repr_int_type(&enum_);
+    if repr.is_none() && variants.iter().all(|it| it.expr().is_none()) {
+        return None;
+    }
+    // Without a `repr`, discriminants are `isize`, but `i32` is what integers default to.
+    let int_type = repr.unwrap_or_else(|| "i32".to_string());
+
+    let enum_def = ctx.sema.to_def(&enum_)?;
+    let module = enum_def.module(ctx.db());
+    let try_from = FamousDefs(&ctx.sema, Some(module.krate())).core_convert_TryFrom()?;
+    let has_impl = hir::Impl::all_for_type(ctx.db(), enum_def.ty(ctx.db()))
+        .into_iter()
+        .any(|it| it.trait_(ctx.db()) == Some(try_from));
+    if has_impl {
+        cov_mark::hit!(try_from_impl_already_exists);
+        return None;
+    }
+    let try_from_path =
+        mod_path_to_ast(&module.find_use_path(ctx.db(), ModuleDef::Trait(try_from))?);
+
+    let target = name.syntax().text_range();
+    acc.add(
+        AssistId("generate_try_from_impl", AssistKind::Generate),
+        "Generate `TryFrom` impl for this enum",
+        target,
+        |builder| {
+            let arms = discriminants(&variants)
+                .into_iter()
+                .map(|(variant, discriminant)| match discriminant {
+                    Some(value) => format!("{} => Ok(Self::{}),", value, variant),
+                    // We can't evaluate the discriminant, compare against it at runtime instead.
+                    None => format!(
+                        "value if value == Self::{0} as {1} => Ok(Self::{0}),",
+                        variant, int_type
+                    ),
+                })
+                .chain(Some("_ => Err(value),".to_string()))
+                .format("\n            ");
+
+            let code = format!(
+                r#"    type Error = {0};
+
+    fn try_from(value: {0}) -> Result<Self, Self::Error> {{
+        match value {{
+            {1}
+        }}
+    }}"#,
+                int_type, arms
+            );
+
+            let trait_text = format!("{}<{}>", try_from_path, int_type);
+            let adt = ast::Adt::Enum(enum_.clone());
+            let impl_text = generate_trait_impl_text(&adt, &trait_text, &code);
+            builder.insert(enum_.syntax().text_range().end(), impl_text);
+        },
+    )
+}
+
+fn repr_int_type(enum_: &ast::Enum) -> Option<String> {
+    enum_
+        .attrs()
+        .filter_map(|attr| attr.as_simple_call())
+        .filter(|(name, _)| name == "repr")
+        .find_map(|(_, tt)| {
+            tt.syntax()
+                .children_with_tokens()
+                .filter_map(|it| it.into_token())
+                .find(|it| INT_TYPES.contains(&it.text()))
+                .map(|it| it.text().to_string())
+        })
+}
+
+/// Computes the discriminant of every variant, following the implicit "previous plus one" rule.
+/// Discriminants which aren't (negated) integer literals are `None`, as are the implicit ones
+/// following them.
+fn discriminants(variants: &[ast::Variant]) -> Vec<(ast::Name, Option<i128>)> {
+    let mut next = Some(0);
+    variants
+        .iter()
+        .filter_map(|variant| {
+            let discriminant = match variant.expr() {
+                Some(expr) => eval_int_literal(&expr),
+                None => next,
+            };
+            next = discriminant.and_then(|it| it.checked_add(1));
+            Some((variant.name()?, discriminant))
+        })
+        .collect()
+}
+
+fn eval_int_literal(expr: &ast::Expr) -> Option<i128> {
+    match expr {
+        ast::Expr::Literal(lit) => match lit.kind() {
+            ast::LiteralKind::IntNumber(num) => num.value()?.try_into().ok(),
+            _ => None,
+        },
+        ast::Expr::PrefixExpr(prefix) if prefix.op_kind() == Some(ast::UnaryOp::Neg) => {
+            eval_int_literal(&prefix.expr()?)?.checked_neg()
+        }
+        ast::Expr::ParenExpr(paren) => eval_int_literal(&paren.expr()?),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::{check_assist, check_assist_not_applicable};
+
+    use super::*;
+
+    #[test]
+    fn generates_impl_for_explicit_discriminants() {
+        check_assist(
+            generate_try_from_impl,
+            r#"
+//- minicore: try_from
+enum Color$0 {
+    Red = 0,
+    Green = 1,
+    Blue = 2,
+}
+"#,
+            r#"
+enum Color {
+    Red = 0,
+    Green = 1,
+    Blue = 2,
+}
+
+impl core::convert::TryFrom<i32> for Color {
+    type Error = i32;
+
+    fn try_from(value: i32) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::Red),
+            1 => Ok(Self::Green),
+            2 => Ok(Self::Blue),
+            _ => Err(value),
+        }
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn generates_impl_for_repr_u8() {
+        check_assist(
+            generate_try_from_impl,
+            r#"
+//- minicore: try_from
+#[derive(Clone, Copy)]
+#[repr(u8)]
+pub enum $0Opcode {
+    Nop,
+    Load = 0x10,
+    Store,
+}
+"#,
+            r#"
+#[derive(Clone, Copy)]
+#[repr(u8)]
+pub enum Opcode {
+    Nop,
+    Load = 0x10,
+    Store,
+}
+
+impl core::convert::TryFrom<u8> for Opcode {
+    type Error = u8;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::Nop),
+            16 => Ok(Self::Load),
+            17 => Ok(Self::Store),
+            _ => Err(value),
+        }
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn generates_impl_for_negative_and_computed_discriminants() {
+        check_assist(
+            generate_try_from_impl,
+            r#"
+//- minicore: try_from
+const BASE: i64 = 8;
+#[repr(i64)]
+enum E$0 {
+    A = -2,
+    B,
+    C = BASE,
+    D,
+}
+"#,
+            r#"
+const BASE: i64 = 8;
+#[repr(i64)]
+enum E {
+    A = -2,
+    B,
+    C = BASE,
+    D,
+}
+
+impl core::convert::TryFrom<i64> for E {
+    type Error = i64;
+
+    fn try_from(value: i64) -> Result<Self, Self::Error> {
+        match value {
+            -2 => Ok(Self::A),
+            -1 => Ok(Self::B),
+            value if value == Self::C as i64 => Ok(Self::C),
+            value if value == Self::D as i64 => Ok(Self::D),
+            _ => Err(value),
+        }
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn uses_prelude_try_from_in_2021() {
+        check_assist(
+            generate_try_from_impl,
+            r#"
+//- /main.rs edition:2021 crate:main deps:core
+enum Color$0 {
+    Red = 1,
+}
+//- /core.rs crate:core
+pub mod convert {
+    pub trait TryFrom<T>: Sized {
+        type Error;
+    }
+}
+pub mod prelude {
+    pub mod rust_2021 {
+        pub use crate::convert::TryFrom;
+    }
+}
+"#,
+            r#"
+enum Color {
+    Red = 1,
+}
+
+impl TryFrom<i32> for Color {
+    type Error = i32;
+
+    fn try_from(value: i32) -> Result<Self, Self::Error> {
+        match value {
+            1 => Ok(Self::Red),
+            _ => Err(value),
+        }
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_without_discriminants() {
+        check_assist_not_applicable(
+            generate_try_from_impl,
+            r#"
+//- minicore: try_from
+enum Color$0 { Red, Green }
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_with_fields() {
+        check_assist_not_applicable(
+            generate_try_from_impl,
+            r#"
+//- minicore: try_from
+#[repr(u8)]
+enum E$0 { A = 1, B(u8) }
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_when_impl_exists() {
+        cov_mark::check!(try_from_impl_already_exists);
+        check_assist_not_applicable(
+            generate_try_from_impl,
+            r#"
+//- minicore: try_from
+enum Color$0 { Red = 1 }
+impl core::convert::TryFrom<i32> for Color {
+    type Error = ();
+    fn try_from(value: i32) -> Result<Self, ()> { Err(()) }
+}
+"#,
+        );
+    }
+}
@@ -0,0 +1,239 @@
+use hir::ModuleSource;
+use syntax::{
+    ast::{self, edit::AstNodeEdit, edit::IndentLevel, HasAttrs},
+    AstNode,
+};
+
+use crate::{AssistContext, AssistId, AssistKind, Assists};
+
+// Assist: inline_module
+//
+// Moves the contents of a module's file into an inline module and deletes the file.
+//
+// ```
+// //- /main.rs
+// mod $0foo;
+// //- /foo.rs
+// fn t() {}
+// ```
+// ->
+// ```
+// mod foo {
+//     fn t() {}
+// }
+// ```
+pub(crate) fn inline_module(acc: &mut Assists, ctx: &AssistContext) -> Option<()> {
+    let module_ast = ctx.find_node_at_offset::<ast::Module>()?;
+    if module_ast.item_list().is_some() {
+        return None;
+    }
+    let semicolon = module_ast.semicolon_token()?;
+
+    let module = ctx.sema.to_def(&module_ast)?;
+    let src = module.definition_source(ctx.db());
+    let module_file_id = src.file_id.original_file(ctx.db());
+    let source_file = match src.value {
+        ModuleSource::SourceFile(it) => it,
+        _ => return None,
+    };
+
+    // Out-of-line submodules are looked up relative to the directory of the module, which doesn't
+    // change when inlining it. Explicit `#[path]`s however are relative to the file they are
+    // written in, so inlining would change what they point to.
+    let has_path_attr = |it: &ast::Module| {
+        it.attrs().filter_map(|attr| attr.simple_name()).any(|name| name == "path")
+    };
+    let mut child_decls = source_file
+        .syntax()
+        .descendants()
+        .filter_map(ast::Module::cast)
+        .filter(|it| it.item_list().is_none());
+    if has_path_attr(&module_ast) {
+        if child_decls.next().is_some() {
+            cov_mark::hit!(inline_module_path_attr);
+            return None;
+        }
+    } else if child_decls.any(|it| has_path_attr(&it)) {
+        cov_mark::hit!(inline_module_path_attr);
+        return None;
+    }
+
+    let target = module_ast.syntax().text_range();
+    acc.add(
+        AssistId("inline_module", AssistKind::RefactorInline),
+        "Inline module",
+        target,
+        |builder| {
+            let indent = IndentLevel::from_node(module_ast.syntax());
+            let items = source_file.indent(indent + 1).to_string();
+            let items = items.trim();
+            let body = if items.is_empty() {
+                " {}".to_string()
+            } else {
+                format!(" {{\n{}{}\n{}}}", indent + 1, items, indent)
+            };
+            builder.replace(semicolon.text_range(), body);
+            builder.delete_file(module_file_id);
+        },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::{check_assist, check_assist_not_applicable};
+
+    use super::*;
+
+    #[test]
+    fn inline_simple_module() {
+        check_assist(
+            inline_module,
+            r#"
+//- /main.rs
+mod $0foo;
+fn main() {}
+//- /foo.rs
+fn f() {}
+
+fn g() {
+    f();
+}
+"#,
+            r#"
+//- /main.rs
+mod foo {
+    fn f() {}
+
+    fn g() {
+        f();
+    }
+}
+fn main() {}
+//- /foo.rs (deleted)
+"#,
+        );
+    }
+
+    #[test]
+    fn inline_empty_module() {
+        check_assist(
+            inline_module,
+            r#"
+//- /main.rs
+mod foo$0;
+//- /foo.rs
+"#,
+            r#"
+//- /main.rs
+mod foo {}
+//- /foo.rs (deleted)
+"#,
+        );
+    }
+
+    #[test]
+    fn inline_module_with_reexport() {
+        check_assist(
+            inline_module,
+            r#"
+//- /main.rs
+pub mod $0foo;
+//- /foo.rs
+pub use self::inner::S;
+
+mod inner {
+    pub struct S;
+}
+"#,
+            r#"
+//- /main.rs
+pub mod foo {
+    pub use self::inner::S;
+
+    mod inner {
+        pub struct S;
+    }
+}
+//- /foo.rs (deleted)
+"#,
+        );
+    }
+
+    #[test]
+    fn inline_cfg_module_into_submodule() {
+        check_assist(
+            inline_module,
+            r#"
+//- /main.rs cfg:test
+mod sub;
+//- /sub.rs
+fn f() {}
+
+#[cfg(test)]
+mod $0tests;
+//- /sub/tests.rs
+#![allow(unused)]
+use super::f;
+
+#[test]
+fn t() { f() }
+"#,
+            r#"
+//- /sub.rs
+fn f() {}
+
+#[cfg(test)]
+mod tests {
+    #![allow(unused)]
+    use super::f;
+
+    #[test]
+    fn t() { f() }
+}
+//- /sub/tests.rs (deleted)
+"#,
+        );
+    }
+
+    #[test]
+    fn inline_module_with_file_submodules() {
+        check_assist(
+            inline_module,
+            r#"
+//- /main.rs
+mod $0foo;
+//- /foo.rs
+mod bar;
+//- /foo/bar.rs
+"#,
+            r#"
+//- /main.rs
+mod foo {
+    mod bar;
+}
+//- /foo.rs (deleted)
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_to_inline_module() {
+        check_assist_not_applicable(inline_module, r#"mod $0foo { fn f() {} }"#);
+    }
+
+    #[test]
+    fn not_applicable_with_path_attrs() {
+        cov_mark::check!(inline_module_path_attr);
+        check_assist_not_applicable(
+            inline_module,
+            r#"
+//- /main.rs
+mod $0foo;
+//- /foo.rs
+#[path = "baz.rs"]
+mod bar;
+//- /foo/baz.rs
+"#,
+        );
+    }
+}
@@ -19,7 +19,7 @@ use crate::{utils::suggest_name, AssistContext, AssistId, AssistKind, Assists};
 pub(crate) fn introduce_named_generic(acc: &mut Assists, ctx: &AssistContext) -> Option<()> {
     let impl_trait_type = ctx.find_node_at_offset::<ast::ImplTraitType>()?;
     let param = impl_trait_type.syntax().parent().and_then(ast::Param::cast)?;
-    let fn_ = param.syntax().ancestors().find_map(ast::Fn::cast)?;
+    let fn_ = ctx.sema.enclosing_fn(param.syntax())?;
 
     let type_bound_list = impl_trait_type.type_bound_list()?;
 
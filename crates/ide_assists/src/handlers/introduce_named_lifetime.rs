@@ -40,9 +40,9 @@ pub(crate) fn introduce_named_lifetime(acc: &mut Assists, ctx: &AssistContext) -
         ctx.find_node_at_offset::<ast::Lifetime>().filter(|lifetime| lifetime.text() == "'_")?;
     let lifetime_loc = lifetime.lifetime_ident_token()?.text_range();
 
-    if let Some(fn_def) = lifetime.syntax().ancestors().find_map(ast::Fn::cast) {
+    if let Some(fn_def) = ctx.sema.enclosing_fn(lifetime.syntax()) {
         generate_fn_def_assist(acc, fn_def, lifetime_loc, lifetime)
-    } else if let Some(impl_def) = lifetime.syntax().ancestors().find_map(ast::Impl::cast) {
+    } else if let Some(impl_def) = ctx.sema.enclosing_impl(lifetime.syntax()) {
         generate_impl_def_assist(acc, impl_def, lifetime_loc, lifetime)
     } else {
         None
@@ -1,7 +1,7 @@
 use std::iter;
 
 use ast::edit::IndentLevel;
-use ide_db::base_db::AnchoredPathBuf;
+use ide_db::base_db::{AnchoredPath, AnchoredPathBuf, FileLoader};
 use itertools::Itertools;
 use stdx::format_to;
 use syntax::{
@@ -43,26 +43,34 @@ pub(crate) fn move_module_to_file(acc: &mut Assists, ctx: &AssistContext) -> Opt
     let module_def = ctx.sema.to_def(&outermost_mod_decl)?;
     let parent_module = module_def.parent(ctx.db())?;
 
+    let dir = {
+        let mut buf = String::from("./");
+        match parent_module.name(ctx.db()) {
+            Some(name) if !parent_module.is_mod_rs(ctx.db()) => format_to!(buf, "{}/", name),
+            _ => (),
+        }
+        let segments = iter::successors(Some(module_ast.clone()), |module| module.parent())
+            .filter_map(|it| it.name())
+            .collect::<Vec<_>>();
+        format_to!(buf, "{}", segments.into_iter().rev().format("/"));
+        buf
+    };
+    let path = format!("{}.rs", dir);
+
+    // Don't clobber an existing file, and don't create a module that would be ambiguous with an
+    // existing `mod.rs`.
+    let file_exists =
+        |path: &str| ctx.db().resolve_path(AnchoredPath { anchor: ctx.file_id(), path }).is_some();
+    if file_exists(&path) || file_exists(&format!("{}/mod.rs", dir)) {
+        cov_mark::hit!(module_file_already_exists);
+        return None;
+    }
+
     acc.add(
         AssistId("move_module_to_file", AssistKind::RefactorExtract),
         "Extract module to file",
         target,
         |builder| {
-            let path = {
-                let mut buf = String::from("./");
-                match parent_module.name(ctx.db()) {
-                    Some(name) if !parent_module.is_mod_rs(ctx.db()) => {
-                        format_to!(buf, "{}/", name)
-                    }
-                    _ => (),
-                }
-                let segments = iter::successors(Some(module_ast.clone()), |module| module.parent())
-                    .filter_map(|it| it.name())
-                    .collect::<Vec<_>>();
-                format_to!(buf, "{}", segments.into_iter().rev().format("/"));
-                format_to!(buf, ".rs");
-                buf
-            };
             let contents = {
                 let items = module_items.dedent(IndentLevel(1)).to_string();
                 let mut items =
@@ -226,6 +234,36 @@ mod tests;
         );
     }
 
+    #[test]
+    fn not_applicable_if_file_exists() {
+        cov_mark::check!(module_file_already_exists);
+        check_assist_not_applicable(
+            move_module_to_file,
+            r#"
+//- /main.rs
+mod $0tests {
+    #[test] fn t() {}
+}
+//- /tests.rs
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_if_mod_rs_exists() {
+        cov_mark::check!(module_file_already_exists);
+        check_assist_not_applicable(
+            move_module_to_file,
+            r#"
+//- /main.rs
+mod submod;
+//- /submod.rs
+mod $0inner {}
+//- /submod/inner/mod.rs
+"#,
+        );
+    }
+
     #[test]
     fn extract_nested() {
         check_assist(
@@ -1140,6 +1140,49 @@ fn main() {}
         );
     }
 
+    #[test]
+    fn keep_generic_args_of_std_type() {
+        check_assist(
+            qualify_path,
+            r#"
+//- /lib.rs crate:std
+pub mod collections { pub struct HashMap<K, V>(K, V); }
+
+//- /main.rs crate:main deps:std
+fn count<K, V>(map: Hash$0Map<K, V>) {}
+"#,
+            r#"
+fn count<K, V>(map: std::collections::HashMap<K, V>) {}
+"#,
+        );
+    }
+
+    #[test]
+    fn keep_turbofish_in_expression() {
+        check_assist(
+            qualify_path,
+            r#"
+//- /lib.rs crate:std
+pub mod collections {
+    pub struct HashMap<K, V>(K, V);
+    impl<K, V> HashMap<K, V> {
+        pub fn new() -> Self { loop {} }
+    }
+}
+
+//- /main.rs crate:main deps:std
+fn main() {
+    let map = HashMap::<u8, u8>$0::new();
+}
+"#,
+            r#"
+fn main() {
+    let map = std::collections::HashMap::<u8, u8>::new();
+}
+"#,
+        );
+    }
+
     #[test]
     fn associated_struct_const_generic() {
         check_assist(
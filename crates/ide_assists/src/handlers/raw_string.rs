@@ -16,7 +16,7 @@ use crate::{AssistContext, AssistId, AssistKind, Assists};
 // ->
 // ```
 // fn main() {
-//     r#"Hello, World!"#;
+//     r"Hello, World!";
 // }
 // ```
 pub(crate) fn make_raw_string(acc: &mut Assists, ctx: &AssistContext) -> Option<()> {
@@ -25,13 +25,18 @@ pub(crate) fn make_raw_string(acc: &mut Assists, ctx: &AssistContext) -> Option<
         return None;
     }
     let value = token.value()?;
+    // Raw strings can't contain escapes, and NUL or a bare carriage return can't be written out
+    // literally.
+    if value.contains(|c| c == '\0' || c == '\r') {
+        return None;
+    }
     let target = token.syntax().text_range();
     acc.add(
         AssistId("make_raw_string", AssistKind::RefactorRewrite),
         "Rewrite as raw string",
         target,
         |edit| {
-            let hashes = "#".repeat(required_hashes(&value).max(1));
+            let hashes = "#".repeat(required_hashes(&value));
             if matches!(value, Cow::Borrowed(_)) {
                 // Avoid replacing the whole string to better position the cursor.
                 edit.insert(token.syntax().text_range().start(), format!("r{}", hashes));
@@ -73,8 +78,8 @@ pub(crate) fn make_usual_string(acc: &mut Assists, ctx: &AssistContext) -> Optio
         "Rewrite as regular string",
         target,
         |edit| {
-            // parse inside string to escape `"`
-            let escaped = value.escape_default().to_string();
+            // Only `\` and `"` need escaping, keep line breaks and other characters as is.
+            let escaped = value.replace('\\', "\\\\").replace('"', "\\\"");
             if let Some(offsets) = token.quote_offsets() {
                 if token.text()[offsets.contents - token.syntax().text_range().start()] == escaped {
                     edit.replace(offsets.quotes.0, "\"");
@@ -207,12 +212,12 @@ fn f() {
     let s = $0"random\nstring";
 }
 "#,
-            r##"
+            r#"
 fn f() {
-    let s = r#"random
-string"#;
+    let s = r"random
+string";
 }
-"##,
+"#,
         )
     }
 
@@ -225,11 +230,11 @@ string"#;
                 format!($0"x = {}", 92)
             }
             "#,
-            r##"
+            r#"
             fn f() {
-                format!(r#"x = {}"#, 92)
+                format!(r"x = {}", 92)
             }
-            "##,
+            "#,
         )
     }
 
@@ -244,8 +249,8 @@ fn f() {
 "###,
             r####"
 fn f() {
-    let s = r#"#random##
-string"#;
+    let s = r"#random##
+string";
 }
 "####,
         )
@@ -278,11 +283,52 @@ string"###;
                 let s = $0"random string";
             }
             "#,
-            r##"
+            r#"
             fn f() {
-                let s = r#"random string"#;
+                let s = r"random string";
             }
-            "##,
+            "#,
+        )
+    }
+
+    #[test]
+    fn make_raw_string_with_quote_works() {
+        check_assist(
+            make_raw_string,
+            r#"
+fn f() {
+    let s = $0"say \"hi\"";
+}
+"#,
+            r##"
+fn f() {
+    let s = r#"say "hi""#;
+}
+"##,
+        )
+    }
+
+    #[test]
+    fn make_raw_string_not_works_with_nul() {
+        check_assist_not_applicable(
+            make_raw_string,
+            r#"
+fn f() {
+    let s = $0"nul\0byte";
+}
+"#,
+        )
+    }
+
+    #[test]
+    fn make_raw_string_not_works_with_carriage_return() {
+        check_assist_not_applicable(
+            make_raw_string,
+            r#"
+fn f() {
+    let s = $0"line\r\n";
+}
+"#,
         )
     }
 
@@ -495,6 +541,59 @@ string"###;
         )
     }
 
+    #[test]
+    fn make_usual_string_escapes_backslashes() {
+        check_assist(
+            make_usual_string,
+            r###"
+fn f() {
+    let s = $0r"C:\dir\n";
+}
+"###,
+            r#"
+fn f() {
+    let s = "C:\\dir\\n";
+}
+"#,
+        )
+    }
+
+    #[test]
+    fn make_usual_string_multiline_works() {
+        check_assist(
+            make_usual_string,
+            r###"
+fn f() {
+    let s = $0r#"first "line"
+second line"#;
+}
+"###,
+            r#"
+fn f() {
+    let s = "first \"line\"
+second line";
+}
+"#,
+        )
+    }
+
+    #[test]
+    fn make_usual_string_keeps_unicode() {
+        check_assist(
+            make_usual_string,
+            r###"
+fn f() {
+    let s = $0r#""Grüße""#;
+}
+"###,
+            r#"
+fn f() {
+    let s = "\"Grüße\"";
+}
+"#,
+        )
+    }
+
     #[test]
     fn make_usual_string_not_works() {
         check_assist_not_applicable(
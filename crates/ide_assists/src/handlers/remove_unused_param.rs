@@ -36,7 +36,7 @@ pub(crate) fn remove_unused_param(acc: &mut Assists, ctx: &AssistContext) -> Opt
         ast::Pat::IdentPat(it) => it,
         _ => return None,
     };
-    let func = param.syntax().ancestors().find_map(ast::Fn::cast)?;
+    let func = ctx.sema.enclosing_fn(param.syntax())?;
     let is_self_present =
         param.syntax().parent()?.children().find_map(ast::SelfParam::cast).is_some();
 
@@ -188,6 +188,25 @@ fn b() { foo(9, ) }
         );
     }
 
+    #[test]
+    fn remove_unused_of_nested_fn() {
+        check_assist(
+            remove_unused_param,
+            r#"
+fn outer(a: i32) {
+    fn inner(x: i32, $0y: i32) { x; }
+    inner(a, 2);
+}
+"#,
+            r#"
+fn outer(a: i32) {
+    fn inner(x: i32) { x; }
+    inner(a);
+}
+"#,
+        );
+    }
+
     #[test]
     fn remove_unused_first_param() {
         check_assist(
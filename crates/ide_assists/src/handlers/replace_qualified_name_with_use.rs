@@ -42,10 +42,24 @@ pub(crate) fn replace_qualified_name_with_use(
     }
 
     // only offer replacement for non assoc items
-    match ctx.sema.resolve_path(&path)? {
-        hir::PathResolution::Def(def) if def.as_assoc_item(ctx.sema.db).is_none() => (),
-        hir::PathResolution::Macro(_) => (),
+    let def = match ctx.sema.resolve_path(&path)? {
+        hir::PathResolution::Def(def) if def.as_assoc_item(ctx.sema.db).is_none() => {
+            hir::ScopeDef::ModuleDef(def)
+        }
+        hir::PathResolution::Macro(mac) => hir::ScopeDef::MacroDef(mac),
         _ => return None,
+    };
+    // don't shadow (or get shadowed by) a different item of the same name
+    let name = path.segment()?.name_ref()?;
+    let mut has_conflict = false;
+    ctx.sema.scope(path.syntax()).process_all_names(&mut |it, scope_def| {
+        if it.to_string() == name.text() && scope_def != def {
+            has_conflict = true;
+        }
+    });
+    if has_conflict {
+        cov_mark::hit!(dont_import_conflicting_names);
+        return None;
     }
     // then search for an import for the first path segment of what we want to replace
     // that way it is less likely that we import the item from a different location due re-exports
@@ -356,6 +370,97 @@ mod bar {
 fn main() {
     Foo;
 }
+",
+        );
+    }
+
+    #[test]
+    fn dont_import_conflicting_names() {
+        cov_mark::check!(dont_import_conflicting_names);
+        check_assist_not_applicable(
+            replace_qualified_name_with_use,
+            r"
+mod std { pub mod collections { pub struct HashMap; } }
+struct HashMap;
+
+fn f(map: std::collections::HashMap$0) {}
+",
+        );
+    }
+
+    #[test]
+    fn replace_in_turbofish() {
+        check_assist(
+            replace_qualified_name_with_use,
+            r"
+mod std { pub mod collections { pub struct HashMap<K, V>(K, V); } }
+fn make<T>() -> T { loop {} }
+
+fn main() {
+    make::<std::collections::HashMap$0<u8, u8>>();
+}
+",
+            r"
+use std::collections::HashMap;
+
+mod std { pub mod collections { pub struct HashMap<K, V>(K, V); } }
+fn make<T>() -> T { loop {} }
+
+fn main() {
+    make::<HashMap<u8, u8>>();
+}
+",
+        );
+    }
+
+    #[test]
+    fn replace_in_pattern() {
+        check_assist(
+            replace_qualified_name_with_use,
+            r"
+mod std { pub mod cmp { pub enum Ordering { Less, Equal, Greater } } }
+
+fn f(ord: std::cmp::Ordering) -> bool {
+    match ord {
+        std::cmp::Ordering$0::Less => true,
+        _ => false,
+    }
+}
+",
+            r"
+use std::cmp::Ordering;
+
+mod std { pub mod cmp { pub enum Ordering { Less, Equal, Greater } } }
+
+fn f(ord: Ordering) -> bool {
+    match ord {
+        Ordering::Less => true,
+        _ => false,
+    }
+}
+",
+        );
+    }
+
+    #[test]
+    fn replace_inserts_use_in_innermost_module() {
+        check_assist(
+            replace_qualified_name_with_use,
+            r"
+mod std { pub mod collections { pub struct HashMap<K, V>(K, V); } }
+
+mod m {
+    fn f(map: crate::std::collections::HashMap$0<u8, u8>) {}
+}
+",
+            r"
+mod std { pub mod collections { pub struct HashMap<K, V>(K, V); } }
+
+mod m {
+    use crate::std::collections::HashMap;
+
+    fn f(map: HashMap<u8, u8>) {}
+}
 ",
         );
     }
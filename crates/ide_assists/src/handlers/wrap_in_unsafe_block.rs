@@ -0,0 +1,297 @@
+use syntax::{ast, AstNode};
+
+use crate::{AssistContext, AssistId, AssistKind, Assists};
+
+// Assist: wrap_in_unsafe_block
+//
+// Wraps an unsafe operation in an `unsafe` block.
+//
+// ```
+// unsafe fn launch() {}
+//
+// fn main() {
+//     launch$0();
+// }
+// ```
+// ->
+// ```
+// unsafe fn launch() {}
+//
+// fn main() {
+//     unsafe { launch() };
+// }
+// ```
+pub(crate) fn wrap_in_unsafe_block(acc: &mut Assists, ctx: &AssistContext) -> Option<()> {
+    let expr = ctx
+        .find_node_at_offset::<ast::Expr>()?
+        .syntax()
+        .ancestors()
+        .take_while(|it| !ast::Item::can_cast(it.kind()))
+        .filter_map(ast::Expr::cast)
+        .find(|expr| ctx.sema.is_unsafe_expr(expr))?;
+
+    for ancestor in expr.syntax().ancestors() {
+        if let Some(block) = ast::BlockExpr::cast(ancestor.clone()) {
+            if block.unsafe_token().is_some() {
+                return None;
+            }
+        }
+        if let Some(func) = ast::Fn::cast(ancestor) {
+            if func.unsafe_token().is_some() {
+                return None;
+            }
+            break;
+        }
+    }
+
+    let expr = enclosing_value_expr(expr);
+    let target = expr.syntax().text_range();
+    acc.add(
+        AssistId("wrap_in_unsafe_block", AssistKind::RefactorRewrite),
+        "Wrap in `unsafe` block",
+        target,
+        |builder| builder.replace(target, format!("unsafe {{ {} }}", expr)),
+    )
+}
+
+/// Wrapping a place expression, like `*ptr` in `&mut *ptr` or `STATIC` in `STATIC = 1`, would turn
+/// it into a value and change what's borrowed or assigned to, so the expression using it as a
+/// place is wrapped instead.
+fn enclosing_value_expr(mut expr: ast::Expr) -> ast::Expr {
+    while let Some(parent) = expr.syntax().parent().and_then(ast::Expr::cast) {
+        let is_place_use = match &parent {
+            ast::Expr::RefExpr(_) => true,
+            ast::Expr::FieldExpr(it) => it.expr().as_ref() == Some(&expr),
+            ast::Expr::IndexExpr(it) => it.base().as_ref() == Some(&expr),
+            ast::Expr::MethodCallExpr(it) => it.receiver().as_ref() == Some(&expr),
+            ast::Expr::BinExpr(it) => {
+                matches!(it.op_kind(), Some(ast::BinaryOp::Assignment { .. }))
+                    && it.lhs().as_ref() == Some(&expr)
+            }
+            _ => false,
+        };
+        if !is_place_use {
+            break;
+        }
+        expr = parent;
+    }
+    expr
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::{check_assist, check_assist_not_applicable};
+
+    use super::*;
+
+    #[test]
+    fn wraps_unsafe_fn_call() {
+        check_assist(
+            wrap_in_unsafe_block,
+            r#"
+unsafe fn f() -> u32 { 0 }
+fn main() {
+    let x = f$0() + 1;
+}
+"#,
+            r#"
+unsafe fn f() -> u32 { 0 }
+fn main() {
+    let x = unsafe { f() } + 1;
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn wraps_unsafe_method_call() {
+        check_assist(
+            wrap_in_unsafe_block,
+            r#"
+struct S;
+impl S {
+    unsafe fn f(&self) {}
+}
+fn main() {
+    S.f$0();
+}
+"#,
+            r#"
+struct S;
+impl S {
+    unsafe fn f(&self) {}
+}
+fn main() {
+    unsafe { S.f() };
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn wraps_raw_pointer_deref() {
+        check_assist(
+            wrap_in_unsafe_block,
+            r#"
+fn main() {
+    let p = &0 as *const i32;
+    let x = $0*p;
+}
+"#,
+            r#"
+fn main() {
+    let p = &0 as *const i32;
+    let x = unsafe { *p };
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn wraps_union_field_access() {
+        check_assist(
+            wrap_in_unsafe_block,
+            r#"
+union U { a: u32, b: f32 }
+fn f(u: U) -> u32 {
+    u.a$0
+}
+"#,
+            r#"
+union U { a: u32, b: f32 }
+fn f(u: U) -> u32 {
+    unsafe { u.a }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn wraps_static_mut_access() {
+        check_assist(
+            wrap_in_unsafe_block,
+            r#"
+static mut COUNTER: u32 = 0;
+fn main() {
+    let x = COUNTER$0;
+}
+"#,
+            r#"
+static mut COUNTER: u32 = 0;
+fn main() {
+    let x = unsafe { COUNTER };
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn wraps_assignment_to_static_mut() {
+        check_assist(
+            wrap_in_unsafe_block,
+            r#"
+static mut COUNTER: u32 = 0;
+fn main() {
+    COUNTER$0 = 1;
+}
+"#,
+            r#"
+static mut COUNTER: u32 = 0;
+fn main() {
+    unsafe { COUNTER = 1 };
+}
+"#,
+        );
+        check_assist(
+            wrap_in_unsafe_block,
+            r#"
+static mut COUNTERS: [u32; 2] = [0; 2];
+fn main() {
+    COUNTERS$0[0] += 1;
+}
+"#,
+            r#"
+static mut COUNTERS: [u32; 2] = [0; 2];
+fn main() {
+    unsafe { COUNTERS[0] += 1 };
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn wraps_borrow_of_raw_pointer_deref() {
+        check_assist(
+            wrap_in_unsafe_block,
+            r#"
+fn f(p: *mut i32) {
+    let r = &mut $0*p;
+}
+"#,
+            r#"
+fn f(p: *mut i32) {
+    let r = unsafe { &mut *p };
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_to_union_field_write() {
+        check_assist_not_applicable(
+            wrap_in_unsafe_block,
+            r#"
+union U { a: u32, b: f32 }
+fn f(mut u: U) {
+    u.a$0 = 1;
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_to_safe_exprs() {
+        check_assist_not_applicable(
+            wrap_in_unsafe_block,
+            r#"
+static COUNTER: u32 = 0;
+fn f() {}
+fn main() {
+    f$0();
+    let x = COUNTER;
+}
+"#,
+        );
+        check_assist_not_applicable(
+            wrap_in_unsafe_block,
+            r#"
+static COUNTER: u32 = 0;
+fn main() {
+    let x = COUNTER$0;
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_in_unsafe_context() {
+        check_assist_not_applicable(
+            wrap_in_unsafe_block,
+            r#"
+unsafe fn f() {}
+fn main() {
+    unsafe { f$0() };
+}
+"#,
+        );
+        check_assist_not_applicable(
+            wrap_in_unsafe_block,
+            r#"
+unsafe fn f() {}
+unsafe fn g() {
+    f$0();
+}
+"#,
+        );
+    }
+}
@@ -107,6 +107,7 @@ mod handlers {
     mod add_explicit_type;
     mod add_lifetime_to_type;
     mod add_missing_impl_members;
+    mod add_must_use;
     mod add_turbo_fish;
     mod apply_demorgan;
     mod auto_import;
@@ -116,9 +117,11 @@ mod handlers {
     mod convert_integer_literal;
     mod convert_into_to_from;
     mod convert_iter_for_each_to_for;
+    mod convert_named_struct_to_tuple_struct;
     mod convert_tuple_struct_to_named_struct;
     mod convert_to_guarded_return;
     mod convert_while_to_loop;
+    mod desugar_async_fn;
     mod destructure_tuple_binding;
     mod expand_glob_import;
     mod extract_function;
@@ -146,10 +149,12 @@ mod handlers {
     mod generate_is_empty_from_len;
     mod generate_new;
     mod generate_setter;
+    mod generate_try_from_impl;
     mod generate_delegate_methods;
     mod add_return_type;
     mod inline_call;
     mod inline_local_variable;
+    mod inline_module;
     mod introduce_named_lifetime;
     mod invert_if;
     mod merge_imports;
@@ -184,6 +189,7 @@ mod handlers {
     mod unmerge_use;
     mod unwrap_block;
     mod unwrap_result_return_type;
+    mod wrap_in_unsafe_block;
     mod wrap_return_type_in_result;
 
     pub(crate) fn all() -> &'static [Handler] {
@@ -192,6 +198,7 @@ mod handlers {
             add_explicit_type::add_explicit_type,
             add_missing_match_arms::add_missing_match_arms,
             add_lifetime_to_type::add_lifetime_to_type,
+            add_must_use::add_must_use,
             add_return_type::add_return_type,
             add_turbo_fish::add_turbo_fish,
             apply_demorgan::apply_demorgan,
@@ -204,9 +211,11 @@ mod handlers {
             convert_into_to_from::convert_into_to_from,
             convert_iter_for_each_to_for::convert_iter_for_each_to_for,
             convert_iter_for_each_to_for::convert_for_loop_with_for_each,
+            convert_named_struct_to_tuple_struct::convert_named_struct_to_tuple_struct,
             convert_to_guarded_return::convert_to_guarded_return,
             convert_tuple_struct_to_named_struct::convert_tuple_struct_to_named_struct,
             convert_while_to_loop::convert_while_to_loop,
+            desugar_async_fn::desugar_async_fn,
             destructure_tuple_binding::destructure_tuple_binding,
             expand_glob_import::expand_glob_import,
             extract_struct_from_enum_variant::extract_struct_from_enum_variant,
@@ -228,9 +237,11 @@ mod handlers {
             generate_impl::generate_impl,
             generate_is_empty_from_len::generate_is_empty_from_len,
             generate_new::generate_new,
+            generate_try_from_impl::generate_try_from_impl,
             inline_call::inline_call,
             inline_call::inline_into_callers,
             inline_local_variable::inline_local_variable,
+            inline_module::inline_module,
             introduce_named_generic::introduce_named_generic,
             introduce_named_lifetime::introduce_named_lifetime,
             invert_if::invert_if,
@@ -268,6 +279,7 @@ mod handlers {
             unmerge_use::unmerge_use,
             unwrap_block::unwrap_block,
             unwrap_result_return_type::unwrap_result_return_type,
+            wrap_in_unsafe_block::wrap_in_unsafe_block,
             wrap_return_type_in_result::wrap_return_type_in_result,
             // These are manually sorted for better priorities. By default,
             // priority is determined by the size of the target range (smaller
@@ -165,6 +165,13 @@ fn check(handler: Handler, before: &str, expected: ExpectedResult, assist_label:
                     FileSystemEdit::MoveFile { src, dst } => {
                         (dst, db.file_text(src).as_ref().to_owned())
                     }
+                    FileSystemEdit::DeleteFile { file_id } => {
+                        let sr = db.file_source_root(file_id);
+                        let sr = db.source_root(sr);
+                        let path = sr.path_for_file(&file_id).unwrap();
+                        format_to!(buf, "//- {} (deleted)\n", path);
+                        continue;
+                    }
                 };
                 let sr = db.file_source_root(dst.anchor);
                 let sr = db.source_root(sr);
@@ -226,6 +233,7 @@ fn assist_order_field_struct() {
     assert_eq!(assists.next().expect("expected assist").label, "Generate a mut getter method");
     assert_eq!(assists.next().expect("expected assist").label, "Generate a setter method");
     assert_eq!(assists.next().expect("expected assist").label, "Generate `Deref` impl using `bar`");
+    assert_eq!(assists.next().expect("expected assist").label, "Convert to tuple struct");
     assert_eq!(assists.next().expect("expected assist").label, "Add `#[derive]`");
 }
 
@@ -147,6 +147,25 @@ fn handle(action: Action) {
     )
 }
 
+#[test]
+fn doctest_add_must_use() {
+    check_doc_test(
+        "add_must_use",
+        r#####"
+//- minicore: option
+fn $0find(id: u32) -> Option<u32> {
+    None
+}
+"#####,
+        r#####"
+#[must_use]
+fn find(id: u32) -> Option<u32> {
+    None
+}
+"#####,
+    )
+}
+
 #[test]
 fn doctest_add_return_type() {
     check_doc_test(
@@ -364,6 +383,39 @@ fn main() {
     )
 }
 
+#[test]
+fn doctest_convert_named_struct_to_tuple_struct() {
+    check_doc_test(
+        "convert_named_struct_to_tuple_struct",
+        r#####"
+struct Point$0 { x: f32, y: f32 }
+
+impl Point {
+    pub fn new(x: f32, y: f32) -> Self {
+        Point { x, y }
+    }
+
+    pub fn x(&self) -> f32 {
+        self.x
+    }
+}
+"#####,
+        r#####"
+struct Point(f32, f32);
+
+impl Point {
+    pub fn new(x: f32, y: f32) -> Self {
+        Point(x, y)
+    }
+
+    pub fn x(&self) -> f32 {
+        self.0
+    }
+}
+"#####,
+    )
+}
+
 #[test]
 fn doctest_convert_to_guarded_return() {
     check_doc_test(
@@ -472,6 +524,26 @@ fn main() {
     )
 }
 
+#[test]
+fn doctest_desugar_async_fn() {
+    check_doc_test(
+        "desugar_async_fn",
+        r#####"
+//- minicore: future
+async fn $0fetch(id: u32) -> String {
+    format!("{}", id)
+}
+"#####,
+        r#####"
+fn fetch(id: u32) -> impl core::future::Future<Output = String> {
+    async move {
+        format!("{}", id)
+    }
+}
+"#####,
+    )
+}
+
 #[test]
 fn doctest_expand_glob_import() {
     check_doc_test(
@@ -1161,6 +1233,43 @@ impl Person {
     )
 }
 
+#[test]
+fn doctest_generate_try_from_impl() {
+    check_doc_test(
+        "generate_try_from_impl",
+        r#####"
+//- minicore: try_from
+#[repr(u8)]
+enum $0Color {
+    Red = 1,
+    Green,
+    Blue = 4,
+}
+"#####,
+        r#####"
+#[repr(u8)]
+enum Color {
+    Red = 1,
+    Green,
+    Blue = 4,
+}
+
+impl core::convert::TryFrom<u8> for Color {
+    type Error = u8;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            1 => Ok(Self::Red),
+            2 => Ok(Self::Green),
+            4 => Ok(Self::Blue),
+            _ => Err(value),
+        }
+    }
+}
+"#####,
+    )
+}
+
 #[test]
 fn doctest_inline_call() {
     check_doc_test(
@@ -1237,6 +1346,24 @@ fn main() {
     )
 }
 
+#[test]
+fn doctest_inline_module() {
+    check_doc_test(
+        "inline_module",
+        r#####"
+//- /main.rs
+mod $0foo;
+//- /foo.rs
+fn t() {}
+"#####,
+        r#####"
+mod foo {
+    fn t() {}
+}
+"#####,
+    )
+}
+
 #[test]
 fn doctest_introduce_named_generic() {
     check_doc_test(
@@ -1320,7 +1447,7 @@ fn main() {
 "#####,
         r#####"
 fn main() {
-    r#"Hello, World!"#;
+    r"Hello, World!";
 }
 "#####,
     )
@@ -2109,6 +2236,27 @@ fn foo() -> i32 { 42i32 }
     )
 }
 
+#[test]
+fn doctest_wrap_in_unsafe_block() {
+    check_doc_test(
+        "wrap_in_unsafe_block",
+        r#####"
+unsafe fn launch() {}
+
+fn main() {
+    launch$0();
+}
+"#####,
+        r#####"
+unsafe fn launch() {}
+
+fn main() {
+    unsafe { launch() };
+}
+"#####,
+    )
+}
+
 #[test]
 fn doctest_wrap_return_type_in_result() {
     check_doc_test(
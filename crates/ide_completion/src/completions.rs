@@ -39,6 +39,8 @@ use crate::{
 /// Represents an in-progress set of completions being built.
 #[derive(Debug, Default)]
 pub struct Completions {
+    // Not a `SmallVec`: items are large, and a request allocates hundreds of times anyway, so
+    // storing the first few inline saves next to nothing.
     buf: Vec<CompletionItem>,
 }
 
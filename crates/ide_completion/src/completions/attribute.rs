@@ -16,7 +16,11 @@ use ide_db::{
 use itertools::Itertools;
 use once_cell::sync::Lazy;
 use rustc_hash::FxHashMap;
-use syntax::{algo::non_trivia_sibling, ast, AstNode, Direction, SyntaxKind, T};
+use syntax::{
+    algo::non_trivia_sibling,
+    ast::{self, HasAttrs},
+    AstNode, Direction, SyntaxKind, T,
+};
 
 use crate::{context::CompletionContext, item::CompletionItem, Completions};
 
@@ -77,7 +81,36 @@ fn complete_new_attribute(acc: &mut Completions, ctx: &CompletionContext, attrib
         }
     });
 
-    let add_completion = |attr_completion: &AttrCompletion| {
+    // Test attributes can only be given once, so don't offer those the item already has.
+    let existing_test_attrs: Vec<String> = attribute
+        .syntax()
+        .parent()
+        .and_then(ast::AnyHasAttrs::cast)
+        .map(|item| {
+            item.attrs()
+                .filter_map(|attr| attr.path())
+                .map(|path| path.syntax().text().to_string())
+                .filter(|name| TEST_ATTRIBUTES.contains(&name.as_str()))
+                .collect()
+        })
+        .unwrap_or_default();
+    // `tokio::test` takes the place of `test`, so either one rules out both.
+    let is_present = |name: &str| {
+        fn as_test(it: &str) -> &str {
+            if it == "tokio::test" {
+                "test"
+            } else {
+                it
+            }
+        }
+        existing_test_attrs.iter().any(|it| as_test(it) == as_test(name))
+    };
+
+    let mut add_completion = |attr_completion: &AttrCompletion| {
+        if is_present(attr_completion.name()) {
+            return;
+        }
+
         let mut item =
             CompletionItem::new(SymbolKind::Attribute, ctx.source_range(), attr_completion.label);
 
@@ -89,6 +122,10 @@ fn complete_new_attribute(acc: &mut Completions, ctx: &CompletionContext, attrib
             item.insert_snippet(cap, snippet);
         }
 
+        if let Some(docs) = attr_completion.docs {
+            item.documentation(hir::Documentation::new(docs.to_owned()));
+        }
+
         if is_inner || !attr_completion.prefer_inner {
             item.add_to(acc);
         }
@@ -99,9 +136,17 @@ fn complete_new_attribute(acc: &mut Completions, ctx: &CompletionContext, attrib
             .iter()
             .flat_map(|name| ATTRIBUTES.binary_search_by(|attr| attr.key().cmp(name)).ok())
             .flat_map(|idx| ATTRIBUTES.get(idx))
-            .for_each(add_completion),
-        None if is_inner => ATTRIBUTES.iter().for_each(add_completion),
-        None => ATTRIBUTES.iter().filter(|compl| !compl.prefer_inner).for_each(add_completion),
+            .for_each(&mut add_completion),
+        None if is_inner => ATTRIBUTES.iter().for_each(&mut add_completion),
+        None => ATTRIBUTES.iter().filter(|compl| !compl.prefer_inner).for_each(&mut add_completion),
+    }
+
+    let takes_test_attrs = attributes.map_or(!is_inner, |applicable| applicable.contains(&"test"));
+    let has_tokio = ctx.krate.map_or(false, |krate| {
+        krate.dependencies(ctx.db).iter().any(|dep| dep.name.to_smol_str() == "tokio")
+    });
+    if takes_test_attrs && has_tokio {
+        add_completion(&TOKIO_TEST);
     }
 }
 
@@ -109,6 +154,7 @@ struct AttrCompletion {
     label: &'static str,
     lookup: Option<&'static str>,
     snippet: Option<&'static str>,
+    docs: Option<&'static str>,
     prefer_inner: bool,
 }
 
@@ -117,9 +163,18 @@ impl AttrCompletion {
         self.lookup.unwrap_or(self.label)
     }
 
+    /// The path of the attribute, without its arguments.
+    fn name(&self) -> &'static str {
+        self.label.split(|c: char| c == '(' || c == ' ').next().unwrap_or(self.label)
+    }
+
     const fn prefer_inner(self) -> AttrCompletion {
         AttrCompletion { prefer_inner: true, ..self }
     }
+
+    const fn docs(self, docs: &'static str) -> AttrCompletion {
+        AttrCompletion { docs: Some(docs), ..self }
+    }
 }
 
 const fn attr(
@@ -127,9 +182,16 @@ const fn attr(
     lookup: Option<&'static str>,
     snippet: Option<&'static str>,
 ) -> AttrCompletion {
-    AttrCompletion { label, lookup, snippet, prefer_inner: false }
+    AttrCompletion { label, lookup, snippet, docs: None, prefer_inner: false }
 }
 
+/// Attributes marking and configuring tests, of which a function can only have one each.
+const TEST_ATTRIBUTES: &[&str] = &["test", "ignore", "should_panic", "tokio::test"];
+
+/// Offered alongside `test` when the crate depends on `tokio`.
+const TOKIO_TEST: AttrCompletion = attr("tokio::test", None, None)
+    .docs("Marks an async function as a test, running it on a tokio runtime.");
+
 macro_rules! attrs {
     // attributes applicable to all items
     [@ { item $($tt:tt)* } {$($acc:tt)*}] => {
@@ -190,8 +252,8 @@ static KIND_TO_ATTRIBUTES: Lazy<FxHashMap<SyntaxKind, &[&str]>> = Lazy::new(|| {
             attrs!(
                 item, linkable,
                 "cold", "ignore", "inline", "must_use", "panic_handler", "proc_macro",
-                "proc_macro_derive", "proc_macro_attribute", "should_panic", "target_feature",
-                "test", "track_caller"
+                "proc_macro_derive", "proc_macro_attribute", "should_panic", "should_panic_expected",
+                "target_feature", "test", "track_caller"
             ),
         ),
         (STATIC, attrs!(item, linkable, "global_allocator", "used")),
@@ -247,7 +309,8 @@ const ATTRIBUTES: &[AttrCompletion] = &[
     attr("feature(…)", Some("feature"), Some("feature(${0:flag})")).prefer_inner(),
     attr("forbid(…)", Some("forbid"), Some("forbid(${0:lint})")),
     attr("global_allocator", None, None),
-    attr(r#"ignore = "…""#, Some("ignore"), Some(r#"ignore = "${0:reason}""#)),
+    attr(r#"ignore = "…""#, Some("ignore"), Some(r#"ignore = "${0:reason}""#))
+        .docs("Skips the test unless the tests are run with `--ignored` or `--include-ignored`."),
     attr("inline", Some("inline"), Some("inline")),
     attr("link", None, None),
     attr(r#"link_name = "…""#, Some("link_name"), Some(r#"link_name = "${0:symbol_name}""#)),
@@ -277,13 +340,20 @@ const ATTRIBUTES: &[AttrCompletion] = &[
     )
     .prefer_inner(),
     attr("repr(…)", Some("repr"), Some("repr(${0:C})")),
-    attr("should_panic", Some("should_panic"), Some(r#"should_panic"#)),
+    attr("should_panic", Some("should_panic"), Some(r#"should_panic"#))
+        .docs("Makes the test pass only if it panics."),
+    attr(
+        r#"should_panic(expected = "…")"#,
+        Some("should_panic_expected"),
+        Some(r#"should_panic(expected = "${0:message}")"#),
+    )
+    .docs("Makes the test pass only if it panics with a message containing the expected string."),
     attr(
         r#"target_feature = "…""#,
         Some("target_feature"),
         Some(r#"target_feature = "${0:feature}""#),
     ),
-    attr("test", None, None),
+    attr("test", None, None).docs("Marks the function as a test, run by `cargo test`."),
     attr("track_caller", None, None),
     attr("type_length_limit = …", Some("type_length_limit"), Some("type_length_limit = ${0:128}"))
         .prefer_inner(),
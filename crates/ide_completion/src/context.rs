@@ -5,13 +5,14 @@ use hir::{Local, Name, ScopeDef, Semantics, SemanticsScope, Type, TypeInfo};
 use ide_db::{
     active_parameter::ActiveParameter,
     base_db::{FilePosition, SourceDatabase},
+    format_args::{FormatArgRef, FormatArgs},
     helpers::FamousDefs,
     RootDatabase,
 };
 use syntax::{
     algo::find_node_at_offset,
     ast::{self, HasName, NameOrNameRef},
-    match_ast, AstNode, NodeOrToken,
+    match_ast, AstNode, AstToken, NodeOrToken,
     SyntaxKind::{self, *},
     SyntaxNode, SyntaxToken, TextRange, TextSize, T,
 };
@@ -504,6 +505,30 @@ impl<'a> CompletionContext<'a> {
         self.fill(&original_file, speculative_file, offset);
     }
 
+    /// When completing an argument of a formatting macro, like `format!("{name}", $0)`, returns
+    /// the first named placeholder which isn't given an argument yet.
+    fn expected_format_arg_name(&self) -> Option<NameOrNameRef> {
+        let macro_call = self.original_token.parent()?.parent().and_then(ast::MacroCall::cast)?;
+        let format_args = FormatArgs::from_macro_call(&macro_call)?;
+        if self.original_token.text_range().start()
+            < format_args.format_string.syntax().text_range().end()
+        {
+            return None;
+        }
+        let is_given = |name: &str| {
+            format_args.args.iter().any(|arg| match (&arg.name, arg.value.as_slice()) {
+                (Some(it), _) => it == name,
+                (None, [NodeOrToken::Token(value)]) => value.text() == name,
+                (None, _) => false,
+            })
+        };
+        let name = format_args.placeholders().into_iter().find_map(|it| match it.arg {
+            FormatArgRef::Name(name) if !is_given(&name) => Some(name),
+            _ => None,
+        })?;
+        Some(NameOrNameRef::Name(ast::make::name(&name)))
+    }
+
     fn expected_type_and_name(&self) -> (Option<Type>, Option<NameOrNameRef>) {
         let mut node = match self.token.parent() {
             Some(it) => it,
@@ -664,7 +689,7 @@ impl<'a> CompletionContext<'a> {
 
         let (expected_type, expected_name) = self.expected_type_and_name();
         self.expected_type = expected_type;
-        self.expected_name = expected_name;
+        self.expected_name = expected_name.or_else(|| self.expected_format_arg_name());
 
         let name_like = match find_node_at_offset(&file_with_fake_ident, offset) {
             Some(it) => it,
@@ -11,6 +11,7 @@ use ide_db::{
     },
     SymbolKind,
 };
+use itertools::Itertools;
 use smallvec::SmallVec;
 use stdx::{impl_from, never};
 use syntax::{algo, SmolStr, TextRange};
@@ -282,6 +283,7 @@ impl CompletionItem {
             detail: None,
             documentation: None,
             lookup: None,
+            doc_aliases: Vec::new(),
             kind: kind.into(),
             text_edit: None,
             deprecated: false,
@@ -388,6 +390,7 @@ pub(crate) struct Builder {
     detail: Option<String>,
     documentation: Option<Documentation>,
     lookup: Option<SmolStr>,
+    doc_aliases: Vec<SmolStr>,
     kind: CompletionItemKind,
     text_edit: Option<TextEdit>,
     deprecated: bool,
@@ -404,6 +407,14 @@ impl Builder {
         let mut lookup = self.lookup;
         let insert_text = self.insert_text.unwrap_or_else(|| label.to_string());
 
+        if !self.doc_aliases.is_empty() {
+            // Let the client match the item by its aliases too.
+            let name = lookup.as_ref().unwrap_or(&label);
+            lookup = Some(SmolStr::from(format!("{} {}", name, self.doc_aliases.iter().join(" "))));
+            label =
+                SmolStr::from(format!("{} (alias {})", label, self.doc_aliases.iter().join(", ")));
+        }
+
         if let [import_edit] = &*self.imports_to_add {
             // snippets can have multiple imports, but normal completions only have up to one
             if let Some(original_path) = import_edit.import.original_path.as_ref() {
@@ -439,6 +450,10 @@ impl Builder {
         self.lookup = Some(lookup.into());
         self
     }
+    pub(crate) fn doc_aliases(&mut self, doc_aliases: Vec<SmolStr>) -> &mut Builder {
+        self.doc_aliases = doc_aliases;
+        self
+    }
     pub(crate) fn label(&mut self, label: impl Into<SmolStr>) -> &mut Builder {
         self.label = label.into();
         self
@@ -94,6 +94,15 @@ pub(crate) fn determine_prev_sibling(name_like: &ast::NameLike) -> Option<Immedi
         }
         _ => node,
     };
+    if let Some(impl_) = node.parent().and_then(ast::Impl::cast) {
+        // `impl Trait w$0` is parsed as an impl missing its `for`, with `w` as the self type.
+        if impl_.for_token().is_none()
+            && impl_.trait_().is_some()
+            && impl_.self_ty().map_or(false, |it| *it.syntax() == node)
+        {
+            return Some(ImmediatePrevSibling::ImplDefType);
+        }
+    }
     let prev_sibling = non_trivia_sibling(node.into(), Direction::Prev)?.into_node()?;
     if prev_sibling.kind() == ERROR {
         let prev_sibling = prev_sibling.first_child()?;
@@ -71,6 +71,10 @@ impl<'a> RenderContext<'a> {
                 .unwrap_or(false)
     }
 
+    fn doc_aliases(&self, def: impl HasAttrs) -> Vec<SmolStr> {
+        def.attrs(self.db()).doc_aliases()
+    }
+
     // FIXME: remove this
     fn docs(&self, def: impl HasAttrs) -> Option<hir::Documentation> {
         def.docs(self.db())
@@ -245,6 +249,9 @@ fn render_resolution_(
     }
     item.set_documentation(scope_def_docs(db, resolution))
         .set_deprecated(scope_def_is_deprecated(&ctx, resolution));
+    if let Some(attrs) = resolution.attrs(db) {
+        item.doc_aliases(attrs.doc_aliases());
+    }
 
     if let Some(import_to_add) = import_to_add {
         item.add_import(import_to_add);
@@ -1115,6 +1122,55 @@ fn f() {
         );
     }
 
+    #[test]
+    fn score_local_matching_named_format_placeholder() {
+        check_relevance(
+            r#"
+#[rustc_builtin_macro]
+macro_rules! format_args {}
+macro_rules! format {
+    ($($arg:tt)*) => { format_args!($($arg)*) };
+}
+fn f() {
+    let other = 1;
+    let width = 5;
+    let name = "x";
+    let s = format!("{} {name:>width$}", other, n$0);
+}
+"#,
+            expect![[r#"
+                lc name [name+local]
+                lc width [local]
+                lc other [local]
+                fn f() []
+                ma format!(…) []
+                ma format_args!(…) []
+            "#]],
+        );
+        check_relevance(
+            r#"
+#[rustc_builtin_macro]
+macro_rules! format_args {}
+macro_rules! writeln {
+    ($dst:expr, $($arg:tt)*) => { $dst.write_fmt(format_args!($($arg)*)) };
+}
+fn f(out: ()) {
+    let value = 1;
+    let name = "x";
+    let _ = writeln!(out, "{name} {value}", name = 1, v$0);
+}
+"#,
+            expect![[r#"
+                lc value [name+local]
+                lc name [local]
+                lc out [local]
+                fn f(…) []
+                ma writeln!(…) []
+                ma format_args!(…) []
+            "#]],
+        );
+    }
+
     #[test]
     fn suggest_ref_mut() {
         cov_mark::check!(suggest_ref);
@@ -83,6 +83,7 @@ fn render(
     }
 
     item.set_documentation(ctx.docs(func))
+        .doc_aliases(ctx.doc_aliases(func))
         .set_deprecated(ctx.is_deprecated(func) || ctx.is_deprecated_assoc_item(func))
         .detail(detail(db, func))
         .add_call_parens(completion, call, params);
@@ -54,6 +54,7 @@ fn render(
         label(&ctx, needs_bang, bra, ket, &name),
     );
     item.set_deprecated(ctx.is_deprecated(macro_))
+        .doc_aliases(ctx.doc_aliases(macro_))
         .set_detail(detail(db, macro_))
         .set_documentation(docs);
 
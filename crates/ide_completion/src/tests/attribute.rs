@@ -1,7 +1,12 @@
 //! Completion tests for attributes.
 use expect_test::{expect, Expect};
 
-use crate::tests::{check_edit, completion_list};
+use ide_db::SymbolKind;
+
+use crate::{
+    tests::{check_edit, completion_list, do_completion},
+    CompletionItemKind,
+};
 
 fn check(ra_fixture: &str, expect: Expect) {
     let actual = completion_list(ra_fixture);
@@ -557,6 +562,7 @@ fn attr_on_fn() {
             at proc_macro_derive(…)
             at proc_macro_attribute
             at should_panic
+            at should_panic(expected = "…")
             at target_feature = "…"
             at test
             at track_caller
@@ -567,6 +573,114 @@ fn attr_on_fn() {
     );
 }
 
+fn check_test_attrs(ra_fixture: &str, expect: Expect) {
+    let actual = completion_list(ra_fixture)
+        .lines()
+        .filter(|it| ["test", "ignore", "should_panic"].iter().any(|attr| it.contains(attr)))
+        .map(|it| format!("{}\n", it))
+        .collect::<String>();
+    expect.assert_eq(&actual);
+}
+
+#[test]
+fn test_attrs_on_fn() {
+    check_test_attrs(
+        r#"
+#[test]
+#[$0]
+fn works() {}
+"#,
+        expect![[r#"
+            at ignore = "…"
+            at should_panic
+            at should_panic(expected = "…")
+        "#]],
+    );
+    check_test_attrs(
+        r#"
+#[test]
+#[ignore = "slow"]
+#[$0]
+fn works() {}
+"#,
+        expect![[r#"
+            at should_panic
+            at should_panic(expected = "…")
+        "#]],
+    );
+    check_test_attrs(
+        r#"
+#[test]
+#[should_panic(expected = "overflow")]
+#[$0]
+fn works() {}
+"#,
+        expect![[r#"
+            at ignore = "…"
+        "#]],
+    );
+}
+
+#[test]
+fn tokio_test_attr() {
+    check_test_attrs(
+        r#"
+//- /main.rs crate:main deps:tokio
+#[$0]
+async fn works() {}
+//- /tokio.rs crate:tokio
+"#,
+        expect![[r#"
+            at ignore = "…"
+            at should_panic
+            at should_panic(expected = "…")
+            at test
+            at tokio::test
+        "#]],
+    );
+    check_test_attrs(
+        r#"
+//- /main.rs crate:main deps:tokio
+#[tokio::test]
+#[$0]
+async fn works() {}
+//- /tokio.rs crate:tokio
+"#,
+        expect![[r#"
+            at ignore = "…"
+            at should_panic
+            at should_panic(expected = "…")
+        "#]],
+    );
+}
+
+#[test]
+fn test_attrs_have_docs() {
+    let completions = do_completion(
+        r#"
+//- /main.rs crate:main deps:tokio
+#[$0]
+fn works() {}
+//- /tokio.rs crate:tokio
+"#,
+        CompletionItemKind::SymbolKind(SymbolKind::Attribute),
+    );
+    let actual = completions
+        .iter()
+        .filter(|it| {
+            ["test", "ignore", "should_panic"].iter().any(|attr| it.label().contains(attr))
+        })
+        .map(|it| format!("{}: {}\n", it.label(), it.documentation().unwrap().as_str()))
+        .collect::<String>();
+    expect![[r#"
+        ignore = "…": Skips the test unless the tests are run with `--ignored` or `--include-ignored`.
+        should_panic: Makes the test pass only if it panics.
+        should_panic(expected = "…"): Makes the test pass only if it panics with a message containing the expected string.
+        test: Marks the function as a test, run by `cargo test`.
+        tokio::test: Marks an async function as a test, running it on a tokio runtime.
+    "#]].assert_eq(&actual);
+}
+
 #[test]
 fn attr_on_expr() {
     cov_mark::check!(no_keyword_completion_in_attr_of_expr);
@@ -622,6 +736,7 @@ fn attr_in_source_file_end() {
             at proc_macro_derive(…)
             at repr(…)
             at should_panic
+            at should_panic(expected = "…")
             at target_feature = "…"
             at test
             at track_caller
@@ -552,3 +552,102 @@ fn func() {
         "#]],
     );
 }
+
+#[test]
+fn completes_items_by_doc_alias() {
+    check_empty(
+        r#"
+#[doc(alias = "foo")]
+struct Bar;
+#[doc(alias("frobnicate", "twiddle"))]
+fn tweak() {}
+#[doc(alias = "log")]
+macro_rules! trace { () => {} }
+fn main() {
+    foo$0
+}
+"#,
+        expect![[r#"
+            kw unsafe
+            kw fn
+            kw const
+            kw type
+            kw impl
+            kw extern
+            kw use
+            kw trait
+            kw static
+            kw mod
+            kw match
+            kw while
+            kw while let
+            kw loop
+            kw if
+            kw if let
+            kw for
+            kw true
+            kw false
+            kw let
+            kw return
+            sn pd
+            sn ppd
+            kw self
+            kw super
+            kw crate
+            fn tweak() (alias frobnicate, twiddle) fn()
+            st Bar (alias foo)
+            fn main()                 fn()
+            ma trace!(…) (alias log)  macro_rules! trace
+            bt u32
+        "#]],
+    );
+}
+
+#[test]
+fn completes_in_impl_missing_self_type() {
+    check_empty(
+        r#"
+trait Trait { fn f(&self); }
+impl Trait for {
+    fn f(&self) {
+        let local = 0;
+        l$0
+    }
+}
+"#,
+        expect![[r#"
+            kw unsafe
+            kw fn
+            kw const
+            kw type
+            kw impl
+            kw extern
+            kw use
+            kw trait
+            kw static
+            kw mod
+            kw match
+            kw while
+            kw while let
+            kw loop
+            kw if
+            kw if let
+            kw for
+            kw true
+            kw false
+            kw let
+            kw return
+            sn pd
+            sn ppd
+            kw self
+            kw super
+            kw crate
+            lc local               i32
+            lc self                &{unknown}
+            sp Self
+            tt Trait
+            bt u32
+            me self.f() (as Trait) fn(&self)
+        "#]],
+    );
+}
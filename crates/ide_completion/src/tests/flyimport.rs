@@ -1126,3 +1126,21 @@ struct Foo;
 "#,
     );
 }
+
+#[test]
+fn local_item_by_doc_alias() {
+    check(
+        r#"
+mod io {
+    #[doc(alias = "stdin")]
+    pub fn read_input() {}
+}
+fn main() {
+    stdi$0
+}
+"#,
+        expect![[r#"
+            fn read_input() (alias stdin) (use io::read_input) fn()
+        "#]],
+    );
+}
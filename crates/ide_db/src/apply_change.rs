@@ -64,6 +64,7 @@ impl RootDatabase {
             // SourceDatabase
             base_db::ParseQuery
             base_db::CrateGraphQuery
+            base_db::CrateParserFeaturesQuery
 
             // SourceDatabaseExt
             base_db::FileTextQuery
@@ -0,0 +1,346 @@
+//! This module parses the format strings and arguments of `format_args!`-like macro calls, to
+//! find out which argument each placeholder of the format string refers to.
+
+use hir::{Semantics, Type};
+use syntax::{
+    ast::{self, FormatSpecifier, HasFormatSpecifier},
+    AstNode, AstToken, NodeOrToken, SmolStr, SyntaxElement, SyntaxKind, TextRange, T,
+};
+
+use crate::RootDatabase;
+
+/// The argument a placeholder of a format string refers to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FormatArgRef {
+    /// `{}` or `{0}`, the (implicit or explicit) index of a positional argument.
+    Index(usize),
+    /// `{name}`, either a named argument or a variable captured from the scope.
+    Name(SmolStr),
+}
+
+/// The formatting trait a placeholder uses, depending on its type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FormatTrait {
+    Display,
+    Debug,
+    LowerHex,
+    UpperHex,
+    Octal,
+    Binary,
+    LowerExp,
+    UpperExp,
+    Pointer,
+}
+
+impl FormatTrait {
+    fn from_type(ty: &str) -> Option<FormatTrait> {
+        let res = match ty {
+            "" => FormatTrait::Display,
+            "?" | "x?" | "X?" => FormatTrait::Debug,
+            "x" => FormatTrait::LowerHex,
+            "X" => FormatTrait::UpperHex,
+            "o" => FormatTrait::Octal,
+            "b" => FormatTrait::Binary,
+            "e" => FormatTrait::LowerExp,
+            "E" => FormatTrait::UpperExp,
+            "p" => FormatTrait::Pointer,
+            _ => return None,
+        };
+        Some(res)
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            FormatTrait::Display => "Display",
+            FormatTrait::Debug => "Debug",
+            FormatTrait::LowerHex => "LowerHex",
+            FormatTrait::UpperHex => "UpperHex",
+            FormatTrait::Octal => "Octal",
+            FormatTrait::Binary => "Binary",
+            FormatTrait::LowerExp => "LowerExp",
+            FormatTrait::UpperExp => "UpperExp",
+            FormatTrait::Pointer => "Pointer",
+        }
+    }
+}
+
+/// A `{...}` placeholder of a format string.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FormatPlaceholder {
+    /// The range of the placeholder, braces included.
+    pub range: TextRange,
+    pub arg: FormatArgRef,
+    pub trait_: FormatTrait,
+}
+
+/// An argument following the format string.
+#[derive(Debug, Clone)]
+pub struct FormatArgument {
+    /// The name of a `name = value` argument.
+    pub name: Option<SmolStr>,
+    /// The range of the argument, including its name.
+    pub range: TextRange,
+    /// The tokens of the value, excluding the name and trivia.
+    pub value: Vec<SyntaxElement>,
+}
+
+#[derive(Debug, Clone)]
+pub struct FormatArgs {
+    pub format_string: ast::String,
+    pub args: Vec<FormatArgument>,
+}
+
+impl FormatArgs {
+    /// Splits the arguments of a call to one of the std formatting macros, like `format!` or
+    /// `writeln!`.
+    pub fn from_macro_call(macro_call: &ast::MacroCall) -> Option<FormatArgs> {
+        let name = macro_call.path()?.segment()?.name_ref()?;
+        let has_destination = match name.text().as_str() {
+            "format" | "format_args" | "format_args_nl" | "print" | "println" | "eprint"
+            | "eprintln" => false,
+            "write" | "writeln" => true,
+            _ => return None,
+        };
+
+        let mut args = split_args(&macro_call.token_tree()?).into_iter();
+        if has_destination {
+            args.next()?;
+        }
+        let format_string = match args.next()?.as_slice() {
+            [NodeOrToken::Token(token)] => ast::String::cast(token.clone())?,
+            _ => return None,
+        };
+        let args = args
+            .map(|arg| {
+                let first = arg.first().unwrap().text_range();
+                let range = first.cover(arg.last().unwrap().text_range());
+                match arg.as_slice() {
+                    [NodeOrToken::Token(name), NodeOrToken::Token(eq), rest @ ..]
+                        if name.kind() == SyntaxKind::IDENT
+                            && eq.kind() == T![=]
+                            && !starts_with_eq(rest) =>
+                    {
+                        FormatArgument {
+                            name: Some(name.text().into()),
+                            range,
+                            value: rest.to_vec(),
+                        }
+                    }
+                    _ => FormatArgument { name: None, range, value: arg },
+                }
+            })
+            .collect();
+        Some(FormatArgs { format_string, args })
+    }
+
+    pub fn placeholders(&self) -> Vec<FormatPlaceholder> {
+        format_placeholders(&self.format_string)
+    }
+
+    /// Returns the index into [`FormatArgs::args`] of the argument a placeholder refers to. This
+    /// is `None` for variables captured from the scope, or if there are too few arguments.
+    pub fn arg_index(&self, placeholder: &FormatPlaceholder) -> Option<usize> {
+        match &placeholder.arg {
+            FormatArgRef::Index(idx) => (*idx < self.args.len()).then(|| *idx),
+            FormatArgRef::Name(name) => {
+                self.args.iter().position(|arg| arg.name.as_ref() == Some(name))
+            }
+        }
+    }
+
+    /// Returns the type of the value a placeholder refers to, as far as it can be inferred.
+    pub fn arg_type(
+        &self,
+        sema: &Semantics<RootDatabase>,
+        placeholder: &FormatPlaceholder,
+    ) -> Option<Type> {
+        let idx = match self.arg_index(placeholder) {
+            Some(idx) => idx,
+            None => {
+                let name = match &placeholder.arg {
+                    FormatArgRef::Name(name) => name,
+                    FormatArgRef::Index(_) => return None,
+                };
+                // Scopes only know about expressions, so look at one from the expansion.
+                let macro_call = self
+                    .format_string
+                    .syntax()
+                    .parent()?
+                    .parent()
+                    .and_then(ast::MacroCall::cast)?;
+                let expr = sema.expand(&macro_call)?.descendants().find_map(ast::Expr::cast)?;
+                let mut res = None;
+                sema.scope(expr.syntax()).process_all_names(&mut |it, def| {
+                    if let hir::ScopeDef::Local(local) = def {
+                        if res.is_none() && it.to_smol_str() == *name {
+                            res = Some(local.ty(sema.db));
+                        }
+                    }
+                });
+                return res;
+            }
+        };
+        let arg = &self.args[idx];
+        let value_range = arg.value.first()?.text_range().cover(arg.value.last()?.text_range());
+        let first_token = arg.value.first()?.clone().into_token()?;
+        // The arguments become expressions in the expansion, take the outermost one which still
+        // belongs to the argument.
+        let expr = sema.descend_into_macros(first_token).into_iter().find_map(|token| {
+            token
+                .ancestors()
+                .filter_map(ast::Expr::cast)
+                .take_while(|expr| {
+                    value_range.contains_range(sema.original_range(expr.syntax()).range)
+                })
+                .last()
+        })?;
+        Some(sema.type_of_expr(&expr)?.original)
+    }
+}
+
+/// Parses the placeholders of a format string, like `{}`, `{0:?}` or `{name:>width$}`.
+pub fn format_placeholders(format_string: &ast::String) -> Vec<FormatPlaceholder> {
+    let text = format_string.text();
+    let offset = format_string.syntax().text_range().start();
+    let mut specifiers = Vec::new();
+    format_string.lex_format_specifier(|range, kind| specifiers.push((range, kind)));
+
+    let mut res = Vec::new();
+    let mut next_implicit = 0;
+    let mut pieces = specifiers.iter().peekable();
+    while let Some((open, kind)) = pieces.next() {
+        if !matches!(kind, FormatSpecifier::Open) {
+            continue;
+        }
+        let mut arg = None;
+        let mut ty = String::new();
+        let mut after_colon = false;
+        let mut close = None;
+        while let Some((range, kind)) =
+            pieces.next_if(|(_, kind)| !matches!(kind, FormatSpecifier::Open))
+        {
+            let piece = &text[*range];
+            match kind {
+                FormatSpecifier::Integer if !after_colon => {
+                    arg = piece.parse().ok().map(FormatArgRef::Index)
+                }
+                FormatSpecifier::Identifier if !after_colon => {
+                    arg = Some(FormatArgRef::Name(piece.into()))
+                }
+                FormatSpecifier::Colon => after_colon = true,
+                // `{:.*}` takes the precision from the next argument.
+                FormatSpecifier::Asterisk => next_implicit += 1,
+                FormatSpecifier::Identifier | FormatSpecifier::QuestionMark => ty.push_str(piece),
+                // An identifier followed by `$` is a width or a precision, not a type.
+                FormatSpecifier::DollarSign => ty.clear(),
+                FormatSpecifier::Close => {
+                    close = Some(*range);
+                    break;
+                }
+                _ => (),
+            }
+        }
+        let (close, trait_) = match (close, FormatTrait::from_type(&ty)) {
+            (Some(close), Some(trait_)) => (close, trait_),
+            _ => continue,
+        };
+        let arg = arg.unwrap_or_else(|| {
+            next_implicit += 1;
+            FormatArgRef::Index(next_implicit - 1)
+        });
+        res.push(FormatPlaceholder { range: open.cover(close) + offset, arg, trait_ });
+    }
+    res
+}
+
+/// Tells `name = value` apart from `name == value`.
+fn starts_with_eq(elements: &[SyntaxElement]) -> bool {
+    matches!(elements.first(), Some(NodeOrToken::Token(it)) if it.kind() == T![=])
+}
+
+/// Splits the token tree of a macro call into its comma separated arguments, skipping trivia.
+fn split_args(tt: &ast::TokenTree) -> Vec<Vec<SyntaxElement>> {
+    let mut res = vec![Vec::new()];
+    let mut elements = tt.syntax().children_with_tokens().skip(1).peekable();
+    while let Some(element) = elements.next() {
+        if elements.peek().is_none() {
+            // The closing delimiter.
+            break;
+        }
+        match &element {
+            NodeOrToken::Token(token) if token.kind().is_trivia() => (),
+            NodeOrToken::Token(token) if token.kind() == T![,] => res.push(Vec::new()),
+            _ => res.last_mut().unwrap().push(element),
+        }
+    }
+    res.retain(|arg| !arg.is_empty());
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::{expect, Expect};
+    use itertools::Itertools;
+    use syntax::SourceFile;
+
+    use super::*;
+
+    fn check(ra_fixture: &str, expect: Expect) {
+        let file = SourceFile::parse(ra_fixture).tree();
+        let macro_call = file.syntax().descendants().find_map(ast::MacroCall::cast).unwrap();
+        let format_args = FormatArgs::from_macro_call(&macro_call).unwrap();
+        let actual = format_args
+            .placeholders()
+            .iter()
+            .map(|it| {
+                format!(
+                    "{} {:?} {} {:?}",
+                    &ra_fixture[it.range],
+                    it.arg,
+                    it.trait_.name(),
+                    format_args.arg_index(it)
+                )
+            })
+            .join("\n");
+        expect.assert_eq(&actual);
+    }
+
+    #[test]
+    fn implicit_and_explicit_positions() {
+        check(
+            r#"fn f() { format!("{} {0:?} {:x} {{}} {:.*}", a, b, 2, c); }"#,
+            expect![[r#"
+                {} Index(0) Display Some(0)
+                {0:?} Index(0) Debug Some(0)
+                {:x} Index(1) LowerHex Some(1)
+                {:.*} Index(3) Display Some(3)"#]],
+        );
+    }
+
+    #[test]
+    fn named_arguments() {
+        check(
+            r#"fn f() { writeln!(out, "{name:.2} {value:>width$} {other:#X?}", value = 1, width = 4); }"#,
+            expect![[r#"
+                {name:.2} Name("name") Display None
+                {value:>width$} Name("value") Display Some(0)
+                {other:#X?} Name("other") Debug None"#]],
+        );
+    }
+
+    #[test]
+    fn types() {
+        check(
+            r#"fn f() { format!("{:o}{:08b}{:e}{:E}{:p}{:10}{:x<5}{:<5x}", a, b); }"#,
+            expect![[r#"
+                {:o} Index(0) Octal Some(0)
+                {:08b} Index(1) Binary Some(1)
+                {:e} Index(2) LowerExp None
+                {:E} Index(3) UpperExp None
+                {:p} Index(4) Pointer None
+                {:10} Index(5) Display None
+                {:x<5} Index(6) Display None
+                {:<5x} Index(7) LowerHex None"#]],
+        );
+    }
+}
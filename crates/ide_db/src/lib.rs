@@ -19,6 +19,7 @@ pub mod path_transform;
 pub mod search;
 pub mod rename;
 pub mod active_parameter;
+pub mod format_args;
 
 use std::{fmt, mem::ManuallyDrop, sync::Arc};
 
@@ -186,4 +187,6 @@ impl From<hir::MacroKind> for SymbolKind {
 #[cfg(test)]
 mod tests {
     mod sourcegen_lints;
+    mod enclosing_items;
+    mod inhabitedness;
 }
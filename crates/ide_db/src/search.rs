@@ -4,18 +4,21 @@
 //! get a super-set of matches. Then, we we confirm each match using precise
 //! name resolution.
 
-use std::{convert::TryInto, mem, sync::Arc};
+use std::{convert::TryInto, iter, mem, sync::Arc};
 
 use base_db::{FileId, FileRange, SourceDatabase, SourceDatabaseExt};
 use hir::{
     AsAssocItem, DefWithBody, HasAttrs, HasSource, InFile, ModuleSource, Semantics, Visibility,
 };
 use once_cell::unsync::Lazy;
+use rayon::prelude::*;
 use rustc_hash::FxHashMap;
+use stdx::never;
 use syntax::{ast, match_ast, AstNode, TextRange, TextSize};
 
 use crate::{
     defs::{Definition, NameClass, NameRefClass},
+    symbol_index::Snap,
     RootDatabase,
 };
 
@@ -66,6 +69,8 @@ pub enum ReferenceCategory {
     // Create
     Write,
     Read,
+    /// The item is imported by a `use` item.
+    Import,
     // FIXME: Some day should be able to search in doc comments. Would probably
     // need to switch from enum to bitflags then?
     // DocComment
@@ -356,26 +361,108 @@ impl<'a> FindUsages<'a> {
     }
 
     pub fn all(self) -> UsageSearchResult {
+        let search_scope = self.search_scope();
+        if search_scope.entries.len() >= 2 {
+            return self.search_in_parallel(&search_scope);
+        }
+
         let mut res = UsageSearchResult::default();
-        self.search(&mut |file_id, reference| {
+        self.search_in(&search_scope, &mut |file_id, reference| {
             res.references.entry(file_id).or_default().push(reference);
             false
         });
         res
     }
 
+    fn search_scope(&self) -> SearchScope {
+        let base = self.def.search_scope(self.sema.db);
+        match &self.scope {
+            None => base,
+            Some(scope) => base.intersection(scope),
+        }
+    }
+
+    /// Searches every file of the scope in parallel, on a separate snapshot of the database.
+    ///
+    /// Syntax trees are `!Send`, so the workers only send back the files and ranges of the
+    /// references, and their names are looked up again in the trees, or macro expansions, of this
+    /// thread. Confirming the matches is the
+    /// expensive part of the search, looking the names up is cheap.
+    fn search_in_parallel(&self, search_scope: &SearchScope) -> UsageSearchResult {
+        let _p = profile::span("FindUsages:search_in_parallel");
+        let def = self.def;
+        let include_self_kw_refs = &self.include_self_kw_refs;
+        let search_self_mod = self.search_self_mod;
+        let entries: Vec<_> =
+            search_scope.entries.iter().map(|(&file, &range)| (file, range)).collect();
+        let found: Vec<_> = entries
+            .into_par_iter()
+            .map_with(Snap::new(self.sema.db), |snap, (file_id, range)| {
+                let db: &RootDatabase = snap;
+                let sema = Semantics::new(db);
+                let usages = FindUsages {
+                    def,
+                    sema: &sema,
+                    scope: None,
+                    include_self_kw_refs: include_self_kw_refs.clone(),
+                    search_self_mod,
+                };
+                let scope = SearchScope::new(iter::once((file_id, range)).collect());
+                let mut found = Vec::new();
+                usages.search_in(&scope, &mut |file_id, reference| {
+                    // The name is in a macro expansion if the reference is inside a macro call.
+                    let name_file = sema.hir_file_for(reference.name.syntax());
+                    let name_range = reference.name.syntax().text_range();
+                    found.push((
+                        file_id,
+                        reference.range,
+                        name_file,
+                        name_range,
+                        reference.category,
+                    ));
+                    false
+                });
+                found
+            })
+            .collect();
+
+        let mut res = UsageSearchResult::default();
+        for (file_id, range, name_file, name_range, category) in found.into_iter().flatten() {
+            let root = if name_file == file_id.into() {
+                Some(self.sema.parse(file_id).syntax().clone())
+            } else {
+                self.sema.parse_or_expand(name_file)
+            };
+            let name = root.and_then(|root| {
+                root.covering_element(name_range)
+                    .ancestors()
+                    .take_while(|it| it.text_range() == name_range)
+                    .find_map(ast::NameLike::cast)
+            });
+            match name {
+                Some(name) => res.references.entry(file_id).or_default().push(FileReference {
+                    range,
+                    name,
+                    category,
+                }),
+                None => never!("the name of a reference was not found again"),
+            }
+        }
+        res
+    }
+
     fn search(&self, sink: &mut dyn FnMut(FileId, FileReference) -> bool) {
+        self.search_in(&self.search_scope(), sink)
+    }
+
+    fn search_in(
+        &self,
+        search_scope: &SearchScope,
+        sink: &mut dyn FnMut(FileId, FileReference) -> bool,
+    ) {
         let _p = profile::span("FindUsages:search");
         let sema = self.sema;
 
-        let search_scope = {
-            let base = self.def.search_scope(sema.db);
-            match &self.scope {
-                None => base,
-                Some(scope) => base.intersection(scope),
-            }
-        };
-
         let name = match self.def {
             // special case crate modules as these do not have a proper name
             Definition::Module(module) if module.crate_root(self.sema.db) == module => {
@@ -715,8 +802,12 @@ fn def_to_ty(sema: &Semantics<RootDatabase>, def: &Definition) -> Option<hir::Ty
 
 impl ReferenceCategory {
     fn new(def: &Definition, r: &ast::NameRef) -> Option<ReferenceCategory> {
-        // Only Locals and Fields have accesses for now.
-        if !matches!(def, Definition::Local(_) | Definition::Field(_)) {
+        if r.syntax().ancestors().any(|it| ast::Use::can_cast(it.kind())) {
+            return Some(ReferenceCategory::Import);
+        }
+
+        // Only Locals, Fields and statics (which can be `static mut`) have accesses for now.
+        if !matches!(def, Definition::Local(_) | Definition::Field(_) | Definition::Static(_)) {
             return None;
         }
 
@@ -36,9 +36,9 @@ use either::Either;
 use fst::{self, Streamer};
 use hir::{
     db::{DefDatabase, HirDatabase},
-    AdtId, AssocItemId, AssocItemLoc, DefHasSource, DefWithBodyId, HasSource, HirFileId, ImplId,
-    InFile, ItemContainerId, ItemLoc, ItemTreeNode, Lookup, MacroDef, Module, ModuleDefId,
-    ModuleId, Semantics, TraitId,
+    AdtId, AssocItemId, AssocItemLoc, AttrsWithOwner, DefHasSource, DefWithBodyId, HasAttrs,
+    HasSource, HirFileId, ImplId, InFile, ItemContainerId, ItemLoc, ItemTreeNode, Lookup, MacroDef,
+    Module, ModuleDef, ModuleDefId, ModuleId, Semantics, TraitId,
 };
 use rayon::prelude::*;
 use rustc_hash::FxHashSet;
@@ -137,9 +137,9 @@ fn module_symbols(db: &dyn SymbolsDatabase, module_id: ModuleId) -> Arc<SymbolIn
 }
 
 /// Need to wrap Snapshot to provide `Clone` impl for `map_with`
-struct Snap<DB>(DB);
+pub(crate) struct Snap<DB>(DB);
 impl<DB: ParallelDatabase> Snap<salsa::Snapshot<DB>> {
-    fn new(db: &DB) -> Self {
+    pub(crate) fn new(db: &DB) -> Self {
         Self(db.snapshot())
     }
 }
@@ -262,8 +262,8 @@ impl Hash for SymbolIndex {
 impl SymbolIndex {
     fn new(mut symbols: Vec<FileSymbol>) -> SymbolIndex {
         fn cmp(lhs: &FileSymbol, rhs: &FileSymbol) -> Ordering {
-            let lhs_chars = lhs.name.chars().map(|c| c.to_ascii_lowercase());
-            let rhs_chars = rhs.name.chars().map(|c| c.to_ascii_lowercase());
+            let lhs_chars = lhs.indexed_name().chars().map(|c| c.to_ascii_lowercase());
+            let rhs_chars = rhs.indexed_name().chars().map(|c| c.to_ascii_lowercase());
             lhs_chars.cmp(rhs_chars)
         }
 
@@ -284,7 +284,7 @@ impl SymbolIndex {
             let end = idx + 1;
             last_batch_start = end;
 
-            let key = symbols[start].name.as_str().to_ascii_lowercase();
+            let key = symbols[start].indexed_name().as_str().to_ascii_lowercase();
             let value = SymbolIndex::range_to_map_value(start, end);
 
             builder.insert(key, value).unwrap();
@@ -335,12 +335,13 @@ impl Query {
                     if self.only_types && !symbol.kind.is_type() {
                         continue;
                     }
+                    let name = symbol.indexed_name();
                     if self.exact {
-                        if symbol.name != self.query {
+                        if *name != self.query {
                             continue;
                         }
                     } else if self.case_sensitive {
-                        if self.query.chars().any(|c| !symbol.name.contains(c)) {
+                        if self.query.chars().any(|c| !name.contains(c)) {
                             continue;
                         }
                     }
@@ -364,6 +365,15 @@ pub struct FileSymbol {
     pub loc: DeclarationLocation,
     pub kind: FileSymbolKind,
     pub container_name: Option<SmolStr>,
+    /// The `#[doc(alias = "...")]` this symbol is indexed under, instead of its name.
+    pub doc_alias: Option<SmolStr>,
+}
+
+impl FileSymbol {
+    /// The name queries are matched against.
+    fn indexed_name(&self) -> &SmolStr {
+        self.doc_alias.as_ref().unwrap_or(&self.name)
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -408,8 +418,10 @@ pub enum FileSymbolKind {
     Const,
     Enum,
     Function,
+    Impl,
     Macro,
     Module,
+    ProcMacro,
     Static,
     Struct,
     Trait,
@@ -436,8 +448,10 @@ impl From<FileSymbolKind> for SymbolKind {
             FileSymbolKind::Const => SymbolKind::Const,
             FileSymbolKind::Enum => SymbolKind::Enum,
             FileSymbolKind::Function => SymbolKind::Function,
+            FileSymbolKind::Impl => SymbolKind::Impl,
             FileSymbolKind::Macro => SymbolKind::Macro,
             FileSymbolKind::Module => SymbolKind::Module,
+            FileSymbolKind::ProcMacro => SymbolKind::Function,
             FileSymbolKind::Static => SymbolKind::Static,
             FileSymbolKind::Struct => SymbolKind::Struct,
             FileSymbolKind::Trait => SymbolKind::Trait,
@@ -525,6 +539,7 @@ impl<'a> SymbolCollector<'a> {
         }
 
         for impl_id in scope.impls() {
+            self.push_impl(impl_id);
             self.collect_from_impl(impl_id);
         }
 
@@ -606,7 +621,7 @@ impl<'a> SymbolCollector<'a> {
 
     fn push_decl_assoc<L, T>(&mut self, id: L, kind: FileSymbolKind)
     where
-        L: Lookup<Data = AssocItemLoc<T>>,
+        L: Lookup<Data = AssocItemLoc<T>> + Into<ModuleDefId> + Copy,
         T: ItemTreeNode,
         <T as ItemTreeNode>::Source: HasName,
     {
@@ -624,7 +639,8 @@ impl<'a> SymbolCollector<'a> {
             }
         }
 
-        self.push_file_symbol(|s| {
+        let doc_aliases = self.doc_aliases(ModuleDef::from(id.into()).attrs(self.db.upcast()));
+        self.push_file_symbol(doc_aliases, |s| {
             let loc = id.lookup(s.db.upcast());
             let source = loc.source(s.db.upcast());
             let name_node = source.value.name()?;
@@ -635,6 +651,7 @@ impl<'a> SymbolCollector<'a> {
                 name: name_node.text().into(),
                 kind,
                 container_name,
+                doc_alias: None,
                 loc: DeclarationLocation {
                     hir_file_id: source.file_id,
                     ptr: SyntaxNodePtr::new(source.value.syntax()),
@@ -646,11 +663,12 @@ impl<'a> SymbolCollector<'a> {
 
     fn push_decl<L, T>(&mut self, id: L, kind: FileSymbolKind)
     where
-        L: Lookup<Data = ItemLoc<T>>,
+        L: Lookup<Data = ItemLoc<T>> + Into<ModuleDefId> + Copy,
         T: ItemTreeNode,
         <T as ItemTreeNode>::Source: HasName,
     {
-        self.push_file_symbol(|s| {
+        let doc_aliases = self.doc_aliases(ModuleDef::from(id.into()).attrs(self.db.upcast()));
+        self.push_file_symbol(doc_aliases, |s| {
             let loc = id.lookup(s.db.upcast());
             let source = loc.source(s.db.upcast());
             let name_node = source.value.name()?;
@@ -659,6 +677,7 @@ impl<'a> SymbolCollector<'a> {
                 name: name_node.text().into(),
                 kind,
                 container_name: s.current_container_name(),
+                doc_alias: None,
                 loc: DeclarationLocation {
                     hir_file_id: source.file_id,
                     ptr: SyntaxNodePtr::new(source.value.syntax()),
@@ -669,7 +688,8 @@ impl<'a> SymbolCollector<'a> {
     }
 
     fn push_module(&mut self, module_id: ModuleId) {
-        self.push_file_symbol(|s| {
+        let doc_aliases = self.doc_aliases(Some(Module::from(module_id).attrs(self.db.upcast())));
+        self.push_file_symbol(doc_aliases, |s| {
             let def_map = module_id.def_map(s.db.upcast());
             let module_data = &def_map[module_id.local_id];
             let declaration = module_data.origin.declaration()?;
@@ -680,6 +700,7 @@ impl<'a> SymbolCollector<'a> {
                 name: name_node.text().into(),
                 kind: FileSymbolKind::Module,
                 container_name: s.current_container_name(),
+                doc_alias: None,
                 loc: DeclarationLocation {
                     hir_file_id: declaration.file_id,
                     ptr: SyntaxNodePtr::new(module.syntax()),
@@ -690,30 +711,74 @@ impl<'a> SymbolCollector<'a> {
     }
 
     fn push_decl_macro(&mut self, macro_def: MacroDef) {
-        self.push_file_symbol(|s| {
+        let doc_aliases = self.doc_aliases(Some(macro_def.attrs(self.db.upcast())));
+        self.push_file_symbol(doc_aliases, |s| {
             let name = macro_def.name(s.db.upcast())?.as_text()?;
             let source = macro_def.source(s.db.upcast())?;
 
-            let (ptr, name_ptr) = match source.value {
-                Either::Left(m) => {
-                    (SyntaxNodePtr::new(m.syntax()), SyntaxNodePtr::new(m.name()?.syntax()))
-                }
-                Either::Right(f) => {
-                    (SyntaxNodePtr::new(f.syntax()), SyntaxNodePtr::new(f.name()?.syntax()))
-                }
+            let (ptr, name_ptr, kind) = match source.value {
+                Either::Left(m) => (
+                    SyntaxNodePtr::new(m.syntax()),
+                    SyntaxNodePtr::new(m.name()?.syntax()),
+                    FileSymbolKind::Macro,
+                ),
+                Either::Right(f) => (
+                    SyntaxNodePtr::new(f.syntax()),
+                    SyntaxNodePtr::new(f.name()?.syntax()),
+                    FileSymbolKind::ProcMacro,
+                ),
             };
 
             Some(FileSymbol {
                 name,
-                kind: FileSymbolKind::Macro,
+                kind,
                 container_name: s.current_container_name(),
+                doc_alias: None,
                 loc: DeclarationLocation { hir_file_id: source.file_id, name_ptr, ptr },
             })
         })
     }
 
-    fn push_file_symbol(&mut self, f: impl FnOnce(&Self) -> Option<FileSymbol>) {
+    fn push_impl(&mut self, impl_id: ImplId) {
+        self.push_file_symbol(Vec::new(), |s| {
+            let loc = impl_id.lookup(s.db.upcast());
+            let source = loc.source(s.db.upcast());
+            let self_ty = source.value.self_ty()?;
+
+            Some(FileSymbol {
+                name: self_ty.syntax().text().to_string().into(),
+                kind: FileSymbolKind::Impl,
+                container_name: s.current_container_name(),
+                doc_alias: None,
+                loc: DeclarationLocation {
+                    hir_file_id: source.file_id,
+                    ptr: SyntaxNodePtr::new(source.value.syntax()),
+                    name_ptr: SyntaxNodePtr::new(self_ty.syntax()),
+                },
+            })
+        })
+    }
+
+    fn doc_aliases(&self, attrs: Option<AttrsWithOwner>) -> Vec<SmolStr> {
+        attrs.map(|attrs| attrs.doc_aliases()).unwrap_or_default()
+    }
+
+    /// Pushes the symbol built by `f`, along with a copy of it for each of its `doc_aliases`.
+    fn push_file_symbol(
+        &mut self,
+        doc_aliases: Vec<SmolStr>,
+        f: impl FnOnce(&Self) -> Option<FileSymbol>,
+    ) {
         if let Some(file_symbol) = f(self) {
+            // Skip names generated by the compiler or by macros, like `__Visitor`.
+            if file_symbol.name.starts_with("__") {
+                return;
+            }
+            self.symbols.extend(
+                doc_aliases
+                    .into_iter()
+                    .map(|alias| FileSymbol { doc_alias: Some(alias), ..file_symbol.clone() }),
+            );
             self.symbols.push(file_symbol);
         }
     }
@@ -723,7 +788,7 @@ impl<'a> SymbolCollector<'a> {
 mod tests {
 
     use base_db::fixture::WithFixture;
-    use expect_test::expect_file;
+    use expect_test::{expect, expect_file};
 
     use super::*;
 
@@ -801,4 +866,47 @@ struct StructInModB;
 
         expect_file!["./test_data/test_symbol_index_collection.txt"].assert_debug_eq(&symbols);
     }
+
+    #[test]
+    fn test_symbol_kinds() {
+        let (db, _) = RootDatabase::with_many_files(
+            r#"
+//- /main.rs
+struct Struct;
+enum Enum {}
+union Union {}
+trait Trait {}
+type Alias = Struct;
+const CONST: u32 = 1;
+static STATIC: u32 = 2;
+fn function() {}
+impl Struct {}
+impl Trait for Struct {}
+macro_rules! macro_rules_macro { () => {} }
+mod module {}
+struct __Generated;
+        "#,
+        );
+
+        let symbols: Vec<_> = module_ids_for_crate(db.upcast(), db.test_crate())
+            .into_iter()
+            .flat_map(|module_id| SymbolCollector::collect(&db, module_id))
+            .map(|symbol| format!("{} {:?}\n", symbol.name, symbol.kind))
+            .collect();
+        expect![[r#"
+            Struct Struct
+            Enum Enum
+            Union Union
+            Trait Trait
+            Alias TypeAlias
+            CONST Const
+            STATIC Static
+            function Function
+            module Module
+            Struct Impl
+            Struct Impl
+            macro_rules_macro Macro
+        "#]]
+        .assert_eq(&symbols.concat());
+    }
 }
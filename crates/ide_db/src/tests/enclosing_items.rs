@@ -0,0 +1,94 @@
+//! Tests for `Semantics::enclosing_impl` and `Semantics::enclosing_fn`.
+
+use base_db::fixture::WithFixture;
+use expect_test::{expect, Expect};
+use hir::Semantics;
+use syntax::{
+    ast::{self, HasName},
+    AstNode,
+};
+
+use crate::RootDatabase;
+
+fn check(ra_fixture: &str, expect: Expect) {
+    let (db, position) = RootDatabase::with_position(ra_fixture);
+    let sema = Semantics::new(&db);
+    let root = sema.parse(position.file_id).syntax().clone();
+    let token = root.token_at_offset(position.offset).right_biased().unwrap();
+    let node = sema.descend_into_macros_single(token).parent().unwrap();
+
+    let mut actual = String::new();
+    if node.ancestors().last().unwrap() != root {
+        actual.push_str("in macro expansion\n");
+    }
+    if let Some(fn_) = sema.enclosing_fn(&node) {
+        // The enclosing items are looked up in the file of the outermost macro call.
+        assert_eq!(fn_.syntax().ancestors().last().unwrap(), root);
+        actual.push_str(&format!("fn {}\n", fn_.name().unwrap()));
+    }
+    if let Some(impl_) = sema.enclosing_impl(&node) {
+        assert_eq!(impl_.syntax().ancestors().last().unwrap(), root);
+        actual.push_str(&format!("impl {}\n", impl_.self_ty().unwrap()));
+    }
+    expect.assert_eq(&actual);
+}
+
+#[test]
+fn finds_nearest_fn() {
+    check(
+        r#"
+fn outer() {
+    fn inner(x$0: u32) {}
+}
+"#,
+        expect![[r#"
+            fn inner
+        "#]],
+    );
+}
+
+#[test]
+fn finds_impl_of_method() {
+    check(
+        r#"
+struct S;
+impl S {
+    fn method(&self) { let _ = $01; }
+}
+"#,
+        expect![[r#"
+            fn method
+            impl S
+        "#]],
+    );
+}
+
+#[test]
+fn nothing_encloses_top_level_items() {
+    check(
+        r#"
+struct S$0;
+"#,
+        expect![[r#""#]],
+    );
+}
+
+#[test]
+fn climbs_out_of_macro_calls() {
+    check(
+        r#"
+macro_rules! id { ($($t:tt)*) => { $($t)* } }
+struct S;
+impl S {
+    fn method(&self) -> u32 {
+        id!(1 + $02)
+    }
+}
+"#,
+        expect![[r#"
+            in macro expansion
+            fn method
+            impl S
+        "#]],
+    );
+}
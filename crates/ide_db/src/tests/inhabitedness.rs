@@ -0,0 +1,104 @@
+//! Tests for `hir::Type::is_inhabited`.
+
+use base_db::fixture::WithFixture;
+use hir::{HirDisplay, Semantics};
+use syntax::{algo::find_node_at_offset, ast, AstNode};
+
+use crate::RootDatabase;
+
+/// Checks the type of the parameter at the cursor.
+fn check(ra_fixture: &str, inhabited: bool) {
+    let (db, position) = RootDatabase::with_position(ra_fixture);
+    let sema = Semantics::new(&db);
+    let file = sema.parse(position.file_id);
+    let pat = find_node_at_offset::<ast::Pat>(file.syntax(), position.offset).unwrap();
+    let ty = sema.type_of_pat(&pat).unwrap().original;
+    assert_eq!(ty.is_inhabited(&db), inhabited, "{}", ty.display(&db));
+}
+
+#[test]
+fn never_type() {
+    check("fn f(x$0: !) {}", false);
+}
+
+#[test]
+fn empty_enum() {
+    check(
+        r#"
+enum Void {}
+fn f(x$0: Void) {}
+"#,
+        false,
+    );
+}
+
+#[test]
+fn enum_with_one_inhabited_variant() {
+    check(
+        r#"
+enum Void {}
+enum Outcome {
+    Value(u32),
+    Error(Void),
+}
+fn f(x$0: Outcome) {}
+"#,
+        true,
+    );
+}
+
+#[test]
+fn enum_without_inhabited_variants() {
+    check(
+        r#"
+enum Void {}
+enum Never {
+    A(!),
+    B(Void, u32),
+}
+fn f(x$0: Never) {}
+"#,
+        false,
+    );
+}
+
+#[test]
+fn structs_and_tuples_with_uninhabited_fields() {
+    check(
+        r#"
+enum Void {}
+struct Wrapper { value: u32, void: Void }
+fn f(x$0: Wrapper) {}
+"#,
+        false,
+    );
+    check(
+        r#"
+enum Void {}
+fn f(x$0: (u32, Void)) {}
+"#,
+        false,
+    );
+}
+
+#[test]
+fn inhabited_types() {
+    check("fn f(x$0: u32) {}", true);
+    check("fn f(x$0: ()) {}", true);
+    // References to uninhabited types are conservatively considered inhabited.
+    check(
+        r#"
+enum Void {}
+fn f(x$0: &Void) {}
+"#,
+        true,
+    );
+    // So are recursive types.
+    check(
+        r#"
+struct List { next: Option<Box<List>> }
+fn f(x$0: List) {}
+"#,
+        true,
+    );
+}
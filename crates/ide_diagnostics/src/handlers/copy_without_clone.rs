@@ -0,0 +1,125 @@
+use ide_db::{
+    assists::Assist, base_db::FileId, helpers::famous_defs::FamousDefs, source_change::SourceChange,
+};
+use syntax::{
+    ast::{self, HasAttrs},
+    AstNode, SyntaxKind, SyntaxNode, SyntaxToken,
+};
+use text_edit::TextEdit;
+
+use crate::{fix, Diagnostic, DiagnosticsContext};
+
+// Diagnostic: copy-without-clone
+//
+// This diagnostic is triggered if a type derives `Copy`, but neither derives nor implements
+// `Clone`, which is a supertrait of `Copy`.
+//
+// Example:
+//
+// ```rust
+// #[derive(Copy)]
+// struct S;
+// ```
+pub(crate) fn copy_without_clone(
+    ctx: &DiagnosticsContext,
+    acc: &mut Vec<Diagnostic>,
+    file_id: FileId,
+    node: &SyntaxNode,
+) -> Option<()> {
+    let adt = ast::Adt::cast(node.clone())?;
+    let derives: Vec<SyntaxToken> = adt
+        .attrs()
+        .filter(|attr| attr.simple_name().map_or(false, |name| name == "derive"))
+        .filter_map(|attr| attr.token_tree())
+        .flat_map(|tt| tt.syntax().children_with_tokens().filter_map(|it| it.into_token()))
+        .filter(|token| token.kind() == SyntaxKind::IDENT)
+        .collect();
+    let copy = derives.iter().find(|token| token.text() == "Copy")?;
+    if derives.iter().any(|token| token.text() == "Clone") {
+        return None;
+    }
+
+    let famous_defs = FamousDefs(&ctx.sema, ctx.sema.scope(adt.syntax()).krate());
+    let clone_trait = famous_defs.core_clone_Clone()?;
+    let ty = ctx.sema.to_def(&adt)?.ty(ctx.sema.db);
+    let implements_clone = hir::Impl::all_for_type(ctx.sema.db, ty)
+        .into_iter()
+        .any(|imp| imp.trait_(ctx.sema.db) == Some(clone_trait));
+    if implements_clone {
+        return None;
+    }
+
+    acc.push(
+        Diagnostic::new(
+            "copy-without-clone",
+            "`Copy` is derived, but `Clone` is not implemented",
+            copy.text_range(),
+        )
+        .with_fixes(Some(fixes(file_id, copy))),
+    );
+
+    Some(())
+}
+
+fn fixes(file_id: FileId, copy: &SyntaxToken) -> Vec<Assist> {
+    let range = copy.text_range();
+    let edit = TextEdit::insert(range.start(), "Clone, ".to_string());
+    vec![fix("derive_clone", "Derive `Clone`", SourceChange::from_text_edit(file_id, edit), range)]
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::{check_diagnostics, check_fix};
+
+    #[test]
+    fn copy_without_clone() {
+        check_diagnostics(
+            r#"
+//- minicore: derive, copy
+#[derive(Copy)]
+       //^^^^ 💡 error: `Copy` is derived, but `Clone` is not implemented
+struct S;
+
+#[derive(Debug, Copy)]
+              //^^^^ 💡 error: `Copy` is derived, but `Clone` is not implemented
+enum E {}
+"#,
+        );
+    }
+
+    #[test]
+    fn copy_with_clone() {
+        check_diagnostics(
+            r#"
+//- minicore: derive, copy
+#[derive(Copy, Clone)]
+struct S;
+
+#[derive(Clone)]
+#[derive(Copy)]
+struct T;
+
+#[derive(Copy)]
+struct U<X>(X);
+impl<X: Clone> Clone for U<X> {
+    fn clone(&self) -> Self { loop {} }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn add_clone_derive() {
+        check_fix(
+            r#"
+//- minicore: derive, copy
+#[derive(Debug, Cop$0y)]
+struct S;
+"#,
+            r#"
+#[derive(Debug, Clone, Copy)]
+struct S;
+"#,
+        );
+    }
+}
@@ -0,0 +1,175 @@
+use ide_db::{
+    defs::Definition,
+    search::{FileReference, ReferenceCategory},
+};
+use syntax::{
+    ast::{self, HasAttrs, HasName, HasVisibility},
+    AstNode, SyntaxNode,
+};
+
+use crate::{Diagnostic, DiagnosticsContext, Severity};
+
+// Diagnostic: dead-field
+//
+// This diagnostic is triggered if a private field of a struct is never read. Fields of
+// `#[repr(C)]` structs are exempt, as they may be read through FFI.
+//
+// Example:
+//
+// ```rust
+// struct Counter { hits: u32 }
+//
+// impl Counter {
+//     fn hit(&mut self) { self.hits = 1; }
+// }
+// ```
+pub(crate) fn dead_field(
+    ctx: &DiagnosticsContext,
+    acc: &mut Vec<Diagnostic>,
+    node: &SyntaxNode,
+) -> Option<()> {
+    let field = &ast::RecordField::cast(node.clone())?;
+    if field.visibility().is_some() {
+        return None;
+    }
+    let strukt = field.syntax().parent()?.parent().and_then(ast::Struct::cast)?;
+    if is_repr_c(&strukt) {
+        return None;
+    }
+
+    let name = field.name()?;
+    let def = ctx.sema.to_def(field)?;
+    let usages = Definition::Field(def).usages(&ctx.sema).all();
+    let is_read = usages.iter().flat_map(|(_, refs)| refs).any(is_read);
+    if is_read {
+        return None;
+    }
+
+    acc.push(
+        Diagnostic::new(
+            "dead-field",
+            format!("field `{}` is never read", name),
+            name.syntax().text_range(),
+        )
+        .severity(Severity::WeakWarning)
+        .with_unused(true)
+        .with_lint_name("dead_code")
+        .experimental(),
+    );
+
+    Some(())
+}
+
+fn is_repr_c(strukt: &ast::Struct) -> bool {
+    strukt.attrs().any(|attr| {
+        let is_repr = attr.simple_name().map_or(false, |name| name == "repr");
+        let has_c = attr.token_tree().map_or(false, |tt| {
+            tt.syntax()
+                .children_with_tokens()
+                .filter_map(|it| it.into_token())
+                .any(|token| token.text() == "C")
+        });
+        is_repr && has_c
+    })
+}
+
+fn is_read(reference: &FileReference) -> bool {
+    if reference.category != Some(ReferenceCategory::Read) {
+        return false;
+    }
+    // Initializing a field in a struct literal doesn't read it.
+    match &reference.name {
+        ast::NameLike::NameRef(name_ref) => {
+            ast::RecordExprField::for_field_name(name_ref).is_none()
+        }
+        _ => true,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        tests::{check_diagnostics_with_config, check_suppress_fix},
+        DiagnosticsConfig,
+    };
+
+    fn check(ra_fixture: &str) {
+        let config = DiagnosticsConfig::default();
+        check_diagnostics_with_config(config, ra_fixture)
+    }
+
+    #[test]
+    fn written_only_field() {
+        check(
+            r#"
+struct Counter { hits: u32 }
+               //^^^^ weak: field `hits` is never read
+
+impl Counter {
+    fn new() -> Counter { Counter { hits: 0 } }
+    fn hit(&mut self) { self.hits = 1; }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn mixed_fields() {
+        check(
+            r#"
+struct S { read: u32, written: u32 }
+                    //^^^^^^^ weak: field `written` is never read
+
+fn f(read: u32) -> u32 {
+    let mut s = S { read, written: 0 };
+    s.written = 92;
+    let S { read, .. } = s;
+    read + s.read
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn ignores_pub_and_repr_c_fields() {
+        check(
+            r#"
+pub struct S { pub written: u32, pub(crate) also_written: u32 }
+
+#[repr(C)]
+struct Ffi { written: u32 }
+
+fn f(s: &mut S, ffi: &mut Ffi) {
+    s.written = 0;
+    s.also_written = 0;
+    ffi.written = 0;
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn suppress_dead_field() {
+        check_suppress_fix(
+            r#"
+pub struct S {
+    used: u32,
+    unused$0: u32,
+}
+pub fn f(s: S) -> u32 {
+    s.used
+}
+"#,
+            r#"
+#[allow(dead_code)]
+pub struct S {
+    used: u32,
+    unused: u32,
+}
+pub fn f(s: S) -> u32 {
+    s.used
+}
+"#,
+        );
+    }
+}
@@ -48,6 +48,7 @@ fn check_expr_field_shorthand(
         acc.push(
             Diagnostic::new("use-field-shorthand", "Shorthand struct initialization", field_range)
                 .severity(Severity::WeakWarning)
+                .with_lint_name("clippy::redundant_field_names")
                 .with_fixes(Some(vec![fix(
                     "use_expr_field_shorthand",
                     "Use struct shorthand initialization",
@@ -89,6 +90,7 @@ fn check_pat_field_shorthand(
         acc.push(
             Diagnostic::new("use-field-shorthand", "Shorthand struct pattern", field_range)
                 .severity(Severity::WeakWarning)
+                .with_lint_name("clippy::redundant_field_names")
                 .with_fixes(Some(vec![fix(
                     "use_pat_field_shorthand",
                     "Use struct field shorthand",
@@ -0,0 +1,248 @@
+use ide_db::{base_db::FileId, format_args::FormatArgs, source_change::SourceChange};
+use syntax::{
+    ast::{self, FormatSpecifier, HasFormatSpecifier},
+    AstNode, AstToken, SyntaxNode, TextRange,
+};
+use text_edit::TextEdit;
+
+use crate::{fix, Diagnostic};
+
+// Diagnostic: format-arg-count-mismatch
+//
+// This diagnostic is triggered if the number of positional arguments passed to a formatting
+// macro like `format!` or `println!` does not match the number of arguments the format string
+// expects.
+//
+// Example:
+//
+// ```rust
+// format!("{} {}", x);
+// ```
+pub(crate) fn format_args(acc: &mut Vec<Diagnostic>, file_id: FileId, node: &SyntaxNode) {
+    let macro_call = match ast::MacroCall::cast(node.clone()) {
+        Some(it) => it,
+        None => return,
+    };
+    let _ = check_format_args(acc, file_id, &macro_call);
+}
+
+fn check_format_args(
+    acc: &mut Vec<Diagnostic>,
+    file_id: FileId,
+    macro_call: &ast::MacroCall,
+) -> Option<()> {
+    let FormatArgs { format_string, args } = FormatArgs::from_macro_call(macro_call)?;
+
+    let expected = expected_positional_count(&format_string);
+    let positional: Vec<TextRange> =
+        args.iter().filter(|arg| arg.name.is_none()).map(|arg| arg.range).collect();
+    let found = positional.len();
+    if expected == found {
+        return None;
+    }
+
+    let s = if expected == 1 { "" } else { "s" };
+    let message = format!("expected {} format argument{}, found {}", expected, s, found);
+    let range = macro_call.syntax().text_range();
+
+    let mut builder = TextEdit::builder();
+    let label = if found < expected {
+        let insert_at =
+            args.last().map_or(format_string.syntax().text_range(), |arg| arg.range).end();
+        builder.insert(insert_at, ", todo!()".repeat(expected - found));
+        "Add missing format arguments"
+    } else {
+        let mut prev_end = match expected {
+            0 => format_string.syntax().text_range().end(),
+            _ => positional[expected - 1].end(),
+        };
+        for arg in args.iter().map(|arg| arg.range) {
+            if positional[expected..].contains(&arg) {
+                builder.delete(TextRange::new(prev_end, arg.end()));
+            }
+            prev_end = arg.end();
+        }
+        "Remove extra format arguments"
+    };
+
+    acc.push(Diagnostic::new("format-arg-count-mismatch", message, range).with_fixes(Some(vec![
+        fix(
+            "fix_format_arg_count",
+            label,
+            SourceChange::from_text_edit(file_id, builder.finish()),
+            range,
+        ),
+    ])));
+
+    Some(())
+}
+
+/// Computes the number of positional arguments a format string refers to, either implicitly
+/// (`{}`, `{:.*}`) or explicitly by index (`{0}`, `{:1$}`).
+fn expected_positional_count(format_string: &ast::String) -> usize {
+    let text = format_string.text();
+    let mut specifiers = Vec::new();
+    format_string.lex_format_specifier(|range, kind| specifiers.push((range, kind)));
+
+    let mut implicit = 0;
+    let mut max_explicit = None;
+    let mut has_argument = false;
+    for (idx, (range, kind)) in specifiers.iter().enumerate() {
+        let prev = idx.checked_sub(1).map(|idx| &specifiers[idx].1);
+        let next = specifiers.get(idx + 1).map(|(_, kind)| kind);
+        match kind {
+            FormatSpecifier::Open => has_argument = false,
+            FormatSpecifier::Integer
+                if matches!(prev, Some(FormatSpecifier::Open))
+                    || matches!(next, Some(FormatSpecifier::DollarSign)) =>
+            {
+                if let Ok(index) = text[*range].parse::<usize>() {
+                    max_explicit = max_explicit.max(Some(index));
+                }
+                if matches!(prev, Some(FormatSpecifier::Open)) {
+                    has_argument = true;
+                }
+            }
+            FormatSpecifier::Identifier if matches!(prev, Some(FormatSpecifier::Open)) => {
+                has_argument = true;
+            }
+            FormatSpecifier::Asterisk => implicit += 1,
+            FormatSpecifier::Close if !has_argument => implicit += 1,
+            _ => (),
+        }
+    }
+
+    max_explicit.map_or(implicit, |max| implicit.max(max + 1))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::{check_diagnostics, check_fix};
+
+    #[test]
+    fn correct_arg_count() {
+        check_diagnostics(
+            r#"
+macro_rules! format { ($($tt:tt)*) => {}; }
+macro_rules! println { ($($tt:tt)*) => {}; }
+macro_rules! writeln { ($($tt:tt)*) => {}; }
+fn main() {
+    let x = 92;
+    format!("{} {}", x, x);
+    format!("{{}} {x}");
+    format!("{0} {0:?} {name}", x, name = x);
+    format!("{:.*} {:1$}", 2, 1.0, x);
+    println!();
+    writeln!(f, "{}", x);
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn missing_args() {
+        check_diagnostics(
+            r#"
+macro_rules! format { ($($tt:tt)*) => {}; }
+macro_rules! println { ($($tt:tt)*) => {}; }
+macro_rules! write { ($($tt:tt)*) => {}; }
+fn main() {
+    let x = 92;
+    format!("{} {}", x);
+  //^^^^^^^^^^^^^^^^^^^ 💡 error: expected 2 format arguments, found 1
+    println!("{1}");
+  //^^^^^^^^^^^^^^^ 💡 error: expected 2 format arguments, found 0
+    write!(f, "{}", name = x);
+  //^^^^^^^^^^^^^^^^^^^^^^^^^ 💡 error: expected 1 format argument, found 0
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn extra_args() {
+        check_diagnostics(
+            r#"
+macro_rules! format { ($($tt:tt)*) => {}; }
+macro_rules! eprintln { ($($tt:tt)*) => {}; }
+fn main() {
+    let x = 92;
+    format!("{}", x, x);
+  //^^^^^^^^^^^^^^^^^^^ 💡 error: expected 1 format argument, found 2
+    eprintln!("no args", x);
+  //^^^^^^^^^^^^^^^^^^^^^^^ 💡 error: expected 0 format arguments, found 1
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn add_missing_args() {
+        check_fix(
+            r#"
+macro_rules! format { ($($tt:tt)*) => {}; }
+fn main() {
+    let x = 92;
+    format!$0("{} {} {}", x);
+}
+"#,
+            r#"
+macro_rules! format { ($($tt:tt)*) => {}; }
+fn main() {
+    let x = 92;
+    format!("{} {} {}", x, todo!(), todo!());
+}
+"#,
+        );
+        check_fix(
+            r#"
+macro_rules! println { ($($tt:tt)*) => {}; }
+fn main() {
+    println!$0("{}",);
+}
+"#,
+            r#"
+macro_rules! println { ($($tt:tt)*) => {}; }
+fn main() {
+    println!("{}", todo!(),);
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn remove_extra_args() {
+        check_fix(
+            r#"
+macro_rules! format { ($($tt:tt)*) => {}; }
+fn main() {
+    let x = 92;
+    format!$0("{}", x, x + 1, x + 2);
+}
+"#,
+            r#"
+macro_rules! format { ($($tt:tt)*) => {}; }
+fn main() {
+    let x = 92;
+    format!("{}", x);
+}
+"#,
+        );
+        check_fix(
+            r#"
+macro_rules! format { ($($tt:tt)*) => {}; }
+fn main() {
+    let x = 92;
+    format!$0("{} {y}", x, x, y = x);
+}
+"#,
+            r#"
+macro_rules! format { ($($tt:tt)*) => {}; }
+fn main() {
+    let x = 92;
+    format!("{} {y}", x, y = x);
+}
+"#,
+        );
+    }
+}
@@ -1,4 +1,4 @@
-use hir::{db::AstDatabase, InFile};
+use hir::{db::AstDatabase, diagnostics::CaseType, InFile};
 use ide_db::{assists::Assist, defs::NameClass};
 use syntax::AstNode;
 
@@ -23,9 +23,18 @@ pub(crate) fn incorrect_case(ctx: &DiagnosticsContext<'_>, d: &hir::IncorrectCas
         ctx.sema.diagnostics_display_range(InFile::new(d.file, d.ident.clone().into())).range,
     )
     .severity(Severity::WeakWarning)
+    .with_lint_name(lint_name(&d.expected_case))
     .with_fixes(fixes(ctx, d))
 }
 
+fn lint_name(expected_case: &CaseType) -> &'static str {
+    match expected_case {
+        CaseType::LowerSnakeCase => "non_snake_case",
+        CaseType::UpperSnakeCase => "non_upper_case_globals",
+        CaseType::UpperCamelCase => "non_camel_case_types",
+    }
+}
+
 fn fixes(ctx: &DiagnosticsContext<'_>, d: &hir::IncorrectCase) -> Option<Vec<Assist>> {
     let root = ctx.sema.db.parse_or_expand(d.file)?;
     let name_node = d.ident.to_node(&root);
@@ -46,7 +55,7 @@ fn fixes(ctx: &DiagnosticsContext<'_>, d: &hir::IncorrectCase) -> Option<Vec<Ass
 
 #[cfg(test)]
 mod change_case {
-    use crate::tests::{check_diagnostics, check_fix};
+    use crate::tests::{check_diagnostics, check_fix, check_suppress_fix};
 
     #[test]
     fn test_rename_incorrect_case() {
@@ -483,4 +492,60 @@ pub static SomeStatic: u8 = 10;
     "#,
         );
     }
+
+    #[test]
+    fn suppress_incorrect_case() {
+        check_suppress_fix(
+            r#"
+struct S;
+impl S {
+    /// Docs.
+    fn NonSnakeCase$0() {}
+}
+"#,
+            r#"
+struct S;
+impl S {
+    #[allow(non_snake_case)]
+    /// Docs.
+    fn NonSnakeCase() {}
+}
+"#,
+        );
+        check_suppress_fix(
+            r#"
+fn f() {
+    let Foo$0 = 92;
+}
+"#,
+            r#"
+#[allow(non_snake_case)]
+fn f() {
+    let Foo = 92;
+}
+"#,
+        );
+        check_suppress_fix(
+            r#"
+const foo$0: u8 = 0;
+"#,
+            r#"
+#[allow(non_upper_case_globals)]
+const foo: u8 = 0;
+"#,
+        );
+        check_suppress_fix(
+            r#"
+mod m {
+    enum my_enum$0 {}
+}
+"#,
+            r#"
+mod m {
+    #[allow(non_camel_case_types)]
+    enum my_enum {}
+}
+"#,
+        );
+    }
 }
@@ -0,0 +1,174 @@
+use hir::db::AstDatabase;
+use ide_db::{assists::Assist, source_change::SourceChange};
+use syntax::{ast, AstNode, AstToken, TextRange};
+use text_edit::TextEdit;
+
+use crate::{fix, Diagnostic, DiagnosticsContext, Severity};
+
+// Diagnostic: irrefutable-let-pattern
+//
+// This diagnostic is triggered if the pattern of an `if let` expression always matches, so the
+// `if let` can be replaced with a plain `let` statement.
+//
+// Example:
+//
+// ```rust
+// if let x = 92 {}
+// ```
+pub(crate) fn irrefutable_let_pattern(
+    ctx: &DiagnosticsContext<'_>,
+    d: &hir::IrrefutableLetPattern,
+) -> Diagnostic {
+    Diagnostic::new(
+        "irrefutable-let-pattern",
+        "irrefutable `if let` pattern",
+        ctx.sema.diagnostics_display_range(d.if_expr.clone().map(|it| it.into())).range,
+    )
+    .severity(Severity::WeakWarning)
+    .with_lint_name("irrefutable_let_patterns")
+    .with_fixes(fixes(ctx, d))
+}
+
+fn fixes(ctx: &DiagnosticsContext<'_>, d: &hir::IrrefutableLetPattern) -> Option<Vec<Assist>> {
+    let root = ctx.sema.db.parse_or_expand(d.if_expr.file_id)?;
+    let if_expr = d.if_expr.value.to_node(&root);
+    // The `else` branch would become dead code, let the user decide what to do with it.
+    if if_expr.else_branch().is_some() {
+        return None;
+    }
+    let condition = if_expr.condition()?;
+    let (pat, expr) = (condition.pat()?, condition.expr()?);
+    let l_curly = if_expr.then_branch()?.stmt_list()?.l_curly_token()?;
+
+    // Keep the formatting of the block by reusing the whitespace after the `{`.
+    let ws = l_curly.next_token().and_then(ast::Whitespace::cast);
+    let ws = ws.as_ref().map_or(" ", |it| it.text());
+
+    let range = TextRange::new(if_expr.syntax().text_range().start(), l_curly.text_range().end());
+    let edit = TextEdit::replace(range, format!("{{{}let {} = {};", ws, pat, expr));
+    let source_change =
+        SourceChange::from_text_edit(d.if_expr.file_id.original_file(ctx.sema.db), edit);
+    Some(vec![fix(
+        "replace_if_let_with_let",
+        "Replace `if let` with `let`",
+        source_change,
+        if_expr.syntax().text_range(),
+    )])
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::{check_diagnostics, check_fix, check_no_fix, check_suppress_fix};
+
+    #[test]
+    fn identifier_pattern() {
+        check_diagnostics(
+            r#"
+fn f(x: u32) {
+    if let y = x {}
+  //^^^^^^^^^^^^^^^ 💡 weak: irrefutable `if let` pattern
+    if let _ = x {}
+  //^^^^^^^^^^^^^^^ 💡 weak: irrefutable `if let` pattern
+    if let y @ _ = x {}
+  //^^^^^^^^^^^^^^^^^^^ 💡 weak: irrefutable `if let` pattern
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn tuple_pattern() {
+        check_diagnostics(
+            r#"
+fn f(x: (u32, (bool, char))) {
+    if let (a, (_, c)) = x {}
+  //^^^^^^^^^^^^^^^^^^^^^^^^^ 💡 weak: irrefutable `if let` pattern
+    if let (a, ..) = &x {}
+  //^^^^^^^^^^^^^^^^^^^^^^ 💡 weak: irrefutable `if let` pattern
+    if let (0, _) = x {}
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn struct_pattern() {
+        check_diagnostics(
+            r#"
+struct S { a: u32, b: (u32, u32) }
+struct T(u32, S);
+enum E { A(u32), B }
+
+fn f(s: S, t: T, e: E) {
+    if let S { a, b: (_, c) } = s {}
+  //^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^ 💡 weak: irrefutable `if let` pattern
+    if let T(_, S { .. }) = t {}
+  //^^^^^^^^^^^^^^^^^^^^^^^^^^^^ 💡 weak: irrefutable `if let` pattern
+    if let T(0, _) = t {}
+    if let E::A(a) = e {}
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn replace_with_let() {
+        check_fix(
+            r#"
+fn f(x: (u32, u32)) -> u32 {
+    if let$0 (a, b) = x {
+        a + b
+    }
+}
+"#,
+            r#"
+fn f(x: (u32, u32)) -> u32 {
+    {
+        let (a, b) = x;
+        a + b
+    }
+}
+"#,
+        );
+        check_fix(
+            r#"
+fn f(x: u32) {
+    let y = if let$0 y = x { y };
+}
+"#,
+            r#"
+fn f(x: u32) {
+    let y = { let y = x; y };
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn no_fix_with_else_branch() {
+        check_no_fix(
+            r#"
+fn f(x: u32) {
+    if let$0 y = x {} else {}
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn suppress_irrefutable_let_pattern() {
+        check_suppress_fix(
+            r#"
+fn main() {
+    if let x$0 = 92 {}
+}
+"#,
+            r#"
+#[allow(irrefutable_let_patterns)]
+fn main() {
+    if let x = 92 {}
+}
+"#,
+        );
+    }
+}
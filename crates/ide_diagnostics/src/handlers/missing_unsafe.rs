@@ -81,6 +81,27 @@ fn main() {
         );
     }
 
+    #[test]
+    fn missing_unsafe_diagnostic_with_union_field_read() {
+        check_diagnostics(
+            r#"
+union U {
+    a: u32,
+    b: f32,
+}
+
+fn main(mut u: U) {
+    let x = u.a;
+          //^^^ error: this operation is unsafe and requires an unsafe function or block
+    u.b = 1.0;
+    unsafe {
+        let x = u.a;
+    }
+}
+"#,
+        );
+    }
+
     #[test]
     fn no_missing_unsafe_diagnostic_with_safe_intrinsic() {
         check_diagnostics(
@@ -21,6 +21,7 @@ pub(crate) fn replace_filter_map_next_with_find_map(
         ctx.sema.diagnostics_display_range(InFile::new(d.file, d.next_expr.clone().into())).range,
     )
     .severity(Severity::WeakWarning)
+    .with_lint_name("clippy::filter_map_next")
     .with_fixes(fixes(ctx, d))
 }
 
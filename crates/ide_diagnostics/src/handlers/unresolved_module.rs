@@ -102,6 +102,7 @@ mod baz {}
                                 },
                             ],
                         ),
+                        lint_name: None,
                     },
                 ]
             "#]],
@@ -0,0 +1,207 @@
+use hir::GenericParam;
+use ide_db::{
+    assists::Assist,
+    base_db::FileId,
+    defs::{Definition, NameRefClass},
+    source_change::SourceChange,
+};
+use syntax::{
+    ast::{self, HasGenericParams},
+    match_ast, AstNode, SyntaxNode, TextRange, T,
+};
+use text_edit::TextEdit;
+
+use crate::{fix, Diagnostic, DiagnosticsContext, Severity};
+
+// Diagnostic: unused-lifetime
+//
+// This diagnostic is triggered if a function or a struct declares a lifetime parameter that is
+// never used.
+//
+// Example:
+//
+// ```rust
+// fn f<'a>(x: &u32) {}
+// ```
+pub(crate) fn unused_lifetime(
+    ctx: &DiagnosticsContext,
+    acc: &mut Vec<Diagnostic>,
+    file_id: FileId,
+    node: &SyntaxNode,
+) -> Option<()> {
+    let generic_param_list = match_ast! {
+        match node {
+            ast::Fn(it) => {
+                // Lifetimes of trait methods have to match the trait's signature.
+                let in_trait_impl = it
+                    .syntax()
+                    .ancestors()
+                    .nth(2)
+                    .and_then(ast::Impl::cast)
+                    .map_or(false, |imp| imp.trait_().is_some());
+                if in_trait_impl {
+                    return None;
+                }
+                it.generic_param_list()?
+            },
+            ast::Struct(it) => it.generic_param_list()?,
+            _ => return None,
+        }
+    };
+
+    for param in generic_param_list.lifetime_params() {
+        let def = match ctx.sema.to_def(&param) {
+            Some(it) => it,
+            None => continue,
+        };
+        let is_used = node.descendants().filter_map(ast::Lifetime::cast).any(|lifetime| {
+            matches!(
+                NameRefClass::classify_lifetime(&ctx.sema, &lifetime),
+                Some(NameRefClass::Definition(Definition::GenericParam(GenericParam::LifetimeParam(it))))
+                    if it == def
+            )
+        });
+        if is_used {
+            continue;
+        }
+
+        let lifetime = match param.lifetime() {
+            Some(it) => it,
+            None => continue,
+        };
+        let range = param.syntax().text_range();
+        acc.push(
+            Diagnostic::new(
+                "unused-lifetime",
+                format!("lifetime parameter `{}` never used", lifetime),
+                range,
+            )
+            .severity(Severity::WeakWarning)
+            .with_unused(true)
+            .with_lint_name("unused_lifetimes")
+            .with_fixes(fixes(file_id, &generic_param_list, &param)),
+        );
+    }
+
+    Some(())
+}
+
+fn fixes(
+    file_id: FileId,
+    generic_param_list: &ast::GenericParamList,
+    param: &ast::LifetimeParam,
+) -> Option<Vec<Assist>> {
+    let param_range = param.syntax().text_range();
+    let delete_range = if generic_param_list.generic_params().count() == 1 {
+        generic_param_list.syntax().text_range()
+    } else {
+        // Remove the parameter together with the comma separating it from its neighbour.
+        let next = param.syntax().next_sibling_or_token();
+        let comma_after = next.as_ref().filter(|it| it.kind() == T![,]);
+        match comma_after {
+            Some(comma) => {
+                let end = comma
+                    .as_token()
+                    .and_then(|it| it.next_token())
+                    .filter(|it| it.kind().is_trivia())
+                    .map_or(comma.text_range().end(), |ws| ws.text_range().end());
+                TextRange::new(param_range.start(), end)
+            }
+            None => {
+                let prev_param = param.syntax().prev_sibling()?;
+                TextRange::new(prev_param.text_range().end(), param_range.end())
+            }
+        }
+    };
+
+    Some(vec![fix(
+        "remove_unused_lifetime",
+        "Remove unused lifetime parameter",
+        SourceChange::from_text_edit(file_id, TextEdit::delete(delete_range)),
+        param_range,
+    )])
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::{check_diagnostics, check_fix, check_suppress_fix};
+
+    #[test]
+    fn unused_lifetime() {
+        check_diagnostics(
+            r#"
+fn f<'a>(x: &u32) {}
+   //^^ 💡 weak: lifetime parameter `'a` never used
+struct S<'a, 'b>(&'a u32);
+           //^^ 💡 weak: lifetime parameter `'b` never used
+"#,
+        );
+    }
+
+    #[test]
+    fn used_lifetimes() {
+        check_diagnostics(
+            r#"
+//- minicore: sized
+struct PhantomData<T: ?Sized>;
+struct S<'a> { _marker: PhantomData<&'a ()> }
+struct W<'a, T> where T: 'a { t: T }
+fn f<'a>(x: &'a u32) -> &'a u32 { x }
+fn g<'a, 'b: 'a>(x: &'b u32) {}
+fn h<'a>() {
+    let x: &'a u32 = &0;
+}
+
+trait Tr { fn m(&self); }
+impl Tr for () {
+    fn m<'a>(&self) {}
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn remove_only_lifetime() {
+        check_fix(
+            r#"
+fn f<'a$0>(x: &u32) {}
+"#,
+            r#"
+fn f(x: &u32) {}
+"#,
+        );
+    }
+
+    #[test]
+    fn remove_one_of_many_lifetimes() {
+        check_fix(
+            r#"
+struct S<'a$0, 'b, T>(&'b T);
+"#,
+            r#"
+struct S<'b, T>(&'b T);
+"#,
+        );
+        check_fix(
+            r#"
+struct S<'b, 'a$0>(&'b u32);
+"#,
+            r#"
+struct S<'b>(&'b u32);
+"#,
+        );
+    }
+
+    #[test]
+    fn suppress_unused_lifetime() {
+        check_suppress_fix(
+            r#"
+fn f<'a$0>() {}
+"#,
+            r#"
+#[allow(unused_lifetimes)]
+fn f<'a>() {}
+"#,
+        );
+    }
+}
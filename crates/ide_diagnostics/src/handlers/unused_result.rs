@@ -0,0 +1,192 @@
+use hir::TypeInfo;
+use ide_db::{
+    assists::Assist, base_db::FileId, helpers::famous_defs::FamousDefs, source_change::SourceChange,
+};
+use syntax::{ast, AstNode, SyntaxNode};
+use text_edit::TextEdit;
+
+use crate::{fix, Diagnostic, DiagnosticsContext, Severity};
+
+// Diagnostic: unused-result
+//
+// This diagnostic is triggered if the `Result` returned by a function or method call is
+// discarded, even if the function is not marked with `#[must_use]`. It is disabled by default,
+// enable it with the `rust-analyzer.diagnostics.warnUnusedResult` setting.
+//
+// Example:
+//
+// ```rust
+// fn fallible() -> Result<(), ()> { Ok(()) }
+// fn main() { fallible(); }
+// ```
+pub(crate) fn unused_result(
+    ctx: &DiagnosticsContext,
+    acc: &mut Vec<Diagnostic>,
+    file_id: FileId,
+    node: &SyntaxNode,
+) -> Option<()> {
+    let stmt = ast::ExprStmt::cast(node.clone())?;
+    let expr = stmt.expr()?;
+    if !matches!(expr, ast::Expr::CallExpr(_) | ast::Expr::MethodCallExpr(_)) {
+        return None;
+    }
+
+    let famous_defs = FamousDefs(&ctx.sema, ctx.sema.scope(expr.syntax()).krate());
+    let result_enum = famous_defs.core_result_Result()?;
+    let ty = ctx.sema.type_of_expr(&expr).map(TypeInfo::original)?;
+    if ty.as_adt() != Some(result_enum.into()) {
+        return None;
+    }
+
+    let range = expr.syntax().text_range();
+    acc.push(
+        Diagnostic::new("unused-result", "unused `Result` that must be used", range)
+            .severity(Severity::WeakWarning)
+            .with_lint_name("unused_must_use")
+            .with_fixes(Some(fixes(ctx, file_id, &expr, result_enum))),
+    );
+
+    Some(())
+}
+
+fn fixes(
+    ctx: &DiagnosticsContext,
+    file_id: FileId,
+    expr: &ast::Expr,
+    result_enum: hir::Enum,
+) -> Vec<Assist> {
+    let range = expr.syntax().text_range();
+    let mut fixes = vec![fix(
+        "discard_result",
+        "Explicitly discard the result with `let _ =`",
+        SourceChange::from_text_edit(file_id, TextEdit::insert(range.start(), "let _ = ".into())),
+        range,
+    )];
+
+    // `?` can only be used if the enclosing function itself returns a `Result`.
+    let returns_result = expr
+        .syntax()
+        .ancestors()
+        .find(|it| ast::Fn::can_cast(it.kind()) || ast::ClosureExpr::can_cast(it.kind()))
+        .and_then(ast::Fn::cast)
+        .and_then(|it| ctx.sema.to_def(&it))
+        .map_or(false, |it| it.ret_type(ctx.sema.db).as_adt() == Some(result_enum.into()));
+    if returns_result {
+        fixes.push(fix(
+            "propagate_result",
+            "Propagate the error with `?`",
+            SourceChange::from_text_edit(file_id, TextEdit::insert(range.end(), "?".into())),
+            range,
+        ));
+    }
+
+    fixes
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        tests::{check_diagnostics_with_config, check_fixes_with_config},
+        DiagnosticsConfig,
+    };
+
+    fn config() -> DiagnosticsConfig {
+        DiagnosticsConfig { warn_unused_result: true, ..DiagnosticsConfig::default() }
+    }
+
+    #[test]
+    fn unused_result() {
+        check_diagnostics_with_config(
+            config(),
+            r#"
+//- minicore: result, option
+struct S;
+impl S {
+    fn fallible(&self) -> Result<(), ()> { Ok(()) }
+    fn unwrap(self) {}
+}
+fn fallible() -> Result<S, ()> { Ok(S) }
+fn infallible() -> Option<S> { None }
+
+fn main() {
+    fallible();
+  //^^^^^^^^^^ 💡 weak: unused `Result` that must be used
+    S.fallible();
+  //^^^^^^^^^^^^ 💡 weak: unused `Result` that must be used
+    infallible();
+    let _ = fallible();
+    let _s = fallible();
+    fallible().ok();
+    if true { fallible() } else { Ok(S) };
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn disabled_by_default() {
+        check_diagnostics_with_config(
+            DiagnosticsConfig::default(),
+            r#"
+//- minicore: result
+fn fallible() -> Result<(), ()> { Ok(()) }
+fn main() {
+    fallible();
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn discard_or_propagate() {
+        check_fixes_with_config(
+            config(),
+            r#"
+//- minicore: result
+fn fallible() -> Result<(), ()> { Ok(()) }
+fn f() -> Result<(), ()> {
+    fallible$0();
+    Ok(())
+}
+"#,
+            vec![
+                r#"
+fn fallible() -> Result<(), ()> { Ok(()) }
+fn f() -> Result<(), ()> {
+    let _ = fallible();
+    Ok(())
+}
+"#,
+                r#"
+fn fallible() -> Result<(), ()> { Ok(()) }
+fn f() -> Result<(), ()> {
+    fallible()?;
+    Ok(())
+}
+"#,
+            ],
+        );
+    }
+
+    #[test]
+    fn no_propagation_outside_of_result_fn() {
+        check_fixes_with_config(
+            config(),
+            r#"
+//- minicore: result
+fn fallible() -> Result<(), ()> { Ok(()) }
+fn f() {
+    fallible$0();
+}
+"#,
+            vec![
+                r#"
+fn fallible() -> Result<(), ()> { Ok(()) }
+fn f() {
+    let _ = fallible();
+}
+"#,
+            ],
+        );
+    }
+}
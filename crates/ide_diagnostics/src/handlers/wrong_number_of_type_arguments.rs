@@ -0,0 +1,141 @@
+use hir::{GenericDef, GenericParam, ModuleDef, PathResolution};
+use syntax::{ast, AstNode, SyntaxNode};
+
+use crate::{Diagnostic, DiagnosticsContext};
+
+// Diagnostic: wrong-number-of-type-arguments
+//
+// This diagnostic is triggered if an ADT or a function is given more or less generic arguments
+// than it has generic parameters.
+//
+// Example:
+//
+// ```rust
+// struct Pair<A, B>(A, B);
+// fn f(p: Pair<u32>) {}
+// ```
+pub(crate) fn wrong_number_of_type_arguments(
+    ctx: &DiagnosticsContext,
+    acc: &mut Vec<Diagnostic>,
+    node: &SyntaxNode,
+) -> Option<()> {
+    let arg_list = ast::GenericArgList::cast(node.clone())?;
+    let def = resolve_generic_def(ctx, &arg_list)?;
+
+    let mut found = 0;
+    for arg in arg_list.generic_args() {
+        match arg {
+            ast::GenericArg::TypeArg(_) | ast::GenericArg::ConstArg(_) => found += 1,
+            ast::GenericArg::LifetimeArg(_) => (),
+            ast::GenericArg::AssocTypeArg(_) => return None,
+        }
+    }
+
+    let (mut min, mut max) = (0, 0);
+    for param in def.params(ctx.sema.db) {
+        match param {
+            GenericParam::TypeParam(it) if it.is_implicit(ctx.sema.db) => {
+                // Explicit generic arguments can't be used together with `impl Trait`.
+                return None;
+            }
+            GenericParam::TypeParam(it) if it.has_default(ctx.sema.db) => max += 1,
+            GenericParam::TypeParam(_) | GenericParam::ConstParam(_) => {
+                min += 1;
+                max += 1;
+            }
+            GenericParam::LifetimeParam(_) => (),
+        }
+    }
+
+    let (qualifier, expected) = if found < min {
+        (if min == max { "" } else { "at least " }, min)
+    } else if found > max {
+        (if min == max { "" } else { "at most " }, max)
+    } else {
+        return None;
+    };
+    let s = if expected == 1 { "" } else { "s" };
+    acc.push(Diagnostic::new(
+        "wrong-number-of-type-arguments",
+        format!("expected {}{} generic argument{}, found {}", qualifier, expected, s, found),
+        ctx.sema.original_range(arg_list.syntax()).range,
+    ));
+
+    Some(())
+}
+
+fn resolve_generic_def(
+    ctx: &DiagnosticsContext,
+    arg_list: &ast::GenericArgList,
+) -> Option<GenericDef> {
+    let parent = arg_list.syntax().parent()?;
+    if let Some(method_call) = ast::MethodCallExpr::cast(parent.clone()) {
+        return ctx.sema.resolve_method_call(&method_call).map(GenericDef::from);
+    }
+
+    let path = ast::PathSegment::cast(parent)?.parent_path();
+    match ctx.sema.resolve_path(&path)? {
+        PathResolution::Def(ModuleDef::Adt(it)) => Some(it.into()),
+        PathResolution::Def(ModuleDef::Function(it)) => Some(it.into()),
+        PathResolution::Def(ModuleDef::TypeAlias(it)) => Some(it.into()),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::check_diagnostics;
+
+    #[test]
+    fn missing_type_arguments() {
+        check_diagnostics(
+            r#"
+struct Map<K, V, S = ()>(K, V, S);
+struct Pair<A, B>(A, B);
+
+fn f(m: Map<u32>, p: Pair<u32>) {}
+         //^^^^^ error: expected at least 2 generic arguments, found 1
+                       //^^^^^ error: expected 2 generic arguments, found 1
+"#,
+        );
+    }
+
+    #[test]
+    fn excess_type_arguments() {
+        check_diagnostics(
+            r#"
+struct Vec<T>(T);
+impl<T> Vec<T> {
+    fn new() -> Self { loop {} }
+}
+fn id<T>(t: T) -> T { t }
+
+fn f(v: Vec<i32, u32>) {
+         //^^^^^^^^^^ error: expected 1 generic argument, found 2
+    let v = Vec::<i32, u32>::new();
+             //^^^^^^^^^^^^ error: expected 1 generic argument, found 2
+    id::<u8, u8>(0);
+    //^^^^^^^^^^ error: expected 1 generic argument, found 2
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn correct_type_arguments() {
+        check_diagnostics(
+            r#"
+struct Map<K, V, S = ()>(K, V, S);
+struct Ref<'a, T, const N: usize>(&'a [T; N]);
+type Alias<T> = Map<T, T>;
+fn id<T>(t: T) -> T { t }
+fn with_impl_trait<T>(t: T, u: impl Copy) {}
+
+fn f<'a>(m: Map<u32, u32>, n: Map<u32, u32, u32>, r: Ref<'a, u8, 3>, a: Alias<u8>) {
+    id::<u8>(0);
+    with_impl_trait::<u8>(0, 0);
+}
+"#,
+        );
+    }
+}
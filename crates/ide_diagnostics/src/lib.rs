@@ -29,6 +29,7 @@ mod handlers {
     pub(crate) mod inactive_code;
     pub(crate) mod incorrect_case;
     pub(crate) mod invalid_derive_target;
+    pub(crate) mod irrefutable_let_pattern;
     pub(crate) mod macro_error;
     pub(crate) mod malformed_derive;
     pub(crate) mod mismatched_arg_count;
@@ -48,8 +49,14 @@ mod handlers {
 
     // The handlers below are unusual, the implement the diagnostics as well.
     pub(crate) mod field_shorthand;
+    pub(crate) mod format_args;
     pub(crate) mod useless_braces;
     pub(crate) mod unlinked_file;
+    pub(crate) mod dead_field;
+    pub(crate) mod copy_without_clone;
+    pub(crate) mod unused_lifetime;
+    pub(crate) mod unused_result;
+    pub(crate) mod wrong_number_of_type_arguments;
 }
 
 #[cfg(test)]
@@ -64,7 +71,11 @@ use ide_db::{
     RootDatabase,
 };
 use rustc_hash::FxHashSet;
-use syntax::{ast::AstNode, TextRange};
+use syntax::{
+    ast::{self, edit::IndentLevel, AstNode},
+    NodeOrToken, TextRange,
+};
+use text_edit::TextEdit;
 
 #[derive(Copy, Clone, Debug, PartialEq)]
 pub struct DiagnosticCode(pub &'static str);
@@ -84,6 +95,9 @@ pub struct Diagnostic {
     pub unused: bool,
     pub experimental: bool,
     pub fixes: Option<Vec<Assist>>,
+    /// The name of the rustc or clippy lint this diagnostic corresponds to, if any. Such
+    /// diagnostics can be silenced with an `#[allow]` attribute, see [`suppress_fix`].
+    pub lint_name: Option<String>,
 }
 
 impl Diagnostic {
@@ -97,6 +111,7 @@ impl Diagnostic {
             unused: false,
             experimental: false,
             fixes: None,
+            lint_name: None,
         }
     }
 
@@ -119,6 +134,11 @@ impl Diagnostic {
         self.unused = unused;
         self
     }
+
+    fn with_lint_name(mut self, lint_name: &str) -> Diagnostic {
+        self.lint_name = Some(lint_name.to_string());
+        self
+    }
 }
 
 #[derive(Debug, Copy, Clone)]
@@ -133,6 +153,7 @@ pub enum Severity {
 pub struct DiagnosticsConfig {
     pub disable_experimental: bool,
     pub disabled: FxHashSet<String>,
+    pub warn_unused_result: bool,
 }
 
 struct DiagnosticsContext<'a> {
@@ -159,14 +180,34 @@ pub fn diagnostics(
         }),
     );
 
+    let module = sema.to_module_def(file_id);
+
+    let ctx = DiagnosticsContext { config, sema, resolve };
+    // Searching for field usages is expensive, so only do it when the diagnostic is enabled.
+    let dead_fields_enabled =
+        !config.disabled.contains("dead-field") && !config.disable_experimental;
+    if module.is_some() {
+        for node in ctx.sema.parse(file_id).syntax().descendants() {
+            if dead_fields_enabled {
+                handlers::dead_field::dead_field(&ctx, &mut res, &node);
+            }
+            handlers::wrong_number_of_type_arguments::wrong_number_of_type_arguments(
+                &ctx, &mut res, &node,
+            );
+            handlers::unused_lifetime::unused_lifetime(&ctx, &mut res, file_id, &node);
+            handlers::copy_without_clone::copy_without_clone(&ctx, &mut res, file_id, &node);
+            if config.warn_unused_result {
+                handlers::unused_result::unused_result(&ctx, &mut res, file_id, &node);
+            }
+        }
+    }
+
     for node in parse.tree().syntax().descendants() {
         handlers::useless_braces::useless_braces(&mut res, file_id, &node);
         handlers::field_shorthand::field_shorthand(&mut res, file_id, &node);
+        handlers::format_args::format_args(&mut res, file_id, &node);
     }
 
-    let module = sema.to_module_def(file_id);
-
-    let ctx = DiagnosticsContext { config, sema, resolve };
     if module.is_none() {
         handlers::unlinked_file::unlinked_file(&ctx, &mut res, file_id);
     }
@@ -199,6 +240,7 @@ pub fn diagnostics(
             AnyDiagnostic::UnresolvedModule(d) => handlers::unresolved_module::unresolved_module(&ctx, &d),
             AnyDiagnostic::UnresolvedProcMacro(d) => handlers::unresolved_proc_macro::unresolved_proc_macro(&ctx, &d),
             AnyDiagnostic::InvalidDeriveTarget(d) => handlers::invalid_derive_target::invalid_derive_target(&ctx, &d),
+            AnyDiagnostic::IrrefutableLetPattern(d) => handlers::irrefutable_let_pattern::irrefutable_let_pattern(&ctx, &d),
 
             AnyDiagnostic::InactiveCode(d) => match handlers::inactive_code::inactive_code(&ctx, &d) {
                 Some(it) => it,
@@ -216,6 +258,29 @@ pub fn diagnostics(
     res
 }
 
+/// Returns a quick fix which silences `diagnostic` by putting an `#[allow]` attribute for its lint
+/// on the enclosing item.
+pub fn suppress_fix(db: &RootDatabase, file_id: FileId, diagnostic: &Diagnostic) -> Option<Assist> {
+    let lint_name = diagnostic.lint_name.as_deref()?;
+    let source_file = db.parse(file_id).tree();
+    let node = match source_file.syntax().covering_element(diagnostic.range) {
+        NodeOrToken::Node(it) => it,
+        NodeOrToken::Token(it) => it.parent()?,
+    };
+    let item = node.ancestors().find_map(ast::Item::cast)?;
+    let indent = IndentLevel::from_node(item.syntax());
+    let edit = TextEdit::insert(
+        item.syntax().text_range().start(),
+        format!("#[allow({})]\n{}", lint_name, indent),
+    );
+    Some(fix(
+        "suppress_diagnostic",
+        &format!("Suppress this diagnostic with `#[allow({})]`", lint_name),
+        SourceChange::from_text_edit(file_id, edit),
+        diagnostic.range,
+    ))
+}
+
 fn fix(id: &'static str, label: &str, source_change: SourceChange, target: TextRange) -> Assist {
     let mut res = unresolved_fix(id, label, target);
     res.source_change = Some(source_change);
@@ -1,6 +1,6 @@
 mod sourcegen;
 
-use expect_test::Expect;
+use expect_test::{expect, Expect};
 use ide_db::{
     assists::AssistResolveStrategy,
     base_db::{fixture::WithFixture, SourceDatabaseExt},
@@ -18,7 +18,7 @@ use crate::{DiagnosticsConfig, Severity};
 ///  * that the contents of the file containing the cursor match `after` after the diagnostic fix is applied
 #[track_caller]
 pub(crate) fn check_fix(ra_fixture_before: &str, ra_fixture_after: &str) {
-    check_nth_fix(0, ra_fixture_before, ra_fixture_after);
+    check_nth_fix(0, &DiagnosticsConfig::default(), ra_fixture_before, ra_fixture_after);
 }
 /// Takes a multi-file input fixture with annotated cursor positions,
 /// and checks that:
@@ -26,24 +26,34 @@ pub(crate) fn check_fix(ra_fixture_before: &str, ra_fixture_after: &str) {
 ///  * every diagnostic fixes trigger range touches the input cursor position
 ///  * that the contents of the file containing the cursor match `after` after each diagnostic fix is applied
 pub(crate) fn check_fixes(ra_fixture_before: &str, ra_fixtures_after: Vec<&str>) {
+    check_fixes_with_config(DiagnosticsConfig::default(), ra_fixture_before, ra_fixtures_after)
+}
+
+/// Same as [`check_fixes`], but with a custom diagnostics config.
+pub(crate) fn check_fixes_with_config(
+    config: DiagnosticsConfig,
+    ra_fixture_before: &str,
+    ra_fixtures_after: Vec<&str>,
+) {
     for (i, ra_fixture_after) in ra_fixtures_after.iter().enumerate() {
-        check_nth_fix(i, ra_fixture_before, ra_fixture_after)
+        check_nth_fix(i, &config, ra_fixture_before, ra_fixture_after)
     }
 }
 
 #[track_caller]
-fn check_nth_fix(nth: usize, ra_fixture_before: &str, ra_fixture_after: &str) {
+fn check_nth_fix(
+    nth: usize,
+    config: &DiagnosticsConfig,
+    ra_fixture_before: &str,
+    ra_fixture_after: &str,
+) {
     let after = trim_indent(ra_fixture_after);
 
     let (db, file_position) = RootDatabase::with_position(ra_fixture_before);
-    let diagnostic = super::diagnostics(
-        &db,
-        &DiagnosticsConfig::default(),
-        &AssistResolveStrategy::All,
-        file_position.file_id,
-    )
-    .pop()
-    .expect("no diagnostics");
+    let diagnostic =
+        super::diagnostics(&db, config, &AssistResolveStrategy::All, file_position.file_id)
+            .pop()
+            .expect("no diagnostics");
     let fix = &diagnostic.fixes.expect("diagnostic misses fixes")[nth];
     let actual = {
         let source_change = fix.source_change.as_ref().unwrap();
@@ -65,6 +75,32 @@ fn check_nth_fix(nth: usize, ra_fixture_before: &str, ra_fixture_after: &str) {
     );
 }
 
+/// Takes an input fixture with a cursor inside a diagnostic's range and checks that the file
+/// contents match `after` once the diagnostic is suppressed with an `#[allow]` attribute.
+#[track_caller]
+pub(crate) fn check_suppress_fix(ra_fixture_before: &str, ra_fixture_after: &str) {
+    let after = trim_indent(ra_fixture_after);
+
+    let (db, file_position) = RootDatabase::with_position(ra_fixture_before);
+    let diagnostic = super::diagnostics(
+        &db,
+        &DiagnosticsConfig::default(),
+        &AssistResolveStrategy::All,
+        file_position.file_id,
+    )
+    .into_iter()
+    .find(|it| it.range.contains_inclusive(file_position.offset))
+    .expect("no diagnostic at cursor");
+    let fix = super::suppress_fix(&db, file_position.file_id, &diagnostic)
+        .expect("diagnostic can't be suppressed");
+    let mut actual = db.file_text(file_position.file_id).to_string();
+    for edit in fix.source_change.unwrap().source_file_edits.values() {
+        edit.apply(&mut actual);
+    }
+
+    assert_eq_text!(&after, &actual);
+}
+
 /// Checks that there's a diagnostic *without* fix at `$0`.
 pub(crate) fn check_no_fix(ra_fixture: &str) {
     let (db, file_position) = RootDatabase::with_position(ra_fixture);
@@ -94,6 +130,7 @@ pub(crate) fn check_expect(ra_fixture: &str, expect: Expect) {
 pub(crate) fn check_diagnostics(ra_fixture: &str) {
     let mut config = DiagnosticsConfig::default();
     config.disabled.insert("inactive-code".to_string());
+    config.disabled.insert("dead-field".to_string());
     check_diagnostics_with_config(config, ra_fixture)
 }
 
@@ -144,3 +181,70 @@ fn test_disabled_diagnostics() {
     );
     assert!(!diagnostics.is_empty());
 }
+
+#[track_caller]
+fn check_syntax_errors(ra_fixture: &str, expect: Expect) {
+    let (db, files) = RootDatabase::with_many_files(ra_fixture);
+    let mut actual = String::new();
+    for file_id in files {
+        let diagnostics = super::diagnostics(
+            &db,
+            &DiagnosticsConfig::default(),
+            &AssistResolveStrategy::All,
+            file_id,
+        );
+        for d in diagnostics.into_iter().filter(|d| d.code.as_str() == "syntax-error") {
+            actual.push_str(&format!("{:?} {:?}: {}\n", file_id, d.range, d.message));
+        }
+    }
+    expect.assert_eq(&actual)
+}
+
+#[test]
+fn unstable_syntax_requires_feature() {
+    check_syntax_errors(
+        r#"
+fn f() {
+    let _ = try { 1 };
+    let _ = || { yield 1; };
+    let _ = static || {};
+}
+"#,
+        expect![[r#"
+            FileId(0) 21..21: Syntax Error: `try` blocks are unstable
+            FileId(0) 49..49: Syntax Error: `yield` expressions are unstable
+            FileId(0) 73..73: Syntax Error: `static` closures are unstable
+        "#]],
+    );
+    check_syntax_errors(
+        r#"
+#![feature(try_blocks)]
+#![feature(generators, generic_const_exprs)]
+fn f<const N: usize>() -> [u8; { N + 1 }] {
+    let _ = try { 1 };
+    let _ = || { yield 1; };
+    let _ = static || {};
+    loop {}
+}
+"#,
+        expect![[r#""#]],
+    );
+}
+
+#[test]
+fn unstable_syntax_uses_features_of_crate_root() {
+    check_syntax_errors(
+        r#"
+//- /main.rs crate:main deps:dep
+#![feature(try_blocks)]
+mod m;
+//- /m.rs
+fn f() { let _ = try { 1 }; }
+//- /dep.rs crate:dep new_source_root:local
+fn f() { let _ = try { 1 }; }
+"#,
+        expect![[r#"
+            FileId(2) 17..17: Syntax Error: `try` blocks are unstable
+        "#]],
+    );
+}
@@ -40,6 +40,7 @@ mod types;
 
 use crate::{
     parser::{CompletedMarker, Marker, Parser},
+    ParserFeatures,
     SyntaxKind::{self, *},
     TokenSet, T,
 };
@@ -130,7 +131,11 @@ pub(crate) fn reparser(
         MATCH_ARM_LIST => items::match_arm_list,
         USE_TREE_LIST => items::use_tree_list,
         EXTERN_ITEM_LIST => items::extern_item_list,
-        TOKEN_TREE if first_child? == T!['{'] => items::token_tree,
+        TOKEN_TREE if first_child? == T!['{'] => match parent {
+            Some(MACRO_RULES) => items::macro_rules_body,
+            _ => items::token_tree,
+        },
+        MACRO_RULES_PAT if first_child? == T!['{'] => items::macro_rules_pat,
         ASSOC_ITEM_LIST => match parent? {
             IMPL | TRAIT => items::assoc_item_list,
             _ => return None,
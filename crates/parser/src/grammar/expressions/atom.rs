@@ -53,6 +53,7 @@ pub(super) const ATOM_EXPR_FIRST: TokenSet =
         T![async],
         T![try],
         T![const],
+        T![static],
         T![loop],
         T![for],
         LIFETIME_IDENT,
@@ -74,6 +75,7 @@ pub(super) fn atom_expr(p: &mut Parser, r: Restrictions) -> Option<(CompletedMar
         T![|] => closure_expr(p),
         T![move] if la == T![|] => closure_expr(p),
         T![async] if la == T![|] || (la == T![move] && p.nth(2) == T![|]) => closure_expr(p),
+        T![static] if la == T![|] || (la == T![move] && p.nth(2) == T![|]) => closure_expr(p),
         T![if] => if_expr(p),
 
         T![loop] => loop_expr(p, None),
@@ -241,8 +243,18 @@ fn closure_expr(p: &mut Parser) -> CompletedMarker {
             || (p.at(T![move]) && p.nth(1) == T![|])
             || (p.at(T![async]) && p.nth(1) == T![|])
             || (p.at(T![async]) && p.nth(1) == T![move] && p.nth(2) == T![|])
+            || (p.at(T![static]) && p.nth(1) == T![|])
+            || (p.at(T![static]) && p.nth(1) == T![move] && p.nth(2) == T![|])
     );
     let m = p.start();
+    // test static_closure
+    // fn main() { let g = static || { yield; }; let h = static move |x| x; }
+    if p.at(T![static]) {
+        if !p.feature_enabled(ParserFeatures::GENERATORS) {
+            p.error("`static` closures are unstable");
+        }
+        p.bump(T![static]);
+    }
     p.eat(T![async]);
     p.eat(T![move]);
     params::param_list_closure(p);
@@ -520,6 +532,9 @@ fn return_expr(p: &mut Parser) -> CompletedMarker {
 fn yield_expr(p: &mut Parser) -> CompletedMarker {
     assert!(p.at(T![yield]));
     let m = p.start();
+    if !p.feature_enabled(ParserFeatures::GENERATORS) {
+        p.error("`yield` expressions are unstable");
+    }
     p.bump(T![yield]);
     if p.at_ts(EXPR_FIRST) {
         expr(p);
@@ -596,6 +611,9 @@ fn try_block_expr(p: &mut Parser, m: Option<Marker>) -> CompletedMarker {
         return m.complete(p, MACRO_CALL);
     }
 
+    if !p.feature_enabled(ParserFeatures::TRY_BLOCKS) {
+        p.error("`try` blocks are unstable");
+    }
     p.bump(T![try]);
     if p.at(T!['{']) {
         stmt_list(p);
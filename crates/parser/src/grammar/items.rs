@@ -342,15 +342,185 @@ fn macro_rules(p: &mut Parser, m: Marker) {
         // macro_rules! m ( ($i:ident) => {} );
         // macro_rules! m [ ($i:ident) => {} ];
         T!['['] | T!['('] => {
-            token_tree(p);
+            macro_rules_body(p);
             p.expect(T![;]);
         }
-        T!['{'] => token_tree(p),
+        T!['{'] => macro_rules_body(p),
         _ => p.error("expected `{`, `[`, `(`"),
     }
     m.complete(p, MACRO_RULES);
 }
 
+const MACRO_RULES_DELIMS: TokenSet = TokenSet::new(&[T!['{'], T!['('], T!['[']]);
+const MACRO_RULES_REPETITION_OPS: TokenSet = TokenSet::new(&[T![*], T![+], T![?]]);
+
+/// Parses the token tree of a `macro_rules!`, checking the shape of its arms. The result is a
+/// plain `TOKEN_TREE`, just like for any other macro.
+pub(crate) fn macro_rules_body(p: &mut Parser) {
+    let closing_paren_kind = match p.current() {
+        T!['{'] => T!['}'],
+        T!['('] => T![')'],
+        T!['['] => T![']'],
+        _ => unreachable!(),
+    };
+    // test macro_rules_arms
+    // macro_rules! m {
+    //     () => {};
+    //     ($e:expr) => { $e };
+    //     ($($i:ident),* $(,)?) => { $(let $i = 0;)* };
+    //     ($($k:ident => $v:expr);+) => [$($k $v)+];
+    //     ($($t:tt)=>*) => ($crate::m!($($t)*))
+    // }
+    // macro_rules! n { () => { $ } }
+    let m = p.start();
+    p.bump_any();
+    while !p.at(EOF) && !p.at(closing_paren_kind) {
+        match p.current() {
+            T!['}'] => {
+                p.error("unmatched `}`");
+                m.complete(p, TOKEN_TREE);
+                return;
+            }
+            T![')'] | T![']'] => p.err_and_bump("unmatched brace"),
+            _ => {
+                macro_rules_arm(p);
+                if !p.at(closing_paren_kind) && !p.eat(T![;]) {
+                    // test_err macro_rules_missing_semicolon
+                    // macro_rules! m { () => {} () => {} }
+                    p.error("expected `;`");
+                }
+            }
+        }
+    }
+    p.expect(closing_paren_kind);
+    m.complete(p, TOKEN_TREE);
+}
+
+fn macro_rules_arm(p: &mut Parser) {
+    // test_err macro_rules_missing_matcher
+    // macro_rules! m { => {} }
+    if !p.at_ts(MACRO_RULES_DELIMS) {
+        p.error("expected macro matcher");
+        macro_rules_skip_arm(p);
+        return;
+    }
+    macro_rules_pat(p);
+
+    // test_err macro_rules_missing_fat_arrow
+    // macro_rules! m { () {} }
+    if !p.at(T![=>]) {
+        p.error("expected `=>`");
+        macro_rules_skip_arm(p);
+        return;
+    }
+    // Keep `=` and `>` as separate tokens, exactly like in any other token tree.
+    p.bump_any();
+    p.bump_any();
+
+    // test_err macro_rules_missing_transcriber
+    // macro_rules! m { () => ; }
+    if !p.at_ts(MACRO_RULES_DELIMS) {
+        p.error("expected macro transcriber");
+        macro_rules_skip_arm(p);
+        return;
+    }
+    macro_rules_tt(p, false);
+}
+
+fn macro_rules_skip_arm(p: &mut Parser) {
+    while !p.at(EOF) && !p.at(T![;]) && !p.at_ts(TokenSet::new(&[T!['}'], T![')'], T![']']])) {
+        if p.at_ts(MACRO_RULES_DELIMS) {
+            token_tree(p);
+        } else {
+            p.bump_any();
+        }
+    }
+}
+
+pub(crate) fn macro_rules_pat(p: &mut Parser) {
+    let m = p.start();
+    macro_rules_tt(p, true);
+    m.complete(p, MACRO_RULES_PAT);
+}
+
+/// Like [`token_tree`], but checks the metavariables and repetitions of a macro matcher or
+/// transcriber.
+fn macro_rules_tt(p: &mut Parser, is_matcher: bool) {
+    let closing_paren_kind = match p.current() {
+        T!['{'] => T!['}'],
+        T!['('] => T![')'],
+        T!['['] => T![']'],
+        _ => unreachable!(),
+    };
+    let m = p.start();
+    p.bump_any();
+    while !p.at(EOF) && !p.at(closing_paren_kind) {
+        match p.current() {
+            T!['{'] | T!['('] | T!['['] => macro_rules_tt(p, is_matcher),
+            T!['}'] => {
+                p.error("unmatched `}`");
+                m.complete(p, TOKEN_TREE);
+                return;
+            }
+            T![')'] | T![']'] => p.err_and_bump("unmatched brace"),
+            T![$] => macro_rules_meta_var(p, is_matcher),
+            _ => p.bump_any(),
+        }
+    }
+    p.expect(closing_paren_kind);
+    m.complete(p, TOKEN_TREE);
+}
+
+fn macro_rules_meta_var(p: &mut Parser, is_matcher: bool) {
+    assert!(p.at(T![$]));
+    let m = p.start();
+    p.bump(T![$]);
+    match p.current() {
+        T!['('] => {
+            macro_rules_tt(p, is_matcher);
+            // Anything but a delimiter can be a separator, including punctuation made up of
+            // several tokens, like `=>`.
+            for _ in 0..3 {
+                if p.at_ts(MACRO_RULES_REPETITION_OPS)
+                    || p.at_ts(MACRO_RULES_DELIMS)
+                    || p.at_ts(TokenSet::new(&[T!['}'], T![')'], T![']'], T![$], EOF]))
+                {
+                    break;
+                }
+                p.bump_any();
+            }
+            if !p.eat(T![*]) && !p.eat(T![+]) && !p.eat(T![?]) {
+                // test_err macro_rules_missing_repetition_op
+                // macro_rules! m { ($($i:ident)) => {}; ($($i:ident),) => {} }
+                // macro_rules! n { () => { $(x) } }
+                p.error("expected one of `*`, `+`, or `?`");
+            }
+            if is_matcher {
+                m.complete(p, MACRO_RULES_REPETITION);
+                return;
+            }
+        }
+        IDENT => {
+            p.bump_any();
+            if is_matcher {
+                // test_err macro_rules_missing_fragment_specifier
+                // macro_rules! m { ($i) => {}; ($i:) => {} }
+                if !p.eat(T![:]) {
+                    p.error("missing fragment specifier");
+                } else if !p.eat(IDENT) {
+                    p.error("expected fragment specifier");
+                }
+                m.complete(p, MACRO_RULES_FRAG);
+                return;
+            }
+        }
+        kind if kind.is_keyword() => p.bump_any(),
+        _ => (),
+    }
+    // Metavariables of a transcriber are left as flat tokens.
+    m.abandon(p);
+}
+
 // test macro_def
 // macro m($i:ident) {}
 fn macro_def(p: &mut Parser, m: Marker) {
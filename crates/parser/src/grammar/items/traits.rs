@@ -62,6 +62,19 @@ pub(super) fn impl_(p: &mut Parser, m: Marker) {
     p.eat(T![!]);
     impl_type(p);
     if p.eat(T![for]) {
+        // test_err impl_missing_type
+        // impl Display for {}
+        // impl<T> Display for where T: Copy {}
+        if p.at(T!['{']) || p.at(T![where]) {
+            p.error("expected type");
+        } else {
+            impl_type(p);
+        }
+    } else if paths::is_use_path_start(p) {
+        // test_err impl_missing_for
+        // impl Display S {}
+        // impl<T> From<T> Wrapper<T> { fn from(t: T) -> Self { Wrapper(t) } }
+        p.error("expected `for`");
         impl_type(p);
     }
     generic_params::opt_where_clause(p);
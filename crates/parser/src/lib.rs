@@ -27,6 +27,7 @@ mod grammar;
 mod input;
 mod output;
 mod shortcuts;
+mod parser_features;
 
 #[cfg(test)]
 mod tests;
@@ -37,6 +38,7 @@ pub use crate::{
     input::Input,
     lexed_str::LexedStr,
     output::{Output, Step},
+    parser_features::ParserFeatures,
     shortcuts::StrStep,
     syntax_kind::SyntaxKind,
 };
@@ -76,7 +78,7 @@ impl PrefixEntryPoint {
             PrefixEntryPoint::Item => grammar::entry::prefix::item,
             PrefixEntryPoint::MetaItem => grammar::entry::prefix::meta_item,
         };
-        let mut p = parser::Parser::new(input);
+        let mut p = parser::Parser::new(input, ParserFeatures::ALL);
         entry_point(&mut p);
         let events = p.finish();
         event::process(events)
@@ -115,6 +117,12 @@ pub enum TopEntryPoint {
 
 impl TopEntryPoint {
     pub fn parse(&self, input: &Input) -> Output {
+        self.parse_with_features(input, ParserFeatures::ALL)
+    }
+
+    /// Like [`TopEntryPoint::parse`], but reports syntax of unstable features which aren't in
+    /// `features` as errors.
+    pub fn parse_with_features(&self, input: &Input, features: ParserFeatures) -> Output {
         let entry_point: fn(&'_ mut parser::Parser) = match self {
             TopEntryPoint::SourceFile => grammar::entry::top::source_file,
             TopEntryPoint::MacroStmts => grammar::entry::top::macro_stmts,
@@ -125,7 +133,7 @@ impl TopEntryPoint {
             TopEntryPoint::Expr => grammar::entry::prefix::expr,
             TopEntryPoint::MetaItem => grammar::entry::prefix::meta_item,
         };
-        let mut p = parser::Parser::new(input);
+        let mut p = parser::Parser::new(input, features);
         entry_point(&mut p);
         let events = p.finish();
         event::process(events)
@@ -151,7 +159,7 @@ impl Reparser {
     /// sequence.
     pub fn parse(self, tokens: &Input) -> Output {
         let Reparser(r) = self;
-        let mut p = parser::Parser::new(tokens);
+        let mut p = parser::Parser::new(tokens, ParserFeatures::ALL);
         r(&mut p);
         let events = p.finish();
         event::process(events)
@@ -8,6 +8,7 @@ use limit::Limit;
 use crate::{
     event::Event,
     input::Input,
+    ParserFeatures,
     SyntaxKind::{self, EOF, ERROR, TOMBSTONE},
     TokenSet, T,
 };
@@ -26,13 +27,14 @@ pub(crate) struct Parser<'t> {
     pos: usize,
     events: Vec<Event>,
     steps: Cell<u32>,
+    features: ParserFeatures,
 }
 
 static PARSER_STEP_LIMIT: Limit = Limit::new(15_000_000);
 
 impl<'t> Parser<'t> {
-    pub(super) fn new(inp: &'t Input) -> Parser<'t> {
-        Parser { inp, pos: 0, events: Vec::new(), steps: Cell::new(0) }
+    pub(super) fn new(inp: &'t Input, features: ParserFeatures) -> Parser<'t> {
+        Parser { inp, pos: 0, events: Vec::new(), steps: Cell::new(0), features }
     }
 
     pub(crate) fn finish(self) -> Vec<Event> {
@@ -143,6 +145,11 @@ impl<'t> Parser<'t> {
             && self.inp.is_joint(self.pos + n + 1)
     }
 
+    /// Checks if the syntax of the unstable `feature` should be accepted.
+    pub(crate) fn feature_enabled(&self, feature: ParserFeatures) -> bool {
+        self.features.contains(feature)
+    }
+
     /// Checks if the current token is in `kinds`.
     pub(crate) fn at_ts(&self, kinds: TokenSet) -> bool {
         kinds.contains(self.current())
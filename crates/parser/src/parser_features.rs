@@ -0,0 +1,59 @@
+//! A bit-set of unstable language features affecting the syntax.
+
+/// The set of unstable features enabled with `#![feature(...)]`, which decides whether the
+/// parser accepts the corresponding syntax.
+///
+/// Parsing doesn't depend on the set: the same tree is produced either way, and syntax of a
+/// disabled feature is only reported as an error.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ParserFeatures(u8);
+
+impl ParserFeatures {
+    pub const EMPTY: ParserFeatures = ParserFeatures(0);
+    pub const ALL: ParserFeatures = ParserFeatures(!0);
+
+    /// `if let Some(x) = a && let Some(y) = b`
+    ///
+    /// FIXME: the grammar doesn't support `let` chains yet, so this doesn't affect parsing.
+    pub const LET_CHAINS: ParserFeatures = ParserFeatures(1 << 0);
+    /// `try { ... }`
+    pub const TRY_BLOCKS: ParserFeatures = ParserFeatures(1 << 1);
+    /// `yield` expressions and `static` closures
+    pub const GENERATORS: ParserFeatures = ParserFeatures(1 << 2);
+    /// Expressions in const generic arguments, like `[u8; { N + 1 }]`
+    ///
+    /// The syntax is always accepted, the feature only matters for type checking.
+    pub const GENERIC_CONST_EXPRS: ParserFeatures = ParserFeatures(1 << 3);
+
+    /// Returns the feature enabled by `#![feature(name)]`, if it is one the parser knows about.
+    pub fn from_name(name: &str) -> Option<ParserFeatures> {
+        let res = match name {
+            "let_chains" => ParserFeatures::LET_CHAINS,
+            "try_blocks" => ParserFeatures::TRY_BLOCKS,
+            "generators" => ParserFeatures::GENERATORS,
+            "generic_const_exprs" => ParserFeatures::GENERIC_CONST_EXPRS,
+            _ => return None,
+        };
+        Some(res)
+    }
+
+    pub const fn union(self, other: ParserFeatures) -> ParserFeatures {
+        ParserFeatures(self.0 | other.0)
+    }
+
+    pub const fn contains(self, other: ParserFeatures) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+#[test]
+fn parser_features_work() {
+    let features = ParserFeatures::from_name("try_blocks")
+        .unwrap()
+        .union(ParserFeatures::from_name("generators").unwrap());
+    assert!(features.contains(ParserFeatures::TRY_BLOCKS));
+    assert!(features.contains(ParserFeatures::GENERATORS));
+    assert!(!features.contains(ParserFeatures::LET_CHAINS));
+    assert!(ParserFeatures::ALL.contains(features));
+    assert!(ParserFeatures::from_name("never_type").is_none());
+}
@@ -137,6 +137,9 @@ pub enum SyntaxKind {
     MACRO_CALL,
     MACRO_RULES,
     MACRO_ARM,
+    MACRO_RULES_PAT,
+    MACRO_RULES_FRAG,
+    MACRO_RULES_REPETITION,
     TOKEN_TREE,
     MACRO_DEF,
     PAREN_TYPE,
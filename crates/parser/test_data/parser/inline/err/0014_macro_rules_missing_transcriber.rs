@@ -0,0 +1 @@
+macro_rules! m { () => ; }
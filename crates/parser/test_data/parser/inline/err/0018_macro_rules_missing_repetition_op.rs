@@ -0,0 +1,2 @@
+macro_rules! m { ($($i:ident)) => {}; ($($i:ident),) => {} }
+macro_rules! n { () => { $(x) } }
@@ -0,0 +1,2 @@
+impl Display S {}
+impl<T> From<T> Wrapper<T> { fn from(t: T) -> Self { Wrapper(t) } }
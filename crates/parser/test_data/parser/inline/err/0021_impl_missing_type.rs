@@ -0,0 +1,2 @@
+impl Display for {}
+impl<T> Display for where T: Copy {}
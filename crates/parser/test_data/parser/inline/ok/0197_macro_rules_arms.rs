@@ -0,0 +1,8 @@
+macro_rules! m {
+    () => {};
+    ($e:expr) => { $e };
+    ($($i:ident),* $(,)?) => { $(let $i = 0;)* };
+    ($($k:ident => $v:expr);+) => [$($k $v)+];
+    ($($t:tt)=>*) => ($crate::m!($($t)*))
+}
+macro_rules! n { () => { $ } }
@@ -0,0 +1 @@
+fn main() { let g = static || { yield; }; let h = static move |x| x; }
@@ -14,13 +14,14 @@ use std::{
     ffi::OsStr,
     fmt, io,
     sync::{Arc, Mutex},
+    time::Duration,
 };
 
 use serde::{Deserialize, Serialize};
 use tt::Subtree;
 
 use crate::{
-    msg::{ExpandMacro, FlatTree, PanicMessage},
+    msg::{ExpandMacro, FlatTree, PanicMessage, ProcMacroDiagnostic},
     process::ProcMacroProcessSrv,
 };
 
@@ -109,11 +110,15 @@ pub struct MacroPanic {
 
 impl ProcMacroServer {
     /// Spawns an external process as the proc macro server and returns a client connected to it.
+    ///
+    /// If the server doesn't respond to a request within `timeout`, it gets restarted and the
+    /// request fails.
     pub fn spawn(
         process_path: AbsPathBuf,
         args: impl IntoIterator<Item = impl AsRef<OsStr>>,
+        timeout: Option<Duration>,
     ) -> io::Result<ProcMacroServer> {
-        let process = ProcMacroProcessSrv::run(process_path, args)?;
+        let process = ProcMacroProcessSrv::run(process_path, args, timeout)?;
         Ok(ProcMacroServer { process: Arc::new(Mutex::new(process)) })
     }
 
@@ -155,7 +160,7 @@ impl ProcMacro {
         subtree: &Subtree,
         attr: Option<&Subtree>,
         env: Vec<(String, String)>,
-    ) -> Result<Result<Subtree, PanicMessage>, ServerError> {
+    ) -> Result<Result<(Subtree, Vec<ProcMacroDiagnostic>), PanicMessage>, ServerError> {
         let task = ExpandMacro {
             macro_body: FlatTree::new(subtree),
             macro_name: self.name.to_string(),
@@ -167,7 +172,9 @@ impl ProcMacro {
         let request = msg::Request::ExpandMacro(task);
         let response = self.process.lock().unwrap_or_else(|e| e.into_inner()).send_task(request)?;
         match response {
-            msg::Response::ExpandMacro(it) => Ok(it.map(FlatTree::to_subtree)),
+            msg::Response::ExpandMacro(it) => {
+                Ok(it.map(|it| (it.expansion.to_subtree(), it.diagnostics)))
+            }
             msg::Response::ListMacros { .. } => {
                 Err(ServerError { message: "unexpected response".to_string(), io: None })
             }
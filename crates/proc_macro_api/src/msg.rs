@@ -21,12 +21,33 @@ pub enum Request {
 #[derive(Debug, Serialize, Deserialize)]
 pub enum Response {
     ListMacros(Result<Vec<(String, ProcMacroKind)>, String>),
-    ExpandMacro(Result<FlatTree, PanicMessage>),
+    ExpandMacro(Result<ExpansionResult, PanicMessage>),
 }
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct PanicMessage(pub String);
 
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ExpansionResult {
+    pub expansion: FlatTree,
+    /// Diagnostics emitted by the macro with `proc_macro::Diagnostic::emit`.
+    pub diagnostics: Vec<ProcMacroDiagnostic>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProcMacroDiagnostic {
+    pub level: DiagnosticLevel,
+    pub message: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DiagnosticLevel {
+    Error,
+    Warning,
+    Note,
+    Help,
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct ExpandMacro {
     /// Argument of macro call.
@@ -151,4 +172,28 @@ mod tests {
 
         assert_eq!(tt, back.macro_body.to_subtree());
     }
+
+    #[test]
+    fn test_expansion_diagnostics_roundtrip() {
+        let tt = fixture_token_tree();
+        let diagnostics = vec![ProcMacroDiagnostic {
+            level: DiagnosticLevel::Error,
+            message: "unsupported input".to_string(),
+        }];
+        let response = Response::ExpandMacro(Ok(ExpansionResult {
+            expansion: FlatTree::new(&tt),
+            diagnostics: diagnostics.clone(),
+        }));
+
+        let json = serde_json::to_string(&response).unwrap();
+        let back: Response = serde_json::from_str(&json).unwrap();
+
+        match back {
+            Response::ExpandMacro(Ok(it)) => {
+                assert_eq!(tt, it.expansion.to_subtree());
+                assert_eq!(diagnostics, it.diagnostics);
+            }
+            _ => panic!("unexpected response: {:?}", back),
+        }
+    }
 }
@@ -2,8 +2,11 @@
 
 use std::{
     ffi::{OsStr, OsString},
-    io::{self, BufRead, BufReader, Write},
+    io::{self, BufReader},
     process::{Child, ChildStdin, ChildStdout, Command, Stdio},
+    sync::mpsc::{self, Receiver, RecvTimeoutError},
+    thread,
+    time::Duration,
 };
 
 use paths::{AbsPath, AbsPathBuf};
@@ -14,22 +17,36 @@ use crate::{
     ProcMacroKind, ServerError,
 };
 
+/// Responses read from the server's stdout, `None` once it has closed.
+type Responses = Receiver<io::Result<Option<Response>>>;
+
 #[derive(Debug)]
 pub(crate) struct ProcMacroProcessSrv {
+    process_path: AbsPathBuf,
+    args: Vec<OsString>,
+    timeout: Option<Duration>,
     _process: Process,
     stdin: ChildStdin,
-    stdout: BufReader<ChildStdout>,
+    responses: Responses,
 }
 
 impl ProcMacroProcessSrv {
     pub(crate) fn run(
         process_path: AbsPathBuf,
         args: impl IntoIterator<Item = impl AsRef<OsStr>>,
+        timeout: Option<Duration>,
     ) -> io::Result<ProcMacroProcessSrv> {
-        let mut process = Process::run(process_path, args)?;
-        let (stdin, stdout) = process.stdio().expect("couldn't access child stdio");
+        let args: Vec<OsString> = args.into_iter().map(|s| s.as_ref().into()).collect();
+        let (process, stdin, responses) = spawn(&process_path, &args)?;
 
-        let srv = ProcMacroProcessSrv { _process: process, stdin, stdout };
+        let srv = ProcMacroProcessSrv {
+            process_path,
+            args,
+            timeout,
+            _process: process,
+            stdin,
+            responses,
+        };
 
         Ok(srv)
     }
@@ -51,23 +68,74 @@ impl ProcMacroProcessSrv {
     }
 
     pub(crate) fn send_task(&mut self, req: Request) -> Result<Response, ServerError> {
-        let mut buf = String::new();
-        send_request(&mut self.stdin, &mut self.stdout, req, &mut buf)
+        req.write(&mut self.stdin).map_err(|err| ServerError {
+            message: "failed to write request".into(),
+            io: Some(err),
+        })?;
+
+        let res = match self.timeout {
+            Some(timeout) => match self.responses.recv_timeout(timeout) {
+                Ok(it) => it,
+                Err(RecvTimeoutError::Timeout) => {
+                    // The server is stuck in a macro, the only way to get it back is to kill it.
+                    // Dylibs are loaded lazily, so the new process doesn't need any setup.
+                    tracing::error!("proc macro server timed out after {:?}, restarting", timeout);
+                    self.restart().map_err(|err| ServerError {
+                        message: "failed to restart the proc macro server".into(),
+                        io: Some(err),
+                    })?;
+                    let message = format!("proc macro server timed out after {:?}", timeout);
+                    return Err(ServerError { message, io: None });
+                }
+                Err(RecvTimeoutError::Disconnected) => Ok(None),
+            },
+            None => self.responses.recv().unwrap_or(Ok(None)),
+        };
+        let res = res.map_err(|err| ServerError {
+            message: "failed to read response".into(),
+            io: Some(err),
+        })?;
+        res.ok_or_else(|| ServerError { message: "server exited".into(), io: None })
+    }
+
+    fn restart(&mut self) -> io::Result<()> {
+        let (process, stdin, responses) = spawn(&self.process_path, &self.args)?;
+        self._process = process;
+        self.stdin = stdin;
+        self.responses = responses;
+        Ok(())
     }
 }
 
+/// Spawns the server together with a thread which reads its responses, so that waiting for them
+/// can time out.
+fn spawn(path: &AbsPath, args: &[OsString]) -> io::Result<(Process, ChildStdin, Responses)> {
+    let mut process = Process::run(path, args)?;
+    let (stdin, mut stdout) = process.stdio().expect("couldn't access child stdio");
+
+    let (sender, receiver) = mpsc::channel();
+    thread::Builder::new().name("ProcMacroStdoutReader".to_owned()).spawn(move || {
+        let mut buf = String::new();
+        loop {
+            let res = Response::read(&mut stdout, &mut buf);
+            let is_done = !matches!(res, Ok(Some(_)));
+            if sender.send(res).is_err() || is_done {
+                break;
+            }
+        }
+    })?;
+
+    Ok((process, stdin, receiver))
+}
+
 #[derive(Debug)]
 struct Process {
     child: JodChild,
 }
 
 impl Process {
-    fn run(
-        path: AbsPathBuf,
-        args: impl IntoIterator<Item = impl AsRef<OsStr>>,
-    ) -> io::Result<Process> {
-        let args: Vec<OsString> = args.into_iter().map(|s| s.as_ref().into()).collect();
-        let child = JodChild(mk_child(&path, &args)?);
+    fn run(path: &AbsPath, args: &[OsString]) -> io::Result<Process> {
+        let child = JodChild(mk_child(path, args)?);
         Ok(Process { child })
     }
 
@@ -92,15 +160,29 @@ fn mk_child(
         .spawn()
 }
 
-fn send_request(
-    mut writer: &mut impl Write,
-    mut reader: &mut impl BufRead,
-    req: Request,
-    buf: &mut String,
-) -> Result<Response, ServerError> {
-    req.write(&mut writer)
-        .map_err(|err| ServerError { message: "failed to write request".into(), io: Some(err) })?;
-    let res = Response::read(&mut reader, buf)
-        .map_err(|err| ServerError { message: "failed to read response".into(), io: Some(err) })?;
-    res.ok_or_else(|| ServerError { message: "server exited".into(), io: None })
+#[cfg(test)]
+mod tests {
+    use std::time::Instant;
+
+    use super::*;
+
+    #[test]
+    #[cfg(unix)]
+    fn restarts_unresponsive_server() {
+        let timeout = Duration::from_millis(100);
+        let mut srv = ProcMacroProcessSrv::run(
+            AbsPathBuf::assert("/bin/sleep".into()),
+            ["10"],
+            Some(timeout),
+        )
+        .unwrap();
+        let dylib_path = AbsPathBuf::assert("/does/not/exist.so".into());
+
+        let start = Instant::now();
+        let err = srv.find_proc_macros(&dylib_path).unwrap_err();
+        assert!(err.message.contains("timed out"), "{}", err);
+        let err = srv.find_proc_macros(&dylib_path).unwrap_err();
+        assert!(err.message.contains("timed out"), "{}", err);
+        assert!(start.elapsed() < Duration::from_secs(5));
+    }
 }
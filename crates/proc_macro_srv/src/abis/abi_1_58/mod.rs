@@ -9,7 +9,7 @@ mod proc_macro;
 mod rustc_server;
 
 use libloading::Library;
-use proc_macro_api::ProcMacroKind;
+use proc_macro_api::{msg::ProcMacroDiagnostic, ProcMacroKind};
 
 use super::PanicMessage;
 
@@ -35,8 +35,9 @@ impl Abi {
         macro_name: &str,
         macro_body: &tt::Subtree,
         attributes: Option<&tt::Subtree>,
-    ) -> Result<tt::Subtree, PanicMessage> {
+    ) -> Result<(tt::Subtree, Vec<ProcMacroDiagnostic>), PanicMessage> {
         let parsed_body = rustc_server::TokenStream::with_subtree(macro_body.clone());
+        let inputs: Vec<_> = std::iter::once(macro_body).chain(attributes).collect();
 
         let parsed_attributes = attributes.map_or(rustc_server::TokenStream::new(), |attr| {
             rustc_server::TokenStream::with_subtree(attr.clone())
@@ -47,36 +48,48 @@ impl Abi {
                 proc_macro::bridge::client::ProcMacro::CustomDerive {
                     trait_name, client, ..
                 } if *trait_name == macro_name => {
+                    let server = rustc_server::Rustc::with_inputs(&inputs);
+                    let diagnostics = server.diagnostics();
                     let res = client.run(
                         &proc_macro::bridge::server::SameThread,
-                        rustc_server::Rustc::default(),
+                        server,
                         parsed_body,
                         false,
                     );
-                    return res.map(|it| it.into_subtree()).map_err(PanicMessage::from);
+                    return res
+                        .map(|it| (it.into_subtree(), diagnostics.take()))
+                        .map_err(PanicMessage::from);
                 }
                 proc_macro::bridge::client::ProcMacro::Bang { name, client }
                     if *name == macro_name =>
                 {
+                    let server = rustc_server::Rustc::with_inputs(&inputs);
+                    let diagnostics = server.diagnostics();
                     let res = client.run(
                         &proc_macro::bridge::server::SameThread,
-                        rustc_server::Rustc::default(),
+                        server,
                         parsed_body,
                         false,
                     );
-                    return res.map(|it| it.into_subtree()).map_err(PanicMessage::from);
+                    return res
+                        .map(|it| (it.into_subtree(), diagnostics.take()))
+                        .map_err(PanicMessage::from);
                 }
                 proc_macro::bridge::client::ProcMacro::Attr { name, client }
                     if *name == macro_name =>
                 {
+                    let server = rustc_server::Rustc::with_inputs(&inputs);
+                    let diagnostics = server.diagnostics();
                     let res = client.run(
                         &proc_macro::bridge::server::SameThread,
-                        rustc_server::Rustc::default(),
+                        server,
                         parsed_attributes,
                         parsed_body,
                         false,
                     );
-                    return res.map(|it| it.into_subtree()).map_err(PanicMessage::from);
+                    return res
+                        .map(|it| (it.into_subtree(), diagnostics.take()))
+                        .map_err(PanicMessage::from);
                 }
                 _ => continue,
             }
@@ -10,10 +10,14 @@
 
 use super::proc_macro::bridge::{self, server};
 
+use proc_macro_api::msg::{DiagnosticLevel, ProcMacroDiagnostic};
+
+use std::cell::RefCell;
 use std::collections::HashMap;
 use std::hash::Hash;
 use std::iter::FromIterator;
 use std::ops::Bound;
+use std::rc::Rc;
 use std::{ascii, vec::IntoIter};
 
 type Group = tt::Subtree;
@@ -21,6 +25,9 @@ type TokenTree = tt::TokenTree;
 type Punct = tt::Punct;
 type Spacing = tt::Spacing;
 type Literal = tt::Literal;
+/// Spans are plain `u32` token ids, which are mapped back to file ranges by the client. The
+/// server itself never stores file paths or ranges, and a `Rustc` server (together with
+/// everything it interned) lives for a single expansion only.
 type Span = tt::TokenId;
 
 #[derive(Debug, Clone)]
@@ -266,9 +273,48 @@ pub struct TokenStreamIter {
 #[derive(Default)]
 pub struct Rustc {
     ident_interner: IdentInterner,
+    /// The text of every token of the macro's input, to answer `Span::source_text`.
+    source_texts: HashMap<Span, String>,
+    /// Diagnostics emitted by the macro. Shared, because running a macro consumes the server.
+    diagnostics: Rc<RefCell<Vec<ProcMacroDiagnostic>>>,
     // FIXME: store span information here.
 }
 
+impl Rustc {
+    pub fn with_inputs(inputs: &[&tt::Subtree]) -> Rustc {
+        fn collect(subtree: &tt::Subtree, acc: &mut HashMap<Span, String>) {
+            for tt in &subtree.token_trees {
+                let (id, text) = match tt {
+                    tt::TokenTree::Subtree(subtree) => {
+                        collect(subtree, acc);
+                        continue;
+                    }
+                    tt::TokenTree::Leaf(tt::Leaf::Ident(ident)) => {
+                        (ident.id, ident.text.to_string())
+                    }
+                    tt::TokenTree::Leaf(tt::Leaf::Punct(punct)) => {
+                        (punct.id, punct.char.to_string())
+                    }
+                    tt::TokenTree::Leaf(tt::Leaf::Literal(lit)) => (lit.id, lit.text.to_string()),
+                };
+                if id != tt::TokenId::unspecified() {
+                    acc.insert(id, text);
+                }
+            }
+        }
+
+        let mut source_texts = HashMap::new();
+        for input in inputs {
+            collect(input, &mut source_texts);
+        }
+        Rustc { source_texts, ..Rustc::default() }
+    }
+
+    pub fn diagnostics(&self) -> Rc<RefCell<Vec<ProcMacroDiagnostic>>> {
+        self.diagnostics.clone()
+    }
+}
+
 impl server::Types for Rustc {
     type FreeFunctions = FreeFunctions;
     type TokenStream = TokenStream;
@@ -621,18 +667,35 @@ impl server::Diagnostic for Rustc {
 
     fn sub(
         &mut self,
-        _diag: &mut Self::Diagnostic,
-        _level: Level,
-        _msg: &str,
-        _spans: Self::MultiSpan,
+        diag: &mut Self::Diagnostic,
+        level: Level,
+        msg: &str,
+        spans: Self::MultiSpan,
     ) {
-        // FIXME handle diagnostic
-        //
-    }
-
-    fn emit(&mut self, _diag: Self::Diagnostic) {
-        // FIXME handle diagnostic
-        // diag.emit()
+        let mut child = Diagnostic::new(level, msg);
+        child.spans = spans;
+        diag.children.push(child);
+    }
+
+    fn emit(&mut self, diag: Self::Diagnostic) {
+        // FIXME: report the spans, so that the diagnostic can point into the macro input.
+        let mut message = diag.message;
+        for child in &diag.children {
+            let level = match child.level {
+                Level::Error => "error",
+                Level::Warning => "warning",
+                Level::Note => "note",
+                Level::Help => "help",
+            };
+            message.push_str(&format!("\n{}: {}", level, child.message));
+        }
+        let level = match diag.level {
+            Level::Error => DiagnosticLevel::Error,
+            Level::Warning => DiagnosticLevel::Warning,
+            Level::Note => DiagnosticLevel::Note,
+            Level::Help => DiagnosticLevel::Help,
+        };
+        self.diagnostics.borrow_mut().push(ProcMacroDiagnostic { level, message });
     }
 }
 
@@ -666,8 +729,10 @@ impl server::Span for Rustc {
     ///
     /// See PR:
     /// https://github.com/rust-lang/rust/pull/55780
-    fn source_text(&mut self, _span: Self::Span) -> Option<String> {
-        None
+    ///
+    /// We only know the text of single tokens, not of delimited groups or joined spans.
+    fn source_text(&mut self, span: Self::Span) -> Option<String> {
+        self.source_texts.get(&span).cloned()
     }
 
     fn parent(&mut self, _span: Self::Span) -> Option<Self::Span> {
@@ -727,7 +792,7 @@ mod tests {
 
     #[test]
     fn test_rustc_server_literals() {
-        let mut srv = Rustc { ident_interner: IdentInterner::default() };
+        let mut srv = Rustc::default();
         assert_eq!(srv.integer("1234").text, "1234");
 
         assert_eq!(srv.typed_integer("12", "u8").text, "12u8");
@@ -762,6 +827,47 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_rustc_server_source_text() {
+        let (subtree, _) = mbe::parse_to_token_tree(r#"foo(92, "bar")"#).unwrap();
+        let mut srv = Rustc::with_inputs(&[&subtree]);
+        let mut source_text =
+            |id| server::Span::source_text(&mut srv, tt::TokenId(id)).unwrap_or_default();
+
+        assert_eq!(source_text(0), "foo");
+        assert_eq!(source_text(2), "92");
+        assert_eq!(source_text(3), ",");
+        assert_eq!(source_text(4), "\"bar\"");
+        // The parens.
+        assert_eq!(source_text(1), "");
+        assert_eq!(server::Span::source_text(&mut srv, tt::TokenId::unspecified()), None);
+    }
+
+    #[test]
+    fn test_rustc_server_diagnostics() {
+        let mut srv = Rustc::default();
+        let diagnostics = srv.diagnostics();
+        let mut diag = server::Diagnostic::new(&mut srv, Level::Error, "unsupported input", vec![]);
+        server::Diagnostic::sub(&mut srv, &mut diag, Level::Help, "use a struct", vec![]);
+        server::Diagnostic::emit(&mut srv, diag);
+        let diag = server::Diagnostic::new(&mut srv, Level::Warning, "deprecated", vec![]);
+        server::Diagnostic::emit(&mut srv, diag);
+
+        assert_eq!(
+            diagnostics.take(),
+            vec![
+                ProcMacroDiagnostic {
+                    level: DiagnosticLevel::Error,
+                    message: "unsupported input\nhelp: use a struct".to_string(),
+                },
+                ProcMacroDiagnostic {
+                    level: DiagnosticLevel::Warning,
+                    message: "deprecated".to_string(),
+                },
+            ]
+        );
+    }
+
     #[test]
     fn test_rustc_server_to_string() {
         let s = TokenStream {
@@ -35,7 +35,7 @@ pub(crate) use abi_1_55::Abi as Abi_1_55;
 pub(crate) use abi_1_56::Abi as Abi_1_56;
 pub(crate) use abi_1_58::Abi as Abi_1_58;
 use libloading::Library;
-use proc_macro_api::{ProcMacroKind, RustCInfo};
+use proc_macro_api::{msg::ProcMacroDiagnostic, ProcMacroKind, RustCInfo};
 
 pub struct PanicMessage {
     message: Option<String>,
@@ -97,11 +97,19 @@ impl Abi {
         macro_name: &str,
         macro_body: &tt::Subtree,
         attributes: Option<&tt::Subtree>,
-    ) -> Result<tt::Subtree, PanicMessage> {
+    ) -> Result<(tt::Subtree, Vec<ProcMacroDiagnostic>), PanicMessage> {
+        // Only the newest ABI reports the diagnostics emitted by the macro.
+        let no_diagnostics = |it| (it, Vec::new());
         match self {
-            Self::Abi1_55(abi) => abi.expand(macro_name, macro_body, attributes),
-            Self::Abi1_47(abi) => abi.expand(macro_name, macro_body, attributes),
-            Self::Abi1_56(abi) => abi.expand(macro_name, macro_body, attributes),
+            Self::Abi1_55(abi) => {
+                abi.expand(macro_name, macro_body, attributes).map(no_diagnostics)
+            }
+            Self::Abi1_47(abi) => {
+                abi.expand(macro_name, macro_body, attributes).map(no_diagnostics)
+            }
+            Self::Abi1_56(abi) => {
+                abi.expand(macro_name, macro_body, attributes).map(no_diagnostics)
+            }
             Self::Abi1_58(abi) => abi.expand(macro_name, macro_body, attributes),
         }
     }
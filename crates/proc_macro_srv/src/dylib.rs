@@ -12,7 +12,7 @@ use libloading::Library;
 use memmap2::Mmap;
 use object::Object;
 use paths::AbsPath;
-use proc_macro_api::{read_dylib_info, ProcMacroKind};
+use proc_macro_api::{msg::ProcMacroDiagnostic, read_dylib_info, ProcMacroKind};
 
 use super::abis::Abi;
 
@@ -151,7 +151,7 @@ impl Expander {
         macro_name: &str,
         macro_body: &tt::Subtree,
         attributes: Option<&tt::Subtree>,
-    ) -> Result<tt::Subtree, String> {
+    ) -> Result<(tt::Subtree, Vec<ProcMacroDiagnostic>), String> {
         let result = self.inner.abi.expand(macro_name, macro_body, attributes);
         result.map_err(|e| e.as_str().unwrap_or_else(|| "<unknown error>".to_string()))
     }
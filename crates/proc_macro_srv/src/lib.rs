@@ -17,12 +17,13 @@ mod abis;
 use std::{
     collections::{hash_map::Entry, HashMap},
     env, fs,
+    panic::{self, AssertUnwindSafe},
     path::{Path, PathBuf},
     time::SystemTime,
 };
 
 use proc_macro_api::{
-    msg::{ExpandMacro, FlatTree, PanicMessage},
+    msg::{ExpandMacro, ExpansionResult, FlatTree, PanicMessage},
     ProcMacroKind,
 };
 
@@ -32,7 +33,7 @@ pub(crate) struct ProcMacroSrv {
 }
 
 impl ProcMacroSrv {
-    pub fn expand(&mut self, task: ExpandMacro) -> Result<FlatTree, PanicMessage> {
+    pub fn expand(&mut self, task: ExpandMacro) -> Result<ExpansionResult, PanicMessage> {
         let expander = self.expander(task.lib.as_ref()).map_err(|err| {
             debug_assert!(false, "should list macros before asking to expand");
             PanicMessage(format!("failed to load macro: {}", err))
@@ -46,9 +47,24 @@ impl ProcMacroSrv {
 
         let macro_body = task.macro_body.to_subtree();
         let attributes = task.attributes.map(|it| it.to_subtree());
-        let result = expander
-            .expand(&task.macro_name, &macro_body, attributes.as_ref())
-            .map(|it| FlatTree::new(&it));
+        // The bridge catches panics of the macro itself, but the server side of it might panic
+        // as well. Catch those too, so that the environment is restored and the server keeps
+        // running.
+        let result = panic::catch_unwind(AssertUnwindSafe(|| {
+            expander.expand(&task.macro_name, &macro_body, attributes.as_ref())
+        }))
+        .unwrap_or_else(|payload| {
+            let msg = payload
+                .downcast_ref::<&str>()
+                .map(|it| it.to_string())
+                .or_else(|| payload.downcast_ref::<String>().cloned())
+                .unwrap_or_else(|| "<unknown panic>".to_string());
+            Err(format!("proc macro server panicked: {}", msg))
+        })
+        .map(|(expansion, diagnostics)| ExpansionResult {
+            expansion: FlatTree::new(&expansion),
+            diagnostics,
+        });
 
         for (k, _) in &task.env {
             match &prev_env[k.as_str()] {
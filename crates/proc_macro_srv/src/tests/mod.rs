@@ -4,6 +4,7 @@
 mod utils;
 use expect_test::expect;
 use paths::AbsPathBuf;
+use proc_macro_api::msg::{DiagnosticLevel, FlatTree, ProcMacroDiagnostic};
 use utils::*;
 
 #[test]
@@ -75,6 +76,66 @@ fn test_attr_macro() {
     );
 }
 
+#[test]
+fn test_panicking_macro_does_not_break_the_server() {
+    let mut srv = crate::ProcMacroSrv::default();
+    let mut expand = |macro_name: &str| {
+        let task = proc_macro_api::msg::ExpandMacro {
+            macro_body: FlatTree::new(&parse_string("foo").unwrap().into_subtree()),
+            macro_name: macro_name.to_string(),
+            attributes: None,
+            lib: fixtures::proc_macro_test_dylib_path(),
+            env: vec![("RA_PROC_MACRO_SRV_TEST".to_string(), "1".to_string())],
+        };
+        srv.expand(task).map(|it| format!("{:?}", it.expansion.to_subtree()))
+    };
+
+    // Caught by the bridge.
+    let err = expand("fn_like_panic").unwrap_err();
+    assert!(err.0.contains("fn_like_panic!(foo)"), "{}", err.0);
+
+    // Escapes the bridge, and is caught by the server.
+    let err = expand("fn_like_panic_on_drop").unwrap_err();
+    assert_eq!(err.0, "proc macro server panicked: fn_like_panic_on_drop");
+    assert_eq!(std::env::var_os("RA_PROC_MACRO_SRV_TEST"), None);
+
+    expect![[r#"
+        SUBTREE $
+          IDENT   foo 4294967295"#]]
+    .assert_eq(&expand("fn_like_noop").unwrap());
+}
+
+#[test]
+fn test_macro_diagnostics_are_reported() {
+    let mut srv = crate::ProcMacroSrv::default();
+    let task = proc_macro_api::msg::ExpandMacro {
+        macro_body: FlatTree::new(&parse_string("foo").unwrap().into_subtree()),
+        macro_name: "fn_like_diagnostics".to_string(),
+        attributes: None,
+        lib: fixtures::proc_macro_test_dylib_path(),
+        env: Vec::new(),
+    };
+    let res = srv.expand(task).unwrap();
+
+    expect![[r#"
+        SUBTREE $
+          IDENT   foo 4294967295"#]]
+    .assert_eq(&format!("{:?}", res.expansion.to_subtree()));
+    assert_eq!(
+        res.diagnostics,
+        vec![
+            ProcMacroDiagnostic {
+                level: DiagnosticLevel::Warning,
+                message: "fn_like_diagnostics!(foo)\nhelp: this is a help".to_string(),
+            },
+            ProcMacroDiagnostic {
+                level: DiagnosticLevel::Error,
+                message: "this is an error".to_string(),
+            },
+        ]
+    );
+}
+
 /// Tests that we find and classify all proc macros correctly.
 #[test]
 fn list_test_macros() {
@@ -83,7 +144,9 @@ fn list_test_macros() {
     expect![[r#"
         fn_like_noop [FuncLike]
         fn_like_panic [FuncLike]
+        fn_like_panic_on_drop [FuncLike]
         fn_like_error [FuncLike]
+        fn_like_diagnostics [FuncLike]
         fn_like_clone_tokens [FuncLike]
         attr_noop [Attr]
         attr_panic [Attr]
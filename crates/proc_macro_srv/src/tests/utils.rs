@@ -11,7 +11,7 @@ pub mod fixtures {
     }
 }
 
-fn parse_string(code: &str) -> Option<crate::abis::abi_1_47::TokenStream> {
+pub fn parse_string(code: &str) -> Option<crate::abis::abi_1_47::TokenStream> {
     // This is a bit strange. We need to parse a string into a token stream into
     // order to create a tt::SubTree from it in fixtures. `into_subtree` is
     // implemented by all the ABIs we have so we arbitrarily choose one ABI to
@@ -35,7 +35,7 @@ fn assert_expand_impl(macro_name: &str, input: &str, attr: Option<&str>, expect:
     let fixture = parse_string(input).unwrap();
     let attr = attr.map(|attr| parse_string(attr).unwrap().into_subtree());
 
-    let res = expander.expand(macro_name, &fixture.into_subtree(), attr.as_ref()).unwrap();
+    let (res, _) = expander.expand(macro_name, &fixture.into_subtree(), attr.as_ref()).unwrap();
     expect.assert_eq(&format!("{:?}", res));
 }
 
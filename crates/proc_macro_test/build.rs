@@ -21,6 +21,9 @@ fn main() {
     let output = Command::new(toolchain::cargo())
         .current_dir("imp")
         .args(&["build", "-p", "proc_macro_test_impl", "--message-format", "json"])
+        // `proc_macro::Diagnostic` is unstable, but tests need a macro which emits diagnostics.
+        .args(["--features", "diagnostics"])
+        .env("RUSTC_BOOTSTRAP", "1")
         // Explicit override the target directory to avoid using the same one which the parent
         // cargo is using, or we'll deadlock.
         // This can happen when `CARGO_TARGET_DIR` is set or global config forces all cargo
@@ -1,5 +1,7 @@
 //! Exports a few trivial procedural macros for testing.
 
+#![cfg_attr(feature = "diagnostics", feature(proc_macro_diagnostic))]
+
 use proc_macro::{Group, Ident, Punct, TokenStream, TokenTree};
 
 #[proc_macro]
@@ -12,11 +14,36 @@ pub fn fn_like_panic(args: TokenStream) -> TokenStream {
     panic!("fn_like_panic!({})", args);
 }
 
+/// Panics with a payload which panics again when the bridge drops it, after the bridge's own
+/// `catch_unwind`. That second panic reaches the server.
+#[proc_macro]
+pub fn fn_like_panic_on_drop(_args: TokenStream) -> TokenStream {
+    struct PanicOnDrop;
+    impl Drop for PanicOnDrop {
+        fn drop(&mut self) {
+            panic!("fn_like_panic_on_drop");
+        }
+    }
+    std::panic::panic_any(PanicOnDrop)
+}
+
 #[proc_macro]
 pub fn fn_like_error(args: TokenStream) -> TokenStream {
     format!("compile_error!(\"fn_like_error!({})\");", args).parse().unwrap()
 }
 
+#[cfg(feature = "diagnostics")]
+#[proc_macro]
+pub fn fn_like_diagnostics(args: TokenStream) -> TokenStream {
+    use proc_macro::{Diagnostic, Level};
+
+    Diagnostic::new(Level::Warning, format!("fn_like_diagnostics!({})", args))
+        .help("this is a help")
+        .emit();
+    Diagnostic::new(Level::Error, "this is an error").emit();
+    args
+}
+
 #[proc_macro]
 pub fn fn_like_clone_tokens(args: TokenStream) -> TokenStream {
     clone_stream(args)
@@ -11,7 +11,7 @@ use std::{
     process::{Command, Stdio},
 };
 
-use anyhow::Result;
+use anyhow::{bail, Result};
 use cargo_metadata::{camino::Utf8Path, Message};
 use la_arena::ArenaMap;
 use paths::AbsPathBuf;
@@ -41,6 +41,32 @@ pub(crate) struct BuildScriptOutput {
     pub(crate) proc_macro_dylib_path: Option<AbsPathBuf>,
 }
 
+impl BuildScriptOutput {
+    /// Records the cfgs, env vars and `OUT_DIR` reported by a `build-script-executed` message.
+    pub(crate) fn add_build_script_output(
+        &mut self,
+        message: cargo_metadata::BuildScript,
+    ) -> Result<()> {
+        let mut cfgs = Vec::new();
+        for cfg in message.cfgs {
+            match cfg.parse::<CfgFlag>() {
+                Ok(it) => cfgs.push(it),
+                Err(err) => bail!("invalid cfg from cargo-metadata: {}", err),
+            };
+        }
+        // cargo_metadata crate returns default (empty) path for
+        // older cargos, which is not absolute, so work around that.
+        if !message.out_dir.as_str().is_empty() {
+            let out_dir = AbsPathBuf::assert(PathBuf::from(message.out_dir.into_os_string()));
+            self.out_dir = Some(out_dir);
+            self.cfgs = cfgs;
+        }
+
+        self.envs = message.env;
+        Ok(())
+    }
+}
+
 impl WorkspaceBuildScripts {
     pub(crate) fn run(
         config: &CargoConfig,
@@ -116,33 +142,9 @@ impl WorkspaceBuildScripts {
                             Some(it) => *it,
                             None => return,
                         };
-                        let cfgs = {
-                            let mut acc = Vec::new();
-                            for cfg in message.cfgs {
-                                match cfg.parse::<CfgFlag>() {
-                                    Ok(it) => acc.push(it),
-                                    Err(err) => {
-                                        callback_err = Some(anyhow::format_err!(
-                                            "invalid cfg from cargo-metadata: {}",
-                                            err
-                                        ));
-                                        return;
-                                    }
-                                };
-                            }
-                            acc
-                        };
-                        let package_build_data = &mut res.outputs[package];
-                        // cargo_metadata crate returns default (empty) path for
-                        // older cargos, which is not absolute, so work around that.
-                        if !message.out_dir.as_str().is_empty() {
-                            let out_dir =
-                                AbsPathBuf::assert(PathBuf::from(message.out_dir.into_os_string()));
-                            package_build_data.out_dir = Some(out_dir);
-                            package_build_data.cfgs = cfgs;
+                        if let Err(err) = res.outputs[package].add_build_script_output(message) {
+                            callback_err = Some(err);
                         }
-
-                        package_build_data.envs = message.env;
                     }
                     Message::CompilerArtifact(message) => {
                         let package = match by_id.get(&message.package_id.repr) {
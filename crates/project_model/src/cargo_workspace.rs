@@ -13,6 +13,7 @@ use rustc_hash::FxHashMap;
 use serde::Deserialize;
 use serde_json::from_value;
 
+use crate::lockfile::LockFile;
 use crate::CfgOverrides;
 use crate::{utf8_stdout, ManifestPath};
 
@@ -208,7 +209,8 @@ pub struct TargetData {
     pub root: AbsPathBuf,
     /// Kind of target
     pub kind: TargetKind,
-    /// Is this target a proc-macro
+    /// Is this target a proc-macro, i.e. `[lib] proc-macro = true`. Such crates get `proc_macro`
+    /// in their extern prelude, but are otherwise analyzed like any other crate.
     pub is_proc_macro: bool,
 }
 
@@ -294,8 +296,22 @@ impl CargoWorkspace {
         Ok(meta)
     }
 
-    pub fn new(mut meta: cargo_metadata::Metadata) -> CargoWorkspace {
+    pub fn new(meta: cargo_metadata::Metadata) -> CargoWorkspace {
+        let workspace_root =
+            AbsPathBuf::assert(PathBuf::from(meta.workspace_root.clone().into_os_string()));
+        let lock_file = LockFile::load(&workspace_root);
+        CargoWorkspace::with_lock_file(meta, lock_file)
+    }
+
+    pub(crate) fn with_lock_file(
+        mut meta: cargo_metadata::Metadata,
+        lock_file: Option<LockFile>,
+    ) -> CargoWorkspace {
+        let workspace_root =
+            AbsPathBuf::assert(PathBuf::from(meta.workspace_root.clone().into_os_string()));
+
         let mut pkg_by_id = FxHashMap::default();
+        let mut pkg_by_version = FxHashMap::default();
         let mut packages = Arena::default();
         let mut targets = Arena::default();
 
@@ -340,6 +356,7 @@ impl CargoWorkspace {
             });
             let pkg_data = &mut packages[pkg];
             pkg_by_id.insert(id, pkg);
+            pkg_by_version.insert((name.as_str(), version), pkg);
             for meta_tgt in &meta_pkg.targets {
                 let is_proc_macro = meta_tgt.kind.as_slice() == ["proc-macro"];
                 let tgt = targets.alloc(TargetData {
@@ -352,6 +369,8 @@ impl CargoWorkspace {
                 pkg_data.targets.push(tgt);
             }
         }
+        // Dependencies inherited from `[workspace.dependencies]` are already resolved by cargo
+        // and need no special treatment.
         let resolve = meta.resolve.expect("metadata executed with deps");
         for mut node in resolve.nodes {
             let source = match pkg_by_id.get(&node.id) {
@@ -370,8 +389,17 @@ impl CargoWorkspace {
                 .iter()
                 .flat_map(|dep| DepKind::iter(&dep.dep_kinds).map(move |kind| (dep, kind)))
             {
-                let pkg = match pkg_by_id.get(&dep_node.pkg) {
-                    Some(&pkg) => pkg,
+                // If the id doesn't match, resolve the dependency to the version it is locked to.
+                let locked = || {
+                    let lock_file = lock_file.as_ref()?;
+                    let dep_name = dep_node.pkg.repr.split(' ').next()?;
+                    let source = &packages[source];
+                    let version =
+                        lock_file.dependency_version(&source.name, &source.version, dep_name)?;
+                    pkg_by_version.get(&(dep_name, version)).copied()
+                };
+                let pkg = match pkg_by_id.get(&dep_node.pkg).copied().or_else(locked) {
+                    Some(pkg) => pkg,
                     None => {
                         tracing::error!(
                             "Dep node id do not match in cargo metadata, ignoring {}",
@@ -386,9 +414,6 @@ impl CargoWorkspace {
             packages[source].active_features.extend(node.features);
         }
 
-        let workspace_root =
-            AbsPathBuf::assert(PathBuf::from(meta.workspace_root.into_os_string()));
-
         CargoWorkspace { packages, targets, workspace_root }
     }
 
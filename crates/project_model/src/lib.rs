@@ -23,6 +23,7 @@ mod sysroot;
 mod workspace;
 mod rustc_cfg;
 mod build_scripts;
+mod lockfile;
 
 #[cfg(test)]
 mod tests;
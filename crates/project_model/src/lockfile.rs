@@ -0,0 +1,69 @@
+//! Reading of `Cargo.lock`, to find out which exact version of a package is used when the
+//! dependency graph contains several of them.
+
+use std::fs;
+
+use anyhow::Result;
+use paths::AbsPath;
+use serde::Deserialize;
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+pub(crate) struct LockFile {
+    #[serde(default, rename = "package")]
+    pub(crate) packages: Vec<LockedPackage>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub(crate) struct LockedPackage {
+    pub(crate) name: String,
+    pub(crate) version: semver::Version,
+    /// Entries of the form `name`, `name version` or `name version (source)`. The version is
+    /// only spelled out when the lock file contains several versions of the package.
+    #[serde(default)]
+    pub(crate) dependencies: Vec<String>,
+}
+
+impl LockFile {
+    /// Reads the `Cargo.lock` next to the workspace's `Cargo.toml`, if there is one.
+    pub(crate) fn load(workspace_root: &AbsPath) -> Option<LockFile> {
+        let path = workspace_root.join("Cargo.lock");
+        let text = fs::read_to_string(&path).ok()?;
+        match LockFile::parse(&text) {
+            Ok(it) => Some(it),
+            Err(err) => {
+                tracing::error!("failed to parse {}: {:#}", path.display(), err);
+                None
+            }
+        }
+    }
+
+    pub(crate) fn parse(text: &str) -> Result<LockFile> {
+        Ok(toml::from_str(text)?)
+    }
+
+    /// Returns the exact version of `dep` that version `version` of `package` is locked to.
+    pub(crate) fn dependency_version(
+        &self,
+        package: &str,
+        version: &semver::Version,
+        dep: &str,
+    ) -> Option<&semver::Version> {
+        let package =
+            self.packages.iter().find(|it| it.name == package && it.version == *version)?;
+        let entry = package.dependencies.iter().find(|it| it.split(' ').next() == Some(dep))?;
+        let mut locked = self.packages.iter().filter(|it| it.name == dep);
+        match entry.split(' ').nth(1) {
+            Some(dep_version) => {
+                let dep_version = semver::Version::parse(dep_version).ok()?;
+                locked.map(|it| &it.version).find(|&it| *it == dep_version)
+            }
+            None => {
+                let only = locked.next()?;
+                if locked.next().is_some() {
+                    return None;
+                }
+                Some(&only.version)
+            }
+        }
+    }
+}
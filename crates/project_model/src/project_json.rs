@@ -18,6 +18,8 @@ use crate::cfg_flag::CfgFlag;
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct ProjectJson {
     pub(crate) sysroot_src: Option<AbsPathBuf>,
+    /// The target rust-analyzer is configured to analyze for, used for crates without a `target`.
+    pub(crate) target: Option<String>,
     project_root: AbsPathBuf,
     crates: Vec<Crate>,
 }
@@ -33,6 +35,7 @@ pub struct Crate {
     pub(crate) deps: Vec<Dependency>,
     pub(crate) cfg: Vec<CfgFlag>,
     pub(crate) target: Option<String>,
+    pub(crate) target_cfgs: FxHashMap<String, Vec<CfgFlag>>,
     pub(crate) env: FxHashMap<String, String>,
     pub(crate) proc_macro_dylib_path: Option<AbsPathBuf>,
     pub(crate) is_workspace_member: bool,
@@ -53,6 +56,7 @@ impl ProjectJson {
     pub fn new(base: &AbsPath, data: ProjectJsonData) -> ProjectJson {
         ProjectJson {
             sysroot_src: data.sysroot_src.map(|it| base.join(it)),
+            target: None,
             project_root: base.to_path_buf(),
             crates: data
                 .crates
@@ -92,6 +96,7 @@ impl ProjectJson {
                             .collect::<Vec<_>>(),
                         cfg: crate_data.cfg,
                         target: crate_data.target,
+                        target_cfgs: crate_data.target_cfgs,
                         env: crate_data.env,
                         proc_macro_dylib_path: crate_data
                             .proc_macro_dylib_path
@@ -137,6 +142,8 @@ struct CrateData {
     #[serde(default)]
     cfg: Vec<CfgFlag>,
     target: Option<String>,
+    #[serde(default, deserialize_with = "deserialize_target_cfgs")]
+    target_cfgs: FxHashMap<String, Vec<CfgFlag>>,
     #[serde(default)]
     env: FxHashMap<String, String>,
     proc_macro_dylib_path: Option<PathBuf>,
@@ -191,3 +198,26 @@ where
     let name = String::deserialize(de)?;
     CrateName::new(&name).map_err(|err| de::Error::custom(format!("invalid crate name: {:?}", err)))
 }
+
+fn deserialize_target_cfgs<'de, D>(de: D) -> Result<FxHashMap<String, Vec<CfgFlag>>, D::Error>
+where
+    D: de::Deserializer<'de>,
+{
+    let target_cfgs = FxHashMap::<String, Vec<CfgFlag>>::deserialize(de)?;
+    if let Some(target) = target_cfgs.keys().find(|it| !is_target_triple(it)) {
+        return Err(de::Error::custom(format!("invalid target triple: {:?}", target)));
+    }
+    Ok(target_cfgs)
+}
+
+/// Checks that `target` looks like `<arch>-<vendor>-<os>[-<env>]`, the vendor being optional.
+fn is_target_triple(target: &str) -> bool {
+    let components = target.split('-').collect::<Vec<_>>();
+    (2..=4).contains(&components.len())
+        && components.iter().all(|it| {
+            !it.is_empty()
+                && it
+                    .chars()
+                    .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || "_.".contains(c))
+        })
+}
@@ -8,10 +8,11 @@ use cfg::{CfgAtom, CfgDiff};
 use expect_test::{expect, Expect};
 use paths::{AbsPath, AbsPathBuf};
 use serde::de::DeserializeOwned;
+use test_utils::skip_slow_tests;
 
 use crate::{
-    CargoWorkspace, CfgOverrides, ProjectJson, ProjectJsonData, ProjectWorkspace, Sysroot,
-    WorkspaceBuildScripts,
+    build_scripts::BuildScriptOutput, lockfile::LockFile, CargoWorkspace, CfgOverrides,
+    ProjectJson, ProjectJsonData, ProjectWorkspace, Sysroot, WorkspaceBuildScripts,
 };
 
 fn load_cargo(file: &str) -> CrateGraph {
@@ -2132,3 +2133,245 @@ fn rust_project_is_proc_macro_has_proc_macro_dep() {
     // on the proc_macro sysroot crate.
     crate_data.dependencies.iter().find(|&dep| dep.name.deref() == "proc_macro").unwrap();
 }
+
+const LOCK_FILE: &str = r#"
+# This file is automatically @generated by Cargo.
+# It is not intended for manual editing.
+version = 3
+
+[[package]]
+name = "app"
+version = "0.1.0"
+dependencies = [
+ "legacy",
+ "serde 1.0.130",
+]
+
+[[package]]
+name = "legacy"
+version = "0.2.0"
+source = "registry+https://github.com/rust-lang/crates.io-index"
+dependencies = [
+ "serde 0.9.15",
+]
+
+[[package]]
+name = "serde"
+version = "0.9.15"
+source = "registry+https://github.com/rust-lang/crates.io-index"
+checksum = "34b623917345a631dc9608d5194cc206b3fe6c3554cd1c75b937e55e285254af"
+
+[[package]]
+name = "serde"
+version = "1.0.130"
+source = "registry+https://github.com/rust-lang/crates.io-index"
+checksum = "f12d06de37cf59146fbdecab66aa99f9fe4f78722e3607577a5375d66bd0c913"
+"#;
+
+#[test]
+fn lock_file_resolves_dependency_versions() {
+    let lock_file = LockFile::parse(LOCK_FILE).unwrap();
+    assert_eq!(lock_file.packages.len(), 4);
+
+    let version = |package: &str, version: &str, dep: &str| {
+        let version = semver::Version::parse(version).unwrap();
+        lock_file.dependency_version(package, &version, dep).map(ToString::to_string)
+    };
+    assert_eq!(version("app", "0.1.0", "serde").as_deref(), Some("1.0.130"));
+    assert_eq!(version("legacy", "0.2.0", "serde").as_deref(), Some("0.9.15"));
+    assert_eq!(version("app", "0.1.0", "legacy").as_deref(), Some("0.2.0"));
+    assert_eq!(version("app", "0.1.0", "rand"), None);
+}
+
+#[test]
+fn cargo_workspace_resolves_mismatched_ids_from_lock_file() {
+    // The ids of the dependencies on `serde` don't match the ids of the packages, like in
+    // https://github.com/rust-lang/cargo/issues/7841, and there are two versions of `serde`.
+    let deps = |cargo: &CargoWorkspace| {
+        let mut deps: Vec<_> = cargo
+            .packages()
+            .flat_map(|pkg| {
+                cargo[pkg].dependencies.iter().map(move |dep| {
+                    format!("{} -> {} {}", cargo[pkg].name, dep.name, cargo[dep.pkg].version)
+                })
+            })
+            .collect();
+        deps.sort();
+        deps
+    };
+
+    let meta = get_test_json_file("locked-versions-metadata.json");
+    let cargo = CargoWorkspace::with_lock_file(meta, None);
+    assert_eq!(deps(&cargo), ["app -> legacy 0.2.0"]);
+
+    let meta = get_test_json_file("locked-versions-metadata.json");
+    let cargo = CargoWorkspace::with_lock_file(meta, Some(LockFile::parse(LOCK_FILE).unwrap()));
+    assert_eq!(
+        deps(&cargo),
+        ["app -> legacy 0.2.0", "app -> serde 1.0.130", "legacy -> serde 0.9.15"]
+    );
+}
+
+#[test]
+fn cargo_workspace_inherited_dependencies() {
+    // `cargo metadata` already substitutes `dep = { workspace = true }` with the definition from
+    // `[workspace.dependencies]`, so the dependency shows up like any other.
+    let meta = get_test_json_file("workspace-dependencies-metadata.json");
+    let cargo = CargoWorkspace::new(meta);
+    for member in cargo.packages().filter(|&pkg| cargo[pkg].is_member) {
+        let deps: Vec<_> = cargo[member]
+            .dependencies
+            .iter()
+            .map(|dep| format!("{} {}", dep.name, cargo[dep.pkg].version))
+            .collect();
+        assert_eq!(deps, ["libc 0.2.98"], "dependencies of {}", cargo[member].name);
+    }
+    assert_eq!(cargo.packages().filter(|&pkg| cargo[pkg].is_member).count(), 2);
+}
+
+#[test]
+fn cargo_build_script_cfgs() {
+    let message = r#"{"reason":"build-script-executed","package_id":"hello-world 0.1.0 (path+file://$ROOT$hello-world)","linked_libs":[],"linked_paths":[],"cfgs":["my_feature","kind=\"fast\""],"env":[["MY_ENV","1"]],"out_dir":"$ROOT$hello-world/target/debug/build/hello-world-0123/out"}"#;
+    let mut message = message.to_string();
+    replace_root(&mut message, true);
+    let message = match serde_json::from_str(&message).unwrap() {
+        cargo_metadata::Message::BuildScriptExecuted(it) => it,
+        it => panic!("unexpected message: {:?}", it),
+    };
+
+    let cargo = CargoWorkspace::new(get_test_json_file("hello-world-metadata.json"));
+    let package = cargo.packages().find(|&pkg| cargo[pkg].name == "hello-world").unwrap();
+    let mut output = BuildScriptOutput::default();
+    output.add_build_script_output(message).unwrap();
+    assert_eq!(output.envs, [("MY_ENV".to_string(), "1".to_string())]);
+    let mut build_scripts = WorkspaceBuildScripts::default();
+    build_scripts.outputs.insert(package, output);
+
+    let crate_graph = to_crate_graph(ProjectWorkspace::Cargo {
+        cargo,
+        build_scripts,
+        sysroot: None,
+        rustc: None,
+        rustc_cfg: Vec::new(),
+        cfg_overrides: CfgOverrides::default(),
+    });
+    let crate_data = crate_graph
+        .iter()
+        .map(|id| &crate_graph[id])
+        .find(|krate| krate.display_name.as_deref() == Some("hello_world"))
+        .unwrap();
+    let check = |atom| crate_data.cfg_options.check(&cfg::CfgExpr::Atom(atom));
+    assert_eq!(check(CfgAtom::Flag("my_feature".into())), Some(true));
+    assert_eq!(check(CfgAtom::KeyValue { key: "kind".into(), value: "fast".into() }), Some(true));
+}
+
+#[test]
+fn rust_project_target_cfgs() {
+    let mut project = rooted_project_json(get_test_json_file("target-cfgs-project.json"));
+    project.target = Some("thumbv7em-none-eabihf".to_string());
+    let crate_graph =
+        to_crate_graph(ProjectWorkspace::Json { project, sysroot: None, rustc_cfg: Vec::new() });
+
+    let crate_id = crate_graph.iter().next().unwrap();
+    let check = |atom| crate_graph[crate_id].cfg_options.check(&cfg::CfgExpr::Atom(atom));
+    assert_eq!(check(CfgAtom::Flag("common".into())), Some(true));
+    assert_eq!(check(CfgAtom::Flag("embedded".into())), Some(true));
+    assert_eq!(check(CfgAtom::KeyValue { key: "fpu".into(), value: "hard".into() }), Some(true));
+    assert_eq!(check(CfgAtom::Flag("simulator".into())), Some(false));
+}
+
+#[test]
+fn rust_project_rejects_invalid_target_triples() {
+    let data = r#"
+{
+    "crates": [
+        {
+            "root_module": "src/lib.rs",
+            "edition": "2021",
+            "deps": [],
+            "target_cfgs": { "not a triple": ["foo"] }
+        }
+    ]
+}
+"#;
+    let err = serde_json::from_str::<ProjectJsonData>(data).unwrap_err();
+    assert!(err.to_string().contains("invalid target triple"), "{}", err);
+}
+
+#[test]
+fn cargo_patched_dependency_is_local() {
+    // With `[patch.crates-io] serde = { path = "../local-serde" }`, `cargo metadata` reports the
+    // local copy of serde, without a registry source.
+    let cargo = CargoWorkspace::new(get_test_json_file("patched-dependency-metadata.json"));
+    let app = cargo.packages().find(|&pkg| cargo[pkg].name == "app").unwrap();
+    let serde = cargo[app].dependencies[0].pkg;
+    assert!(cargo[serde].is_local);
+    assert!(!cargo[serde].is_member);
+    assert!(cargo[serde].manifest.as_ref().ends_with("local-serde/Cargo.toml"));
+}
+
+#[test]
+fn cargo_proc_macro_crate_has_proc_macro_in_extern_prelude() {
+    let cargo = CargoWorkspace::new(get_test_json_file("proc-macro-metadata.json"));
+    let tgt = cargo[cargo.packages().next().unwrap()].targets[0];
+    assert!(cargo[tgt].is_proc_macro);
+
+    let crate_graph = to_crate_graph(ProjectWorkspace::Cargo {
+        cargo,
+        build_scripts: WorkspaceBuildScripts::default(),
+        sysroot: Some(get_fake_sysroot()),
+        rustc: None,
+        rustc_cfg: Vec::new(),
+        cfg_overrides: CfgOverrides::default(),
+    });
+    let proc_macro_dep = |name: &str| {
+        let krate = crate_graph
+            .iter()
+            .find(|&id| crate_graph[id].display_name.as_deref() == Some(name))
+            .unwrap();
+        let dep = crate_graph[krate].dependencies.iter().find(|dep| &*dep.name == "proc_macro");
+        dep.map(|dep| dep.is_prelude())
+    };
+    // `extern crate proc_macro;` is only implied for the proc macro target itself.
+    assert_eq!(proc_macro_dep("macros"), Some(true));
+    assert_eq!(proc_macro_dep("expand"), Some(false));
+}
+
+#[test]
+fn rust_project_cross_compilation_target_cfgs() {
+    // The cfgs of the crate's target come from `rustc --print cfg --target`, which works for
+    // built-in targets even if their standard library isn't installed.
+    if skip_slow_tests() {
+        return;
+    }
+    let project = rooted_project_json(get_test_json_file("embedded-project.json"));
+    let crate_graph =
+        to_crate_graph(ProjectWorkspace::Json { project, sysroot: None, rustc_cfg: Vec::new() });
+
+    let crate_id = crate_graph.iter().next().unwrap();
+    let check = |key: &str, value: &str| {
+        let atom = CfgAtom::KeyValue { key: key.into(), value: value.into() };
+        crate_graph[crate_id].cfg_options.check(&cfg::CfgExpr::Atom(atom))
+    };
+    assert_eq!(check("target_arch", "arm"), Some(true));
+    assert_eq!(check("target_os", "none"), Some(true));
+    assert_eq!(check("target_endian", "little"), Some(true));
+    assert_eq!(check("target_pointer_width", "32"), Some(true));
+    assert_eq!(check("target_pointer_width", "64"), Some(false));
+}
+
+#[test]
+fn cargo_default_features_are_enabled() {
+    let crate_graph = load_cargo("features-metadata.json");
+    let crate_id = crate_graph.iter().next().unwrap();
+    let krate = &crate_graph[crate_id];
+    let feature = |value: &str| {
+        cfg::CfgExpr::Atom(CfgAtom::KeyValue { key: "feature".into(), value: value.into() })
+    };
+    // `default = ["serde"]`, so `cfg(feature = "serde")` code takes part in name resolution.
+    assert_eq!(krate.cfg_options.check(&feature("default")), Some(true));
+    assert_eq!(krate.cfg_options.check(&feature("serde")), Some(true));
+    assert_eq!(krate.cfg_options.check(&feature("extra")), Some(false));
+    // Disabled features can still be suggested.
+    assert_eq!(krate.potential_cfg_options.check(&feature("extra")), Some(true));
+}
@@ -227,9 +227,10 @@ impl ProjectWorkspace {
     }
 
     pub fn load_inline(
-        project_json: ProjectJson,
+        mut project_json: ProjectJson,
         target: Option<&str>,
     ) -> Result<ProjectWorkspace> {
+        project_json.target = target.map(ToOwned::to_owned);
         let sysroot = match &project_json.sysroot_src {
             Some(path) => Some(Sysroot::load(path.clone())?),
             None => None,
@@ -461,8 +462,20 @@ fn project_json_to_crate_graph(
                 None => &rustc_cfg,
             };
 
+            let extra_cfgs = krate
+                .target
+                .as_ref()
+                .or(project.target.as_ref())
+                .and_then(|target| krate.target_cfgs.get(target));
+
             let mut cfg_options = CfgOptions::default();
-            cfg_options.extend(target_cfgs.iter().chain(krate.cfg.iter()).cloned());
+            cfg_options.extend(
+                target_cfgs
+                    .iter()
+                    .chain(krate.cfg.iter())
+                    .chain(extra_cfgs.into_iter().flatten())
+                    .cloned(),
+            );
             (
                 crate_id,
                 crate_graph.add_crate_root(
@@ -133,6 +133,10 @@ fn run_server() -> Result<()> {
 
     let (initialize_id, initialize_params) = connection.initialize_start()?;
     tracing::info!("InitializeParams: {}", initialize_params);
+    let diagnostics_refresh_support = initialize_params
+        .pointer("/capabilities/workspace/diagnostics/refreshSupport")
+        .and_then(|it| it.as_bool())
+        .unwrap_or(false);
     let initialize_params =
         from_json::<lsp_types::InitializeParams>("InitializeParams", initialize_params)?;
 
@@ -149,6 +153,7 @@ fn run_server() -> Result<()> {
     };
 
     let mut config = Config::new(root_path, initialize_params.capabilities);
+    config.diagnostics_refresh_support = diagnostics_refresh_support;
     if let Some(json) = initialize_params.initialization_options {
         config.update(json);
     }
@@ -164,7 +169,16 @@ fn run_server() -> Result<()> {
         offset_encoding: if supports_utf8(&config.caps) { Some("utf-8".to_string()) } else { None },
     };
 
-    let initialize_result = serde_json::to_value(initialize_result).unwrap();
+    let mut initialize_result = serde_json::to_value(initialize_result).unwrap();
+    // `lsp-types` doesn't know about type hierarchies yet.
+    initialize_result["capabilities"]["typeHierarchyProvider"] = serde_json::json!(true);
+    if config.pull_diagnostics() {
+        // `lsp-types` doesn't know about pull diagnostics yet.
+        initialize_result["capabilities"]["diagnosticProvider"] = serde_json::json!({
+            "interFileDependencies": true,
+            "workspaceDiagnostics": true,
+        });
+    }
 
     connection.initialize_finish(initialize_id, initialize_result)?;
 
@@ -82,6 +82,14 @@ impl CargoTargetSpec {
                     spec.push_to(&mut args, kind);
                 }
             }
+            RunnableKind::CargoDoc => {
+                args.push("doc".to_string());
+                args.push("--open".to_string());
+                args.push("--no-deps".to_string());
+                if let Some(spec) = spec {
+                    spec.push_to(&mut args, kind);
+                }
+            }
         }
 
         let cargo_config = snap.config.cargo();
@@ -135,8 +143,8 @@ impl CargoTargetSpec {
         buf.push("--package".to_string());
         buf.push(self.package);
 
-        // Can't mix --doc with other target flags
-        if let RunnableKind::DocTest { .. } = kind {
+        // Can't mix --doc with other target flags, and the docs are of the whole package
+        if let RunnableKind::DocTest { .. } | RunnableKind::CargoDoc = kind {
             return;
         }
         match self.target_kind {
@@ -60,7 +60,7 @@ pub fn load_workspace(
 
     let proc_macro_client = if load_config.with_proc_macro {
         let path = AbsPathBuf::assert(std::env::current_exe()?);
-        Some(ProcMacroServer::spawn(path, &["proc-macro"]).unwrap())
+        Some(ProcMacroServer::spawn(path, ["proc-macro"], None).unwrap())
     } else {
         None
     };
@@ -87,7 +87,8 @@ pub fn load_workspace(
         load_crate_graph(crate_graph, project_folders.source_root_config, &mut vfs, &receiver);
 
     if load_config.prefill_caches {
-        host.analysis().prime_caches(|_| {})?;
+        // Priming on a single thread keeps the profiles of the CLI commands readable.
+        host.analysis().prime_caches(1, |_| {})?;
     }
     Ok((host, vfs, proc_macro_client))
 }
@@ -7,12 +7,12 @@
 //! configure the server itself, feature flags are passed into analysis, and
 //! tweak things like automatic insertion of `()` in completions.
 
-use std::{ffi::OsString, iter, path::PathBuf};
+use std::{ffi::OsString, iter, path::PathBuf, time::Duration};
 
 use flycheck::FlycheckConfig;
 use ide::{
-    AssistConfig, CompletionConfig, DiagnosticsConfig, HighlightRelatedConfig, HoverConfig,
-    HoverDocFormat, InlayHintsConfig, JoinLinesConfig, Snippet, SnippetScope,
+    AssistConfig, CompletionConfig, DiagnosticsConfig, HighlightConfig, HighlightRelatedConfig,
+    HoverConfig, HoverDocFormat, InlayHintsConfig, JoinLinesConfig, Snippet, SnippetScope,
 };
 use ide_db::helpers::{
     insert_use::{ImportGranularity, InsertUseConfig, PrefixKind},
@@ -172,6 +172,10 @@ config_data! {
         /// Whether to show experimental rust-analyzer diagnostics that might
         /// have more false positives than usual.
         diagnostics_enableExperimental: bool    = "true",
+        /// Whether to answer `textDocument/diagnostic` and `workspace/diagnostic`
+        /// requests instead of publishing native rust-analyzer diagnostics.
+        /// Diagnostics from `cargo check` are still published.
+        diagnostics_pull_enable: bool           = "false",
         /// List of rust-analyzer diagnostics to disable.
         diagnostics_disabled: FxHashSet<String> = "[]",
         /// Map of prefixes to be substituted when parsing diagnostic file paths.
@@ -187,6 +191,9 @@ config_data! {
         /// The warnings will be indicated by a blue squiggly underline in code
         /// and a blue icon in the `Problems Panel`.
         diagnostics_warningsAsInfo: Vec<String> = "[]",
+        /// Whether to warn about discarded `Result`s of function calls, even if
+        /// the function is not marked with `#[must_use]`.
+        diagnostics_warnUnusedResult: bool = "false",
 
         /// Expand attribute macros.
         experimental_procAttrMacros: bool = "true",
@@ -247,6 +254,21 @@ config_data! {
         inlayHints_parameterHints: bool             = "true",
         /// Whether to show inlay type hints for variables.
         inlayHints_typeHints: bool                  = "true",
+        /// Whether to show inlay type hints for closure parameters. Only applies if
+        /// `#rust-analyzer.inlayHints.typeHints#` is set.
+        inlayHints_closureParameterTypes_enable: bool = "true",
+        /// Whether to show inlay hints for the `ref` and `ref mut` binding modes that
+        /// match ergonomics implicitly add to bindings in patterns.
+        inlayHints_bindingModeHints_enable: bool    = "false",
+        /// Whether to show inlay hints for the inferred type arguments of generic
+        /// function calls, as a turbofish after the function name.
+        inlayHints_genericParameterHints_enable: bool = "false",
+        /// Whether to show inlay hints telling if the `#[cfg]` conditions of
+        /// items are enabled in the current configuration.
+        inlayHints_cfgConditionHints_enable: bool = "false",
+        /// Whether to show inlay hints for the lifetimes of borrows of fields,
+        /// elements and referents, when that lifetime has a name.
+        inlayHints_lifetimeHints_enable: bool = "false",
         /// Whether to hide inlay hints for constructors.
         inlayHints_hideNamedConstructorHints: bool  = "false",
 
@@ -296,11 +318,18 @@ config_data! {
         /// Whether to show `can't find Cargo.toml` error message.
         notifications_cargoTomlNotFound: bool      = "true",
 
+        /// How many worker threads to use for priming caches on startup. The default `0`
+        /// means to use one thread per physical CPU core.
+        primeCaches_numThreads: usize              = "0",
+
         /// Enable support for procedural macros, implies `#rust-analyzer.cargo.runBuildScripts#`.
         procMacro_enable: bool                     = "true",
         /// Internal config, path to proc-macro server executable (typically,
         /// this is rust-analyzer itself, but we override this in tests).
         procMacro_server: Option<PathBuf>          = "null",
+        /// Time in milliseconds after which a proc macro expansion is aborted and the proc-macro
+        /// server is restarted. `0` means no timeout.
+        procMacro_timeout: u64                     = "5000",
 
         /// Command to be executed instead of 'cargo' for runnables.
         runnables_overrideCargo: Option<String> = "null",
@@ -328,6 +357,10 @@ config_data! {
         /// available on a nightly build.
         rustfmt_enableRangeFormatting: bool = "false",
 
+        /// Use semantic tokens for the format specifiers and argument names inside format
+        /// string literals, like `:.2` and `name` in `format!("{name:.2}")`.
+        semanticHighlighting_formatStringLiterals: bool = "true",
+
         /// Workspace symbol search scope.
         workspace_symbol_search_scope: WorkspaceSymbolSearchScopeDef = "\"workspace\"",
         /// Workspace symbol search kind.
@@ -347,6 +380,9 @@ pub struct Config {
     data: ConfigData,
     detached_files: Vec<AbsPathBuf>,
     pub discovered_projects: Option<Vec<ProjectManifest>>,
+    /// Whether the client supports `workspace/diagnostic/refresh`. `lsp-types` doesn't know
+    /// about pull diagnostics yet, so this is read from the raw client capabilities.
+    pub diagnostics_refresh_support: bool,
     pub root_path: AbsPathBuf,
     snippets: Vec<Snippet>,
 }
@@ -484,6 +520,7 @@ impl Config {
             data: ConfigData::default(),
             detached_files: Vec::new(),
             discovered_projects: None,
+            diagnostics_refresh_support: false,
             root_path,
             snippets: Default::default(),
         }
@@ -677,6 +714,10 @@ impl Config {
         }
     }
 
+    pub fn inlay_hint_resolve(&self) -> bool {
+        self.experimental("inlayHintResolve")
+    }
+
     fn experimental(&self, index: &'static str) -> bool {
         try_or!(self.caps.experimental.as_ref()?.get(index)?.as_bool()?, false)
     }
@@ -688,12 +729,16 @@ impl Config {
     }
 
     pub fn publish_diagnostics(&self) -> bool {
-        self.data.diagnostics_enable
+        self.data.diagnostics_enable && !self.data.diagnostics_pull_enable
+    }
+    pub fn pull_diagnostics(&self) -> bool {
+        self.data.diagnostics_enable && self.data.diagnostics_pull_enable
     }
     pub fn diagnostics(&self) -> DiagnosticsConfig {
         DiagnosticsConfig {
             disable_experimental: !self.data.diagnostics_enableExperimental,
             disabled: self.data.diagnostics_disabled.clone(),
+            warn_unused_result: self.data.diagnostics_warnUnusedResult,
         }
     }
     pub fn diagnostics_map(&self) -> DiagnosticsMapConfig {
@@ -706,6 +751,12 @@ impl Config {
     pub fn lru_capacity(&self) -> Option<usize> {
         self.data.lruCapacity
     }
+    pub fn prime_caches_num_threads(&self) -> usize {
+        match self.data.primeCaches_numThreads {
+            0 => num_cpus::get_physical(),
+            n => n,
+        }
+    }
     pub fn proc_macro_srv(&self) -> Option<(AbsPathBuf, Vec<OsString>)> {
         if !self.data.procMacro_enable {
             return None;
@@ -716,6 +767,12 @@ impl Config {
         };
         Some((path, vec!["proc-macro".into()]))
     }
+    pub fn proc_macro_timeout(&self) -> Option<Duration> {
+        match self.data.procMacro_timeout {
+            0 => None,
+            ms => Some(Duration::from_millis(ms)),
+        }
+    }
     pub fn expand_proc_attr_macros(&self) -> bool {
         self.data.experimental_procAttrMacros
     }
@@ -818,8 +875,15 @@ impl Config {
             type_hints: self.data.inlayHints_typeHints,
             parameter_hints: self.data.inlayHints_parameterHints,
             chaining_hints: self.data.inlayHints_chainingHints,
+            closure_param_type_hints: self.data.inlayHints_closureParameterTypes_enable,
+            binding_mode_hints: self.data.inlayHints_bindingModeHints_enable,
+            generic_parameter_hints: self.data.inlayHints_genericParameterHints_enable,
+            cfg_hints: self.data.inlayHints_cfgConditionHints_enable,
+            lifetime_hints: self.data.inlayHints_lifetimeHints_enable,
             hide_named_constructor_hints: self.data.inlayHints_hideNamedConstructorHints,
             max_length: self.data.inlayHints_maxLength,
+            // Clients which can resolve hints get their tooltips on demand.
+            render_tooltips: !self.inlay_hint_resolve(),
         }
     }
     fn insert_use_config(&self) -> InsertUseConfig {
@@ -904,6 +968,11 @@ impl Config {
     pub fn highlighting_strings(&self) -> bool {
         self.data.highlighting_strings
     }
+    pub fn highlighting(&self) -> HighlightConfig {
+        HighlightConfig {
+            format_string_literals: self.data.semanticHighlighting_formatStringLiterals,
+        }
+    }
     pub fn hover(&self) -> HoverConfig {
         HoverConfig {
             links_in_hover: self.data.hover_linksInHover,
@@ -1227,6 +1296,10 @@ fn field_props(field: &str, ty: &str, doc: &[&str], default: &str) -> serde_json
         "FxHashMap<String, String>" => set! {
             "type": "object",
         },
+        "usize" | "u64" => set! {
+            "type": "integer",
+            "minimum": 0,
+        },
         "Option<usize>" => set! {
             "type": ["null", "integer"],
             "minimum": 0,
@@ -190,6 +190,7 @@ fn map_rust_child_diagnostic(
                     title: message,
                     group: None,
                     kind: Some(lsp_types::CodeActionKind::QUICKFIX),
+                    command: None,
                     edit: Some(lsp_ext::SnippetWorkspaceEdit {
                         // FIXME: there's no good reason to use edit_map here....
                         changes: Some(edit_map),
@@ -1,6 +1,11 @@
 //! See [RequestDispatcher].
-use std::{fmt, panic, thread};
+use std::{
+    fmt, panic,
+    sync::{atomic::AtomicBool, Arc},
+    thread,
+};
 
+use crossbeam_channel::Sender;
 use serde::{de::DeserializeOwned, Serialize};
 
 use crate::{
@@ -10,6 +15,14 @@ use crate::{
     LspError, Result,
 };
 
+/// A handler which reports its progress through the sender and checks the flag for cancellation.
+type CancellableHandler<R> = fn(
+    GlobalStateSnapshot,
+    <R as lsp_types::request::Request>::Params,
+    Sender<Task>,
+    Arc<AtomicBool>,
+) -> Result<<R as lsp_types::request::Request>::Result>;
+
 /// A visitor for routing a raw JSON request to an appropriate handler function.
 ///
 /// Most requests are read-only and async and are handled on the threadpool
@@ -22,6 +35,9 @@ use crate::{
 /// Some requests modify the state, and are run on the main thread to get
 /// `&mut` (`on_sync_mut`).
 ///
+/// Long running requests are handled on the threadpool as well, but can report
+/// their progress and notice when the client cancels them (`on_cancellable`).
+///
 /// Read-only requests are wrapped into `catch_unwind` -- they don't modify the
 /// state, so it's OK to recover from their failures.
 pub(crate) struct RequestDispatcher<'a> {
@@ -111,6 +127,40 @@ impl<'a> RequestDispatcher<'a> {
         self
     }
 
+    /// Dispatches the request onto thread pool, giving the handler the sender
+    /// of the tasks to report its progress and a flag which is set once the
+    /// client cancels the request.
+    pub(crate) fn on_cancellable<R>(&mut self, f: CancellableHandler<R>) -> &mut Self
+    where
+        R: lsp_types::request::Request + 'static,
+        R::Params: DeserializeOwned + panic::UnwindSafe + Send + fmt::Debug + 'static,
+        R::Result: Serialize + 'static,
+    {
+        let (id, params, panic_context) = match self.parse::<R>() {
+            Some(it) => it,
+            None => return self,
+        };
+        let cancelled = Arc::new(AtomicBool::new(false));
+        self.global_state.cancellable_requests.insert(id.clone(), cancelled.clone());
+
+        self.global_state.task_pool.handle.spawn_with_sender({
+            let world = self.global_state.snapshot();
+            move |sender| {
+                let result = panic::catch_unwind({
+                    let sender = sender.clone();
+                    move || {
+                        let _pctx = stdx::panic_context::enter(panic_context);
+                        f(world, params, sender, cancelled)
+                    }
+                });
+                let response = thread_result_to_response::<R>(id, result);
+                sender.send(Task::Response(response)).unwrap();
+            }
+        });
+
+        self
+    }
+
     pub(crate) fn finish(&mut self) {
         if let Some(req) = self.req.take() {
             tracing::error!("unknown request: {:?}", req);
@@ -115,5 +115,8 @@ pub(crate) fn annotation(
                 },
             })
         }
+        lsp_ext::CodeLensResolveData::Run { .. } | lsp_ext::CodeLensResolveData::Debug(_) => {
+            Err(invalid_params_error("runnable code lens is already resolved".to_string()).into())
+        }
     }
 }
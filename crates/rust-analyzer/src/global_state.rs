@@ -3,7 +3,13 @@
 //!
 //! Each tick provides an immutable snapshot of the state as `WorldSnapshot`.
 
-use std::{sync::Arc, time::Instant};
+use std::{
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    time::Instant,
+};
 
 use crossbeam_channel::{unbounded, Receiver, Sender};
 use flycheck::FlycheckHandle;
@@ -104,6 +110,10 @@ pub(crate) struct GlobalState {
         OpQueue<(Arc<Vec<ProjectWorkspace>>, Vec<anyhow::Result<WorkspaceBuildScripts>>)>,
 
     pub(crate) prime_caches_queue: OpQueue<()>,
+
+    /// Flags telling the handlers of the long running requests in flight that
+    /// the client cancelled them.
+    pub(crate) cancellable_requests: FxHashMap<lsp_server::RequestId, Arc<AtomicBool>>,
 }
 
 /// An immutable snapshot of the world's state at a point in time.
@@ -167,6 +177,8 @@ impl GlobalState {
             fetch_workspaces_queue: OpQueue::default(),
             prime_caches_queue: OpQueue::default(),
 
+            cancellable_requests: FxHashMap::default(),
+
             fetch_build_data_queue: OpQueue::default(),
         };
         // Apply any required database inputs from the config.
@@ -278,6 +290,7 @@ impl GlobalState {
             .register(request.id.clone(), (request.method.clone(), request_received));
     }
     pub(crate) fn respond(&mut self, response: lsp_server::Response) {
+        self.cancellable_requests.remove(&response.id);
         if let Some((method, start)) = self.req_queue.incoming.complete(response.id.clone()) {
             if let Some(err) = &response.error {
                 if err.message.starts_with("server panicked") {
@@ -291,6 +304,9 @@ impl GlobalState {
         }
     }
     pub(crate) fn cancel(&mut self, request_id: lsp_server::RequestId) {
+        if let Some(cancelled) = self.cancellable_requests.remove(&request_id) {
+            cancelled.store(true, Ordering::Relaxed);
+        }
         if let Some(response) = self.req_queue.incoming.cancel(request_id) {
             self.send(response.into());
         }
@@ -323,6 +339,27 @@ impl GlobalStateSnapshot {
         Ok(res)
     }
 
+    /// Returns the ids of all Rust files that belong to the workspace, rather than to a library.
+    pub(crate) fn workspace_rust_files(&self) -> Cancellable<Vec<FileId>> {
+        let file_ids: Vec<_> = self
+            .vfs
+            .read()
+            .0
+            .iter()
+            .filter(|(_, path)| {
+                path.name_and_extension().map_or(false, |(_, ext)| ext == Some("rs"))
+            })
+            .map(|(file_id, _)| file_id)
+            .collect();
+        let mut res = Vec::new();
+        for file_id in file_ids {
+            if !self.analysis.is_library_file(file_id)? {
+                res.push(file_id);
+            }
+        }
+        Ok(res)
+    }
+
     pub(crate) fn url_file_version(&self, url: &Url) -> Option<i32> {
         let path = from_proto::vfs_path(url).ok()?;
         Some(self.mem_docs.get(&path)?.version)
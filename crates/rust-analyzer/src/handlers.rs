@@ -3,15 +3,27 @@
 //! `ide` crate.
 
 use std::{
-    io::Write as _,
+    collections::hash_map::DefaultHasher,
+    env,
+    hash::{Hash, Hasher},
+    io::{Read, Write as _},
+    path::PathBuf,
     process::{self, Stdio},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    thread,
+    time::Duration,
 };
 
 use anyhow::Context;
+use crossbeam_channel::Sender;
+use flycheck::FlycheckKind;
 use ide::{
-    AnnotationConfig, AssistKind, AssistResolveStrategy, FileId, FilePosition, FileRange,
-    HoverAction, HoverGotoTypeData, Query, RangeInfo, Runnable, RunnableKind, SingleResolve,
-    SourceChange, TextEdit,
+    AnnotationConfig, AssistKind, AssistResolveStrategy, DocsLocation, FileId, FilePosition,
+    FileRange, HoverAction, HoverGotoTypeData, ItemDocumentation, Query, RangeInfo, Runnable,
+    RunnableKind, SingleResolve, SourceChange, TextEdit,
 };
 use ide_db::SymbolKind;
 use itertools::Itertools;
@@ -28,7 +40,7 @@ use lsp_types::{
 };
 use project_model::{ManifestPath, ProjectWorkspace, TargetKind};
 use serde_json::json;
-use stdx::{format_to, never};
+use stdx::{format_to, never, JodChild};
 use syntax::{algo, ast, AstNode, TextRange, TextSize, T};
 use vfs::AbsPathBuf;
 
@@ -36,14 +48,15 @@ use crate::{
     cargo_target_spec::CargoTargetSpec,
     config::RustfmtConfig,
     diff::diff,
-    from_proto,
+    from_json, from_proto,
     global_state::{GlobalState, GlobalStateSnapshot},
-    line_index::LineEndings,
+    line_index::{LineEndings, LineIndex},
     lsp_ext::{
         self, InlayHint, InlayHintsParams, PositionOrRange, ViewCrateGraphParams,
         WorkspaceSymbolParams,
     },
-    lsp_utils::{all_edits_are_disjoint, invalid_params_error},
+    lsp_utils::{all_edits_are_disjoint, invalid_params_error, Progress},
+    main_loop::Task,
     to_proto, LspError, Result,
 };
 
@@ -102,6 +115,40 @@ pub(crate) fn handle_shuffle_crate_graph(state: &mut GlobalState, _: ()) -> Resu
     Ok(())
 }
 
+pub(crate) fn handle_check_current_file(
+    state: &mut GlobalState,
+    params: lsp_ext::CheckCurrentFileParams,
+) -> Result<()> {
+    let _p = profile::span("handle_check_current_file");
+    let snap = state.snapshot();
+    let file_id = from_proto::file_id(&snap, &params.text_document.uri)?;
+    let spec = CargoTargetSpec::for_file(&snap, file_id)?;
+    // The index of the workspace is the id of its flycheck.
+    let workspace = spec.as_ref().and_then(|spec| {
+        state.workspaces.iter().position(|ws| match ws {
+            ProjectWorkspace::Cargo { cargo, .. } => {
+                *cargo.workspace_root() == *spec.workspace_root
+            }
+            _ => false,
+        })
+    });
+    match spec.zip(workspace) {
+        Some((spec, workspace)) => {
+            let kind = FlycheckKind::SingleCrate { package: spec.package };
+            for flycheck in state.flycheck.iter().filter(|it| it.id() == workspace) {
+                flycheck.restart(kind.clone());
+            }
+        }
+        // Not part of a Cargo package, check everything instead.
+        None => {
+            for flycheck in &state.flycheck {
+                flycheck.update();
+            }
+        }
+    }
+    Ok(())
+}
+
 pub(crate) fn handle_syntax_tree(
     snap: GlobalStateSnapshot,
     params: lsp_ext::SyntaxTreeParams,
@@ -114,6 +161,16 @@ pub(crate) fn handle_syntax_tree(
     Ok(res)
 }
 
+pub(crate) fn handle_show_type(
+    snap: GlobalStateSnapshot,
+    params: lsp_types::TextDocumentPositionParams,
+) -> Result<Option<String>> {
+    let _p = profile::span("handle_show_type");
+    let position = from_proto::file_position(&snap, params)?;
+    let res = snap.analysis.show_type(position)?;
+    Ok(res)
+}
+
 pub(crate) fn handle_view_hir(
     snap: GlobalStateSnapshot,
     params: lsp_types::TextDocumentPositionParams,
@@ -152,8 +209,24 @@ pub(crate) fn handle_expand_macro(
     let line_index = snap.file_line_index(file_id)?;
     let offset = from_proto::offset(&line_index, params.position);
 
-    let res = snap.analysis.expand_macro(FilePosition { file_id, offset })?;
-    Ok(res.map(|it| lsp_ext::ExpandedMacro { name: it.name, expansion: it.expansion }))
+    let res = match snap.analysis.expand_macro(FilePosition { file_id, offset })? {
+        Some(it) => it,
+        None => return Ok(None),
+    };
+    let expansion_index = LineIndex {
+        index: Arc::new(ide::LineIndex::new(&res.expansion)),
+        endings: LineEndings::Unix,
+        encoding: snap.config.offset_encoding(),
+    };
+    let mappings = res
+        .mappings
+        .into_iter()
+        .map(|(source, expansion)| lsp_ext::ExpandedMacroMapping {
+            source: to_proto::range(&line_index, source),
+            expansion: to_proto::range(&expansion_index, expansion),
+        })
+        .collect();
+    Ok(Some(lsp_ext::ExpandedMacro { name: res.name, expansion: res.expansion, mappings }))
 }
 
 pub(crate) fn handle_selection_range(
@@ -221,6 +294,43 @@ pub(crate) fn handle_matching_brace(
     Ok(res)
 }
 
+pub(crate) fn handle_parent_expression(
+    snap: GlobalStateSnapshot,
+    params: lsp_ext::ParentExpressionParams,
+) -> Result<Vec<Range>> {
+    let _p = profile::span("handle_parent_expression");
+    let file_id = from_proto::file_id(&snap, &params.text_document.uri)?;
+    let line_index = snap.file_line_index(file_id)?;
+    let res = params
+        .selections
+        .into_iter()
+        .map(|selection| {
+            let range = from_proto::text_range(&line_index, selection);
+            let range = match snap.analysis.parent_expression(FileRange { file_id, range }) {
+                Ok(Some(parent_range)) => parent_range,
+                Err(_) | Ok(None) => range,
+            };
+            to_proto::range(&line_index, range)
+        })
+        .collect();
+    Ok(res)
+}
+
+pub(crate) fn handle_linked_editing_range(
+    snap: GlobalStateSnapshot,
+    params: lsp_types::LinkedEditingRangeParams,
+) -> Result<Option<lsp_types::LinkedEditingRanges>> {
+    let _p = profile::span("handle_linked_editing_range");
+    let position = from_proto::file_position(&snap, params.text_document_position_params)?;
+    let line_index = snap.file_line_index(position.file_id)?;
+    let ranges = match snap.analysis.linked_editing_range(position)? {
+        None => return Ok(None),
+        Some(ranges) => ranges,
+    };
+    let ranges = ranges.into_iter().map(|range| to_proto::range(&line_index, range)).collect();
+    Ok(Some(lsp_types::LinkedEditingRanges { ranges, word_pattern: None }))
+}
+
 pub(crate) fn handle_join_lines(
     snap: GlobalStateSnapshot,
     params: lsp_ext::JoinLinesParams,
@@ -891,6 +1001,21 @@ pub(crate) fn handle_folding_range(
     Ok(Some(res))
 }
 
+pub(crate) fn handle_document_link(
+    snap: GlobalStateSnapshot,
+    params: lsp_types::DocumentLinkParams,
+) -> Result<Option<Vec<lsp_types::DocumentLink>>> {
+    let _p = profile::span("handle_document_link");
+    let file_id = from_proto::file_id(&snap, &params.text_document.uri)?;
+    let links = snap.analysis.doc_links(file_id)?;
+    let line_index = snap.file_line_index(file_id)?;
+    let res = links
+        .into_iter()
+        .map(|it| to_proto::document_link(&snap, &line_index, it))
+        .collect::<Result<Vec<_>>>()?;
+    Ok(Some(res))
+}
+
 pub(crate) fn handle_signature_help(
     snap: GlobalStateSnapshot,
     params: lsp_types::SignatureHelpParams,
@@ -1082,6 +1207,10 @@ pub(crate) fn handle_code_action(
         res.push(code_action)
     }
 
+    if params.context.only.is_none() {
+        res.extend(run_test_code_actions(&snap, frange)?);
+    }
+
     // Fixes from `cargo check`.
     for fix in snap.check_fixes.get(&frange.file_id).into_iter().flatten() {
         // FIXME: this mapping is awkward and shouldn't exist. Refactor
@@ -1100,6 +1229,48 @@ pub(crate) fn handle_code_action(
     Ok(Some(res))
 }
 
+/// Offers to run the test function whose body contains `frange`.
+fn run_test_code_actions(
+    snap: &GlobalStateSnapshot,
+    frange: FileRange,
+) -> Result<Vec<lsp_ext::CodeAction>> {
+    if !snap.config.client_commands().run_single {
+        return Ok(Vec::new());
+    }
+    let source_file = snap.analysis.parse(frange.file_id)?;
+    let body =
+        match algo::find_node_at_offset::<ast::Fn>(source_file.syntax(), frange.range.start())
+            .and_then(|it| it.body())
+        {
+            Some(it) if it.syntax().text_range().contains_range(frange.range) => it,
+            _ => return Ok(Vec::new()),
+        };
+    let body_range = body.syntax().text_range();
+    let cargo_spec = CargoTargetSpec::for_file(snap, frange.file_id)?;
+
+    let mut res = Vec::new();
+    for runnable in snap.analysis.runnables(frange.file_id)? {
+        if !matches!(runnable.kind, RunnableKind::Test { .. })
+            || !runnable.nav.full_range.contains_range(body_range)
+            || should_skip_target(&runnable, cargo_spec.as_ref())
+        {
+            continue;
+        }
+        let title = "Run test";
+        let runnable = to_proto::runnable(snap, runnable)?;
+        res.push(lsp_ext::CodeAction {
+            title: title.to_string(),
+            group: None,
+            kind: None,
+            command: Some(to_proto::command::run_single(&runnable, title)),
+            edit: None,
+            is_preferred: None,
+            data: None,
+        });
+    }
+    Ok(res)
+}
+
 pub(crate) fn handle_code_action_resolve(
     snap: GlobalStateSnapshot,
     mut code_action: lsp_ext::CodeAction,
@@ -1224,6 +1395,22 @@ pub(crate) fn handle_code_lens_resolve(
     snap: GlobalStateSnapshot,
     code_lens: CodeLens,
 ) -> Result<CodeLens> {
+    // Run and debug lenses carry their runnable, so there's no need to go through `ide`.
+    if let Some(data) = code_lens.data.clone() {
+        match from_json::<lsp_ext::CodeLensResolveData>("CodeLensResolveData", data)? {
+            lsp_ext::CodeLensResolveData::Run { title, runnable } => {
+                let command = to_proto::command::run_single(&runnable, &title);
+                return Ok(CodeLens { command: Some(command), ..code_lens });
+            }
+            lsp_ext::CodeLensResolveData::Debug(runnable) => {
+                let command = to_proto::command::debug_single(&runnable);
+                return Ok(CodeLens { command: Some(command), ..code_lens });
+            }
+            lsp_ext::CodeLensResolveData::Impls(_)
+            | lsp_ext::CodeLensResolveData::References(_) => (),
+        }
+    }
+
     let annotation = from_proto::annotation(&snap, code_lens.clone())?;
     let annotation = snap.analysis.resolve_annotation(annotation)?;
 
@@ -1315,6 +1502,47 @@ pub(crate) fn publish_diagnostics(
     Ok(diagnostics)
 }
 
+pub(crate) fn handle_document_diagnostic(
+    snap: GlobalStateSnapshot,
+    params: lsp_ext::DocumentDiagnosticParams,
+) -> Result<lsp_ext::FullDocumentDiagnosticReport> {
+    let _p = profile::span("handle_document_diagnostic");
+    let file_id = from_proto::file_id(&snap, &params.text_document.uri)?;
+    // Like for pushed diagnostics, don't report anything for crates.io deps or the sysroot.
+    let items = if !snap.config.pull_diagnostics() || snap.analysis.is_library_file(file_id)? {
+        Vec::new()
+    } else {
+        publish_diagnostics(&snap, file_id)?
+    };
+    Ok(lsp_ext::FullDocumentDiagnosticReport {
+        kind: lsp_ext::DocumentDiagnosticReportKind::Full,
+        result_id: None,
+        items,
+    })
+}
+
+pub(crate) fn handle_workspace_diagnostic(
+    snap: GlobalStateSnapshot,
+    _params: lsp_ext::WorkspaceDiagnosticParams,
+) -> Result<lsp_ext::WorkspaceDiagnosticReport> {
+    let _p = profile::span("handle_workspace_diagnostic");
+    let mut items = Vec::new();
+    if !snap.config.pull_diagnostics() {
+        return Ok(lsp_ext::WorkspaceDiagnosticReport { items });
+    }
+    for file_id in snap.workspace_rust_files()? {
+        let uri = to_proto::url(&snap, file_id);
+        let version = snap.url_file_version(&uri);
+        let report = lsp_ext::FullDocumentDiagnosticReport {
+            kind: lsp_ext::DocumentDiagnosticReportKind::Full,
+            result_id: None,
+            items: publish_diagnostics(&snap, file_id)?,
+        };
+        items.push(lsp_ext::WorkspaceFullDocumentDiagnosticReport { uri, version, report });
+    }
+    Ok(lsp_ext::WorkspaceDiagnosticReport { items })
+}
+
 pub(crate) fn handle_inlay_hints(
     snap: GlobalStateSnapshot,
     params: InlayHintsParams,
@@ -1326,10 +1554,35 @@ pub(crate) fn handle_inlay_hints(
         .analysis
         .inlay_hints(&snap.config.inlay_hints(), file_id)?
         .into_iter()
-        .map(|it| to_proto::inlay_hint(&line_index, it))
+        .map(|it| to_proto::inlay_hint(&snap, &line_index, &params.text_document, it))
         .collect())
 }
 
+pub(crate) fn handle_inlay_hint_resolve(
+    snap: GlobalStateSnapshot,
+    mut hint: InlayHint,
+) -> Result<InlayHint> {
+    let _p = profile::span("handle_inlay_hint_resolve");
+    let data = match hint.data.take() {
+        Some(it) => it,
+        None => return Ok(hint),
+    };
+    let file_id = from_proto::file_id(&snap, &data.text_document.uri)?;
+    let line_index = snap.file_line_index(file_id)?;
+    let range = from_proto::text_range(&line_index, hint.range);
+    let kind = match hint.kind {
+        lsp_ext::InlayKind::TypeHint => ide::InlayKind::TypeHint,
+        lsp_ext::InlayKind::ParameterHint => ide::InlayKind::ParameterHint,
+        lsp_ext::InlayKind::ChainingHint => ide::InlayKind::ChainingHint,
+        lsp_ext::InlayKind::BindingModeHint => ide::InlayKind::BindingModeHint,
+        lsp_ext::InlayKind::GenericArgsHint => ide::InlayKind::GenericArgsHint,
+        lsp_ext::InlayKind::CfgHint => ide::InlayKind::CfgHint,
+        lsp_ext::InlayKind::LifetimeHint => ide::InlayKind::LifetimeHint,
+    };
+    hint.tooltip = snap.analysis.inlay_hint_tooltip(file_id, range, kind)?;
+    Ok(hint)
+}
+
 pub(crate) fn handle_call_hierarchy_prepare(
     snap: GlobalStateSnapshot,
     params: CallHierarchyPrepareParams,
@@ -1422,6 +1675,74 @@ pub(crate) fn handle_call_hierarchy_outgoing(
     Ok(Some(res))
 }
 
+pub(crate) fn handle_prepare_type_hierarchy(
+    snap: GlobalStateSnapshot,
+    params: lsp_ext::TypeHierarchyPrepareParams,
+) -> Result<Option<Vec<lsp_ext::TypeHierarchyItem>>> {
+    let _p = profile::span("handle_prepare_type_hierarchy");
+    let position = from_proto::file_position(&snap, params.text_document_position_params)?;
+
+    let nav_info = match snap.analysis.type_hierarchy(position)? {
+        None => return Ok(None),
+        Some(it) => it,
+    };
+
+    let RangeInfo { range: _, info: navs } = nav_info;
+    let res = navs
+        .into_iter()
+        .map(|it| to_proto::type_hierarchy_item(&snap, it))
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(Some(res))
+}
+
+pub(crate) fn handle_type_hierarchy_supertypes(
+    snap: GlobalStateSnapshot,
+    params: lsp_ext::TypeHierarchyItemParams,
+) -> Result<Option<Vec<lsp_ext::TypeHierarchyItem>>> {
+    let _p = profile::span("handle_type_hierarchy_supertypes");
+    let fpos = type_hierarchy_item_position(&snap, params.item)?;
+
+    let navs = match snap.analysis.supertypes(fpos)? {
+        None => return Ok(None),
+        Some(it) => it,
+    };
+    let res = navs
+        .into_iter()
+        .map(|it| to_proto::type_hierarchy_item(&snap, it))
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(Some(res))
+}
+
+pub(crate) fn handle_type_hierarchy_subtypes(
+    snap: GlobalStateSnapshot,
+    params: lsp_ext::TypeHierarchyItemParams,
+) -> Result<Option<Vec<lsp_ext::TypeHierarchyItem>>> {
+    let _p = profile::span("handle_type_hierarchy_subtypes");
+    let fpos = type_hierarchy_item_position(&snap, params.item)?;
+
+    let navs = match snap.analysis.subtypes(fpos)? {
+        None => return Ok(None),
+        Some(it) => it,
+    };
+    let res = navs
+        .into_iter()
+        .map(|it| to_proto::type_hierarchy_item(&snap, it))
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(Some(res))
+}
+
+fn type_hierarchy_item_position(
+    snap: &GlobalStateSnapshot,
+    item: lsp_ext::TypeHierarchyItem,
+) -> Result<FilePosition> {
+    let doc = TextDocumentIdentifier::new(item.uri);
+    let frange = from_proto::file_range(snap, doc, item.selection_range)?;
+    Ok(FilePosition { file_id: frange.file_id, offset: frange.range.start() })
+}
+
 pub(crate) fn handle_semantic_tokens_full(
     snap: GlobalStateSnapshot,
     params: SemanticTokensParams,
@@ -1432,7 +1753,7 @@ pub(crate) fn handle_semantic_tokens_full(
     let text = snap.analysis.file_text(file_id)?;
     let line_index = snap.file_line_index(file_id)?;
 
-    let highlights = snap.analysis.highlight(file_id)?;
+    let highlights = snap.analysis.highlight(snap.config.highlighting(), file_id)?;
     let highlight_strings = snap.config.highlighting_strings();
     let semantic_tokens =
         to_proto::semantic_tokens(&text, &line_index, highlights, highlight_strings);
@@ -1453,7 +1774,7 @@ pub(crate) fn handle_semantic_tokens_full_delta(
     let text = snap.analysis.file_text(file_id)?;
     let line_index = snap.file_line_index(file_id)?;
 
-    let highlights = snap.analysis.highlight(file_id)?;
+    let highlights = snap.analysis.highlight(snap.config.highlighting(), file_id)?;
     let highlight_strings = snap.config.highlighting_strings();
     let semantic_tokens =
         to_proto::semantic_tokens(&text, &line_index, highlights, highlight_strings);
@@ -1484,7 +1805,7 @@ pub(crate) fn handle_semantic_tokens_range(
     let text = snap.analysis.file_text(frange.file_id)?;
     let line_index = snap.file_line_index(frange.file_id)?;
 
-    let highlights = snap.analysis.highlight_range(frange)?;
+    let highlights = snap.analysis.highlight_range(snap.config.highlighting(), frange)?;
     let highlight_strings = snap.config.highlighting_strings();
     let semantic_tokens =
         to_proto::semantic_tokens(&text, &line_index, highlights, highlight_strings);
@@ -1494,13 +1815,96 @@ pub(crate) fn handle_semantic_tokens_range(
 pub(crate) fn handle_open_docs(
     snap: GlobalStateSnapshot,
     params: lsp_types::TextDocumentPositionParams,
+    sender: Sender<Task>,
+    cancelled: Arc<AtomicBool>,
 ) -> Result<Option<lsp_types::Url>> {
     let _p = profile::span("handle_open_docs");
     let position = from_proto::file_position(&snap, params)?;
 
-    let remote = snap.analysis.external_docs(position)?;
+    let res = match snap.analysis.open_docs(position)? {
+        Some(DocsLocation::Remote(url)) => Url::parse(&url).ok(),
+        Some(DocsLocation::Local { crate_root, path }) => {
+            let spec = match CargoTargetSpec::for_file(&snap, crate_root)? {
+                Some(it) => it,
+                None => return Ok(None),
+            };
+            sender.send(Task::BuildDocs(Progress::Begin)).unwrap();
+            let doc_dir = build_local_docs(&spec, &cancelled);
+            sender.send(Task::BuildDocs(Progress::End)).unwrap();
+            Url::from_directory_path(doc_dir?).ok().and_then(|dir| dir.join(&path).ok())
+        }
+        None => None,
+    };
+    Ok(res)
+}
+
+/// Documents the package of `spec` with `cargo doc`, in a temporary target directory to not
+/// block the builds of the workspace, and returns the directory holding the generated docs.
+/// `cargo doc` is killed once `cancelled` is set.
+fn build_local_docs(spec: &CargoTargetSpec, cancelled: &AtomicBool) -> Result<PathBuf> {
+    let mut hasher = DefaultHasher::new();
+    spec.workspace_root.hash(&mut hasher);
+    let target_dir =
+        env::temp_dir().join("rust-analyzer-docs").join(format!("{:016x}", hasher.finish()));
+
+    let mut child = process::Command::new(toolchain::cargo())
+        .args(["doc", "--no-deps", "--package", &spec.package, "--manifest-path"])
+        .arg(spec.cargo_toml.as_ref())
+        .arg("--target-dir")
+        .arg(&target_dir)
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map(JodChild)
+        .context("failed to spawn `cargo doc`")?;
+    // Read stderr on its own thread, so that `cargo doc` doesn't block on a full pipe.
+    let stderr = child.stderr.take().map(|mut stderr| {
+        thread::spawn(move || {
+            let mut buf = String::new();
+            let _ = stderr.read_to_string(&mut buf);
+            buf
+        })
+    });
+
+    let status = loop {
+        if cancelled.load(Ordering::Relaxed) {
+            // Dropping the child kills it.
+            return Err(LspError::new(
+                ErrorCode::RequestCanceled as i32,
+                "cargo doc was cancelled".to_string(),
+            )
+            .into());
+        }
+        if let Some(status) = child.try_wait()? {
+            break status;
+        }
+        thread::sleep(Duration::from_millis(100));
+    };
+    if !status.success() {
+        let stderr = stderr.and_then(|it| it.join().ok()).unwrap_or_default();
+        return Err(LspError::new(
+            ErrorCode::InternalError as i32,
+            format!("cargo doc exited with {}:\n{}", status, stderr),
+        )
+        .into());
+    }
+    Ok(target_dir.join("doc"))
+}
+
+pub(crate) fn handle_show_documentation(
+    snap: GlobalStateSnapshot,
+    params: lsp_types::TextDocumentPositionParams,
+) -> Result<Option<lsp_ext::Documentation>> {
+    let _p = profile::span("handle_show_documentation");
+    let position = from_proto::file_position(&snap, params)?;
 
-    Ok(remote.and_then(|remote| Url::parse(&remote).ok()))
+    let res = match snap.analysis.show_documentation(position)? {
+        Some(ItemDocumentation::Url(url)) => Url::parse(&url).ok().map(lsp_ext::Documentation::Url),
+        Some(ItemDocumentation::Markdown(docs)) => Some(lsp_ext::Documentation::Markdown(docs)),
+        None => None,
+    };
+    Ok(res)
 }
 
 pub(crate) fn handle_open_cargo_toml(
@@ -1543,6 +1947,23 @@ pub(crate) fn handle_move_item(
     }
 }
 
+pub(crate) fn handle_move_item_to_module(
+    snap: GlobalStateSnapshot,
+    params: lsp_ext::MoveItemToModuleParams,
+) -> Result<Option<lsp_ext::MoveItemToModuleResult>> {
+    let _p = profile::span("handle_move_item_to_module");
+    let position = from_proto::file_position(&snap, params.position)?;
+    let target = from_proto::file_id(&snap, &params.target.uri)?;
+
+    match snap.analysis.move_item_to_module(position, target)? {
+        Some(moved) => {
+            let edit = to_proto::workspace_edit(&snap, moved.source_change)?;
+            Ok(Some(lsp_ext::MoveItemToModuleResult { edit, warnings: moved.warnings }))
+        }
+        None => Ok(None),
+    }
+}
+
 fn to_command_link(command: lsp_types::Command, tooltip: String) -> lsp_ext::CommandLink {
     lsp_ext::CommandLink { tooltip: Some(tooltip), command }
 }
@@ -1690,6 +2111,12 @@ fn should_skip_target(runnable: &Runnable, cargo_spec: Option<&CargoTargetSpec>)
                 None => true,
             }
         }
+        // Tests and examples are crates of their own, but their package is documented from the
+        // library and the binaries.
+        RunnableKind::CargoDoc => !matches!(
+            cargo_spec,
+            Some(CargoTargetSpec { target_kind: TargetKind::Lib | TargetKind::Bin, .. })
+        ),
         _ => false,
     }
 }
@@ -47,6 +47,20 @@ impl Request for ReloadWorkspace {
     const METHOD: &'static str = "rust-analyzer/reloadWorkspace";
 }
 
+pub enum CheckCurrentFile {}
+
+impl Request for CheckCurrentFile {
+    type Params = CheckCurrentFileParams;
+    type Result = ();
+    const METHOD: &'static str = "rust-analyzer/checkCurrentFile";
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct CheckCurrentFileParams {
+    pub text_document: TextDocumentIdentifier,
+}
+
 pub enum SyntaxTree {}
 
 impl Request for SyntaxTree {
@@ -62,6 +76,14 @@ pub struct SyntaxTreeParams {
     pub range: Option<Range>,
 }
 
+pub enum ShowType {}
+
+impl Request for ShowType {
+    type Params = lsp_types::TextDocumentPositionParams;
+    type Result = Option<String>;
+    const METHOD: &'static str = "rust-analyzer/showType";
+}
+
 pub enum ViewHir {}
 
 impl Request for ViewHir {
@@ -119,6 +141,16 @@ pub struct ExpandMacroParams {
 pub struct ExpandedMacro {
     pub name: String,
     pub expansion: String,
+    pub mappings: Vec<ExpandedMacroMapping>,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ExpandedMacroMapping {
+    /// The range of a token in the macro call.
+    pub source: Range,
+    /// The range it takes up in `ExpandedMacro::expansion`.
+    pub expansion: Range,
 }
 
 pub enum MatchingBrace {}
@@ -136,6 +168,21 @@ pub struct MatchingBraceParams {
     pub positions: Vec<Position>,
 }
 
+pub enum ParentExpression {}
+
+impl Request for ParentExpression {
+    type Params = ParentExpressionParams;
+    type Result = Vec<Range>;
+    const METHOD: &'static str = "experimental/parentExpression";
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ParentExpressionParams {
+    pub text_document: TextDocumentIdentifier,
+    pub selections: Vec<Range>,
+}
+
 pub enum ParentModule {}
 
 impl Request for ParentModule {
@@ -182,7 +229,7 @@ pub struct RunnablesParams {
     pub position: Option<Position>,
 }
 
-#[derive(Deserialize, Serialize, Debug)]
+#[derive(Deserialize, Serialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct Runnable {
     pub label: String,
@@ -192,13 +239,13 @@ pub struct Runnable {
     pub args: CargoRunnable,
 }
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "lowercase")]
 pub enum RunnableKind {
     Cargo,
 }
 
-#[derive(Deserialize, Serialize, Debug)]
+#[derive(Deserialize, Serialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct CargoRunnable {
     // command to be executed instead of cargo
@@ -247,6 +294,10 @@ pub enum InlayKind {
     TypeHint,
     ParameterHint,
     ChainingHint,
+    BindingModeHint,
+    GenericArgsHint,
+    CfgHint,
+    LifetimeHint,
 }
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -254,6 +305,24 @@ pub struct InlayHint {
     pub range: Range,
     pub kind: InlayKind,
     pub label: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tooltip: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data: Option<InlayHintResolveData>,
+}
+
+pub enum InlayHintResolve {}
+
+impl Request for InlayHintResolve {
+    type Params = InlayHint;
+    type Result = InlayHint;
+    const METHOD: &'static str = "experimental/inlayHintResolve";
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InlayHintResolveData {
+    pub text_document: TextDocumentIdentifier,
 }
 
 pub enum Ssr {}
@@ -324,9 +393,9 @@ pub struct CodeAction {
     pub group: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub kind: Option<CodeActionKind>,
-    // We don't handle commands on the client-side
-    // #[serde(skip_serializing_if = "Option::is_none")]
-    // pub command: Option<lsp_types::Command>,
+    /// Only used to run tests, the other actions are edits.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub command: Option<lsp_types::Command>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub edit: Option<SnippetWorkspaceEdit>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -438,6 +507,21 @@ impl Request for ExternalDocs {
     const METHOD: &'static str = "experimental/externalDocs";
 }
 
+pub enum ShowDocumentation {}
+
+impl Request for ShowDocumentation {
+    type Params = lsp_types::TextDocumentPositionParams;
+    type Result = Option<Documentation>;
+    const METHOD: &'static str = "experimental/showDocumentation";
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub enum Documentation {
+    Url(lsp_types::Url),
+    Markdown(String),
+}
+
 pub enum OpenCargoToml {}
 
 impl Request for OpenCargoToml {
@@ -458,6 +542,8 @@ pub struct OpenCargoTomlParams {
 pub(crate) enum CodeLensResolveData {
     Impls(lsp_types::request::GotoImplementationParams),
     References(lsp_types::TextDocumentPositionParams),
+    Run { title: String, runnable: Runnable },
+    Debug(Runnable),
 }
 
 pub fn supports_utf8(caps: &lsp_types::ClientCapabilities) -> bool {
@@ -486,6 +572,30 @@ pub enum MoveItemDirection {
     Down,
 }
 
+pub enum MoveItemToModule {}
+
+impl Request for MoveItemToModule {
+    type Params = MoveItemToModuleParams;
+    type Result = Option<MoveItemToModuleResult>;
+    const METHOD: &'static str = "experimental/moveItemToModule";
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct MoveItemToModuleParams {
+    #[serde(flatten)]
+    pub position: lsp_types::TextDocumentPositionParams,
+    /// The file of the module to move the item to.
+    pub target: TextDocumentIdentifier,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct MoveItemToModuleResult {
+    pub edit: lsp_types::WorkspaceEdit,
+    pub warnings: Vec<String>,
+}
+
 #[derive(Debug)]
 pub enum WorkspaceSymbol {}
 
@@ -525,6 +635,148 @@ pub enum WorkspaceSymbolSearchKind {
     AllSymbols,
 }
 
+pub enum DocumentDiagnostic {}
+
+impl Request for DocumentDiagnostic {
+    type Params = DocumentDiagnosticParams;
+    type Result = FullDocumentDiagnosticReport;
+    const METHOD: &'static str = "textDocument/diagnostic";
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct DocumentDiagnosticParams {
+    pub text_document: TextDocumentIdentifier,
+    pub identifier: Option<String>,
+    pub previous_result_id: Option<String>,
+    #[serde(flatten)]
+    pub work_done_progress_params: WorkDoneProgressParams,
+    #[serde(flatten)]
+    pub partial_result_params: PartialResultParams,
+}
+
+/// rust-analyzer doesn't track result ids, so it always sends full reports.
+#[derive(Deserialize, Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct FullDocumentDiagnosticReport {
+    pub kind: DocumentDiagnosticReportKind,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub result_id: Option<String>,
+    pub items: Vec<lsp_types::Diagnostic>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Eq, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub enum DocumentDiagnosticReportKind {
+    Full,
+}
+
+pub enum WorkspaceDiagnostic {}
+
+impl Request for WorkspaceDiagnostic {
+    type Params = WorkspaceDiagnosticParams;
+    type Result = WorkspaceDiagnosticReport;
+    const METHOD: &'static str = "workspace/diagnostic";
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkspaceDiagnosticParams {
+    pub identifier: Option<String>,
+    pub previous_result_ids: Vec<PreviousResultId>,
+    #[serde(flatten)]
+    pub work_done_progress_params: WorkDoneProgressParams,
+    #[serde(flatten)]
+    pub partial_result_params: PartialResultParams,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct PreviousResultId {
+    pub uri: lsp_types::Url,
+    pub value: String,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct WorkspaceDiagnosticReport {
+    pub items: Vec<WorkspaceFullDocumentDiagnosticReport>,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct WorkspaceFullDocumentDiagnosticReport {
+    pub uri: lsp_types::Url,
+    pub version: Option<i32>,
+    #[serde(flatten)]
+    pub report: FullDocumentDiagnosticReport,
+}
+
+pub enum WorkspaceDiagnosticRefresh {}
+
+impl Request for WorkspaceDiagnosticRefresh {
+    type Params = ();
+    type Result = ();
+    const METHOD: &'static str = "workspace/diagnostic/refresh";
+}
+
+pub enum PrepareTypeHierarchy {}
+
+impl Request for PrepareTypeHierarchy {
+    type Params = TypeHierarchyPrepareParams;
+    type Result = Option<Vec<TypeHierarchyItem>>;
+    const METHOD: &'static str = "textDocument/prepareTypeHierarchy";
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct TypeHierarchyPrepareParams {
+    #[serde(flatten)]
+    pub text_document_position_params: lsp_types::TextDocumentPositionParams,
+    #[serde(flatten)]
+    pub work_done_progress_params: WorkDoneProgressParams,
+}
+
+pub enum TypeHierarchySupertypes {}
+
+impl Request for TypeHierarchySupertypes {
+    type Params = TypeHierarchyItemParams;
+    type Result = Option<Vec<TypeHierarchyItem>>;
+    const METHOD: &'static str = "typeHierarchy/supertypes";
+}
+
+pub enum TypeHierarchySubtypes {}
+
+impl Request for TypeHierarchySubtypes {
+    type Params = TypeHierarchyItemParams;
+    type Result = Option<Vec<TypeHierarchyItem>>;
+    const METHOD: &'static str = "typeHierarchy/subtypes";
+}
+
+/// The parameters of both `typeHierarchy/supertypes` and `typeHierarchy/subtypes`.
+#[derive(Deserialize, Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct TypeHierarchyItemParams {
+    pub item: TypeHierarchyItem,
+    #[serde(flatten)]
+    pub work_done_progress_params: WorkDoneProgressParams,
+    #[serde(flatten)]
+    pub partial_result_params: PartialResultParams,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct TypeHierarchyItem {
+    pub name: String,
+    pub kind: lsp_types::SymbolKind,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tags: Option<Vec<lsp_types::SymbolTag>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub detail: Option<String>,
+    pub uri: lsp_types::Url,
+    pub range: Range,
+    pub selection_range: Range,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data: Option<serde_json::Value>,
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct CompletionResolveData {
     pub position: lsp_types::TextDocumentPositionParams,
@@ -64,6 +64,7 @@ pub(crate) enum Task {
     PrimeCaches(PrimeCachesProgress),
     FetchWorkspace(ProjectWorkspaceProgress),
     FetchBuildData(BuildDataProgress),
+    BuildDocs(Progress),
 }
 
 #[derive(Debug)]
@@ -271,6 +272,9 @@ impl GlobalState {
                                 self.report_progress("Loading", state, msg, None);
                             }
                         }
+                        Task::BuildDocs(state) => {
+                            self.report_progress("cargo doc", state, None, None)
+                        }
                     }
 
                     // Coalesce multiple task events into one loop turn
@@ -494,9 +498,10 @@ impl GlobalState {
         if self.prime_caches_queue.should_start_op() {
             self.task_pool.handle.spawn_with_sender({
                 let analysis = self.snapshot().analysis;
+                let num_worker_threads = self.config.prime_caches_num_threads();
                 move |sender| {
                     sender.send(Task::PrimeCaches(PrimeCachesProgress::Begin)).unwrap();
-                    let res = analysis.prime_caches(|progress| {
+                    let res = analysis.prime_caches(num_worker_threads, |progress| {
                         let report = PrimeCachesProgress::Report(progress);
                         sender.send(Task::PrimeCaches(report)).unwrap();
                     });
@@ -568,12 +573,18 @@ impl GlobalState {
             })?
             .on_sync_mut::<lsp_ext::MemoryUsage>(handlers::handle_memory_usage)?
             .on_sync_mut::<lsp_ext::ShuffleCrateGraph>(handlers::handle_shuffle_crate_graph)?
+            .on_sync_mut::<lsp_ext::CheckCurrentFile>(handlers::handle_check_current_file)?
             .on_sync::<lsp_ext::JoinLines>(handlers::handle_join_lines)?
             .on_sync::<lsp_ext::OnEnter>(handlers::handle_on_enter)?
             .on_sync::<lsp_types::request::SelectionRangeRequest>(handlers::handle_selection_range)?
             .on_sync::<lsp_ext::MatchingBrace>(handlers::handle_matching_brace)?
+            .on_sync::<lsp_ext::ParentExpression>(handlers::handle_parent_expression)?
+            .on_sync::<lsp_types::request::LinkedEditingRange>(
+                handlers::handle_linked_editing_range,
+            )?
             .on::<lsp_ext::AnalyzerStatus>(handlers::handle_analyzer_status)
             .on::<lsp_ext::SyntaxTree>(handlers::handle_syntax_tree)
+            .on::<lsp_ext::ShowType>(handlers::handle_show_type)
             .on::<lsp_ext::ViewHir>(handlers::handle_view_hir)
             .on::<lsp_ext::ViewCrateGraph>(handlers::handle_view_crate_graph)
             .on::<lsp_ext::ViewItemTree>(handlers::handle_view_item_tree)
@@ -582,12 +593,15 @@ impl GlobalState {
             .on::<lsp_ext::Runnables>(handlers::handle_runnables)
             .on::<lsp_ext::RelatedTests>(handlers::handle_related_tests)
             .on::<lsp_ext::InlayHints>(handlers::handle_inlay_hints)
+            .on::<lsp_ext::InlayHintResolve>(handlers::handle_inlay_hint_resolve)
             .on::<lsp_ext::CodeActionRequest>(handlers::handle_code_action)
             .on::<lsp_ext::CodeActionResolveRequest>(handlers::handle_code_action_resolve)
             .on::<lsp_ext::HoverRequest>(handlers::handle_hover)
-            .on::<lsp_ext::ExternalDocs>(handlers::handle_open_docs)
+            .on_cancellable::<lsp_ext::ExternalDocs>(handlers::handle_open_docs)
+            .on::<lsp_ext::ShowDocumentation>(handlers::handle_show_documentation)
             .on::<lsp_ext::OpenCargoToml>(handlers::handle_open_cargo_toml)
             .on::<lsp_ext::MoveItem>(handlers::handle_move_item)
+            .on::<lsp_ext::MoveItemToModule>(handlers::handle_move_item_to_module)
             .on::<lsp_ext::WorkspaceSymbol>(handlers::handle_workspace_symbol)
             .on::<lsp_types::request::OnTypeFormatting>(handlers::handle_on_type_formatting)
             .on::<lsp_types::request::DocumentSymbolRequest>(handlers::handle_document_symbol)
@@ -600,6 +614,9 @@ impl GlobalState {
             .on::<lsp_types::request::CodeLensRequest>(handlers::handle_code_lens)
             .on::<lsp_types::request::CodeLensResolve>(handlers::handle_code_lens_resolve)
             .on::<lsp_types::request::FoldingRangeRequest>(handlers::handle_folding_range)
+            .on::<lsp_types::request::DocumentLinkRequest>(handlers::handle_document_link)
+            .on::<lsp_ext::DocumentDiagnostic>(handlers::handle_document_diagnostic)
+            .on::<lsp_ext::WorkspaceDiagnostic>(handlers::handle_workspace_diagnostic)
             .on::<lsp_types::request::SignatureHelpRequest>(handlers::handle_signature_help)
             .on::<lsp_types::request::PrepareRenameRequest>(handlers::handle_prepare_rename)
             .on::<lsp_types::request::Rename>(handlers::handle_rename)
@@ -614,6 +631,9 @@ impl GlobalState {
             .on::<lsp_types::request::CallHierarchyOutgoingCalls>(
                 handlers::handle_call_hierarchy_outgoing,
             )
+            .on::<lsp_ext::PrepareTypeHierarchy>(handlers::handle_prepare_type_hierarchy)
+            .on::<lsp_ext::TypeHierarchySupertypes>(handlers::handle_type_hierarchy_supertypes)
+            .on::<lsp_ext::TypeHierarchySubtypes>(handlers::handle_type_hierarchy_subtypes)
             .on::<lsp_types::request::SemanticTokensFullRequest>(
                 handlers::handle_semantic_tokens_full,
             )
@@ -8,7 +8,7 @@ use ide_db::base_db::{
     CrateGraph, Env, ProcMacro, ProcMacroExpander, ProcMacroExpansionError, ProcMacroKind,
     SourceRoot, VfsPath,
 };
-use proc_macro_api::{MacroDylib, ProcMacroServer};
+use proc_macro_api::{msg::DiagnosticLevel, MacroDylib, ProcMacroServer};
 use project_model::{ProjectWorkspace, WorkspaceBuildScripts};
 use vfs::{file_set::FileSetConfig, AbsPath, AbsPathBuf, ChangeKind};
 
@@ -52,6 +52,13 @@ impl GlobalState {
         } else if self.config.flycheck() != old_config.flycheck() {
             self.reload_flycheck();
         }
+        // The diagnostics configuration might have changed, or pull diagnostics might have been
+        // turned off, so the client has to ask for diagnostics again.
+        if (self.config.pull_diagnostics() || old_config.pull_diagnostics())
+            && self.config.diagnostics_refresh_support
+        {
+            self.send_request::<lsp_ext::WorkspaceDiagnosticRefresh>((), |_, _| ());
+        }
 
         // Apply experimental feature flags.
         self.analysis_host
@@ -283,7 +290,11 @@ impl GlobalState {
         if self.proc_macro_client.is_none() {
             self.proc_macro_client = match self.config.proc_macro_srv() {
                 None => None,
-                Some((path, args)) => match ProcMacroServer::spawn(path.clone(), args) {
+                Some((path, args)) => match ProcMacroServer::spawn(
+                    path.clone(),
+                    args,
+                    self.config.proc_macro_timeout(),
+                ) {
                     Ok(it) => Some(it),
                     Err(err) => {
                         tracing::error!(
@@ -563,7 +574,23 @@ pub(crate) fn load_proc_macro(client: Option<&ProcMacroServer>, path: &AbsPath)
         ) -> Result<tt::Subtree, ProcMacroExpansionError> {
             let env = env.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect();
             match self.0.expand(subtree, attrs, env) {
-                Ok(Ok(subtree)) => Ok(subtree),
+                Ok(Ok((subtree, diagnostics))) if diagnostics.is_empty() => Ok(subtree),
+                Ok(Ok((subtree, diagnostics))) => {
+                    // Errors are shown as they are, everything else says what it is.
+                    let messages: Vec<_> = diagnostics
+                        .into_iter()
+                        .map(|it| match it.level {
+                            DiagnosticLevel::Error => it.message,
+                            DiagnosticLevel::Warning => format!("warning: {}", it.message),
+                            DiagnosticLevel::Note => format!("note: {}", it.message),
+                            DiagnosticLevel::Help => format!("help: {}", it.message),
+                        })
+                        .collect();
+                    Err(ProcMacroExpansionError::Reported {
+                        value: subtree,
+                        message: messages.join("\n"),
+                    })
+                }
                 Ok(Err(err)) => Err(ProcMacroExpansionError::Panic(err.0)),
                 Err(err) => Err(ProcMacroExpansionError::System(err.to_string())),
             }
@@ -7,12 +7,14 @@ use std::{
 
 use ide::{
     Annotation, AnnotationKind, Assist, AssistKind, CallInfo, Cancellable, CompletionItem,
-    CompletionItemKind, CompletionRelevance, Documentation, FileId, FileRange, FileSystemEdit,
-    Fold, FoldKind, Highlight, HlMod, HlOperator, HlPunct, HlRange, HlTag, Indel, InlayHint,
-    InlayKind, Markup, NavigationTarget, ReferenceCategory, RenameError, Runnable, Severity,
-    SourceChange, StructureNodeKind, SymbolKind, TextEdit, TextRange, TextSize,
+    CompletionItemKind, CompletionRelevance, DocLink, DocLinkTarget, Documentation, FileId,
+    FileRange, FileSystemEdit, Fold, FoldKind, Highlight, HlMod, HlOperator, HlPunct, HlRange,
+    HlTag, Indel, InlayHint, InlayKind, Markup, NavigationTarget, ReferenceCategory, RenameError,
+    Runnable, RunnableKind, Severity, SourceChange, StructureNodeKind, SymbolKind, TextEdit,
+    TextRange, TextSize,
 };
 use itertools::Itertools;
+use project_model::TargetKind;
 use serde_json::to_value;
 use vfs::AbsPath;
 
@@ -50,14 +52,14 @@ pub(crate) fn symbol_kind(symbol_kind: SymbolKind) -> lsp_types::SymbolKind {
         SymbolKind::Enum => lsp_types::SymbolKind::ENUM,
         SymbolKind::Variant => lsp_types::SymbolKind::ENUM_MEMBER,
         SymbolKind::Trait => lsp_types::SymbolKind::INTERFACE,
-        SymbolKind::Macro
-        | SymbolKind::BuiltinAttr
-        | SymbolKind::Attribute
-        | SymbolKind::Derive => lsp_types::SymbolKind::FUNCTION,
+        SymbolKind::Macro => lsp_types::SymbolKind::OPERATOR,
+        SymbolKind::BuiltinAttr | SymbolKind::Attribute | SymbolKind::Derive => {
+            lsp_types::SymbolKind::FUNCTION
+        }
         SymbolKind::Module | SymbolKind::ToolModule => lsp_types::SymbolKind::MODULE,
         SymbolKind::TypeAlias | SymbolKind::TypeParam => lsp_types::SymbolKind::TYPE_PARAMETER,
         SymbolKind::Field => lsp_types::SymbolKind::FIELD,
-        SymbolKind::Static => lsp_types::SymbolKind::CONSTANT,
+        SymbolKind::Static => lsp_types::SymbolKind::VARIABLE,
         SymbolKind::Const => lsp_types::SymbolKind::CONSTANT,
         SymbolKind::ConstParam => lsp_types::SymbolKind::CONSTANT,
         SymbolKind::Impl => lsp_types::SymbolKind::OBJECT,
@@ -83,6 +85,7 @@ pub(crate) fn document_highlight_kind(
     match category {
         ReferenceCategory::Read => lsp_types::DocumentHighlightKind::READ,
         ReferenceCategory::Write => lsp_types::DocumentHighlightKind::WRITE,
+        ReferenceCategory::Import => lsp_types::DocumentHighlightKind::TEXT,
     }
 }
 
@@ -410,7 +413,21 @@ pub(crate) fn signature_help(
     }
 }
 
-pub(crate) fn inlay_hint(line_index: &LineIndex, inlay_hint: InlayHint) -> lsp_ext::InlayHint {
+pub(crate) fn inlay_hint(
+    snap: &GlobalStateSnapshot,
+    line_index: &LineIndex,
+    text_document: &lsp_types::TextDocumentIdentifier,
+    inlay_hint: InlayHint,
+) -> lsp_ext::InlayHint {
+    // Parameter hints never have a tooltip, so there's nothing to resolve.
+    let data = match (&inlay_hint.tooltip, &inlay_hint.kind) {
+        (None, InlayKind::TypeHint | InlayKind::ChainingHint)
+            if snap.config.inlay_hint_resolve() =>
+        {
+            Some(lsp_ext::InlayHintResolveData { text_document: text_document.clone() })
+        }
+        _ => None,
+    };
     lsp_ext::InlayHint {
         label: inlay_hint.label.to_string(),
         range: range(line_index, inlay_hint.range),
@@ -418,7 +435,13 @@ pub(crate) fn inlay_hint(line_index: &LineIndex, inlay_hint: InlayHint) -> lsp_e
             InlayKind::ParameterHint => lsp_ext::InlayKind::ParameterHint,
             InlayKind::TypeHint => lsp_ext::InlayKind::TypeHint,
             InlayKind::ChainingHint => lsp_ext::InlayKind::ChainingHint,
+            InlayKind::BindingModeHint => lsp_ext::InlayKind::BindingModeHint,
+            InlayKind::GenericArgsHint => lsp_ext::InlayKind::GenericArgsHint,
+            InlayKind::CfgHint => lsp_ext::InlayKind::CfgHint,
+            InlayKind::LifetimeHint => lsp_ext::InlayKind::LifetimeHint,
         },
+        tooltip: inlay_hint.tooltip,
+        data,
     }
 }
 
@@ -493,7 +516,6 @@ fn semantic_token_type_and_modifiers(
             }
             SymbolKind::Const => {
                 mods |= semantic_tokens::CONSTANT;
-                mods |= lsp_types::SemanticTokenModifier::STATIC;
                 lsp_types::SemanticTokenType::VARIABLE
             }
             SymbolKind::Static => {
@@ -548,6 +570,10 @@ fn semantic_token_type_and_modifiers(
             HlMod::Async => semantic_tokens::ASYNC,
             HlMod::Attribute => semantic_tokens::ATTRIBUTE_MODIFIER,
             HlMod::Callable => semantic_tokens::CALLABLE,
+            HlMod::Const => {
+                mods |= lsp_types::SemanticTokenModifier::READONLY;
+                lsp_types::SemanticTokenModifier::STATIC
+            }
             HlMod::Consuming => semantic_tokens::CONSUMING,
             HlMod::ControlFlow => semantic_tokens::CONTROL_FLOW,
             HlMod::CrateRoot => semantic_tokens::CRATE_ROOT,
@@ -579,9 +605,10 @@ pub(crate) fn folding_range(
     let kind = match fold.kind {
         FoldKind::Comment => Some(lsp_types::FoldingRangeKind::Comment),
         FoldKind::Imports => Some(lsp_types::FoldingRangeKind::Imports),
-        FoldKind::Region => Some(lsp_types::FoldingRangeKind::Region),
+        FoldKind::Region | FoldKind::Block | FoldKind::Cfg => {
+            Some(lsp_types::FoldingRangeKind::Region)
+        }
         FoldKind::Mods
-        | FoldKind::Block
         | FoldKind::ArgList
         | FoldKind::Consts
         | FoldKind::Statics
@@ -626,6 +653,30 @@ pub(crate) fn folding_range(
     }
 }
 
+pub(crate) fn document_link(
+    snap: &GlobalStateSnapshot,
+    line_index: &LineIndex,
+    link: DocLink,
+) -> Result<lsp_types::DocumentLink> {
+    let target = match link.target {
+        DocLinkTarget::Url(it) => lsp_types::Url::parse(&it).ok(),
+        DocLinkTarget::Def(nav) => {
+            let mut target = url(snap, nav.file_id);
+            let target_line_index = snap.file_line_index(nav.file_id)?;
+            let pos = position(&target_line_index, nav.focus_or_full_range().start());
+            // VS Code understands `#L<line>,<column>` fragments, with both being 1-based.
+            target.set_fragment(Some(&format!("L{},{}", pos.line + 1, pos.character + 1)));
+            Some(target)
+        }
+    };
+    Ok(lsp_types::DocumentLink {
+        range: range(line_index, link.range),
+        target,
+        tooltip: None,
+        data: None,
+    })
+}
+
 pub(crate) fn url(snap: &GlobalStateSnapshot, file_id: FileId) -> lsp_types::Url {
     snap.file_id_to_url(file_id)
 }
@@ -817,6 +868,13 @@ pub(crate) fn snippet_text_document_ops(
                 rename_file,
             )))
         }
+        FileSystemEdit::DeleteFile { file_id } => {
+            let uri = snap.file_id_to_url(file_id);
+            let delete_file = lsp_types::DeleteFile { uri, options: None };
+            ops.push(lsp_ext::SnippetDocumentChangeOperation::Op(lsp_types::ResourceOp::Delete(
+                delete_file,
+            )))
+        }
     }
     Ok(ops)
 }
@@ -931,6 +989,26 @@ pub(crate) fn call_hierarchy_item(
     })
 }
 
+pub(crate) fn type_hierarchy_item(
+    snap: &GlobalStateSnapshot,
+    target: NavigationTarget,
+) -> Result<lsp_ext::TypeHierarchyItem> {
+    let name = target.name.to_string();
+    let detail = target.description.clone();
+    let kind = target.kind.map(symbol_kind).unwrap_or(lsp_types::SymbolKind::STRUCT);
+    let (uri, range, selection_range) = location_info(snap, target)?;
+    Ok(lsp_ext::TypeHierarchyItem {
+        name,
+        kind,
+        tags: None,
+        detail,
+        uri,
+        range,
+        selection_range,
+        data: None,
+    })
+}
+
 pub(crate) fn code_action_kind(kind: AssistKind) -> lsp_types::CodeActionKind {
     match kind {
         AssistKind::None | AssistKind::Generate => lsp_types::CodeActionKind::EMPTY,
@@ -951,6 +1029,7 @@ pub(crate) fn code_action(
         title: assist.label.to_string(),
         group: assist.group.filter(|_| snap.config.code_action_group()).map(|gr| gr.0),
         kind: Some(code_action_kind(assist.id.1)),
+        command: None,
         edit: None,
         is_preferred: None,
         data: None,
@@ -978,9 +1057,13 @@ pub(crate) fn runnable(
     let spec = CargoTargetSpec::for_file(snap, runnable.nav.file_id)?;
     let workspace_root = spec.as_ref().map(|it| it.workspace_root.clone());
     let target = spec.as_ref().map(|s| s.target.clone());
+    let is_example = matches!(spec, Some(CargoTargetSpec { target_kind: TargetKind::Example, .. }));
     let (cargo_args, executable_args) =
         CargoTargetSpec::runnable_args(snap, spec, &runnable.kind, &runnable.cfg)?;
-    let label = runnable.label(target);
+    let label = match (&runnable.kind, target) {
+        (RunnableKind::Bin, Some(target)) if is_example => format!("run example {}", target),
+        (_, target) => runnable.label(target),
+    };
     let location = location_link(snap, None, runnable.nav)?;
 
     Ok(lsp_ext::Runnable {
@@ -1011,7 +1094,7 @@ pub(crate) fn code_lens(
 
             let title = run.title();
             let can_debug = match run.kind {
-                ide::RunnableKind::DocTest { .. } => false,
+                ide::RunnableKind::DocTest { .. } | ide::RunnableKind::CargoDoc => false,
                 ide::RunnableKind::TestMod { .. }
                 | ide::RunnableKind::Test { .. }
                 | ide::RunnableKind::Bench { .. }
@@ -1019,21 +1102,23 @@ pub(crate) fn code_lens(
             };
             let r = runnable(snap, run)?;
 
+            // The commands are filled in by `codeLens/resolve`, directly from the runnable
+            // stored in `data`.
             let lens_config = snap.config.lens();
             if lens_config.run && client_commands_config.run_single {
-                let command = command::run_single(&r, &title);
+                let data = lsp_ext::CodeLensResolveData::Run { title, runnable: r.clone() };
                 acc.push(lsp_types::CodeLens {
                     range: annotation_range,
-                    command: Some(command),
-                    data: None,
+                    command: None,
+                    data: Some(to_value(data).unwrap()),
                 })
             }
             if lens_config.debug && can_debug && client_commands_config.debug_single {
-                let command = command::debug_single(&r);
+                let data = lsp_ext::CodeLensResolveData::Debug(r);
                 acc.push(lsp_types::CodeLens {
                     range: annotation_range,
-                    command: Some(command),
-                    data: None,
+                    command: None,
+                    data: Some(to_value(data).unwrap()),
                 })
             }
         }
@@ -17,22 +17,29 @@ use std::{collections::HashMap, path::PathBuf, time::Instant};
 
 use expect_test::expect;
 use lsp_types::{
-    notification::DidOpenTextDocument,
+    notification::{DidChangeConfiguration, DidChangeTextDocument, DidOpenTextDocument},
     request::{
-        CodeActionRequest, Completion, Formatting, GotoTypeDefinition, HoverRequest,
-        WillRenameFiles,
+        CodeActionRequest, Completion, Formatting, GotoDefinition, GotoImplementation,
+        GotoTypeDefinition, HoverRequest, Request as _, WillRenameFiles, WorkspaceConfiguration,
     },
-    CodeActionContext, CodeActionParams, CompletionParams, DidOpenTextDocumentParams,
-    DocumentFormattingParams, FileRename, FormattingOptions, GotoDefinitionParams, HoverParams,
-    PartialResultParams, Position, Range, RenameFilesParams, TextDocumentItem,
-    TextDocumentPositionParams, WorkDoneProgressParams,
+    CodeActionContext, CodeActionParams, CompletionParams, DidChangeConfigurationParams,
+    DidChangeTextDocumentParams, DidOpenTextDocumentParams, DocumentFormattingParams, FileRename,
+    FormattingOptions, GotoDefinitionParams, HoverParams, PartialResultParams, Position, Range,
+    RenameFilesParams, TextDocumentContentChangeEvent, TextDocumentItem,
+    TextDocumentPositionParams, Url, VersionedTextDocumentIdentifier, WorkDoneProgressParams,
+};
+use rust_analyzer::lsp_ext::{
+    CheckCurrentFile, CheckCurrentFileParams, DocumentDiagnostic, DocumentDiagnosticParams,
+    OnEnter, PrepareTypeHierarchy, Runnables, RunnablesParams, ShowType, TypeHierarchyItem,
+    TypeHierarchyItemParams, TypeHierarchyPrepareParams, TypeHierarchySubtypes,
+    TypeHierarchySupertypes, WorkspaceDiagnostic, WorkspaceDiagnosticParams,
+    WorkspaceDiagnosticRefresh,
 };
-use rust_analyzer::lsp_ext::{OnEnter, Runnables, RunnablesParams};
 use serde_json::json;
 use test_utils::skip_slow_tests;
 
 use crate::{
-    support::{project, Project},
+    support::{project, DiagnosticsByFile, Project},
     testdir::TestDir,
 };
 
@@ -202,6 +209,80 @@ fn main() {}
     );
 }
 
+#[test]
+fn test_runnables_example() {
+    if skip_slow_tests() {
+        return;
+    }
+
+    let server = Project::with_fixture(
+        r#"
+//- /Cargo.toml
+[package]
+name = "foo"
+version = "0.0.0"
+
+//- /src/lib.rs
+pub fn foo() {}
+
+//- /examples/demo.rs
+fn main() {}
+"#,
+    )
+    .server()
+    .wait_until_workspace_is_loaded();
+
+    server.request::<Runnables>(
+        RunnablesParams { text_document: server.doc_id("examples/demo.rs"), position: None },
+        json!([
+          {
+            "args": {
+              "cargoArgs": ["run", "--package", "foo", "--example", "demo"],
+              "executableArgs": [],
+              "cargoExtraArgs": [],
+              "overrideCargo": null,
+              "workspaceRoot": server.path()
+            },
+            "kind": "cargo",
+            "label": "run example demo",
+            "location": {
+              "targetRange": {
+                "end": { "character": 12, "line": 0 },
+                "start": { "character": 0, "line": 0 }
+              },
+              "targetSelectionRange": {
+                "end": { "character": 7, "line": 0 },
+                "start": { "character": 3, "line": 0 }
+              },
+              "targetUri": "file:///[..]/examples/demo.rs"
+            }
+          },
+          {
+            "args": {
+              "cargoArgs": ["check", "--package", "foo", "--all-targets"],
+              "executableArgs": [],
+              "cargoExtraArgs": [],
+              "overrideCargo": null,
+              "workspaceRoot": server.path()
+            },
+            "kind": "cargo",
+            "label": "cargo check -p foo --all-targets"
+          },
+          {
+            "args": {
+              "cargoArgs": ["test", "--package", "foo", "--all-targets"],
+              "executableArgs": [],
+              "cargoExtraArgs": [],
+              "overrideCargo": null,
+              "workspaceRoot": server.path()
+            },
+            "kind": "cargo",
+            "label": "cargo test -p foo --all-targets"
+          }
+        ]),
+    );
+}
+
 // Each package in these workspaces should be run from its own root
 #[test]
 fn test_path_dependency_runnables() {
@@ -282,6 +363,24 @@ mod tests {
                         "executableArgs": []
                     },
                 },
+                {
+                    "label": "open docs [..]",
+                    "kind": "cargo",
+                    "location": "{...}",
+                    "args": {
+                        "overrideCargo": null,
+                        "workspaceRoot": server.path().join(runnable),
+                        "cargoArgs": [
+                            "doc",
+                            "--open",
+                            "--no-deps",
+                            "--package",
+                            runnable
+                        ],
+                        "cargoExtraArgs": [],
+                        "executableArgs": []
+                    },
+                },
                 "{...}",
                 "{...}"
             ]),
@@ -434,6 +533,255 @@ fn main() {}
     );
 }
 
+#[test]
+fn pull_diagnostics_are_refreshed_on_config_change() {
+    if skip_slow_tests() {
+        return;
+    }
+
+    let config = json!({
+        "cargo": { "noSysroot": true, "useRustcWrapperForBuildScripts": false },
+        "diagnostics": { "pull": { "enable": true } },
+    });
+    let server = Project::with_fixture(
+        r#"
+//- /Cargo.toml
+[package]
+name = "foo"
+version = "0.0.0"
+
+//- /src/lib.rs
+mod bar;
+"#,
+    )
+    .with_config(config.clone())
+    .with_diagnostics_refresh_support()
+    .server()
+    .wait_until_workspace_is_loaded();
+
+    server.notification::<DidChangeConfiguration>(DidChangeConfigurationParams {
+        settings: json!(null),
+    });
+    let request = server.wait_for_request(WorkspaceConfiguration::METHOD);
+    let mut new_config = config;
+    new_config["diagnostics"]["disabled"] = json!(["unresolved-module"]);
+    server.respond(request.id, json!([new_config]));
+    server.wait_for_request(WorkspaceDiagnosticRefresh::METHOD);
+
+    server.request::<DocumentDiagnostic>(
+        DocumentDiagnosticParams {
+            text_document: server.doc_id("src/lib.rs"),
+            identifier: None,
+            previous_result_id: None,
+            work_done_progress_params: WorkDoneProgressParams::default(),
+            partial_result_params: PartialResultParams::default(),
+        },
+        json!({ "kind": "full", "items": [] }),
+    );
+}
+
+#[test]
+fn pull_diagnostics_after_edit() {
+    if skip_slow_tests() {
+        return;
+    }
+
+    let server = Project::with_fixture(
+        r#"
+//- /Cargo.toml
+[package]
+name = "foo"
+version = "0.0.0"
+
+//- /src/lib.rs
+fn main() {}
+"#,
+    )
+    .with_config(json!({ "diagnostics": { "pull": { "enable": true } } }))
+    .server()
+    .wait_until_workspace_is_loaded();
+
+    let params = || DocumentDiagnosticParams {
+        text_document: server.doc_id("src/lib.rs"),
+        identifier: None,
+        previous_result_id: None,
+        work_done_progress_params: WorkDoneProgressParams::default(),
+        partial_result_params: PartialResultParams::default(),
+    };
+    server.request::<DocumentDiagnostic>(params(), json!({ "kind": "full", "items": [] }));
+
+    server.notification::<DidOpenTextDocument>(DidOpenTextDocumentParams {
+        text_document: TextDocumentItem {
+            uri: server.doc_id("src/lib.rs").uri,
+            language_id: "rust".to_string(),
+            version: 0,
+            text: "fn main() {}".to_string(),
+        },
+    });
+    server.notification::<DidChangeTextDocument>(DidChangeTextDocumentParams {
+        text_document: VersionedTextDocumentIdentifier {
+            uri: server.doc_id("src/lib.rs").uri,
+            version: 1,
+        },
+        content_changes: vec![TextDocumentContentChangeEvent {
+            range: None,
+            range_length: None,
+            text: "mod bar;\nfn main() {}".to_string(),
+        }],
+    });
+
+    let unresolved_module = json!({
+        "code": "unresolved-module",
+        "codeDescription": {
+            "href": "https://rust-analyzer.github.io/manual.html#unresolved-module"
+        },
+        "message": "unresolved module",
+        "range": {
+            "end": { "character": 8, "line": 0 },
+            "start": { "character": 0, "line": 0 }
+        },
+        "severity": 1,
+        "source": "rust-analyzer"
+    });
+    server.request::<DocumentDiagnostic>(
+        params(),
+        json!({ "kind": "full", "items": [unresolved_module.clone()] }),
+    );
+    server.request::<WorkspaceDiagnostic>(
+        WorkspaceDiagnosticParams {
+            identifier: None,
+            previous_result_ids: Vec::new(),
+            work_done_progress_params: WorkDoneProgressParams::default(),
+            partial_result_params: PartialResultParams::default(),
+        },
+        json!({
+            "items": [{
+                "kind": "full",
+                "uri": server.doc_id("src/lib.rs").uri,
+                "version": 1,
+                "items": [unresolved_module]
+            }]
+        }),
+    );
+}
+
+#[test]
+fn check_current_file_only_checks_its_package() {
+    if skip_slow_tests() {
+        return;
+    }
+
+    let server = Project::with_fixture(
+        r#"
+//- /Cargo.toml
+[workspace]
+members = ["a", "b"]
+
+//- /a/Cargo.toml
+[package]
+name = "a"
+version = "0.0.0"
+
+//- /a/src/lib.rs
+pub fn a() { let unused_in_a = 0; }
+
+//- /b/Cargo.toml
+[package]
+name = "b"
+version = "0.0.0"
+
+//- /b/src/lib.rs
+pub fn b() { let unused_in_b = 0; }
+"#,
+    )
+    .with_work_done_progress()
+    .server()
+    .wait_until_workspace_is_loaded();
+
+    let a = server.doc_id("a/src/lib.rs").uri;
+    let b = server.doc_id("b/src/lib.rs").uri;
+    let has_warning = |diagnostics: &DiagnosticsByFile, uri: &Url| {
+        diagnostics.get(uri).map_or(false, |it| !it.is_empty())
+    };
+
+    // The whole workspace gets checked once it is loaded.
+    server.wait_for_diagnostics(0, &|it| has_warning(it, &a) && has_warning(it, &b));
+
+    let start = server.message_count();
+    server.request::<CheckCurrentFile>(
+        CheckCurrentFileParams { text_document: server.doc_id("b/src/lib.rs") },
+        json!(null),
+    );
+    server.wait_for_diagnostics(start, &|it| has_warning(it, &b));
+    server.wait_for_progress_end(start, "cargo check");
+    for params in server.published_diagnostics(start) {
+        if params.uri == a {
+            assert!(params.diagnostics.is_empty(), "{:#?}", params.diagnostics);
+        }
+    }
+}
+
+#[test]
+fn type_hierarchy_of_trait() {
+    if skip_slow_tests() {
+        return;
+    }
+
+    let server = project(
+        r#"
+//- /Cargo.toml
+[package]
+name = "foo"
+version = "0.0.0"
+
+//- /src/lib.rs
+pub trait Named {}
+pub trait Shape: Named {}
+pub struct Circle;
+impl Named for Circle {}
+impl Shape for Circle {}
+"#,
+    )
+    .wait_until_workspace_is_loaded();
+
+    let item = |name: &str, kind: u8, detail: &str, line: u32, len: u32, selection: (u32, u32)| {
+        json!({
+            "name": name,
+            "kind": kind,
+            "detail": detail,
+            "uri": server.doc_id("src/lib.rs").uri,
+            "range": {
+                "start": { "line": line, "character": 0 },
+                "end": { "line": line, "character": len }
+            },
+            "selectionRange": {
+                "start": { "line": line, "character": selection.0 },
+                "end": { "line": line, "character": selection.1 }
+            }
+        })
+    };
+    let named = item("Named", 11, "pub trait Named", 0, 18, (10, 15));
+    let shape = item("Shape", 11, "pub trait Shape\nwhere\n    Self: Named,", 1, 25, (10, 15));
+    let circle = item("Circle", 23, "pub struct Circle", 2, 18, (11, 17));
+
+    let prepared = server.send_request::<PrepareTypeHierarchy>(TypeHierarchyPrepareParams {
+        text_document_position_params: TextDocumentPositionParams::new(
+            server.doc_id("src/lib.rs"),
+            Position::new(1, 12),
+        ),
+        work_done_progress_params: WorkDoneProgressParams::default(),
+    });
+    assert_eq!(prepared, json!([shape.clone()]));
+
+    let params = || TypeHierarchyItemParams {
+        item: serde_json::from_value::<TypeHierarchyItem>(shape.clone()).unwrap(),
+        work_done_progress_params: WorkDoneProgressParams::default(),
+        partial_result_params: PartialResultParams::default(),
+    };
+    server.request::<TypeHierarchySupertypes>(params(), json!([named]));
+    server.request::<TypeHierarchySubtypes>(params(), json!([circle]));
+}
+
 #[test]
 fn test_missing_module_code_action() {
     if skip_slow_tests() {
@@ -489,6 +837,254 @@ fn main() {}
     );
 }
 
+#[test]
+fn run_test_code_action() {
+    if skip_slow_tests() {
+        return;
+    }
+
+    let server = project(
+        r#"
+//- /Cargo.toml
+[package]
+name = "foo"
+version = "0.0.0"
+
+//- /src/lib.rs
+#[test]
+fn works() {
+    assert!(true);
+}
+"#,
+    )
+    .wait_until_workspace_is_loaded();
+
+    let params = |range| CodeActionParams {
+        text_document: server.doc_id("src/lib.rs"),
+        range,
+        context: CodeActionContext::default(),
+        partial_result_params: PartialResultParams::default(),
+        work_done_progress_params: WorkDoneProgressParams::default(),
+    };
+    server.request::<CodeActionRequest>(
+        params(Range::new(Position::new(2, 4), Position::new(2, 4))),
+        json!([{
+            "title": "Run test",
+            "command": {
+                "title": "Run test",
+                "command": "rust-analyzer.runSingle",
+                "arguments": [{
+                    "args": {
+                        "cargoArgs": ["test", "--package", "foo", "--lib"],
+                        "executableArgs": ["works", "--exact", "--nocapture"],
+                        "cargoExtraArgs": [],
+                        "overrideCargo": null,
+                        "workspaceRoot": server.path()
+                    },
+                    "kind": "cargo",
+                    "label": "test works",
+                    "location": {
+                        "targetRange": {
+                            "end": { "character": 1, "line": 3 },
+                            "start": { "character": 0, "line": 0 }
+                        },
+                        "targetSelectionRange": {
+                            "end": { "character": 8, "line": 1 },
+                            "start": { "character": 3, "line": 1 }
+                        },
+                        "targetUri": "file:///[..]/src/lib.rs"
+                    }
+                }]
+            }
+        }]),
+    );
+    server.request::<CodeActionRequest>(
+        params(Range::new(Position::new(1, 4), Position::new(1, 4))),
+        json!([]),
+    );
+}
+
+#[test]
+fn goto_definition_location_link() {
+    if skip_slow_tests() {
+        return;
+    }
+
+    let server = project(
+        r#"
+//- /Cargo.toml
+[package]
+name = "foo"
+version = "0.0.0"
+
+//- /src/lib.rs
+pub struct Circle {
+    pub radius: f64,
+}
+
+pub fn area(circle: &Circle) -> f64 {
+    circle.radius * circle.radius
+}
+"#,
+    )
+    .wait_until_workspace_is_loaded();
+
+    server.request::<GotoDefinition>(
+        GotoDefinitionParams {
+            text_document_position_params: TextDocumentPositionParams::new(
+                server.doc_id("src/lib.rs"),
+                Position::new(5, 12),
+            ),
+            work_done_progress_params: Default::default(),
+            partial_result_params: Default::default(),
+        },
+        json!([{
+            "originSelectionRange": {
+                "end": { "character": 17, "line": 5 },
+                "start": { "character": 11, "line": 5 }
+            },
+            "targetRange": {
+                "end": { "character": 19, "line": 1 },
+                "start": { "character": 4, "line": 1 }
+            },
+            "targetSelectionRange": {
+                "end": { "character": 14, "line": 1 },
+                "start": { "character": 8, "line": 1 }
+            },
+            "targetUri": "file:///[..]src/lib.rs"
+        }]),
+    );
+}
+
+#[test]
+fn goto_implementation_location_links() {
+    if skip_slow_tests() {
+        return;
+    }
+
+    // The "Navigate to Implementations" command labels each implementation with the text of its
+    // target selection range, which must be the self type of the impl.
+    let server = project(
+        r#"
+//- /Cargo.toml
+[package]
+name = "foo"
+version = "0.0.0"
+
+//- /src/lib.rs
+pub trait Shape {}
+mod circle;
+mod polygons;
+
+//- /src/circle.rs
+pub struct Circle;
+impl crate::Shape for Circle {}
+
+//- /src/polygons.rs
+pub struct Square;
+pub struct Triangle;
+impl crate::Shape for Square {}
+impl crate::Shape for Triangle {}
+"#,
+    )
+    .wait_until_workspace_is_loaded();
+
+    server.request::<GotoImplementation>(
+        GotoDefinitionParams {
+            text_document_position_params: TextDocumentPositionParams::new(
+                server.doc_id("src/lib.rs"),
+                Position::new(0, 12),
+            ),
+            work_done_progress_params: Default::default(),
+            partial_result_params: Default::default(),
+        },
+        json!([
+        {
+            "originSelectionRange": {
+                "end": { "character": 15, "line": 0 },
+                "start": { "character": 10, "line": 0 }
+            },
+            "targetRange": {
+                "end": { "character": 31, "line": 1 },
+                "start": { "character": 0, "line": 1 }
+            },
+            "targetSelectionRange": {
+                "end": { "character": 28, "line": 1 },
+                "start": { "character": 22, "line": 1 }
+            },
+            "targetUri": "file:///[..]src/circle.rs"
+        },
+        {
+            "originSelectionRange": {
+                "end": { "character": 15, "line": 0 },
+                "start": { "character": 10, "line": 0 }
+            },
+            "targetRange": {
+                "end": { "character": 31, "line": 2 },
+                "start": { "character": 0, "line": 2 }
+            },
+            "targetSelectionRange": {
+                "end": { "character": 28, "line": 2 },
+                "start": { "character": 22, "line": 2 }
+            },
+            "targetUri": "file:///[..]src/polygons.rs"
+        },
+        {
+            "originSelectionRange": {
+                "end": { "character": 15, "line": 0 },
+                "start": { "character": 10, "line": 0 }
+            },
+            "targetRange": {
+                "end": { "character": 33, "line": 3 },
+                "start": { "character": 0, "line": 3 }
+            },
+            "targetSelectionRange": {
+                "end": { "character": 30, "line": 3 },
+                "start": { "character": 22, "line": 3 }
+            },
+            "targetUri": "file:///[..]src/polygons.rs"
+        }
+        ]),
+    );
+}
+
+#[test]
+fn show_type() {
+    if skip_slow_tests() {
+        return;
+    }
+
+    let server = project(
+        r#"
+//- /Cargo.toml
+[package]
+name = "foo"
+version = "0.0.0"
+
+//- /src/lib.rs
+mod shapes {
+    pub struct Circle;
+}
+
+pub struct Wrapper<T>(T);
+
+fn wrap<T>(value: T) -> Wrapper<T> {
+    Wrapper(value)
+}
+
+fn f() {
+    let circle = wrap(shapes::Circle);
+}
+"#,
+    )
+    .wait_until_workspace_is_loaded();
+
+    server.request::<ShowType>(
+        TextDocumentPositionParams::new(server.doc_id("src/lib.rs"), Position::new(11, 9)),
+        json!("crate::Wrapper<crate::shapes::Circle>"),
+    );
+}
+
 #[test]
 fn test_missing_module_code_action_in_json_project() {
     if skip_slow_tests() {
@@ -1024,3 +1620,90 @@ fn main() {}
         }),
     );
 }
+
+#[test]
+fn test_will_rename_files_nested_module_updates_use_paths() {
+    if skip_slow_tests() {
+        return;
+    }
+
+    let tmp_dir = TestDir::new();
+    let tmp_dir_path = tmp_dir.path().to_owned();
+    let tmp_dir_str = tmp_dir_path.to_str().unwrap();
+    let base_path = PathBuf::from(format!("file://{}", tmp_dir_str));
+
+    let code = r#"
+//- /Cargo.toml
+[package]
+name = "foo"
+version = "0.0.0"
+
+//- /src/lib.rs
+mod outer;
+use crate::outer::inner::f;
+
+//- /src/outer.rs
+pub mod inner;
+
+//- /src/outer/inner.rs
+pub fn f() {}
+"#;
+    let server =
+        Project::with_fixture(code).tmp_dir(tmp_dir).server().wait_until_workspace_is_loaded();
+
+    let uri = |path: &str| {
+        format!(
+            "file://{}",
+            tmp_dir_path
+                .join(path)
+                .to_str()
+                .unwrap()
+                .to_string()
+                .replace("C:\\", "/c:/")
+                .replace("\\", "/")
+        )
+    };
+
+    server.request::<WillRenameFiles>(
+        RenameFilesParams {
+            files: vec![FileRename {
+                old_uri: base_path.join("src/outer/inner.rs").to_str().unwrap().to_string(),
+                new_uri: base_path.join("src/outer/renamed.rs").to_str().unwrap().to_string(),
+            }],
+        },
+        json!({
+          "documentChanges": [
+            {
+              "textDocument": {
+                "uri": uri("src/outer.rs"),
+                "version": null
+              },
+              "edits": [
+                {
+                  "range": {
+                    "start": { "line": 0, "character": 8 },
+                    "end": { "line": 0, "character": 13 }
+                  },
+                  "newText": "renamed"
+                }
+              ]
+            },
+            {
+              "textDocument": {
+                "uri": uri("src/lib.rs"),
+                "version": null
+              },
+              "edits": [
+                {
+                  "range": {
+                    "start": { "line": 1, "character": 18 },
+                    "end": { "line": 1, "character": 23 }
+                  },
+                  "newText": "renamed"
+                }
+              ]
+            }
+          ]
+        }),
+    );
+}
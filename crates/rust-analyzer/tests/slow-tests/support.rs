@@ -1,5 +1,6 @@
 use std::{
     cell::{Cell, RefCell},
+    collections::HashMap,
     fs,
     path::{Path, PathBuf},
     sync::Once,
@@ -7,8 +8,13 @@ use std::{
 };
 
 use crossbeam_channel::{after, select, Receiver};
-use lsp_server::{Connection, Message, Notification, Request};
-use lsp_types::{notification::Exit, request::Shutdown, TextDocumentIdentifier, Url};
+use lsp_server::{Connection, Message, Notification, Request, RequestId, Response};
+use lsp_types::{
+    notification::{Exit, Notification as _, Progress, PublishDiagnostics},
+    request::{Request as _, Shutdown, WorkDoneProgressCreate},
+    Diagnostic, ProgressParams, ProgressParamsValue, ProgressToken, PublishDiagnosticsParams,
+    TextDocumentIdentifier, Url, WorkDoneProgress,
+};
 use project_model::ProjectManifest;
 use rust_analyzer::{config::Config, lsp_ext, main_loop};
 use serde::Serialize;
@@ -23,6 +29,8 @@ pub(crate) struct Project<'a> {
     tmp_dir: Option<TestDir>,
     roots: Vec<PathBuf>,
     config: serde_json::Value,
+    work_done_progress: bool,
+    diagnostics_refresh_support: bool,
 }
 
 impl<'a> Project<'a> {
@@ -39,6 +47,8 @@ impl<'a> Project<'a> {
                     "useRustcWrapperForBuildScripts": false,
                 }
             }),
+            work_done_progress: false,
+            diagnostics_refresh_support: false,
         }
     }
 
@@ -52,6 +62,18 @@ impl<'a> Project<'a> {
         self
     }
 
+    /// Makes the client accept `$/progress` notifications, so that tests can wait for them.
+    pub(crate) fn with_work_done_progress(mut self) -> Project<'a> {
+        self.work_done_progress = true;
+        self
+    }
+
+    /// Makes the client accept `workspace/diagnostic/refresh` requests.
+    pub(crate) fn with_diagnostics_refresh_support(mut self) -> Project<'a> {
+        self.diagnostics_refresh_support = true;
+        self
+    }
+
     pub(crate) fn with_config(mut self, config: serde_json::Value) -> Project<'a> {
         fn merge(dst: &mut serde_json::Value, src: serde_json::Value) {
             match (dst, src) {
@@ -119,7 +141,7 @@ impl<'a> Project<'a> {
                     ..Default::default()
                 }),
                 window: Some(lsp_types::WindowClientCapabilities {
-                    work_done_progress: Some(false),
+                    work_done_progress: Some(self.work_done_progress),
                     ..Default::default()
                 }),
                 experimental: Some(json!({
@@ -129,6 +151,7 @@ impl<'a> Project<'a> {
             },
         );
         config.discovered_projects = Some(discovered_projects);
+        config.diagnostics_refresh_support = self.diagnostics_refresh_support;
         config.update(self.config);
 
         Server::new(tmp_dir, config)
@@ -218,6 +241,9 @@ impl Server {
                             continue;
                         }
                     }
+                    if req.method == WorkDoneProgressCreate::METHOD {
+                        continue;
+                    }
                     panic!("unexpected request: {:?}", req)
                 }
                 Message::Notification(_) => (),
@@ -246,6 +272,90 @@ impl Server {
         .unwrap_or_else(|Timeout| panic!("timeout while waiting for ws to load"));
         self
     }
+    /// Waits for the server to send a request with the given method, and returns it.
+    pub(crate) fn wait_for_request(&self, method: &str) -> Request {
+        loop {
+            let msg = self
+                .recv()
+                .unwrap_or_else(|Timeout| panic!("timeout while waiting for {}", method))
+                .expect("no response");
+            match msg {
+                Message::Request(req) if req.method == method => return req,
+                _ => (),
+            }
+        }
+    }
+    /// Answers a request sent by the server.
+    pub(crate) fn respond(&self, id: RequestId, result: Value) {
+        self.client.sender.send(Response::new_ok(id, result).into()).unwrap();
+    }
+    /// The number of messages received from the server so far.
+    pub(crate) fn message_count(&self) -> usize {
+        self.messages.borrow().len()
+    }
+    /// The diagnostics published after the first `start` messages, in the order they were sent.
+    pub(crate) fn published_diagnostics(&self, start: usize) -> Vec<PublishDiagnosticsParams> {
+        self.messages.borrow()[start..]
+            .iter()
+            .filter_map(|msg| match msg {
+                Message::Notification(n) if n.method == PublishDiagnostics::METHOD => {
+                    Some(serde_json::from_value(n.params.clone()).unwrap())
+                }
+                _ => None,
+            })
+            .collect()
+    }
+    /// Waits until `cond` holds for the diagnostics published after the first `start` messages,
+    /// and returns the last ones published for each file.
+    pub(crate) fn wait_for_diagnostics(
+        &self,
+        start: usize,
+        cond: &dyn Fn(&DiagnosticsByFile) -> bool,
+    ) -> DiagnosticsByFile {
+        loop {
+            let diagnostics: DiagnosticsByFile = self
+                .published_diagnostics(start)
+                .into_iter()
+                .map(|params| (params.uri, params.diagnostics))
+                .collect();
+            if cond(&diagnostics) {
+                return diagnostics;
+            }
+            self.recv()
+                .unwrap_or_else(|Timeout| panic!("timeout while waiting for diagnostics"))
+                .expect("no response");
+        }
+    }
+    /// Waits until the progress with the given title has begun and then ended again after the
+    /// first `start` messages. Needs a project created with `with_work_done_progress`.
+    pub(crate) fn wait_for_progress_end(&self, start: usize, title: &str) {
+        let token = ProgressToken::String(format!("rustAnalyzer/{}", title));
+        let mut began = false;
+        let mut seen = start;
+        loop {
+            let messages = self.messages.borrow()[seen..].to_vec();
+            seen += messages.len();
+            for msg in messages {
+                let params = match msg {
+                    Message::Notification(n) if n.method == Progress::METHOD => {
+                        serde_json::from_value::<ProgressParams>(n.params).unwrap()
+                    }
+                    _ => continue,
+                };
+                if params.token != token {
+                    continue;
+                }
+                match params.value {
+                    ProgressParamsValue::WorkDone(WorkDoneProgress::Begin(_)) => began = true,
+                    ProgressParamsValue::WorkDone(WorkDoneProgress::End(_)) if began => return,
+                    _ => (),
+                }
+            }
+            self.recv()
+                .unwrap_or_else(|Timeout| panic!("timeout while waiting for {:?}", title))
+                .expect("no response");
+        }
+    }
     fn wait_for_message_cond(
         &self,
         n: usize,
@@ -289,6 +399,9 @@ impl Drop for Server {
     }
 }
 
+/// The diagnostics last published for each file.
+pub(crate) type DiagnosticsByFile = HashMap<Url, Vec<Diagnostic>>;
+
 struct Timeout;
 
 fn recv_timeout(receiver: &Receiver<Message>) -> Result<Option<Message>, Timeout> {
@@ -285,6 +285,8 @@ fn check_todo(path: &Path, text: &str) {
         "ide_assists/src/tests/generated.rs",
         // The tests for missing fields
         "ide_diagnostics/src/handlers/missing_fields.rs",
+        // The fix for missing format arguments
+        "ide_diagnostics/src/handlers/format_args.rs",
     ];
     if need_todo.iter().any(|p| path.ends_with(p)) {
         return;
@@ -349,6 +351,8 @@ fn check_test_attrs(path: &Path, text: &str) {
         "ide_assists/src/handlers/toggle_ignore.rs",
         // See above.
         "ide_assists/src/tests/generated.rs",
+        // Completes test attributes in fixtures.
+        "ide_completion/src/tests/attribute.rs",
     ];
     if text.contains("#[ignore") && !need_ignore.iter().any(|p| path.ends_with(p)) {
         panic!("\ndon't `#[ignore]` tests, see:\n\n    {}\n\n   {}\n", ignore_rule, path.display(),)
@@ -360,6 +364,8 @@ fn check_test_attrs(path: &Path, text: &str) {
         // This file.
         "slow-tests/tidy.rs",
         "test_utils/src/fixture.rs",
+        // Completes test attributes in fixtures.
+        "ide_completion/src/tests/attribute.rs",
     ];
     if text.contains("#[should_panic") && !need_panic.iter().any(|p| path.ends_with(p)) {
         panic!(
@@ -21,8 +21,9 @@ pub use self::{
     expr_ext::{ArrayExprKind, BlockModifier, CallableExpr, ElseBranch, LiteralKind},
     generated::{nodes::*, tokens::*},
     node_ext::{
-        AttrKind, FieldKind, Macro, NameLike, NameOrNameRef, PathSegmentKind, SelfParamKind,
-        SlicePatComponents, StructKind, TypeBoundKind, VisibilityKind,
+        AttrKind, FieldKind, Macro, MacroRulesFrag, MacroRulesPat, MacroRulesRepetition, NameLike,
+        NameOrNameRef, PathSegmentKind, SelfParamKind, SlicePatComponents, StructKind,
+        TypeBoundKind, VisibilityKind,
     },
     operators::{ArithOp, BinaryOp, CmpOp, LogicOp, Ordering, RangeOp, UnaryOp},
     token_ext::{
@@ -368,3 +369,41 @@ where
     assert_eq!("F", pred.ty().unwrap().syntax().text().to_string());
     assert_bound("Fn(&'a str)", bounds.next());
 }
+
+#[test]
+fn test_macro_rules_fragments() {
+    let file = SourceFile::parse(
+        r#"
+macro_rules! m {
+    ($a:expr, $($b: ident),* $c:) => { $a };
+    () => {};
+}
+"#,
+    )
+    .tree();
+    let mac = file.syntax().descendants().find_map(MacroRules::cast).unwrap();
+    let patterns: Vec<_> = mac.patterns().collect();
+    assert_eq!(patterns.len(), 2);
+
+    let fragments: Vec<_> = patterns[0]
+        .fragments()
+        .map(|it| {
+            (
+                it.name_token().unwrap().text().to_string(),
+                it.specifier().map(|it| it.as_str().to_string()),
+            )
+        })
+        .collect();
+    assert_eq!(
+        fragments,
+        [
+            ("a".to_string(), Some("expr".to_string())),
+            ("b".to_string(), Some("ident".to_string())),
+            ("c".to_string(), None),
+        ]
+    );
+    let repetition =
+        patterns[0].syntax().descendants().find_map(MacroRulesRepetition::cast).unwrap();
+    assert_eq!(repetition.op_token().unwrap().text(), "*");
+    assert_eq!(patterns[1].fragments().count(), 0);
+}
@@ -11,7 +11,7 @@ use rowan::{GreenNodeData, GreenTokenData};
 
 use crate::{
     ast::{self, support, AstNode, AstToken, HasAttrs, HasGenericParams, HasName, SyntaxNode},
-    NodeOrToken, SmolStr, SyntaxElement, SyntaxToken, TokenText, T,
+    Direction, NodeOrToken, SmolStr, SyntaxElement, SyntaxToken, TokenText, T,
 };
 
 impl ast::Lifetime {
@@ -108,6 +108,138 @@ impl HasName for Macro {
 
 impl HasAttrs for Macro {}
 
+impl ast::MacroRules {
+    /// The matchers of the arms, in order.
+    pub fn patterns(&self) -> impl Iterator<Item = MacroRulesPat> {
+        self.token_tree()
+            .into_iter()
+            .flat_map(|tt| tt.syntax().children())
+            .filter_map(MacroRulesPat::cast)
+    }
+}
+
+/// The matcher of a `macro_rules!` arm, like `($a:expr, $($b:ident),*)`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct MacroRulesPat {
+    pub(crate) syntax: SyntaxNode,
+}
+
+impl MacroRulesPat {
+    pub fn token_tree(&self) -> Option<ast::TokenTree> {
+        support::child(&self.syntax)
+    }
+
+    /// All the fragments of the matcher, including those nested in repetitions.
+    pub fn fragments(&self) -> impl Iterator<Item = MacroRulesFrag> {
+        self.syntax.descendants().filter_map(MacroRulesFrag::cast)
+    }
+}
+
+impl AstNode for MacroRulesPat {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == SyntaxKind::MACRO_RULES_PAT
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        Self::can_cast(syntax.kind()).then(|| MacroRulesPat { syntax })
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+
+/// A metavariable of a matcher, like `$a:expr`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct MacroRulesFrag {
+    pub(crate) syntax: SyntaxNode,
+}
+
+impl MacroRulesFrag {
+    pub fn dollar_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T![$])
+    }
+
+    /// The name of the metavariable, without the `$`.
+    pub fn name_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, SyntaxKind::IDENT)
+    }
+
+    pub fn colon_token(&self) -> Option<SyntaxToken> {
+        support::token(&self.syntax, T![:])
+    }
+
+    pub fn specifier_token(&self) -> Option<SyntaxToken> {
+        self.colon_token()?
+            .siblings_with_tokens(Direction::Next)
+            .filter_map(|it| it.into_token())
+            .find(|it| it.kind() == SyntaxKind::IDENT)
+    }
+
+    /// The fragment specifier, like `expr` or `ident`. It isn't checked to be a valid one.
+    pub fn specifier(&self) -> Option<TokenText<'_>> {
+        fn specifier_token(green_ref: &GreenNodeData) -> Option<&GreenTokenData> {
+            green_ref
+                .children()
+                .filter_map(NodeOrToken::into_token)
+                .map(|it| (SyntaxKind::from(it.kind().0), it))
+                .skip_while(|(kind, _)| *kind != T![:])
+                .find(|(kind, _)| *kind == SyntaxKind::IDENT)
+                .map(|(_, it)| it)
+        }
+
+        match self.syntax.green() {
+            Cow::Borrowed(green_ref) => {
+                specifier_token(green_ref).map(|it| TokenText::borrowed(it.text()))
+            }
+            Cow::Owned(green) => specifier_token(&green).map(|it| TokenText::owned(it.to_owned())),
+        }
+    }
+}
+
+impl AstNode for MacroRulesFrag {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == SyntaxKind::MACRO_RULES_FRAG
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        Self::can_cast(syntax.kind()).then(|| MacroRulesFrag { syntax })
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+
+/// A repetition of a matcher, like `$($a:ident),*`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct MacroRulesRepetition {
+    pub(crate) syntax: SyntaxNode,
+}
+
+impl MacroRulesRepetition {
+    pub fn token_tree(&self) -> Option<ast::TokenTree> {
+        support::child(&self.syntax)
+    }
+
+    /// The repetition operator, one of `*`, `+` or `?`.
+    pub fn op_token(&self) -> Option<SyntaxToken> {
+        self.syntax
+            .children_with_tokens()
+            .filter_map(|it| it.into_token())
+            .filter(|it| matches!(it.kind(), T![*] | T![+] | T![?]))
+            .last()
+    }
+}
+
+impl AstNode for MacroRulesRepetition {
+    fn can_cast(kind: SyntaxKind) -> bool {
+        kind == SyntaxKind::MACRO_RULES_REPETITION
+    }
+    fn cast(syntax: SyntaxNode) -> Option<Self> {
+        Self::can_cast(syntax.kind()).then(|| MacroRulesRepetition { syntax })
+    }
+    fn syntax(&self) -> &SyntaxNode {
+        &self.syntax
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum AttrKind {
     Inner,
@@ -308,7 +440,8 @@ impl ast::UseTreeList {
 impl ast::Impl {
     pub fn self_ty(&self) -> Option<ast::Type> {
         match self.target() {
-            (Some(t), None) | (_, Some(t)) => Some(t),
+            (Some(t), None) if self.for_token().is_none() => Some(t),
+            (_, Some(t)) => Some(t),
             _ => None,
         }
     }
@@ -316,6 +449,8 @@ impl ast::Impl {
     pub fn trait_(&self) -> Option<ast::Type> {
         match self.target() {
             (Some(t), Some(_)) => Some(t),
+            // `impl Trait for {}`, with the self type missing.
+            (Some(t), None) if self.for_token().is_some() => Some(t),
             _ => None,
         }
     }
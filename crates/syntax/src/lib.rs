@@ -57,7 +57,7 @@ pub use crate::{
     },
     token_text::TokenText,
 };
-pub use parser::{SyntaxKind, T};
+pub use parser::{ParserFeatures, SyntaxKind, T};
 pub use rowan::{
     Direction, GreenNode, NodeOrToken, SyntaxText, TextRange, TextSize, TokenAtOffset, WalkEvent,
 };
@@ -158,7 +158,12 @@ pub use crate::ast::SourceFile;
 
 impl SourceFile {
     pub fn parse(text: &str) -> Parse<SourceFile> {
-        let (green, mut errors) = parsing::parse_text(text);
+        SourceFile::parse_with_features(text, ParserFeatures::ALL)
+    }
+
+    /// Parses `text`, reporting syntax of unstable features which aren't enabled as errors.
+    pub fn parse_with_features(text: &str, features: ParserFeatures) -> Parse<SourceFile> {
+        let (green, mut errors) = parsing::parse_text(text, features);
         let root = SyntaxNode::new_root(green.clone());
 
         errors.extend(validation::validate(&root));
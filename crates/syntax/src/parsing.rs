@@ -9,10 +9,14 @@ use crate::{syntax_node::GreenNode, SyntaxError, SyntaxTreeBuilder};
 
 pub(crate) use crate::parsing::reparsing::incremental_reparse;
 
-pub(crate) fn parse_text(text: &str) -> (GreenNode, Vec<SyntaxError>) {
+pub(crate) fn parse_text(
+    text: &str,
+    features: parser::ParserFeatures,
+) -> (GreenNode, Vec<SyntaxError>) {
     let lexed = parser::LexedStr::new(text);
     let parser_input = lexed.to_input();
-    let parser_output = parser::TopEntryPoint::SourceFile.parse(&parser_input);
+    let parser_output =
+        parser::TopEntryPoint::SourceFile.parse_with_features(&parser_input, features);
     let (node, errors, _eof) = build_tree(lexed, parser_output);
     (node, errors)
 }
@@ -1,10 +1,16 @@
 //! Implementation of incremental re-parsing.
 //!
-//! We use two simple strategies for this:
+//! We use three simple strategies for this:
+//!   - if the edit only inserts or removes whitespace, we update the whitespace
+//!     tokens directly, as the structure of the tree doesn't change.
 //!   - if the edit modifies only a single token (like changing an identifier's
 //!     letter), we replace only this token.
 //!   - otherwise, we search for the nearest `{}` block which contains the edit
 //!     and try to parse only this block.
+//!
+//! None of these lex more than the edited block, so there is no lexer cache:
+//! only the full reparse fallback lexes the whole file, and lexing is about a
+//! tenth of a full parse (see `benchmark_parser`).
 
 use parser::Reparser;
 use text_edit::Indel;
@@ -22,6 +28,10 @@ pub(crate) fn incremental_reparse(
     edit: &Indel,
     errors: Vec<SyntaxError>,
 ) -> Option<(GreenNode, Vec<SyntaxError>, TextRange)> {
+    if let Some((green, old_range)) = reparse_whitespace(node, edit, &errors) {
+        return Some((green, merge_errors(errors, Vec::new(), old_range, edit), old_range));
+    }
+
     if let Some((green, new_errors, old_range)) = reparse_token(node, edit) {
         return Some((green, merge_errors(errors, new_errors, old_range, edit), old_range));
     }
@@ -32,6 +42,40 @@ pub(crate) fn incremental_reparse(
     None
 }
 
+fn reparse_whitespace(
+    root: &SyntaxNode,
+    edit: &Indel,
+    errors: &[SyntaxError],
+) -> Option<(GreenNode, TextRange)> {
+    let is_whitespace = |text: &str| text.chars().all(|c| matches!(c, ' ' | '\t' | '\n' | '\r'));
+    if !is_whitespace(&edit.insert) {
+        return None;
+    }
+    let whitespace = root
+        .token_at_offset(edit.delete.start())
+        .find(|it| it.kind() == WHITESPACE && it.text_range().contains_range(edit.delete))?;
+    let new_text = get_text_after_edit(whitespace.clone().into(), edit);
+    if new_text.is_empty() {
+        // The neighbouring tokens might merge.
+        return None;
+    }
+
+    // Line comments, shebangs and unterminated literals extend up to the end of the line, and
+    // line breaks next to comments decide whether the comments are attached to items.
+    let (prev, next) = (whitespace.prev_token(), whitespace.next_token());
+    if prev.iter().chain(&next).any(|it| it.kind() == COMMENT) {
+        return None;
+    }
+    if let Some(prev) = prev {
+        if prev.kind() == SHEBANG || errors.iter().any(|it| it.range() == prev.text_range()) {
+            return None;
+        }
+    }
+
+    let new_token = GreenToken::new(rowan::SyntaxKind(WHITESPACE.into()), &new_text);
+    Some((whitespace.replace_with(new_token), whitespace.text_range()))
+}
+
 fn reparse_token(
     root: &SyntaxNode,
     edit: &Indel,
@@ -39,8 +83,8 @@ fn reparse_token(
     let prev_token = root.covering_element(edit.delete).as_token()?.clone();
     let prev_token_kind = prev_token.kind();
     match prev_token_kind {
-        WHITESPACE | COMMENT | IDENT | STRING => {
-            if prev_token_kind == WHITESPACE || prev_token_kind == COMMENT {
+        COMMENT | IDENT | STRING => {
+            if prev_token_kind == COMMENT {
                 // removing a new line may extends previous token
                 let deleted_range = edit.delete - prev_token.text_range().start();
                 if prev_token.text()[deleted_range].contains('\n') {
@@ -120,7 +164,15 @@ fn find_reparsable_node(node: &SyntaxNode, range: TextRange) -> Option<(SyntaxNo
     let node = node.covering_element(range);
 
     node.ancestors().find_map(|node| {
-        let first_child = node.first_child_or_token().map(|it| it.kind());
+        // Token trees of a macro matcher contain fragments and repetitions, which only the
+        // matcher as a whole knows how to parse.
+        if node.ancestors().skip(1).any(|it| it.kind() == MACRO_RULES_PAT) {
+            return None;
+        }
+        let first_child = match node.kind() {
+            MACRO_RULES_PAT => node.first_token().map(|it| it.kind()),
+            _ => node.first_child_or_token().map(|it| it.kind()),
+        };
         let parent = node.parent().map(|it| it.kind());
         Reparser::for_node(node.kind(), first_child, parent).map(|r| (node, r))
     })
@@ -307,6 +359,9 @@ extern {
             " exit(code: c_int)",
             11,
         );
+        do_check(r"macro_rules! m { {$a:expr$0$0} => {} }", ", $b:ty", 9);
+        do_check(r"macro_rules! m { ({$a:expr$0$0}) => {} }", ", $b:ty", 21);
+        do_check(r"macro_rules! m { () => {$0$0} }", "$a", 2);
     }
 
     #[test]
@@ -401,6 +456,85 @@ enum Foo {
         );
     }
 
+    #[test]
+    fn reparse_whitespace_tests() {
+        do_check(
+            r"
+fn foo() {
+    let x = 1;$0
+$0    let y = 2;
+}
+",
+            "",
+            5,
+        );
+        do_check(
+            r"
+fn foo() {
+    let x = foo(a,$0$0 b);
+}
+",
+            "\n       ",
+            1,
+        );
+        do_check(
+            r"
+fn foo() {
+    let x: Vec<Vec<u8> $0$0> = Vec::new();
+}
+",
+            "\t",
+            1,
+        );
+    }
+
+    #[test]
+    fn reparse_whitespace_next_to_comment() {
+        // The line comment would swallow the next line.
+        do_check(
+            r"
+fn foo() {
+    // comment$0
+$0    bar();
+}
+",
+            "",
+            29,
+        );
+        do_check(
+            r"
+fn foo() {
+    // comment$0$0
+    bar();
+}
+",
+            " ",
+            10,
+        );
+        // The comment is no longer attached to the item.
+        do_check(
+            r"
+mod m {
+    // comment
+$0$0    fn foo() {}
+}
+",
+            "\n",
+            34,
+        );
+    }
+
+    #[test]
+    fn reparse_whitespace_after_unterminated_literal() {
+        // Without the line break, the literal swallows the closing brace.
+        let (range, before) = extract_range("fn foo() {\n    b'x$0\n$0}\n");
+        let edit = Indel::delete(range);
+        let before = SourceFile::parse(&before);
+        assert!(
+            incremental_reparse(before.tree().syntax(), &edit, before.errors.to_vec()).is_none()
+        );
+    }
+
     #[test]
     fn reparse_str_token_with_error_unchanged() {
         do_check(r#""$0Unclosed$0 string literal"#, "Still unclosed", 24);
@@ -33,6 +33,11 @@ fn benchmark_parser() {
     }
 
     let data = bench_fixture::glorious_old_parser();
+    {
+        let _b = bench("lexing");
+        let lexed = parser::LexedStr::new(&data);
+        assert_eq!(lexed.errors().count(), 0);
+    }
     let tree = {
         let _b = bench("parsing");
         let p = SourceFile::parse(&data);
@@ -49,6 +54,33 @@ fn benchmark_parser() {
     }
 }
 
+#[test]
+fn benchmark_parser_many_fns() {
+    if std::env::var("RUN_SLOW_BENCHES").is_err() {
+        return;
+    }
+
+    let file = |body: &str| -> String {
+        (0..100)
+            .map(|i| format!("fn f{}(x: u32) -> u32 {}\n", i, body.replace("$i", &i.to_string())))
+            .collect()
+    };
+    let data = file("{\n    let y = x * $i;\n    if y > 92 { y - 1 } else { y + 1 }\n}");
+    // What deferring the parsing of bodies could save at most.
+    let headers_only = file("{}");
+    for (label, data) in
+        [("parsing many functions", &data), ("parsing many function headers", &headers_only)]
+    {
+        let fn_names = {
+            let _b = bench(label);
+            let p = SourceFile::parse(data);
+            assert!(p.errors.is_empty());
+            p.tree().syntax().descendants().filter_map(ast::Fn::cast).count()
+        };
+        assert_eq!(fn_names, 100);
+    }
+}
+
 #[test]
 fn validation_tests() {
     dir_tests(&test_data_dir(), &["parser/validation"], "rast", |text, path| {
@@ -91,6 +91,9 @@ pub(crate) const KINDS_SRC: KindsSrc = KindsSrc {
         "MACRO_CALL",
         "MACRO_RULES",
         "MACRO_ARM",
+        "MACRO_RULES_PAT",
+        "MACRO_RULES_FRAG",
+        "MACRO_RULES_REPETITION",
         "TOKEN_TREE",
         "MACRO_DEF",
         "PAREN_TYPE",
@@ -331,8 +331,36 @@ fn validate_macro_rules(mac: ast::MacroRules, errors: &mut Vec<SyntaxError>) {
             vis.syntax().text_range(),
         ));
     }
+    for fragment in mac.patterns().flat_map(|it| it.fragments()) {
+        if let Some(specifier) = fragment.specifier_token() {
+            if !MACRO_FRAGMENT_SPECIFIERS.contains(&specifier.text()) {
+                errors.push(SyntaxError::new(
+                    format!("invalid fragment specifier `{}`", specifier.text()),
+                    specifier.text_range(),
+                ));
+            }
+        }
+    }
 }
 
+const MACRO_FRAGMENT_SPECIFIERS: &[&str] = &[
+    "block",
+    "expr",
+    "expr_2021",
+    "ident",
+    "item",
+    "lifetime",
+    "literal",
+    "meta",
+    "pat",
+    "pat_param",
+    "path",
+    "stmt",
+    "tt",
+    "ty",
+    "vis",
+];
+
 fn validate_const(const_: ast::Const, errors: &mut Vec<SyntaxError>) {
     if let Some(mut_token) = const_
         .const_token()
@@ -0,0 +1,5 @@
+macro_rules! m {
+    ($e:expr, $t:ty, $($i:ident),*) => { $e:foo };
+    ($x:expression) => {};
+    ($($y:typ)*) => {};
+}
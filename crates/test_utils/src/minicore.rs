@@ -37,6 +37,7 @@
 //!     add:
 //!     as_ref: sized
 //!     drop:
+//!     try_from: from, result
 
 pub mod marker {
     // region:sized
@@ -144,6 +145,13 @@ pub mod convert {
     }
     // endregion:from
 
+    // region:try_from
+    pub trait TryFrom<T>: Sized {
+        type Error;
+        fn try_from(value: T) -> crate::result::Result<Self, Self::Error>;
+    }
+    // endregion:try_from
+
     // region:as_ref
     pub trait AsRef<T: ?Sized> {
         fn as_ref(&self) -> &T;
@@ -658,6 +666,7 @@ pub mod prelude {
 
     pub mod rust_2021 {
         pub use super::v1::*;
+        pub use crate::convert::TryFrom; // :try_from
     }
 }
 
@@ -97,9 +97,10 @@ export function createClient(serverPath: string, workspace: Workspace, extraEnv:
                     const result: (vscode.CodeAction | vscode.Command)[] = [];
                     const groups = new Map<string, { index: number; items: vscode.CodeAction[] }>();
                     for (const item of values) {
-                        // In our case we expect to get code edits only from diagnostics
+                        // In our case we expect to get code edits only from diagnostics,
+                        // and commands only to run tests
                         if (lc.CodeAction.is(item)) {
-                            assert(!item.command, "We don't expect to receive commands in CodeActions");
+                            assert(!item.command || item.command.command === "rust-analyzer.runSingle", "We don't expect to receive other commands in CodeActions");
                             const action = client.protocol2CodeConverter.asCodeAction(item);
                             result.push(action);
                             continue;
@@ -178,6 +179,7 @@ class ExperimentalFeatures implements lc.StaticFeature {
         caps.codeActionGroup = true;
         caps.hoverActions = true;
         caps.serverStatusNotification = true;
+        caps.inlayHintResolve = true;
         caps.commands = {
             commands: [
                 "rust-analyzer.runSingle",
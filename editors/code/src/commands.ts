@@ -94,6 +94,18 @@ export function shuffleCrateGraph(ctx: Ctx): Cmd {
     };
 }
 
+export function checkCurrentFile(ctx: Ctx): Cmd {
+    return async () => {
+        const editor = ctx.activeRustEditor;
+        const client = ctx.client;
+        if (!editor || !client) return;
+
+        await client.sendRequest(ra.checkCurrentFile, {
+            textDocument: ctx.client.code2ProtocolConverter.asTextDocumentIdentifier(editor.document),
+        });
+    };
+}
+
 export function matchingBrace(ctx: Ctx): Cmd {
     return async () => {
         const editor = ctx.activeRustEditor;
@@ -117,6 +129,50 @@ export function matchingBrace(ctx: Ctx): Cmd {
     };
 }
 
+export function parentExpression(ctx: Ctx): Cmd {
+    return async () => {
+        const editor = ctx.activeRustEditor;
+        const client = ctx.client;
+        if (!editor || !client) return;
+
+        const response = await client.sendRequest(ra.parentExpression, {
+            textDocument: ctx.client.code2ProtocolConverter.asTextDocumentIdentifier(editor.document),
+            selections: editor.selections.map(s =>
+                client.code2ProtocolConverter.asRange(s),
+            ),
+        });
+        editor.selections = response.map(range => {
+            const parent = client.protocol2CodeConverter.asRange(range);
+            return new vscode.Selection(parent.start, parent.end);
+        });
+        editor.revealRange(editor.selection);
+    };
+}
+
+export function showType(ctx: Ctx): Cmd {
+    return async () => {
+        const editor = ctx.activeRustEditor;
+        const client = ctx.client;
+        if (!editor || !client) return;
+
+        const ty = await client.sendRequest(ra.showType, {
+            textDocument: ctx.client.code2ProtocolConverter.asTextDocumentIdentifier(editor.document),
+            position: client.code2ProtocolConverter.asPosition(
+                editor.selection.active,
+            ),
+        });
+        if (!ty) {
+            await vscode.window.showInformationMessage("No expression at the cursor");
+            return;
+        }
+
+        const copy = await vscode.window.showInformationMessage(ty, "Copy");
+        if (copy) {
+            await vscode.env.clipboard.writeText(ty);
+        }
+    };
+}
+
 export function joinLines(ctx: Ctx): Cmd {
     return async () => {
         const editor = ctx.activeRustEditor;
@@ -162,6 +218,37 @@ export function moveItem(ctx: Ctx, direction: ra.Direction): Cmd {
     };
 }
 
+export function moveItemToModule(ctx: Ctx): Cmd {
+    return async () => {
+        const editor = ctx.activeRustEditor;
+        const client = ctx.client;
+        if (!editor || !client) return;
+
+        const files = await vscode.workspace.findFiles("**/*.rs", "**/target/**");
+        const items = files
+            .filter((uri) => uri.toString() !== editor.document.uri.toString())
+            .map((uri) => ({ label: vscode.workspace.asRelativePath(uri), uri }))
+            .sort((a, b) => a.label.localeCompare(b.label));
+        const item = await vscode.window.showQuickPick(items, { placeHolder: "Select the module to move the item to" });
+        if (!item) return;
+
+        const res = await client.sendRequest(ra.moveItemToModule, {
+            textDocument: client.code2ProtocolConverter.asTextDocumentIdentifier(editor.document),
+            position: client.code2ProtocolConverter.asPosition(editor.selection.active),
+            target: { uri: item.uri.toString() },
+        });
+        if (!res) {
+            await vscode.window.showInformationMessage("The item under the cursor can't be moved to this module");
+            return;
+        }
+
+        await vscode.workspace.applyEdit(client.protocol2CodeConverter.asWorkspaceEdit(res.edit));
+        if (res.warnings.length !== 0) {
+            await vscode.window.showWarningMessage(res.warnings.join("\n"));
+        }
+    };
+}
+
 export function onEnter(ctx: Ctx): Cmd {
     async function handleKeypress() {
         const editor = ctx.activeRustEditor;
@@ -671,6 +758,79 @@ export function openDocs(ctx: Ctx): Cmd {
 
 }
 
+export function showDocumentation(ctx: Ctx): Cmd {
+    return async () => {
+        const client = ctx.client;
+        const editor = vscode.window.activeTextEditor;
+        if (!editor || !client) {
+            return;
+        };
+
+        const position = editor.selection.active;
+        const textDocument = { uri: editor.document.uri.toString() };
+
+        const docs = await client.sendRequest(ra.showDocumentation, { position, textDocument });
+        if (docs == null) {
+            return;
+        }
+
+        if ("url" in docs) {
+            await vscode.commands.executeCommand("vscode.open", vscode.Uri.parse(docs.url));
+        } else {
+            const document = await vscode.workspace.openTextDocument({ language: "markdown", content: docs.markdown });
+            await vscode.commands.executeCommand("markdown.showPreview", document.uri);
+        }
+    };
+}
+
+export function navigateToImplementations(ctx: Ctx): Cmd {
+    return async () => {
+        const editor = ctx.activeRustEditor;
+        if (!editor) return;
+
+        const targets = await vscode.commands.executeCommand<(vscode.Location | vscode.LocationLink)[]>(
+            "vscode.executeImplementationProvider",
+            editor.document.uri,
+            editor.selection.active,
+        );
+        if (!targets || targets.length === 0) {
+            await vscode.window.showInformationMessage("No implementations found");
+            return;
+        }
+
+        const locations = targets.map((target) =>
+            "targetUri" in target
+                ? { uri: target.targetUri, range: target.targetSelectionRange ?? target.targetRange }
+                : { uri: target.uri, range: target.range }
+        );
+
+        // Only open the files containing implementations, and each of them once. The selection
+        // range of an implementation is its self type.
+        const documents = new Map<string, vscode.TextDocument>();
+        const items = [];
+        for (const { uri, range } of locations) {
+            let document = documents.get(uri.toString());
+            if (!document) {
+                document = await vscode.workspace.openTextDocument(uri);
+                documents.set(uri.toString(), document);
+            }
+            items.push({
+                label: document.getText(range),
+                description: vscode.workspace.asRelativePath(uri),
+                uri,
+                range,
+            });
+        }
+
+        const item = items.length === 1
+            ? items[0]
+            : await vscode.window.showQuickPick(items, { placeHolder: "Select an implementation" });
+        if (!item) return;
+
+        await vscode.window.showTextDocument(item.uri, { selection: item.range });
+    };
+}
+
 export function resolveCodeAction(ctx: Ctx): Cmd {
     const client = ctx.client;
     return async (params: lc.CodeAction) => {
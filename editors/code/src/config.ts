@@ -103,6 +103,10 @@ export class Config {
             typeHints: this.get<boolean>("inlayHints.typeHints"),
             parameterHints: this.get<boolean>("inlayHints.parameterHints"),
             chainingHints: this.get<boolean>("inlayHints.chainingHints"),
+            bindingModeHints: this.get<boolean>("inlayHints.bindingModeHints.enable"),
+            genericParameterHints: this.get<boolean>("inlayHints.genericParameterHints.enable"),
+            cfgConditionHints: this.get<boolean>("inlayHints.cfgConditionHints.enable"),
+            lifetimeHints: this.get<boolean>("inlayHints.lifetimeHints.enable"),
             hideNamedConstructorHints: this.get<boolean>("inlayHints.hideNamedConstructorHints"),
             smallerHints: this.get<boolean>("inlayHints.smallerHints"),
             maxLength: this.get<null | number>("inlayHints.maxLength"),
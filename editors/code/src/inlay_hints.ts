@@ -14,6 +14,10 @@ interface InlayHintsStyles {
     typeHints: InlayHintStyle;
     paramHints: InlayHintStyle;
     chainingHints: InlayHintStyle;
+    bindingModeHints: InlayHintStyle;
+    genericArgsHints: InlayHintStyle;
+    cfgHints: InlayHintStyle;
+    lifetimeHints: InlayHintStyle;
 }
 
 
@@ -23,7 +27,11 @@ export function activateInlayHints(ctx: Ctx) {
         async onConfigChange() {
             const anyEnabled = ctx.config.inlayHints.typeHints
                 || ctx.config.inlayHints.parameterHints
-                || ctx.config.inlayHints.chainingHints;
+                || ctx.config.inlayHints.chainingHints
+                || ctx.config.inlayHints.bindingModeHints
+                || ctx.config.inlayHints.genericParameterHints
+                || ctx.config.inlayHints.cfgConditionHints
+                || ctx.config.inlayHints.lifetimeHints;
             const enabled = ctx.config.inlayHints.enable && anyEnabled;
 
             if (!enabled) return this.dispose();
@@ -51,13 +59,17 @@ export function activateInlayHints(ctx: Ctx) {
     maybeUpdater.onConfigChange().catch(console.error);
 }
 
-function createHintStyle(hintKind: "type" | "parameter" | "chaining", smallerHints: boolean): InlayHintStyle {
+function createHintStyle(hintKind: "type" | "parameter" | "chaining" | "bindingMode" | "genericArgs" | "cfg" | "lifetime", smallerHints: boolean): InlayHintStyle {
     // U+200C is a zero-width non-joiner to prevent the editor from forming a ligature
     // between code and type hints
     const [pos, render] = ({
         type: ["after", (label: string) => `\u{200c}: ${label}`],
         parameter: ["before", (label: string) => `${label}: `],
         chaining: ["after", (label: string) => `\u{200c}: ${label}`],
+        bindingMode: ["before", (label: string) => `${label} `],
+        genericArgs: ["after", (label: string) => `\u{200c}${label}`],
+        cfg: ["after", (label: string) => ` ${label}`],
+        lifetime: ["after", (label: string) => `${label} `],
     } as const)[hintKind];
 
     const fg = new vscode.ThemeColor(`rust_analyzer.inlayHints.foreground.${hintKind}Hints`);
@@ -85,12 +97,20 @@ const smallHintsStyles = {
     typeHints: createHintStyle("type", true),
     paramHints: createHintStyle("parameter", true),
     chainingHints: createHintStyle("chaining", true),
+    bindingModeHints: createHintStyle("bindingMode", true),
+    genericArgsHints: createHintStyle("genericArgs", true),
+    cfgHints: createHintStyle("cfg", true),
+    lifetimeHints: createHintStyle("lifetime", true),
 };
 
 const biggerHintsStyles = {
     typeHints: createHintStyle("type", false),
     paramHints: createHintStyle("parameter", false),
     chainingHints: createHintStyle("chaining", false),
+    bindingModeHints: createHintStyle("bindingMode", false),
+    genericArgsHints: createHintStyle("genericArgs", false),
+    cfgHints: createHintStyle("cfg", false),
+    lifetimeHints: createHintStyle("lifetime", false),
 };
 
 class HintsUpdater implements Disposable {
@@ -128,7 +148,7 @@ class HintsUpdater implements Disposable {
 
     dispose() {
         this.sourceFiles.forEach(file => file.inlaysRequest?.cancel());
-        this.ctx.visibleRustEditors.forEach(editor => this.renderDecorations(editor, { param: [], type: [], chaining: [] }));
+        this.ctx.visibleRustEditors.forEach(editor => this.renderDecorations(editor, { param: [], type: [], chaining: [], bindingMode: [], genericArgs: [], cfg: [], lifetime: [] }));
         this.disposables.forEach(d => d.dispose());
     }
 
@@ -193,21 +213,29 @@ class HintsUpdater implements Disposable {
     }
 
     private renderDecorations(editor: RustEditor, decorations: InlaysDecorations) {
-        const { typeHints, paramHints, chainingHints } = this.inlayHintsStyles;
+        const { typeHints, paramHints, chainingHints, bindingModeHints, genericArgsHints, cfgHints, lifetimeHints } = this.inlayHintsStyles;
         if (this.pendingDisposeDecorations !== undefined) {
-            const { typeHints, paramHints, chainingHints } = this.pendingDisposeDecorations;
+            const { typeHints, paramHints, chainingHints, bindingModeHints, genericArgsHints, cfgHints, lifetimeHints } = this.pendingDisposeDecorations;
             editor.setDecorations(typeHints.decorationType, []);
             editor.setDecorations(paramHints.decorationType, []);
             editor.setDecorations(chainingHints.decorationType, []);
+            editor.setDecorations(bindingModeHints.decorationType, []);
+            editor.setDecorations(genericArgsHints.decorationType, []);
+            editor.setDecorations(cfgHints.decorationType, []);
+            editor.setDecorations(lifetimeHints.decorationType, []);
         }
         editor.setDecorations(typeHints.decorationType, decorations.type);
         editor.setDecorations(paramHints.decorationType, decorations.param);
         editor.setDecorations(chainingHints.decorationType, decorations.chaining);
+        editor.setDecorations(bindingModeHints.decorationType, decorations.bindingMode);
+        editor.setDecorations(genericArgsHints.decorationType, decorations.genericArgs);
+        editor.setDecorations(cfgHints.decorationType, decorations.cfg);
+        editor.setDecorations(lifetimeHints.decorationType, decorations.lifetime);
     }
 
     private hintsToDecorations(hints: ra.InlayHint[]): InlaysDecorations {
-        const { typeHints, paramHints, chainingHints } = this.inlayHintsStyles;
-        const decorations: InlaysDecorations = { type: [], param: [], chaining: [] };
+        const { typeHints, paramHints, chainingHints, bindingModeHints, genericArgsHints, cfgHints, lifetimeHints } = this.inlayHintsStyles;
+        const decorations: InlaysDecorations = { type: [], param: [], chaining: [], bindingMode: [], genericArgs: [], cfg: [], lifetime: [] };
         const conv = this.ctx.client.protocol2CodeConverter;
 
         for (const hint of hints) {
@@ -224,6 +252,22 @@ class HintsUpdater implements Disposable {
                     decorations.chaining.push(chainingHints.toDecoration(hint, conv));
                     continue;
                 }
+                case ra.InlayHint.Kind.BindingModeHint: {
+                    decorations.bindingMode.push(bindingModeHints.toDecoration(hint, conv));
+                    continue;
+                }
+                case ra.InlayHint.Kind.GenericArgsHint: {
+                    decorations.genericArgs.push(genericArgsHints.toDecoration(hint, conv));
+                    continue;
+                }
+                case ra.InlayHint.Kind.CfgHint: {
+                    decorations.cfg.push(cfgHints.toDecoration(hint, conv));
+                    continue;
+                }
+                case ra.InlayHint.Kind.LifetimeHint: {
+                    decorations.lifetime.push(lifetimeHints.toDecoration(hint, conv));
+                    continue;
+                }
             }
         }
         return decorations;
@@ -251,6 +295,10 @@ interface InlaysDecorations {
     type: vscode.DecorationOptions[];
     param: vscode.DecorationOptions[];
     chaining: vscode.DecorationOptions[];
+    bindingMode: vscode.DecorationOptions[];
+    genericArgs: vscode.DecorationOptions[];
+    cfg: vscode.DecorationOptions[];
+    lifetime: vscode.DecorationOptions[];
 }
 
 interface RustSourceFile {
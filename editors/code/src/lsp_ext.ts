@@ -20,6 +20,11 @@ export const serverStatus = new lc.NotificationType<ServerStatusParams>("experim
 
 export const reloadWorkspace = new lc.RequestType0<null, void>("rust-analyzer/reloadWorkspace");
 
+export interface CheckCurrentFileParams {
+    textDocument: lc.TextDocumentIdentifier;
+}
+export const checkCurrentFile = new lc.RequestType<CheckCurrentFileParams, null, void>("rust-analyzer/checkCurrentFile");
+
 export const hover = new lc.RequestType<HoverParams, lc.Hover | null, void>("textDocument/hover");
 
 export interface HoverParams extends lc.WorkDoneProgressParams {
@@ -33,6 +38,8 @@ export interface SyntaxTreeParams {
 }
 export const syntaxTree = new lc.RequestType<SyntaxTreeParams, string, void>("rust-analyzer/syntaxTree");
 
+export const showType = new lc.RequestType<lc.TextDocumentPositionParams, string | null, void>("rust-analyzer/showType");
+
 export const viewHir = new lc.RequestType<lc.TextDocumentPositionParams, string, void>("rust-analyzer/viewHir");
 
 export interface ViewItemTreeParams {
@@ -54,6 +61,11 @@ export interface ExpandMacroParams {
 export interface ExpandedMacro {
     name: string;
     expansion: string;
+    mappings: ExpandedMacroMapping[];
+}
+export interface ExpandedMacroMapping {
+    source: lc.Range;
+    expansion: lc.Range;
 }
 export const expandMacro = new lc.RequestType<ExpandMacroParams, ExpandedMacro | null, void>("rust-analyzer/expandMacro");
 
@@ -63,6 +75,12 @@ export interface MatchingBraceParams {
 }
 export const matchingBrace = new lc.RequestType<MatchingBraceParams, lc.Position[], void>("experimental/matchingBrace");
 
+export interface ParentExpressionParams {
+    textDocument: lc.TextDocumentIdentifier;
+    selections: lc.Range[];
+}
+export const parentExpression = new lc.RequestType<ParentExpressionParams, lc.Range[], void>("experimental/parentExpression");
+
 export const parentModule = new lc.RequestType<lc.TextDocumentPositionParams, lc.LocationLink[] | null, void>("experimental/parentModule");
 
 export interface JoinLinesParams {
@@ -99,13 +117,17 @@ export interface TestInfo {
 
 export const relatedTests = new lc.RequestType<lc.TextDocumentPositionParams, TestInfo[], void>("rust-analyzer/relatedTests");
 
-export type InlayHint = InlayHint.TypeHint | InlayHint.ParamHint | InlayHint.ChainingHint;
+export type InlayHint = InlayHint.TypeHint | InlayHint.ParamHint | InlayHint.ChainingHint | InlayHint.BindingModeHint | InlayHint.GenericArgsHint | InlayHint.CfgHint | InlayHint.LifetimeHint;
 
 export namespace InlayHint {
     export const enum Kind {
         TypeHint = "TypeHint",
         ParamHint = "ParameterHint",
         ChainingHint = "ChainingHint",
+        BindingModeHint = "BindingModeHint",
+        GenericArgsHint = "GenericArgsHint",
+        CfgHint = "CfgHint",
+        LifetimeHint = "LifetimeHint",
     }
     interface Common {
         range: lc.Range;
@@ -114,6 +136,10 @@ export namespace InlayHint {
     export type TypeHint = Common & { kind: Kind.TypeHint };
     export type ParamHint = Common & { kind: Kind.ParamHint };
     export type ChainingHint = Common & { kind: Kind.ChainingHint };
+    export type BindingModeHint = Common & { kind: Kind.BindingModeHint };
+    export type GenericArgsHint = Common & { kind: Kind.GenericArgsHint };
+    export type CfgHint = Common & { kind: Kind.CfgHint };
+    export type LifetimeHint = Common & { kind: Kind.LifetimeHint };
 }
 export interface InlayHintsParams {
     textDocument: lc.TextDocumentIdentifier;
@@ -143,6 +169,9 @@ export interface CommandLinkGroup {
 
 export const openDocs = new lc.RequestType<lc.TextDocumentPositionParams, string | void, void>('experimental/externalDocs');
 
+export type Documentation = { url: string } | { markdown: string };
+export const showDocumentation = new lc.RequestType<lc.TextDocumentPositionParams, Documentation | null, void>('experimental/showDocumentation');
+
 export const openCargoToml = new lc.RequestType<OpenCargoTomlParams, lc.Location, void>("experimental/openCargoToml");
 
 export interface OpenCargoTomlParams {
@@ -161,3 +190,14 @@ export const enum Direction {
     Up = "Up",
     Down = "Down"
 }
+
+export const moveItemToModule = new lc.RequestType<MoveItemToModuleParams, MoveItemToModuleResult | null, void>("experimental/moveItemToModule");
+
+export interface MoveItemToModuleParams extends lc.TextDocumentPositionParams {
+    target: lc.TextDocumentIdentifier;
+}
+
+export interface MoveItemToModuleResult {
+    edit: lc.WorkspaceEdit;
+    warnings: string[];
+}
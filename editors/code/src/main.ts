@@ -109,7 +109,10 @@ async function initCommonContext(context: vscode.ExtensionContext, ctx: Ctx) {
     ctx.registerCommand('memoryUsage', commands.memoryUsage);
     ctx.registerCommand('shuffleCrateGraph', commands.shuffleCrateGraph);
     ctx.registerCommand('reloadWorkspace', commands.reloadWorkspace);
+    ctx.registerCommand('checkCurrentFile', commands.checkCurrentFile);
     ctx.registerCommand('matchingBrace', commands.matchingBrace);
+    ctx.registerCommand('parentExpression', commands.parentExpression);
+    ctx.registerCommand('showType', commands.showType);
     ctx.registerCommand('joinLines', commands.joinLines);
     ctx.registerCommand('parentModule', commands.parentModule);
     ctx.registerCommand('syntaxTree', commands.syntaxTree);
@@ -123,10 +126,13 @@ async function initCommonContext(context: vscode.ExtensionContext, ctx: Ctx) {
     ctx.registerCommand('debug', commands.debug);
     ctx.registerCommand('newDebugConfig', commands.newDebugConfig);
     ctx.registerCommand('openDocs', commands.openDocs);
+    ctx.registerCommand('showDocumentation', commands.showDocumentation);
+    ctx.registerCommand('navigateToImplementations', commands.navigateToImplementations);
     ctx.registerCommand('openCargoToml', commands.openCargoToml);
     ctx.registerCommand('peekTests', commands.peekTests);
     ctx.registerCommand('moveItemUp', commands.moveItemUp);
     ctx.registerCommand('moveItemDown', commands.moveItemDown);
+    ctx.registerCommand('moveItemToModule', commands.moveItemToModule);
 
     defaultOnEnter.dispose();
     ctx.registerCommand('onEnter', commands.onEnter);